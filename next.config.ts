@@ -1,4 +1,5 @@
 import { NextConfig } from "next";
+import { SERVER_ACTION_BODY_SIZE_LIMIT } from "./src/app/admin/lib/validations/upload";
 
 const nextConfig: NextConfig = {
   typescript: {
@@ -19,7 +20,11 @@ const nextConfig: NextConfig = {
   poweredByHeader: false,
   experimental: {
     scrollRestoration: false,
-
+    serverActions: {
+      // Attachment and communication import uploads go through server actions;
+      // the file validators keep each upload under this limit
+      bodySizeLimit: SERVER_ACTION_BODY_SIZE_LIMIT,
+    },
   },
   skipTrailingSlashRedirect: true,
   async headers() {
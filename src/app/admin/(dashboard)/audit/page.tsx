@@ -0,0 +1,63 @@
+/**
+ * Audit Log Page
+ *
+ * Who created, changed or deleted what, across every audited table.
+ * `table` and `record` open one record's history, `admin` one admin's
+ * activity and `action` limits the log to creates, updates or deletes.
+ */
+
+import { AuditLogView } from '@/components/audit/audit-log-view';
+import { AUDIT_ACTIONS, AUDIT_TABLES } from '@/lib/validations/audit';
+import type { AuditTable } from '@/lib/types/audit';
+import type { AuditAction } from '@/lib/types/database';
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+export const metadata = {
+  title: 'Audit Log | Pure Life Pools CRM',
+  description: 'Changes made to customers, estimates, events and communications',
+};
+
+// ============================================================================
+// Page Component
+// ============================================================================
+
+export default async function AuditLogPage({
+  searchParams,
+}: {
+  searchParams: Promise<{ table?: string; record?: string; action?: string; admin?: string }>;
+}) {
+  const params = await searchParams;
+
+  const tableName = AUDIT_TABLES.find((table) => table === params.table) as
+    | AuditTable
+    | undefined;
+  const action = AUDIT_ACTIONS.find((value) => value === params.action) as
+    | AuditAction
+    | undefined;
+
+  return (
+    <div className="space-y-6">
+      {/* Page Header */}
+      <div>
+        <h1 className="text-2xl font-semibold text-zinc-900">Audit Log</h1>
+        <p className="text-sm text-zinc-500 mt-1">
+          Every create, update and delete, with who made it and what changed
+        </p>
+      </div>
+
+      <AuditLogView
+        initialFilters={{
+          tableName,
+          recordId: params.record,
+          action,
+          changedBy: params.admin,
+        }}
+      />
+    </div>
+  );
+}
+
+export const dynamic = 'force-dynamic';
@@ -0,0 +1,82 @@
+/**
+ * Communication Import Page
+ *
+ * Upload exported email (.eml/.mbox), text message history (CSV/JSON) and
+ * phone system call records (CSV) into the communication log, and review
+ * messages that could not be matched to a customer.
+ */
+
+import {
+  importEmailFiles,
+  importSmsFiles,
+  importCdrFiles,
+  listImportQueue,
+} from '@/app/actions/imports';
+import { ImportUploadCard } from '@/components/communications/import-upload-card';
+import { ImportReviewQueue } from '@/components/communications/import-review-queue';
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+export const metadata = {
+  title: 'Import Communications | Pure Life Pools CRM',
+  description: 'Import customer email, text messages and calls and review unmatched messages',
+};
+
+// ============================================================================
+// Page Component
+// ============================================================================
+
+export default async function CommunicationImportPage() {
+  const queue = await listImportQueue({ status: 'pending', limit: 50 });
+
+  return (
+    <div className="space-y-6">
+      {/* Page Header */}
+      <div>
+        <h1 className="text-2xl font-semibold text-zinc-900">Import Communications</h1>
+        <p className="text-sm text-zinc-500 mt-1">
+          Bring past customer conversations into the communication log
+        </p>
+      </div>
+
+      <ImportUploadCard
+        title="Email"
+        description="Upload .eml files or an .mbox export. Messages are matched to customers by email address; re-importing the same file skips messages already logged."
+        accept=".eml,.mbox"
+        action={importEmailFiles}
+      />
+
+      <ImportUploadCard
+        title="Text Messages"
+        description="Upload a CSV or JSON export from the business phone line. Numbers are matched to customers by phone; use Preview to see matched, unmatched and duplicate rows before saving."
+        accept=".csv,.json"
+        action={importSmsFiles}
+        supportsDryRun
+      />
+
+      <ImportUploadCard
+        title="Phone Calls"
+        description="Upload a call-detail record (CDR) CSV from the phone system with caller, callee, start, duration and disposition columns. Missed calls from customers are flagged for a callback."
+        accept=".csv"
+        action={importCdrFiles}
+        supportsDryRun
+      />
+
+      {/* Review Queue */}
+      <div className="space-y-3">
+        <h2 className="text-lg font-semibold text-zinc-900">Needs Review</h2>
+        {queue.success ? (
+          <ImportReviewQueue items={queue.data.items} total={queue.data.total} />
+        ) : (
+          <div className="p-6 bg-red-50 border border-red-200 rounded-lg text-red-700">
+            <p className="text-sm">Failed to load review queue: {queue.error}</p>
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
+
+export const dynamic = 'force-dynamic';
@@ -0,0 +1,48 @@
+/**
+ * Needs Response Page
+ *
+ * Customers whose inbound calls, texts and emails have not been answered,
+ * oldest first, with the response SLA deadline for each.
+ */
+
+import { getNeedsResponseQueue } from '@/app/actions/communications';
+import { NeedsResponseQueue } from '@/components/communications/needs-response-queue';
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+export const metadata = {
+  title: 'Needs Response | Pure Life Pools CRM',
+  description: 'Customers waiting on a reply',
+};
+
+// ============================================================================
+// Page Component
+// ============================================================================
+
+export default async function NeedsResponsePage() {
+  const queue = await getNeedsResponseQueue();
+
+  return (
+    <div className="space-y-6">
+      {/* Page Header */}
+      <div>
+        <h1 className="text-2xl font-semibold text-zinc-900">Needs Response</h1>
+        <p className="text-sm text-zinc-500 mt-1">
+          Customers who contacted us and have not heard back yet
+        </p>
+      </div>
+
+      {queue.success ? (
+        <NeedsResponseQueue queue={queue.data} />
+      ) : (
+        <div className="p-6 bg-red-50 border border-red-200 rounded-lg text-red-700">
+          <p className="text-sm">Failed to load queue: {queue.error}</p>
+        </div>
+      )}
+    </div>
+  );
+}
+
+export const dynamic = 'force-dynamic';
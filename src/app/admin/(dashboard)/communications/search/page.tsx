@@ -0,0 +1,46 @@
+/**
+ * Communication Search Page
+ *
+ * Full-text search across every customer's communications with the shared
+ * filters and saved views. `q` pre-fills the query and `view` opens a
+ * saved view.
+ */
+
+import { CommunicationSearch } from '@/components/communications/communication-search';
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+export const metadata = {
+  title: 'Search Communications | Pure Life Pools CRM',
+  description: 'Search calls, texts and emails across customers',
+};
+
+// ============================================================================
+// Page Component
+// ============================================================================
+
+export default async function CommunicationSearchPage({
+  searchParams,
+}: {
+  searchParams: Promise<{ q?: string; view?: string }>;
+}) {
+  const params = await searchParams;
+
+  return (
+    <div className="space-y-6">
+      {/* Page Header */}
+      <div>
+        <h1 className="text-2xl font-semibold text-zinc-900">Search Communications</h1>
+        <p className="text-sm text-zinc-500 mt-1">
+          Find calls, texts, emails and notes across all customers
+        </p>
+      </div>
+
+      <CommunicationSearch initialQuery={params.q ?? ''} viewId={params.view ?? null} />
+    </div>
+  );
+}
+
+export const dynamic = 'force-dynamic';
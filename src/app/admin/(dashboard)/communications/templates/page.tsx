@@ -0,0 +1,48 @@
+/**
+ * Message Templates Page
+ *
+ * Manage reusable email and text message templates with merge fields,
+ * and preview them against a customer.
+ */
+
+import { listTemplates } from '@/app/actions/templates';
+import { TemplateManager } from '@/components/communications/template-manager';
+
+// ============================================================================
+// Metadata
+// ============================================================================
+
+export const metadata = {
+  title: 'Message Templates | Pure Life Pools CRM',
+  description: 'Reusable email and text message templates',
+};
+
+// ============================================================================
+// Page Component
+// ============================================================================
+
+export default async function MessageTemplatesPage() {
+  const templates = await listTemplates();
+
+  return (
+    <div className="space-y-6">
+      {/* Page Header */}
+      <div>
+        <h1 className="text-2xl font-semibold text-zinc-900">Message Templates</h1>
+        <p className="text-sm text-zinc-500 mt-1">
+          Reusable emails and text messages, filled in with customer details when sent
+        </p>
+      </div>
+
+      {templates.success ? (
+        <TemplateManager templates={templates.data} />
+      ) : (
+        <div className="p-6 bg-red-50 border border-red-200 rounded-lg text-red-700">
+          <p className="text-sm">Failed to load templates: {templates.error}</p>
+        </div>
+      )}
+    </div>
+  );
+}
+
+export const dynamic = 'force-dynamic';
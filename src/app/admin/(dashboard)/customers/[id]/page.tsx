@@ -1,6 +1,7 @@
 import { Suspense } from 'react';
 import { notFound } from 'next/navigation';
 import { getCustomer } from '@/app/actions/customers';
+import { getCommunicationStats } from '@/app/actions/communications';
 import { CustomerDetailHeader } from '@/components/customers/customer-detail-header';
 import { CustomerDetailTabs } from '@/components/customers/customer-detail-tabs';
 import { CustomerDetailSkeleton } from '@/components/customers/customer-detail-skeleton';
@@ -38,7 +39,10 @@ export default async function CustomerDetailPage({
  * Separated to work with Suspense boundary.
  */
 async function CustomerDetailContent({ customerId }: { customerId: string }) {
-  const result = await getCustomer(customerId);
+  const [result, stats] = await Promise.all([
+    getCustomer(customerId),
+    getCommunicationStats(customerId),
+  ]);
 
   console.log("Customer fetch result:", result);
 
@@ -51,7 +55,10 @@ async function CustomerDetailContent({ customerId }: { customerId: string }) {
   return (
     <div className="space-y-6">
       {/* Breadcrumb + Header */}
-      <CustomerDetailHeader customer={customer} />
+      <CustomerDetailHeader
+        customer={customer}
+        lastContactAt={stats.success ? stats.data.lastContactAt : undefined}
+      />
 
       {/* Tabbed Content */}
       <CustomerDetailTabs customer={customer} />
@@ -1,9 +1,12 @@
 import { Metadata } from 'next';
 import Link from 'next/link';
-import { Users, Calendar, FileText, ArrowRight } from 'lucide-react';
+import { Users, Calendar, FileText, ArrowRight, MessageCircleReply } from 'lucide-react';
 
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
 import { Button } from '@/components/ui/button';
+import { getNeedsResponseCount } from '@/app/actions/communications';
+import { getCommunicationAnalytics } from '@/app/actions/analytics';
+import { CommunicationAnalyticsPanel } from '@/components/communications/communication-analytics-panel';
 
 export const metadata: Metadata = {
   title: 'Dashboard',
@@ -14,9 +17,15 @@ export const metadata: Metadata = {
  * Dashboard Home Page
  *
  * Overview page with quick stats and actions.
- * For now, displays navigation cards to main sections.
+ * Shows customers waiting on a reply, communication analytics and
+ * navigation cards to main sections.
  */
-export default function DashboardPage() {
+export default async function DashboardPage() {
+  const [needsResponse, analytics] = await Promise.all([
+    getNeedsResponseCount(),
+    getCommunicationAnalytics(),
+  ]);
+
   return (
     <div className="space-y-6">
       {/* Page Header */}
@@ -27,6 +36,15 @@ export default function DashboardPage() {
         </p>
       </div>
 
+      {/* Needs Response */}
+      {needsResponse.success && (
+        <NeedsResponseCard
+          total={needsResponse.data.total}
+          overdue={needsResponse.data.overdue}
+          slaHours={needsResponse.data.slaHours}
+        />
+      )}
+
       {/* Quick Actions */}
       <div className="grid grid-cols-1 md:grid-cols-3 gap-4">
         <QuickActionCard
@@ -52,6 +70,11 @@ export default function DashboardPage() {
         />
       </div>
 
+      {/* Communication Analytics */}
+      <CommunicationAnalyticsPanel
+        initialAnalytics={analytics.success ? analytics.data : null}
+      />
+
       {/* Recent Activity - placeholder */}
       <Card>
         <CardHeader>
@@ -67,6 +90,54 @@ export default function DashboardPage() {
   );
 }
 
+interface NeedsResponseCardProps {
+  total: number;
+  overdue: number;
+  slaHours: number;
+}
+
+function NeedsResponseCard({ total, overdue, slaHours }: NeedsResponseCardProps) {
+  return (
+    <Card className={overdue > 0 ? 'border-red-200' : undefined}>
+      <CardContent className="p-4">
+        <div className="flex items-center gap-4">
+          <div
+            className={
+              overdue > 0
+                ? 'w-10 h-10 rounded-lg bg-red-50 flex items-center justify-center shrink-0'
+                : 'w-10 h-10 rounded-lg bg-zinc-100 flex items-center justify-center shrink-0'
+            }
+          >
+            <MessageCircleReply
+              className={overdue > 0 ? 'w-5 h-5 text-red-600' : 'w-5 h-5 text-zinc-600'}
+            />
+          </div>
+          <div className="flex-1 min-w-0">
+            <h3 className="text-sm font-semibold text-zinc-900">
+              {total === 0
+                ? 'No customers waiting on a reply'
+                : `${total} ${total === 1 ? 'customer' : 'customers'} waiting on a reply`}
+            </h3>
+            <p className="text-sm text-zinc-500 mt-0.5">
+              {overdue > 0
+                ? `${overdue} past the ${slaHours} business hour response target`
+                : `All within the ${slaHours} business hour response target`}
+            </p>
+          </div>
+          {total > 0 && (
+            <Button variant="link" asChild className="px-0 h-auto">
+              <Link href="/admin/communications/needs-response" className="text-sm">
+                View Queue
+                <ArrowRight className="w-4 h-4 ml-1" />
+              </Link>
+            </Button>
+          )}
+        </div>
+      </CardContent>
+    </Card>
+  );
+}
+
 interface QuickActionCardProps {
   href: string;
   icon: React.ElementType;
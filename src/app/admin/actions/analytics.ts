@@ -0,0 +1,82 @@
+'use server';
+
+/**
+ * Analytics Server Actions
+ *
+ * @file src/app/actions/analytics.ts
+ *
+ * Server actions for communication response-time and workload reporting.
+ */
+
+import { createClient } from '@/lib/supabase/server';
+import { AnalyticsService } from '@/lib/services/analytics.service';
+import {
+  communicationAnalyticsSchema,
+  type CommunicationAnalyticsInput,
+} from '@/lib/validations/analytics';
+import type { ActionResult } from '@/lib/types/api';
+import type { CommunicationAnalytics } from '@/lib/types/analytics';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+// =============================================================================
+// Communication Analytics
+// =============================================================================
+
+/**
+ * Response times, volume and busiest hours over a date range,
+ * team-wide and per admin
+ */
+export async function getCommunicationAnalytics(
+  input: Partial<CommunicationAnalyticsInput> = {}
+): Promise<ActionResult<CommunicationAnalytics>> {
+  try {
+    const validated = communicationAnalyticsSchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new AnalyticsService(supabase);
+    const analytics = await service.getCommunicationAnalytics(validated);
+
+    return { success: true, data: analytics };
+  } catch (error) {
+    console.error('Failed to get communication analytics:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to get communication analytics' };
+  }
+}
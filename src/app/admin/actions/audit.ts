@@ -0,0 +1,161 @@
+'use server';
+
+/**
+ * Audit Server Actions
+ *
+ * @file src/app/actions/audit.ts
+ *
+ * Server actions for reading the audit log: per-record history, per-admin
+ * activity and the filterable log.
+ */
+
+import { createClient } from '@/lib/supabase/server';
+import { AuditService } from '@/lib/services/audit.service';
+import {
+  adminAuditActivitySchema,
+  listAuditLogSchema,
+  recordAuditHistorySchema,
+  type AdminAuditActivityInput,
+  type ListAuditLogInput,
+  type RecordAuditHistoryInput,
+} from '@/lib/validations/audit';
+import type { ActionResult } from '@/lib/types/api';
+import type { AuditFilterOptions, AuditLogListResult } from '@/lib/types/audit';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+// =============================================================================
+// Audit Log
+// =============================================================================
+
+/**
+ * Audit log across every audited table, newest first, with optional
+ * table, record, action, admin and date filters
+ */
+export async function listAuditLog(
+  input: Partial<ListAuditLogInput> = {}
+): Promise<ActionResult<AuditLogListResult>> {
+  try {
+    const validated = listAuditLogSchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new AuditService(supabase);
+    const result = await service.list(validated);
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to list audit log:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load audit log' };
+  }
+}
+
+/**
+ * Every change to one record, newest first
+ */
+export async function getRecordAuditHistory(
+  input: RecordAuditHistoryInput
+): Promise<ActionResult<AuditLogListResult>> {
+  try {
+    const validated = recordAuditHistorySchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new AuditService(supabase);
+    const result = await service.listByRecord(validated);
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to get record audit history:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load history' };
+  }
+}
+
+/**
+ * Every change one admin made, newest first
+ */
+export async function getAdminAuditActivity(
+  input: AdminAuditActivityInput
+): Promise<ActionResult<AuditLogListResult>> {
+  try {
+    const validated = adminAuditActivitySchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new AuditService(supabase);
+    const result = await service.listByAdmin(validated);
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to get admin audit activity:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load activity' };
+  }
+}
+
+/**
+ * Admins for the audit log filter bar
+ */
+export async function getAuditFilterOptions(): Promise<ActionResult<AuditFilterOptions>> {
+  try {
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new AuditService(supabase);
+    const options = await service.getFilterOptions();
+
+    return { success: true, data: options };
+  } catch (error) {
+    console.error('Failed to get audit filter options:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load filter options' };
+  }
+}
@@ -0,0 +1,228 @@
+'use server';
+
+/**
+ * Communication View Server Actions
+ *
+ * @file src/app/actions/communication-views.ts
+ *
+ * Server actions for saved communication filter sets and filter options.
+ */
+
+import { createClient } from '@/lib/supabase/server';
+import { CommunicationViewService } from '@/lib/services/communication-view.service';
+import {
+  saveCommunicationViewSchema,
+  updateCommunicationViewSchema,
+  type SaveCommunicationViewInput,
+  type UpdateCommunicationViewInput,
+} from '@/lib/validations/communication';
+import type { ActionResult } from '@/lib/types/api';
+import type {
+  CommunicationFilterOptions,
+  SavedCommunicationView,
+} from '@/lib/types/communication';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+// =============================================================================
+// List Views
+// =============================================================================
+
+/**
+ * Shared views plus the current admin's own
+ */
+export async function listCommunicationViews(): Promise<
+  ActionResult<SavedCommunicationView[]>
+> {
+  try {
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationViewService(supabase);
+    const views = await service.list(admin.id);
+
+    return { success: true, data: views };
+  } catch (error) {
+    console.error('Failed to list saved views:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to list saved views' };
+  }
+}
+
+// =============================================================================
+// Get View
+// =============================================================================
+
+/**
+ * Open a saved view by ID, e.g. from a shared link
+ */
+export async function getCommunicationView(
+  id: string
+): Promise<ActionResult<SavedCommunicationView>> {
+  try {
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationViewService(supabase);
+    const view = await service.getById(id, admin.id);
+
+    return { success: true, data: view };
+  } catch (error) {
+    console.error('Failed to get saved view:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to get saved view' };
+  }
+}
+
+// =============================================================================
+// Save View
+// =============================================================================
+
+/**
+ * Save a filter set under a name
+ */
+export async function saveCommunicationView(
+  input: SaveCommunicationViewInput
+): Promise<ActionResult<SavedCommunicationView>> {
+  try {
+    const validated = saveCommunicationViewSchema.parse(input);
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationViewService(supabase);
+    const view = await service.create(validated, admin.id);
+
+    return { success: true, data: view };
+  } catch (error) {
+    console.error('Failed to save view:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to save view' };
+  }
+}
+
+// =============================================================================
+// Update View
+// =============================================================================
+
+/**
+ * Rename, re-share or replace the filters of one of the admin's views
+ */
+export async function updateCommunicationView(
+  input: UpdateCommunicationViewInput
+): Promise<ActionResult<SavedCommunicationView>> {
+  try {
+    const validated = updateCommunicationViewSchema.parse(input);
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationViewService(supabase);
+    const view = await service.update(validated, admin.id);
+
+    return { success: true, data: view };
+  } catch (error) {
+    console.error('Failed to update view:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to update view' };
+  }
+}
+
+// =============================================================================
+// Delete View
+// =============================================================================
+
+/**
+ * Delete one of the admin's views
+ */
+export async function deleteCommunicationView(
+  id: string
+): Promise<ActionResult<{ deleted: boolean }>> {
+  try {
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationViewService(supabase);
+    await service.delete(id, admin.id);
+
+    return { success: true, data: { deleted: true } };
+  } catch (error) {
+    console.error('Failed to delete view:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to delete view' };
+  }
+}
+
+// =============================================================================
+// Filter Options
+// =============================================================================
+
+/**
+ * Admins and customer tags offered by the filter bar
+ */
+export async function getCommunicationFilterOptions(): Promise<
+  ActionResult<CommunicationFilterOptions>
+> {
+  try {
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationViewService(supabase);
+    const options = await service.getFilterOptions();
+
+    return { success: true, data: options };
+  } catch (error) {
+    console.error('Failed to load filter options:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load filter options' };
+  }
+}
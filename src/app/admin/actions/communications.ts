@@ -12,22 +12,53 @@
 import { revalidatePath } from 'next/cache';
 import { createClient } from '@/lib/supabase/server';
 import { CommunicationService } from '@/lib/services/communication.service';
+import { AttachmentService } from '@/lib/services/attachment.service';
+import { ResponseQueueService } from '@/lib/services/response-queue.service';
+import { CommunicationRevisionService } from '@/lib/services/communication-revision.service';
+import { attachmentIdSchema, validateAttachmentFiles } from '@/lib/validations/attachment';
+import { ConflictError } from '@/lib/utils/errors';
 import {
   createCommunicationSchema,
   updateCommunicationSchema,
   deleteCommunicationSchema,
   listCommunicationsSchema,
   searchCommunicationsSchema,
+  updateThreadStatusSchema,
+  communicationIdSchema,
+  communicationStatsSchema,
+  needsResponseQueueSchema,
+  snoozeNeedsResponseSchema,
+  dismissNeedsResponseSchema,
+  restoreCommunicationRevisionSchema,
+  listCommunicationTrashSchema,
+  listLinkedCommunicationsSchema,
+  communicationLinkOptionsSchema,
   type CreateCommunicationInput,
   type UpdateCommunicationInput,
   type ListCommunicationsInput,
   type SearchCommunicationsInput,
+  type UpdateThreadStatusInput,
+  type NeedsResponseQueueInput,
+  type SnoozeNeedsResponseInput,
+  type DismissNeedsResponseInput,
+  type RestoreCommunicationRevisionInput,
+  type ListCommunicationTrashInput,
+  type ListLinkedCommunicationsInput,
 } from '@/lib/validations/communication';
 import type { ActionResult } from '@/lib/types/api';
+import type { CommunicationThreadRow } from '@/lib/types/database';
 import type {
   CommunicationWithLogger,
-  CommunicationListResult,
+  CommunicationAttachment,
+  CommunicationLinkOptions,
+  CommunicationThread,
+  CommunicationThreadListResult,
   CommunicationSearchResult,
+  CommunicationStats,
+  CommunicationRevisionEntry,
+  CommunicationTrashListResult,
+  NeedsResponseQueue,
+  UpdateCommunicationResult,
 } from '@/lib/types/communication';
 
 // =============================================================================
@@ -86,6 +117,12 @@ export async function createCommunication(
 
     // Revalidate customer detail page
     revalidatePath(`/admin/customers/${validated.customerId}`);
+    if (validated.followUp) {
+      revalidatePath('/admin/calendar');
+    }
+    if (validated.estimateId) {
+      revalidatePath(`/admin/estimates/${validated.estimateId}`);
+    }
 
     return { success: true, data: communication };
   } catch (error) {
@@ -132,11 +169,11 @@ export async function getCommunication(
 // =============================================================================
 
 /**
- * List communications for a customer with filters and pagination
+ * List conversation threads for a customer with filters and pagination
  */
 export async function listCommunications(
   input: ListCommunicationsInput
-): Promise<ActionResult<CommunicationListResult>> {
+): Promise<ActionResult<CommunicationThreadListResult>> {
   try {
     // Validate input
     const validated = listCommunicationsSchema.parse(input);
@@ -160,6 +197,155 @@ export async function listCommunications(
   }
 }
 
+/**
+ * Communications about an estimate, calendar event, property or pool
+ */
+export async function listLinkedCommunications(
+  input: Partial<ListLinkedCommunicationsInput>
+): Promise<ActionResult<CommunicationWithLogger[]>> {
+  try {
+    // Validate input
+    const validated = listLinkedCommunicationsSchema.parse(input);
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationService(supabase);
+    const communications = await service.listLinked(validated);
+
+    return { success: true, data: communications };
+  } catch (error) {
+    console.error('Failed to list linked communications:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load related communications' };
+  }
+}
+
+/**
+ * A customer's estimates, calendar events, properties and pools that a
+ * communication can be linked to
+ */
+export async function getCommunicationLinkOptions(
+  customerId: string
+): Promise<ActionResult<CommunicationLinkOptions>> {
+  try {
+    // Validate input
+    const validated = communicationLinkOptionsSchema.parse({ customerId });
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationService(supabase);
+    const options = await service.getLinkOptions(validated.customerId);
+
+    return { success: true, data: options };
+  } catch (error) {
+    console.error('Failed to get communication link options:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load link options' };
+  }
+}
+
+// =============================================================================
+// Threads
+// =============================================================================
+
+/**
+ * Get a single conversation thread with its communications
+ */
+export async function getCommunicationThread(
+  threadId: string
+): Promise<ActionResult<CommunicationThread | null>> {
+  try {
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationService(supabase);
+    const thread = await service.getThread(threadId);
+
+    return { success: true, data: thread };
+  } catch (error) {
+    console.error('Failed to get communication thread:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to get communication thread' };
+  }
+}
+
+/**
+ * Manually mark a thread as open or answered
+ */
+export async function updateThreadStatus(
+  input: UpdateThreadStatusInput
+): Promise<ActionResult<CommunicationThreadRow>> {
+  try {
+    // Validate input
+    const validated = updateThreadStatusSchema.parse(input);
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    // Update thread
+    const service = new CommunicationService(supabase);
+    const thread = await service.updateThreadStatus(validated.threadId, validated.status);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${thread.customer_id}`);
+
+    return { success: true, data: thread };
+  } catch (error) {
+    console.error('Failed to update thread status:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to update thread status' };
+  }
+}
+
+/**
+ * Mark a missed call as called back
+ */
+export async function completeCallback(
+  id: string
+): Promise<ActionResult<CommunicationWithLogger>> {
+  try {
+    // Validate input
+    const validated = communicationIdSchema.parse({ id });
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    // Clear the flag
+    const service = new CommunicationService(supabase);
+    const communication = await service.completeCallback(validated.id);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${communication.customer_id}`);
+
+    return { success: true, data: communication };
+  } catch (error) {
+    console.error('Failed to complete callback:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to complete callback' };
+  }
+}
+
 // =============================================================================
 // Search Communications
 // =============================================================================
@@ -199,10 +385,13 @@ export async function searchCommunications(
 
 /**
  * Update an existing communication
+ *
+ * Fails with code CONFLICT and the current copy when the communication has
+ * changed since `input.version`.
  */
 export async function updateCommunication(
   input: UpdateCommunicationInput
-): Promise<ActionResult<CommunicationWithLogger>> {
+): Promise<UpdateCommunicationResult> {
   try {
     // Validate input
     const validated = updateCommunicationSchema.parse(input);
@@ -221,6 +410,15 @@ export async function updateCommunication(
   } catch (error) {
     console.error('Failed to update communication:', error);
 
+    if (error instanceof ConflictError) {
+      return {
+        success: false,
+        error: error.message,
+        code: 'CONFLICT',
+        current: error.details?.current as CommunicationWithLogger,
+      };
+    }
+
     if (error instanceof Error) {
       return { success: false, error: error.message };
     }
@@ -229,12 +427,74 @@ export async function updateCommunication(
   }
 }
 
+// =============================================================================
+// Revisions
+// =============================================================================
+
+/**
+ * List a communication's edit history, newest first
+ */
+export async function listCommunicationRevisions(
+  communicationId: string
+): Promise<ActionResult<CommunicationRevisionEntry[]>> {
+  try {
+    const { id } = communicationIdSchema.parse({ id: communicationId });
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationRevisionService(supabase);
+    const revisions = await service.listByCommunication(id);
+
+    return { success: true, data: revisions };
+  } catch (error) {
+    console.error('Failed to list communication revisions:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to list revisions' };
+  }
+}
+
+/**
+ * Restore the values a revision replaced
+ */
+export async function restoreCommunicationRevision(
+  input: RestoreCommunicationRevisionInput
+): Promise<ActionResult<CommunicationWithLogger>> {
+  try {
+    // Validate input
+    const validated = restoreCommunicationRevisionSchema.parse(input);
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationRevisionService(supabase);
+    const communication = await service.restore(validated.revisionId);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${communication.customer_id}`);
+
+    return { success: true, data: communication };
+  } catch (error) {
+    console.error('Failed to restore communication revision:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to restore revision' };
+  }
+}
+
 // =============================================================================
 // Delete Communication
 // =============================================================================
 
 /**
- * Delete a communication (hard delete)
+ * Move a communication to the trash
  */
 export async function deleteCommunication(
   id: string,
@@ -245,11 +505,11 @@ export async function deleteCommunication(
     const validated = deleteCommunicationSchema.parse({ id });
 
     // Get authenticated admin
-    const { supabase } = await getCurrentAdmin();
+    const { supabase, admin } = await getCurrentAdmin();
 
     // Delete communication
     const service = new CommunicationService(supabase);
-    await service.delete(validated.id);
+    await service.delete(validated.id, admin.id);
 
     // Revalidate customer detail page
     revalidatePath(`/admin/customers/${customerId}`);
@@ -266,6 +526,208 @@ export async function deleteCommunication(
   }
 }
 
+// =============================================================================
+// Trash
+// =============================================================================
+
+/**
+ * List trashed communications, for one customer or all
+ */
+export async function listTrashedCommunications(
+  input: ListCommunicationTrashInput
+): Promise<ActionResult<CommunicationTrashListResult>> {
+  try {
+    // Validate input
+    const validated = listCommunicationTrashSchema.parse(input);
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationService(supabase);
+    const result = await service.listTrash(validated);
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to list trashed communications:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load trash' };
+  }
+}
+
+/**
+ * Restore a communication from the trash
+ */
+export async function restoreCommunication(
+  id: string,
+  customerId: string
+): Promise<ActionResult<CommunicationWithLogger>> {
+  try {
+    // Validate input
+    const validated = deleteCommunicationSchema.parse({ id });
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationService(supabase);
+    const communication = await service.restore(validated.id);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${customerId}`);
+
+    return { success: true, data: communication };
+  } catch (error) {
+    console.error('Failed to restore communication:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to restore communication' };
+  }
+}
+
+/**
+ * Permanently delete a trashed communication and its attachments
+ */
+export async function deleteCommunicationPermanently(
+  id: string,
+  customerId: string
+): Promise<ActionResult<boolean>> {
+  try {
+    // Validate input
+    const validated = deleteCommunicationSchema.parse({ id });
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationService(supabase);
+    await service.deletePermanently(validated.id);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${customerId}`);
+
+    return { success: true, data: true };
+  } catch (error) {
+    console.error('Failed to permanently delete communication:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to delete communication' };
+  }
+}
+
+// =============================================================================
+// Attachments
+// =============================================================================
+
+/**
+ * Attach uploaded files to a communication
+ *
+ * Expects FormData with `communicationId`, `customerId` and one or more
+ * `files` entries (JPEG, PNG, WebP or PDF, 3 MB together).
+ */
+export async function uploadCommunicationAttachments(
+  formData: FormData
+): Promise<ActionResult<CommunicationAttachment[]>> {
+  try {
+    const { id: communicationId } = communicationIdSchema.parse({
+      id: formData.get('communicationId'),
+    });
+    const customerId = String(formData.get('customerId') ?? '');
+    const files = formData
+      .getAll('files')
+      .filter((entry): entry is File => entry instanceof File && entry.size > 0);
+
+    const fileError = validateAttachmentFiles(files);
+    if (fileError) {
+      return { success: false, error: fileError, code: 'VALIDATION_ERROR' };
+    }
+
+    // Get authenticated admin
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new AttachmentService(supabase);
+    const attachments = await service.attachToCommunication(communicationId, files, admin.id);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${customerId}`);
+
+    return { success: true, data: attachments };
+  } catch (error) {
+    console.error('Failed to upload attachments:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to upload attachments' };
+  }
+}
+
+/**
+ * Get a short-lived download link for an attachment
+ */
+export async function getAttachmentDownloadUrl(
+  id: string
+): Promise<ActionResult<{ url: string; filename: string }>> {
+  try {
+    const validatedId = attachmentIdSchema.parse(id);
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new AttachmentService(supabase);
+    const link = await service.getDownloadUrl(validatedId);
+
+    return { success: true, data: link };
+  } catch (error) {
+    console.error('Failed to get attachment link:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to open attachment' };
+  }
+}
+
+/**
+ * Delete an attachment and its file
+ */
+export async function deleteAttachment(
+  id: string,
+  customerId: string
+): Promise<ActionResult<boolean>> {
+  try {
+    const validatedId = attachmentIdSchema.parse(id);
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new AttachmentService(supabase);
+    await service.remove([validatedId]);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${customerId}`);
+
+    return { success: true, data: true };
+  } catch (error) {
+    console.error('Failed to delete attachment:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to delete attachment' };
+  }
+}
+
 // =============================================================================
 // Get Communication Stats
 // =============================================================================
@@ -273,18 +735,17 @@ export async function deleteCommunication(
 /**
  * Get communication statistics for a customer
  */
-export async function getCommunicationStats(customerId: string): Promise<
-  ActionResult<{
-    total: number;
-    byType: Record<'call' | 'text' | 'email', number>;
-    byDirection: Record<'inbound' | 'outbound', number>;
-  }>
-> {
+export async function getCommunicationStats(
+  customerId: string,
+  range: { from?: string; to?: string } = {}
+): Promise<ActionResult<CommunicationStats>> {
   try {
+    const validated = communicationStatsSchema.parse({ customerId, ...range });
+
     const { supabase } = await getCurrentAdmin();
 
     const service = new CommunicationService(supabase);
-    const stats = await service.getStats(customerId);
+    const stats = await service.getStats(validated);
 
     return { success: true, data: stats };
   } catch (error) {
@@ -297,3 +758,115 @@ export async function getCommunicationStats(customerId: string): Promise<
     return { success: false, error: 'Failed to get communication stats' };
   }
 }
+
+// =============================================================================
+// Needs Response Queue
+// =============================================================================
+
+/**
+ * Get customers waiting on a reply, oldest first
+ */
+export async function getNeedsResponseQueue(
+  input: Partial<NeedsResponseQueueInput> = {}
+): Promise<ActionResult<NeedsResponseQueue>> {
+  try {
+    const validated = needsResponseQueueSchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new ResponseQueueService(supabase);
+    const queue = await service.getQueue(validated);
+
+    return { success: true, data: queue };
+  } catch (error) {
+    console.error('Failed to get needs-response queue:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to get needs-response queue' };
+  }
+}
+
+/**
+ * Count customers waiting on a reply and how many are overdue
+ */
+export async function getNeedsResponseCount(): Promise<
+  ActionResult<{ total: number; overdue: number; slaHours: number }>
+> {
+  try {
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new ResponseQueueService(supabase);
+    const counts = await service.getCounts();
+
+    return { success: true, data: counts };
+  } catch (error) {
+    console.error('Failed to get needs-response count:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to get needs-response count' };
+  }
+}
+
+/**
+ * Hide a customer from the queue until later
+ */
+export async function snoozeNeedsResponse(
+  input: SnoozeNeedsResponseInput
+): Promise<ActionResult<{ snoozed: boolean }>> {
+  try {
+    const validated = snoozeNeedsResponseSchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new ResponseQueueService(supabase);
+    await service.snooze(validated.customerId, validated.until);
+
+    revalidatePath('/admin/communications/needs-response');
+    revalidatePath('/admin');
+
+    return { success: true, data: { snoozed: true } };
+  } catch (error) {
+    console.error('Failed to snooze:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to snooze' };
+  }
+}
+
+/**
+ * Remove a customer from the queue without replying
+ */
+export async function dismissNeedsResponse(
+  input: DismissNeedsResponseInput
+): Promise<ActionResult<{ dismissed: boolean }>> {
+  try {
+    const validated = dismissNeedsResponseSchema.parse(input);
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new ResponseQueueService(supabase);
+    await service.dismiss(validated.customerId, admin.id);
+
+    revalidatePath('/admin/communications/needs-response');
+    revalidatePath('/admin');
+
+    return { success: true, data: { dismissed: true } };
+  } catch (error) {
+    console.error('Failed to dismiss:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to dismiss' };
+  }
+}
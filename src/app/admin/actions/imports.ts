@@ -0,0 +1,292 @@
+'use server';
+
+/**
+ * Import Server Actions
+ *
+ * @file src/app/actions/imports.ts
+ *
+ * Server actions for importing communications from external sources and
+ * working the review queue of messages that could not be matched.
+ * These actions validate input, check authentication, and delegate to the service layer.
+ */
+
+import { revalidatePath } from 'next/cache';
+import { createClient } from '@/lib/supabase/server';
+import { CommunicationImportService } from '@/lib/services/communication-import.service';
+import {
+  EMAIL_IMPORT_EXTENSIONS,
+  SMS_IMPORT_EXTENSIONS,
+  CDR_IMPORT_EXTENSIONS,
+  listImportQueueSchema,
+  resolveImportQueueItemSchema,
+  importQueueItemIdSchema,
+  validateImportFiles,
+  type ListImportQueueInput,
+  type ResolveImportQueueItemInput,
+} from '@/lib/validations/import';
+import type { ActionResult } from '@/lib/types/api';
+import type { ImportResult, ImportQueueListResult } from '@/lib/types/import';
+import type { CommunicationWithLogger } from '@/lib/types/communication';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+/**
+ * Get uploaded files from the `files` form field
+ */
+function getUploadedFiles(formData: FormData): File[] {
+  return formData.getAll('files').filter((value): value is File => value instanceof File);
+}
+
+/**
+ * Read uploaded files as text
+ */
+async function readFiles(files: File[]): Promise<{ name: string; content: string }[]> {
+  return Promise.all(
+    files.map(async (file) => ({ name: file.name, content: await file.text() }))
+  );
+}
+
+// =============================================================================
+// Import Emails
+// =============================================================================
+
+/**
+ * Import emails from uploaded .eml and .mbox files
+ *
+ * Expects the files under the `files` form field.
+ */
+export async function importEmailFiles(
+  formData: FormData
+): Promise<ActionResult<ImportResult>> {
+  try {
+    const uploads = getUploadedFiles(formData);
+    const fileError = validateImportFiles(uploads, EMAIL_IMPORT_EXTENSIONS);
+    if (fileError) {
+      return { success: false, error: fileError, code: 'VALIDATION_ERROR' };
+    }
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationImportService(supabase);
+    const result = await service.importEmails(await readFiles(uploads), admin.id);
+
+    revalidatePath('/admin/customers');
+    revalidatePath('/admin/communications/import');
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to import emails:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to import emails' };
+  }
+}
+
+// =============================================================================
+// Import Text Messages
+// =============================================================================
+
+/**
+ * Import text message history from uploaded CSV or JSON exports
+ *
+ * Expects the files under the `files` form field. When `dryRun` is "true"
+ * nothing is written and the result reports what would happen.
+ */
+export async function importSmsFiles(
+  formData: FormData
+): Promise<ActionResult<ImportResult>> {
+  try {
+    const uploads = getUploadedFiles(formData);
+    const fileError = validateImportFiles(uploads, SMS_IMPORT_EXTENSIONS);
+    if (fileError) {
+      return { success: false, error: fileError, code: 'VALIDATION_ERROR' };
+    }
+
+    const dryRun = formData.get('dryRun') === 'true';
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationImportService(supabase);
+    const result = await service.importSms(await readFiles(uploads), admin.id, { dryRun });
+
+    if (!dryRun) {
+      revalidatePath('/admin/customers');
+      revalidatePath('/admin/communications/import');
+    }
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to import text messages:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to import text messages' };
+  }
+}
+
+// =============================================================================
+// Import Call-Detail Records
+// =============================================================================
+
+/**
+ * Import call-detail records from uploaded phone system CSV exports
+ *
+ * Expects the files under the `files` form field. When `dryRun` is "true"
+ * nothing is written and the result reports what would happen.
+ */
+export async function importCdrFiles(
+  formData: FormData
+): Promise<ActionResult<ImportResult>> {
+  try {
+    const uploads = getUploadedFiles(formData);
+    const fileError = validateImportFiles(uploads, CDR_IMPORT_EXTENSIONS);
+    if (fileError) {
+      return { success: false, error: fileError, code: 'VALIDATION_ERROR' };
+    }
+
+    const dryRun = formData.get('dryRun') === 'true';
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationImportService(supabase);
+    const result = await service.importCdr(await readFiles(uploads), admin.id, { dryRun });
+
+    if (!dryRun) {
+      revalidatePath('/admin/customers');
+      revalidatePath('/admin/communications/import');
+    }
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to import call records:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to import call records' };
+  }
+}
+
+// =============================================================================
+// Review Queue
+// =============================================================================
+
+/**
+ * List imported messages awaiting review
+ */
+export async function listImportQueue(
+  input: Partial<ListImportQueueInput> = {}
+): Promise<ActionResult<ImportQueueListResult>> {
+  try {
+    const validated = listImportQueueSchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new CommunicationImportService(supabase);
+    const result = await service.listQueue(validated);
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to list import queue:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to list import queue' };
+  }
+}
+
+/**
+ * Assign a queued message to a customer
+ */
+export async function resolveImportQueueItem(
+  input: ResolveImportQueueItemInput
+): Promise<ActionResult<CommunicationWithLogger>> {
+  try {
+    const validated = resolveImportQueueItemSchema.parse(input);
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationImportService(supabase);
+    const communication = await service.resolveQueueItem(validated, admin.id);
+
+    revalidatePath('/admin/communications/import');
+    revalidatePath(`/admin/customers/${validated.customerId}`);
+
+    return { success: true, data: communication };
+  } catch (error) {
+    console.error('Failed to resolve import queue item:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to assign message' };
+  }
+}
+
+/**
+ * Dismiss a queued message without logging it
+ */
+export async function dismissImportQueueItem(
+  id: string
+): Promise<ActionResult<{ dismissed: boolean }>> {
+  try {
+    const validated = importQueueItemIdSchema.parse({ id });
+
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new CommunicationImportService(supabase);
+    const dismissed = await service.dismissQueueItem(validated.id, admin.id);
+
+    revalidatePath('/admin/communications/import');
+
+    return { success: true, data: { dismissed } };
+  } catch (error) {
+    console.error('Failed to dismiss import queue item:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to dismiss message' };
+  }
+}
@@ -0,0 +1,89 @@
+'use server';
+
+/**
+ * Notification Server Actions
+ *
+ * @file src/app/actions/notifications.ts
+ *
+ * Server actions for sending email and text messages to customers.
+ * Every send is logged as an outbound communication with its delivery status.
+ */
+
+import { revalidatePath } from 'next/cache';
+import { createClient } from '@/lib/supabase/server';
+import { NotificationService } from '@/lib/services/notification.service';
+import { sendMessageSchema, type SendMessageInput } from '@/lib/validations/notification';
+import type { ActionResult } from '@/lib/types/api';
+import type { CommunicationWithLogger } from '@/lib/types/communication';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+// =============================================================================
+// Send Message
+// =============================================================================
+
+/**
+ * Send an email or text message to a customer
+ *
+ * Succeeds whenever the attempt was logged; check `delivery_status` on the
+ * returned communication to see whether the provider accepted it.
+ */
+export async function sendMessage(
+  input: SendMessageInput
+): Promise<ActionResult<CommunicationWithLogger>> {
+  try {
+    // Validate input
+    const validated = sendMessageSchema.parse(input);
+
+    // Get authenticated admin
+    const { supabase, admin } = await getCurrentAdmin();
+
+    // Send and log
+    const service = new NotificationService(supabase);
+    const communication = await service.send(validated, admin.id);
+
+    // Revalidate customer detail page
+    revalidatePath(`/admin/customers/${validated.customerId}`);
+
+    return { success: true, data: communication };
+  } catch (error) {
+    console.error('Failed to send message:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to send message' };
+  }
+}
@@ -0,0 +1,78 @@
+'use server';
+
+/**
+ * Search Server Actions
+ *
+ * @file src/app/actions/search.ts
+ *
+ * Server actions for the global header search.
+ */
+
+import { createClient } from '@/lib/supabase/server';
+import { SearchService } from '@/lib/services/search.service';
+import { globalSearchSchema, type GlobalSearchInput } from '@/lib/validations/search';
+import type { ActionResult } from '@/lib/types/api';
+import type { GlobalSearchResponse } from '@/lib/types/search';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+// =============================================================================
+// Global Search
+// =============================================================================
+
+/**
+ * Search customers, communications, notes, estimates and properties
+ */
+export async function globalSearch(
+  input: Pick<GlobalSearchInput, 'query'> & Partial<GlobalSearchInput>
+): Promise<ActionResult<GlobalSearchResponse>> {
+  try {
+    const validated = globalSearchSchema.parse(input);
+
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new SearchService(supabase);
+    const results = await service.globalSearch(validated);
+
+    return { success: true, data: results };
+  } catch (error) {
+    console.error('Failed to search:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to search' };
+  }
+}
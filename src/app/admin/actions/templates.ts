@@ -0,0 +1,219 @@
+'use server';
+
+/**
+ * Message Template Server Actions
+ *
+ * @file src/app/actions/templates.ts
+ *
+ * Server actions for managing message templates, previewing them against a
+ * customer and sending messages from them.
+ */
+
+import { revalidatePath } from 'next/cache';
+import { createClient } from '@/lib/supabase/server';
+import { TemplateService } from '@/lib/services/template.service';
+import { NotificationService } from '@/lib/services/notification.service';
+import { isAppError } from '@/lib/utils/errors';
+import {
+  createTemplateSchema,
+  updateTemplateSchema,
+  templateIdSchema,
+  previewTemplateSchema,
+  sendTemplateSchema,
+  type CreateTemplateInput,
+  type UpdateTemplateInput,
+  type PreviewTemplateInput,
+  type SendTemplateInput,
+} from '@/lib/validations/template';
+import type { ActionResult } from '@/lib/types/api';
+import type { MessageTemplateChannel } from '@/lib/types/database';
+import type { MessageTemplateWithAuthor, RenderedTemplate } from '@/lib/types/template';
+import type { CommunicationWithLogger } from '@/lib/types/communication';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+/**
+ * Convert a thrown error into a failed ActionResult
+ */
+function toErrorResult<T>(error: unknown, fallback: string): ActionResult<T> {
+  if (isAppError(error)) {
+    return { success: false, error: error.message, code: error.code };
+  }
+
+  if (error instanceof Error) {
+    return { success: false, error: error.message };
+  }
+
+  return { success: false, error: fallback };
+}
+
+// =============================================================================
+// Template CRUD
+// =============================================================================
+
+/**
+ * List message templates
+ *
+ * @param channel - Only return templates for this channel
+ */
+export async function listTemplates(
+  channel?: MessageTemplateChannel
+): Promise<ActionResult<MessageTemplateWithAuthor[]>> {
+  try {
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new TemplateService(supabase);
+    const templates = await service.list(channel);
+
+    return { success: true, data: templates };
+  } catch (error) {
+    console.error('Failed to list templates:', error);
+    return toErrorResult(error, 'Failed to load templates');
+  }
+}
+
+/**
+ * Create a message template
+ */
+export async function createTemplate(
+  input: CreateTemplateInput
+): Promise<ActionResult<MessageTemplateWithAuthor>> {
+  try {
+    const validated = createTemplateSchema.parse(input);
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new TemplateService(supabase);
+    const template = await service.create(validated, admin.id);
+
+    revalidatePath('/admin/communications/templates');
+
+    return { success: true, data: template };
+  } catch (error) {
+    console.error('Failed to create template:', error);
+    return toErrorResult(error, 'Failed to create template');
+  }
+}
+
+/**
+ * Update a message template
+ */
+export async function updateTemplate(
+  input: UpdateTemplateInput
+): Promise<ActionResult<MessageTemplateWithAuthor>> {
+  try {
+    const validated = updateTemplateSchema.parse(input);
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new TemplateService(supabase);
+    const template = await service.update(validated);
+
+    revalidatePath('/admin/communications/templates');
+
+    return { success: true, data: template };
+  } catch (error) {
+    console.error('Failed to update template:', error);
+    return toErrorResult(error, 'Failed to update template');
+  }
+}
+
+/**
+ * Delete a message template
+ */
+export async function deleteTemplate(id: string): Promise<ActionResult<{ deleted: boolean }>> {
+  try {
+    const validatedId = templateIdSchema.parse(id);
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new TemplateService(supabase);
+    await service.delete(validatedId);
+
+    revalidatePath('/admin/communications/templates');
+
+    return { success: true, data: { deleted: true } };
+  } catch (error) {
+    console.error('Failed to delete template:', error);
+    return toErrorResult(error, 'Failed to delete template');
+  }
+}
+
+// =============================================================================
+// Preview & Send
+// =============================================================================
+
+/**
+ * Render a template for a customer without sending it
+ *
+ * Fails with VALIDATION_ERROR naming any unknown variables or variables
+ * with no value for this customer.
+ */
+export async function previewTemplate(
+  input: PreviewTemplateInput
+): Promise<ActionResult<RenderedTemplate>> {
+  try {
+    const { templateId, ...context } = previewTemplateSchema.parse(input);
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new TemplateService(supabase);
+    const rendered = await service.render(templateId, context);
+
+    return { success: true, data: rendered };
+  } catch (error) {
+    console.error('Failed to preview template:', error);
+    return toErrorResult(error, 'Failed to preview template');
+  }
+}
+
+/**
+ * Send a message to a customer from a template
+ *
+ * The resulting communication records the template ID.
+ */
+export async function sendTemplate(
+  input: SendTemplateInput
+): Promise<ActionResult<CommunicationWithLogger>> {
+  try {
+    const validated = sendTemplateSchema.parse(input);
+    const { supabase, admin } = await getCurrentAdmin();
+
+    const service = new NotificationService(supabase);
+    const communication = await service.sendTemplate(validated, admin.id);
+
+    revalidatePath(`/admin/customers/${validated.customerId}`);
+
+    return { success: true, data: communication };
+  } catch (error) {
+    console.error('Failed to send template:', error);
+    return toErrorResult(error, 'Failed to send message');
+  }
+}
@@ -0,0 +1,81 @@
+'use server';
+
+/**
+ * Timeline Server Actions
+ *
+ * @file src/app/actions/timeline.ts
+ *
+ * Server actions for the customer activity timeline.
+ */
+
+import { createClient } from '@/lib/supabase/server';
+import { TimelineService } from '@/lib/services/timeline.service';
+import { customerTimelineSchema, type CustomerTimelineInput } from '@/lib/validations/timeline';
+import type { ActionResult } from '@/lib/types/api';
+import type { CustomerTimelineResult } from '@/lib/types/timeline';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Get the current authenticated admin or throw
+ */
+async function getCurrentAdmin() {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+    error: authError,
+  } = await supabase.auth.getUser();
+
+  if (authError || !user) {
+    throw new Error('You must be logged in to perform this action');
+  }
+
+  // Verify user is an admin
+  const { data: admin, error: adminError } = await supabase
+    .from('admins')
+    .select('id, email, full_name')
+    .eq('id', user.id)
+    .single();
+
+  if (adminError || !admin) {
+    throw new Error('You do not have permission to perform this action');
+  }
+
+  return { supabase, admin };
+}
+
+// =============================================================================
+// Customer Timeline
+// =============================================================================
+
+/**
+ * A page of a customer's communications, notes, estimates, events and
+ * attachments, newest first, with the communication filters
+ */
+export async function getCustomerTimeline(
+  input: CustomerTimelineInput
+): Promise<ActionResult<CustomerTimelineResult>> {
+  try {
+    // Validate input
+    const validated = customerTimelineSchema.parse(input);
+
+    // Get authenticated admin
+    const { supabase } = await getCurrentAdmin();
+
+    const service = new TimelineService(supabase);
+    const result = await service.listByCustomer(validated);
+
+    return { success: true, data: result };
+  } catch (error) {
+    console.error('Failed to get customer timeline:', error);
+
+    if (error instanceof Error) {
+      return { success: false, error: error.message };
+    }
+
+    return { success: false, error: 'Failed to load timeline' };
+  }
+}
@@ -0,0 +1,453 @@
+'use client';
+
+/**
+ * Audit Log View
+ *
+ * @file src/components/audit/audit-log-view.tsx
+ *
+ * Filterable audit log, newest first. Each entry shows who made the change
+ * and can be expanded to the fields that changed. Clicking a record or an
+ * admin narrows the log to that record's history or that admin's activity.
+ */
+
+import * as React from 'react';
+import Link from 'next/link';
+import { formatDistanceToNow } from 'date-fns';
+import { ChevronDown, ChevronRight, History, Loader2, X } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { cn } from '@/lib/utils';
+import {
+  formatDateTime,
+  getEndOfDayUTC,
+  getStartOfDayUTC,
+} from '@/lib/utils/timezone';
+import { getAuditFilterOptions, listAuditLog } from '@/app/actions/audit';
+import {
+  auditActionLabels,
+  auditTableLabels,
+  describeAuditRecord,
+  type AuditFilterOptions,
+  type AuditLogEntry,
+  type AuditLogFilters,
+  type AuditTable,
+} from '@/lib/types/audit';
+import type { AuditAction } from '@/lib/types/database';
+import { AUDIT_ACTIONS, AUDIT_TABLES } from '@/lib/validations/audit';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface AuditLogViewProps {
+  initialFilters?: AuditLogFilters;
+}
+
+const PAGE_SIZE = 50;
+
+const actionStyles: Record<AuditAction, string> = {
+  INSERT: 'bg-green-50 text-green-700',
+  UPDATE: 'bg-blue-50 text-blue-700',
+  DELETE: 'bg-red-50 text-red-700',
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Display a snapshot value: dates and text as-is, other values as JSON
+ */
+function formatAuditValue(value: unknown): string {
+  if (value === null || value === undefined || value === '') return '—';
+  const text = typeof value === 'string' ? value : JSON.stringify(value);
+  return text.length > 200 ? `${text.slice(0, 200)}…` : text;
+}
+
+/**
+ * Page for the record an entry is about, when it still has one
+ */
+function getRecordHref(entry: AuditLogEntry): string | null {
+  if (entry.action === 'DELETE') return null;
+
+  const values = entry.new_values ?? {};
+  const customerId = typeof values.customer_id === 'string' ? values.customer_id : null;
+
+  switch (entry.table_name as AuditTable) {
+    case 'customers':
+      return `/admin/customers/${entry.record_id}`;
+    case 'estimates':
+      return `/admin/estimates/${entry.record_id}`;
+    case 'calendar_events':
+      return '/admin/calendar';
+    default:
+      return customerId ? `/admin/customers/${customerId}` : null;
+  }
+}
+
+/**
+ * Local date (yyyy-MM-dd) to the start or end of that day, as ISO
+ */
+function toRangeBoundary(date: string, edge: 'start' | 'end'): string | undefined {
+  if (!date) return undefined;
+  const local = new Date(`${date}T00:00:00`);
+  return (edge === 'start' ? getStartOfDayUTC(local) : getEndOfDayUTC(local)).toISOString();
+}
+
+// =============================================================================
+// Entry Row
+// =============================================================================
+
+interface AuditEntryRowProps {
+  entry: AuditLogEntry;
+  onShowRecord: (entry: AuditLogEntry) => void;
+  onShowAdmin: (adminId: string) => void;
+}
+
+function AuditEntryRow({ entry, onShowRecord, onShowAdmin }: AuditEntryRowProps) {
+  const [expanded, setExpanded] = React.useState(false);
+  const label = describeAuditRecord(entry);
+  const href = getRecordHref(entry);
+  const tableLabel = auditTableLabels[entry.table_name as AuditTable] ?? entry.table_name;
+
+  return (
+    <li className="border border-zinc-200 rounded-lg">
+      <div className="flex items-start justify-between gap-4 p-4">
+        <div className="min-w-0 space-y-1 text-sm">
+          <div className="flex flex-wrap items-center gap-2">
+            <span
+              className={cn(
+                'px-2 py-0.5 rounded text-xs font-medium',
+                actionStyles[entry.action]
+              )}
+            >
+              {auditActionLabels[entry.action]}
+            </span>
+            <span className="font-medium text-zinc-900">{tableLabel}</span>
+            {label &&
+              (href ? (
+                <Link href={href} className="text-zinc-600 hover:text-zinc-900 truncate">
+                  {label}
+                </Link>
+              ) : (
+                <span className="text-zinc-600 truncate">{label}</span>
+              ))}
+          </div>
+          <p className="text-xs text-zinc-500">
+            {entry.changed_by_admin ? (
+              <button
+                type="button"
+                onClick={() => onShowAdmin(entry.changed_by_admin!.id)}
+                className="hover:text-zinc-900 hover:underline"
+              >
+                {entry.changed_by_admin.full_name}
+              </button>
+            ) : (
+              'System'
+            )}
+            {' · '}
+            <span title={formatDateTime(entry.created_at, 'mediumWithTime')}>
+              {formatDistanceToNow(new Date(entry.created_at), { addSuffix: true })}
+            </span>
+          </p>
+        </div>
+
+        <div className="flex items-center gap-1 flex-shrink-0">
+          <Button variant="ghost" size="sm" onClick={() => onShowRecord(entry)}>
+            <History className="w-4 h-4 mr-1.5" />
+            History
+          </Button>
+          {entry.changes.length > 0 && (
+            <Button variant="ghost" size="sm" onClick={() => setExpanded((prev) => !prev)}>
+              {expanded ? (
+                <ChevronDown className="w-4 h-4 mr-1.5" />
+              ) : (
+                <ChevronRight className="w-4 h-4 mr-1.5" />
+              )}
+              {entry.changes.length} {entry.changes.length === 1 ? 'field' : 'fields'}
+            </Button>
+          )}
+        </div>
+      </div>
+
+      {expanded && (
+        <table className="w-full text-sm border-t border-zinc-200">
+          <thead>
+            <tr className="text-left text-xs text-zinc-500">
+              <th className="px-4 py-2 font-medium w-1/5">Field</th>
+              {entry.action !== 'INSERT' && (
+                <th className="px-4 py-2 font-medium">Before</th>
+              )}
+              {entry.action !== 'DELETE' && <th className="px-4 py-2 font-medium">After</th>}
+            </tr>
+          </thead>
+          <tbody>
+            {entry.changes.map((change) => (
+              <tr key={change.field} className="border-t border-zinc-100 align-top">
+                <td className="px-4 py-2 font-mono text-xs text-zinc-600">{change.field}</td>
+                {entry.action !== 'INSERT' && (
+                  <td className="px-4 py-2 text-zinc-500 whitespace-pre-wrap break-words">
+                    {formatAuditValue(change.from)}
+                  </td>
+                )}
+                {entry.action !== 'DELETE' && (
+                  <td className="px-4 py-2 text-zinc-900 whitespace-pre-wrap break-words">
+                    {formatAuditValue(change.to)}
+                  </td>
+                )}
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </li>
+  );
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function AuditLogView({ initialFilters = {} }: AuditLogViewProps) {
+  const [filters, setFilters] = React.useState<AuditLogFilters>(initialFilters);
+  const [fromDate, setFromDate] = React.useState('');
+  const [toDate, setToDate] = React.useState('');
+  const [options, setOptions] = React.useState<AuditFilterOptions | null>(null);
+  const [items, setItems] = React.useState<AuditLogEntry[] | null>(null);
+  const [total, setTotal] = React.useState<number | null>(null);
+  const [nextCursor, setNextCursor] = React.useState<string | null>(null);
+  const [isLoadingMore, setIsLoadingMore] = React.useState(false);
+
+  React.useEffect(() => {
+    getAuditFilterOptions().then((result) => {
+      if (result.success) setOptions(result.data);
+    });
+  }, []);
+
+  const loadEntries = React.useCallback(
+    async (cursor?: string) => {
+      try {
+        const result = await listAuditLog({
+          ...filters,
+          from: toRangeBoundary(fromDate, 'start'),
+          to: toRangeBoundary(toDate, 'end'),
+          limit: PAGE_SIZE,
+          cursor,
+        });
+
+        if (!result.success) {
+          toast.error(result.error || 'Failed to load audit log');
+          setItems((prev) => prev ?? []);
+          return;
+        }
+
+        setItems((prev) =>
+          cursor && prev ? [...prev, ...result.data.items] : result.data.items
+        );
+        setTotal(result.data.total ?? null);
+        setNextCursor(result.data.nextCursor);
+      } catch (error) {
+        console.error('Failed to load audit log:', error);
+        toast.error('Failed to load audit log');
+      }
+    },
+    [filters, fromDate, toDate]
+  );
+
+  React.useEffect(() => {
+    setItems(null);
+    loadEntries();
+  }, [loadEntries]);
+
+  const handleLoadMore = async () => {
+    if (!nextCursor) return;
+    setIsLoadingMore(true);
+    await loadEntries(nextCursor);
+    setIsLoadingMore(false);
+  };
+
+  const handleShowRecord = (entry: AuditLogEntry) => {
+    setFilters({ tableName: entry.table_name as AuditTable, recordId: entry.record_id });
+  };
+
+  const handleShowAdmin = (adminId: string) => {
+    setFilters((prev) => ({ ...prev, changedBy: adminId }));
+  };
+
+  const handleClear = () => {
+    setFilters({});
+    setFromDate('');
+    setToDate('');
+  };
+
+  const hasFilters =
+    Object.values(filters).some((value) => value !== undefined) || !!fromDate || !!toDate;
+
+  return (
+    <div className="space-y-4">
+      {/* Filters */}
+      <div className="flex flex-wrap items-center gap-3">
+        <Select
+          value={filters.tableName ?? 'all'}
+          onValueChange={(value) =>
+            setFilters((prev) => ({
+              ...prev,
+              tableName: value === 'all' ? undefined : (value as AuditTable),
+              recordId: undefined,
+            }))
+          }
+        >
+          <SelectTrigger className="w-[180px]">
+            <SelectValue placeholder="Record Type" />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="all">All Record Types</SelectItem>
+            {AUDIT_TABLES.map((table) => (
+              <SelectItem key={table} value={table}>
+                {auditTableLabels[table]}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+
+        <Select
+          value={filters.action ?? 'all'}
+          onValueChange={(value) =>
+            setFilters((prev) => ({
+              ...prev,
+              action: value === 'all' ? undefined : (value as AuditAction),
+            }))
+          }
+        >
+          <SelectTrigger className="w-[150px]">
+            <SelectValue placeholder="Action" />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="all">All Actions</SelectItem>
+            {AUDIT_ACTIONS.map((action) => (
+              <SelectItem key={action} value={action}>
+                {auditActionLabels[action]}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+
+        {options && (
+          <Select
+            value={filters.changedBy ?? 'all'}
+            onValueChange={(value) =>
+              setFilters((prev) => ({
+                ...prev,
+                changedBy: value === 'all' ? undefined : value,
+              }))
+            }
+          >
+            <SelectTrigger className="w-[180px]">
+              <SelectValue placeholder="Admin" />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="all">Anyone</SelectItem>
+              {options.admins.map((admin) => (
+                <SelectItem key={admin.id} value={admin.id}>
+                  {admin.full_name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        )}
+
+        <Input
+          type="date"
+          value={fromDate}
+          onChange={(e) => setFromDate(e.target.value)}
+          className="w-[160px]"
+          aria-label="From date"
+        />
+        <span className="text-sm text-zinc-400">to</span>
+        <Input
+          type="date"
+          value={toDate}
+          onChange={(e) => setToDate(e.target.value)}
+          className="w-[160px]"
+          aria-label="To date"
+        />
+
+        {hasFilters && (
+          <Button variant="ghost" size="sm" onClick={handleClear}>
+            <X className="w-4 h-4 mr-1.5" />
+            Clear
+          </Button>
+        )}
+      </div>
+
+      {filters.recordId && (
+        <div className="flex items-center justify-between gap-4 p-3 bg-zinc-50 border border-zinc-200 rounded-lg text-sm text-zinc-600">
+          <span>
+            Showing the history of one{' '}
+            {filters.tableName ? auditTableLabels[filters.tableName].toLowerCase() : 'record'}
+          </span>
+          <Button
+            variant="ghost"
+            size="sm"
+            onClick={() => setFilters((prev) => ({ ...prev, recordId: undefined }))}
+          >
+            Show all
+          </Button>
+        </div>
+      )}
+
+      {/* Entries */}
+      {items === null ? (
+        <div className="flex justify-center py-12 text-zinc-400">
+          <Loader2 className="w-5 h-5 animate-spin" />
+        </div>
+      ) : items.length === 0 ? (
+        <div className="flex flex-col items-center py-12 text-center">
+          <History className="w-6 h-6 text-zinc-300" />
+          <p className="text-sm text-zinc-500 mt-2">No changes match these filters.</p>
+        </div>
+      ) : (
+        <>
+          {total !== null && (
+            <p className="text-xs text-zinc-500">
+              {total} {total === 1 ? 'change' : 'changes'}
+            </p>
+          )}
+          <ul className="space-y-3">
+            {items.map((entry) => (
+              <AuditEntryRow
+                key={entry.id}
+                entry={entry}
+                onShowRecord={handleShowRecord}
+                onShowAdmin={handleShowAdmin}
+              />
+            ))}
+          </ul>
+        </>
+      )}
+
+      {nextCursor && (
+        <div className="flex justify-center">
+          <Button variant="secondary" onClick={handleLoadMore} disabled={isLoadingMore}>
+            {isLoadingMore ? (
+              <>
+                <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                Loading...
+              </>
+            ) : (
+              'Load More'
+            )}
+          </Button>
+        </div>
+      )}
+    </div>
+  );
+}
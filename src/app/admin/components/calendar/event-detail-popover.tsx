@@ -3,8 +3,9 @@
  *
  * @file src/components/admin/calendar/event-detail-popover.tsx
  *
- * Popover component that displays event details when clicking an event.
- * Includes quick actions for completing, canceling, editing, and deleting.
+ * Popover component that displays event details and the communications
+ * linked to it when clicking an event. Includes quick actions for
+ * completing, canceling, editing, deleting and logging a communication.
  */
 
 'use client';
@@ -26,6 +27,7 @@ import {
   ExternalLink,
   MoreHorizontal,
   Loader2,
+  MessageSquare,
 } from 'lucide-react';
 import { toast } from 'sonner';
 
@@ -65,6 +67,8 @@ import {
 } from '@/lib/validations/calendar';
 import { getEventColors, getStatusStyles } from '@/lib/types/calendar';
 import { EditEventModal } from './edit-event-modal';
+import { RelatedCommunications } from '@/components/communications/related-communications';
+import { LogCommunicationModal } from '@/components/communications/log-communication-modal';
 
 // =============================================================================
 // Types
@@ -100,6 +104,8 @@ export function EventDetailPopover({
   const [isDeleting, setIsDeleting] = React.useState(false);
   const [showDeleteConfirm, setShowDeleteConfirm] = React.useState(false);
   const [showEditModal, setShowEditModal] = React.useState(false);
+  const [showLogModal, setShowLogModal] = React.useState(false);
+  const [communicationsKey, setCommunicationsKey] = React.useState(0);
 
   // Get colors and styles
   const colors = getEventColors(event.event_type);
@@ -336,6 +342,20 @@ export function EventDetailPopover({
                   </p>
                 </div>
               )}
+
+              {/* Communications */}
+              <div className="pt-2 border-t border-zinc-100">
+                <p className="text-xs font-medium text-zinc-500 uppercase tracking-wide mb-2">
+                  Communications
+                </p>
+                <RelatedCommunications
+                  calendarEventId={event.id}
+                  refreshKey={communicationsKey}
+                  limit={5}
+                  emptyMessage="None linked to this event."
+                  className="max-h-48 overflow-y-auto"
+                />
+              </div>
             </div>
 
             {/* Actions */}
@@ -400,6 +420,13 @@ export function EventDetailPopover({
                       <Pencil className="w-4 h-4 mr-2" />
                       Edit event
                     </DropdownMenuItem>
+                    <DropdownMenuItem
+                      onClick={() => setShowLogModal(true)}
+                      className="cursor-pointer"
+                    >
+                      <MessageSquare className="w-4 h-4 mr-2" />
+                      Log communication
+                    </DropdownMenuItem>
                     <DropdownMenuItem asChild>
                       <Link href={`/admin/customers/${event.customer_id}`}>
                         <User className="w-4 h-4 mr-2" />
@@ -462,6 +489,16 @@ export function EventDetailPopover({
           onUpdated={handleEditComplete}
         />
       )}
+
+      {/* Log communication modal */}
+      <LogCommunicationModal
+        customerId={event.customer_id}
+        customerName={event.customer.name}
+        open={showLogModal}
+        onOpenChange={setShowLogModal}
+        onSuccess={() => setCommunicationsKey((key) => key + 1)}
+        defaultCalendarEventId={event.id}
+      />
     </>
   );
 }
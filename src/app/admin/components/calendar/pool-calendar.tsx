@@ -165,6 +165,7 @@ export function PoolCalendar({
           property_id: eventData.extendedProps.propertyId as string | null,
           pool_id: eventData.extendedProps.poolId as string | null,
           location_url: eventData.extendedProps.locationUrl as string | null,
+          communication_id: (eventData.extendedProps.communicationId as string | null) ?? null,
           version: eventData.extendedProps.version as number,
           reminder_24h_sent: false,
           reminder_2h_sent: false,
@@ -0,0 +1,106 @@
+'use client';
+
+/**
+ * Call Details Fields
+ *
+ * @file src/components/communications/call-details-fields.tsx
+ *
+ * Optional structured call fields (outcome, duration, number) shared by the
+ * log and edit communication modals. Only rendered for calls.
+ */
+
+import * as React from 'react';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { cn } from '@/lib/utils';
+import {
+  callOutcomeOptions,
+  type CallOutcome,
+} from '@/lib/validations/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface CallDetailsFieldsProps {
+  outcome: CallOutcome | undefined;
+  onOutcomeChange: (value: CallOutcome | undefined) => void;
+  durationInput: string;
+  onDurationInputChange: (value: string) => void;
+  durationError?: string;
+  callNumber: string;
+  onCallNumberChange: (value: string) => void;
+  direction: 'inbound' | 'outbound';
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function CallDetailsFields({
+  outcome,
+  onOutcomeChange,
+  durationInput,
+  onDurationInputChange,
+  durationError,
+  callNumber,
+  onCallNumberChange,
+  direction,
+}: CallDetailsFieldsProps) {
+  return (
+    <div className="space-y-4 rounded-md border border-zinc-200 bg-zinc-50 p-3">
+      {/* Outcome */}
+      <div className="space-y-2">
+        <Label>Outcome</Label>
+        <div className="flex flex-wrap gap-2">
+          {callOutcomeOptions.map((option) => (
+            <button
+              key={option.value}
+              type="button"
+              onClick={() =>
+                onOutcomeChange(outcome === option.value ? undefined : option.value)
+              }
+              className={cn(
+                'px-2.5 py-1.5 rounded-md border text-xs font-medium transition-colors',
+                outcome === option.value
+                  ? 'bg-zinc-900 text-white border-zinc-900'
+                  : 'bg-white text-zinc-700 border-zinc-300 hover:bg-zinc-50 hover:border-zinc-400'
+              )}
+            >
+              {option.label}
+            </button>
+          ))}
+        </div>
+      </div>
+
+      <div className="grid grid-cols-2 gap-3">
+        {/* Duration */}
+        <div className="space-y-2">
+          <Label htmlFor="callDuration">Duration</Label>
+          <Input
+            id="callDuration"
+            placeholder="m:ss"
+            value={durationInput}
+            onChange={(e) => onDurationInputChange(e.target.value)}
+            className={cn(durationError && 'border-red-500')}
+          />
+          {durationError && <p className="text-sm text-red-600">{durationError}</p>}
+        </div>
+
+        {/* Number */}
+        <div className="space-y-2">
+          <Label htmlFor="callNumber">
+            {direction === 'inbound' ? 'Called from' : 'Number dialed'}
+          </Label>
+          <Input
+            id="callNumber"
+            type="tel"
+            placeholder="(555) 123-4567"
+            value={callNumber}
+            onChange={(e) => onCallNumberChange(e.target.value)}
+          />
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,223 @@
+'use client';
+
+/**
+ * Communication Analytics Panel
+ *
+ * @file src/components/communications/communication-analytics-panel.tsx
+ *
+ * Dashboard panel with team and per-admin response times, communication
+ * volume and busiest hours over a selectable date range.
+ */
+
+import * as React from 'react';
+import { Loader2 } from 'lucide-react';
+import { toast } from 'sonner';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { cn } from '@/lib/utils';
+import { formatSecondsDuration } from '@/lib/utils/timezone';
+import { getCommunicationAnalytics } from '@/app/actions/analytics';
+import { communicationTypeLabels } from '@/lib/types/communication';
+import type { CommunicationAnalytics, ResponseTimeMetrics } from '@/lib/types/analytics';
+import { COMMUNICATION_TYPES } from '@/lib/validations/communication';
+import { analyticsRangeOptions, DEFAULT_ANALYTICS_DAYS } from '@/lib/validations/analytics';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface CommunicationAnalyticsPanelProps {
+  initialAnalytics: CommunicationAnalytics | null;
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function formatHour(hour: number): string {
+  const suffix = hour < 12 ? 'am' : 'pm';
+  const display = hour % 12 === 0 ? 12 : hour % 12;
+  return `${display}${suffix}`;
+}
+
+function formatMedian(metrics: ResponseTimeMetrics): string {
+  return metrics.medianSeconds != null ? formatSecondsDuration(metrics.medianSeconds) : '—';
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function CommunicationAnalyticsPanel({
+  initialAnalytics,
+}: CommunicationAnalyticsPanelProps) {
+  const [days, setDays] = React.useState(String(DEFAULT_ANALYTICS_DAYS));
+  const [analytics, setAnalytics] = React.useState(initialAnalytics);
+  const [isLoading, setIsLoading] = React.useState(false);
+
+  const handleRangeChange = async (value: string) => {
+    setDays(value);
+    setIsLoading(true);
+
+    try {
+      const to = new Date();
+      const from = new Date(to.getTime() - Number(value) * 24 * 60 * 60 * 1000);
+      const result = await getCommunicationAnalytics({
+        from: from.toISOString(),
+        to: to.toISOString(),
+      });
+
+      if (result.success) {
+        setAnalytics(result.data);
+      } else {
+        toast.error(result.error || 'Failed to load analytics');
+      }
+    } catch (error) {
+      console.error('Failed to load analytics:', error);
+      toast.error('Failed to load analytics');
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  const team = analytics?.team;
+  const maxHour = team ? Math.max(1, ...team.activity.byHour) : 1;
+
+  return (
+    <Card>
+      <CardHeader className="flex flex-row items-center justify-between space-y-0">
+        <CardTitle className="flex items-center gap-2">
+          Communications
+          {isLoading && <Loader2 className="w-4 h-4 animate-spin text-zinc-400" />}
+        </CardTitle>
+        <Select value={days} onValueChange={handleRangeChange} disabled={isLoading}>
+          <SelectTrigger className="w-40">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {analyticsRangeOptions.map((option) => (
+              <SelectItem key={option.value} value={option.value}>
+                {option.label}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </CardHeader>
+      <CardContent className="space-y-6">
+        {!team ? (
+          <p className="text-sm text-zinc-500">Analytics are unavailable right now.</p>
+        ) : (
+          <>
+            {/* Team Summary */}
+            <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
+              <Stat label="Median response" value={formatMedian(team.responseTime)} />
+              <Stat label="Replies" value={String(team.responseTime.responses)} />
+              <Stat label="Inbound" value={String(team.volume.byDirection.inbound)} />
+              <Stat label="Outbound" value={String(team.volume.byDirection.outbound)} />
+            </div>
+
+            {/* Volume by Type */}
+            <div className="flex flex-wrap gap-2">
+              {COMMUNICATION_TYPES.filter((type) => team.volume.byType[type] > 0).map((type) => (
+                <span
+                  key={type}
+                  className="px-2 py-0.5 text-xs rounded bg-zinc-100 text-zinc-700"
+                >
+                  {communicationTypeLabels[type]}: {team.volume.byType[type]}
+                </span>
+              ))}
+            </div>
+
+            {/* Busiest Hours */}
+            <div className="space-y-2">
+              <p className="text-sm font-medium text-zinc-900">
+                Busiest hours
+                {team.activity.busiestHours.length > 0 && (
+                  <span className="font-normal text-zinc-500">
+                    {' '}
+                    · {team.activity.busiestHours.map((entry) => formatHour(entry.hour)).join(', ')}
+                  </span>
+                )}
+              </p>
+              <div className="flex items-end gap-0.5 h-16">
+                {team.activity.byHour.map((total, hour) => (
+                  <div
+                    key={hour}
+                    title={`${formatHour(hour)}: ${total}`}
+                    className={cn(
+                      'flex-1 rounded-sm',
+                      total > 0 ? 'bg-zinc-700' : 'bg-zinc-100'
+                    )}
+                    style={{ height: `${Math.max(4, (total / maxHour) * 100)}%` }}
+                  />
+                ))}
+              </div>
+              <div className="flex justify-between text-xs text-zinc-400">
+                <span>12am</span>
+                <span>6am</span>
+                <span>12pm</span>
+                <span>6pm</span>
+                <span>11pm</span>
+              </div>
+            </div>
+
+            {/* Per Admin */}
+            {analytics && analytics.admins.length > 0 && (
+              <table className="w-full text-sm">
+                <thead>
+                  <tr className="text-left text-xs text-zinc-500 border-b border-zinc-200">
+                    <th className="py-2 font-medium">Team member</th>
+                    <th className="py-2 font-medium text-right">Median response</th>
+                    <th className="py-2 font-medium text-right">Replies</th>
+                    <th className="py-2 font-medium text-right">Logged</th>
+                    <th className="py-2 font-medium text-right">Busiest hour</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {analytics.admins.map((entry) => (
+                    <tr key={entry.admin.id} className="border-b border-zinc-100 last:border-0">
+                      <td className="py-2 text-zinc-900">
+                        {entry.admin.full_name || entry.admin.email}
+                      </td>
+                      <td className="py-2 text-right text-zinc-700">
+                        {formatMedian(entry.responseTime)}
+                      </td>
+                      <td className="py-2 text-right text-zinc-700">
+                        {entry.responseTime.responses}
+                      </td>
+                      <td className="py-2 text-right text-zinc-700">{entry.volume.total}</td>
+                      <td className="py-2 text-right text-zinc-700">
+                        {entry.activity.busiestHours[0]
+                          ? formatHour(entry.activity.busiestHours[0].hour)
+                          : '—'}
+                      </td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            )}
+          </>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
+
+// =============================================================================
+// Stat
+// =============================================================================
+
+function Stat({ label, value }: { label: string; value: string }) {
+  return (
+    <div>
+      <p className="text-xs text-zinc-500">{label}</p>
+      <p className="text-lg font-semibold text-zinc-900 mt-0.5">{value}</p>
+    </div>
+  );
+}
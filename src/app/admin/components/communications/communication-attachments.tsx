@@ -0,0 +1,176 @@
+'use client';
+
+/**
+ * Communication Attachment Components
+ *
+ * @file src/components/communications/communication-attachments.tsx
+ *
+ * AttachmentPicker chooses files to upload with a communication;
+ * AttachmentList shows a communication's attachments and opens them
+ * through short-lived download links.
+ */
+
+import * as React from 'react';
+import { FileText, Image as ImageIcon, Loader2, Paperclip, X } from 'lucide-react';
+import { toast } from 'sonner';
+import { cn } from '@/lib/utils';
+import { getAttachmentDownloadUrl } from '@/app/actions/communications';
+import type { CommunicationAttachment } from '@/lib/types/communication';
+import {
+  ATTACHMENT_ACCEPT,
+  MAX_ATTACHMENTS_PER_UPLOAD,
+  validateAttachmentFiles,
+} from '@/lib/validations/attachment';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function formatFileSize(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${Math.round(bytes / 1024)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+function FileTypeIcon({ contentType }: { contentType: string }) {
+  return contentType.startsWith('image/') ? (
+    <ImageIcon className="w-3.5 h-3.5 flex-shrink-0" />
+  ) : (
+    <FileText className="w-3.5 h-3.5 flex-shrink-0" />
+  );
+}
+
+// =============================================================================
+// Attachment Picker
+// =============================================================================
+
+interface AttachmentPickerProps {
+  files: File[];
+  onChange: (files: File[]) => void;
+  disabled?: boolean;
+}
+
+export function AttachmentPicker({ files, onChange, disabled }: AttachmentPickerProps) {
+  const inputRef = React.useRef<HTMLInputElement>(null);
+
+  const handleSelect = (event: React.ChangeEvent<HTMLInputElement>) => {
+    const selected = [...files, ...Array.from(event.target.files ?? [])];
+    event.target.value = '';
+
+    const error = validateAttachmentFiles(selected);
+    if (error) {
+      toast.error(error);
+      return;
+    }
+
+    onChange(selected);
+  };
+
+  return (
+    <div className="space-y-2">
+      {files.length > 0 && (
+        <ul className="space-y-1">
+          {files.map((file, index) => (
+            <li
+              key={`${file.name}-${index}`}
+              className="flex items-center gap-2 px-2.5 py-1.5 rounded-md border border-zinc-200 text-sm text-zinc-700"
+            >
+              <FileTypeIcon contentType={file.type} />
+              <span className="truncate flex-1">{file.name}</span>
+              <span className="text-xs text-zinc-400">{formatFileSize(file.size)}</span>
+              <button
+                type="button"
+                onClick={() => onChange(files.filter((_, i) => i !== index))}
+                disabled={disabled}
+                className="text-zinc-400 hover:text-zinc-600"
+                aria-label={`Remove ${file.name}`}
+              >
+                <X className="w-4 h-4" />
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {files.length < MAX_ATTACHMENTS_PER_UPLOAD && (
+        <button
+          type="button"
+          onClick={() => inputRef.current?.click()}
+          disabled={disabled}
+          className="inline-flex items-center gap-1.5 text-sm text-zinc-600 hover:text-zinc-900"
+        >
+          <Paperclip className="w-4 h-4" />
+          Attach photos or PDFs
+        </button>
+      )}
+
+      <input
+        ref={inputRef}
+        type="file"
+        accept={ATTACHMENT_ACCEPT}
+        multiple
+        onChange={handleSelect}
+        className="hidden"
+      />
+    </div>
+  );
+}
+
+// =============================================================================
+// Attachment List
+// =============================================================================
+
+interface AttachmentListProps {
+  attachments: CommunicationAttachment[];
+  className?: string;
+}
+
+export function AttachmentList({ attachments, className }: AttachmentListProps) {
+  const [openingId, setOpeningId] = React.useState<string | null>(null);
+
+  if (attachments.length === 0) {
+    return null;
+  }
+
+  const handleOpen = async (attachment: CommunicationAttachment) => {
+    setOpeningId(attachment.id);
+
+    try {
+      const result = await getAttachmentDownloadUrl(attachment.id);
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to open attachment');
+        return;
+      }
+
+      window.open(result.data.url, '_blank', 'noopener,noreferrer');
+    } catch (error) {
+      console.error('Failed to open attachment:', error);
+      toast.error('Failed to open attachment');
+    } finally {
+      setOpeningId(null);
+    }
+  };
+
+  return (
+    <div className={cn('flex flex-wrap gap-1.5', className)}>
+      {attachments.map((attachment) => (
+        <button
+          key={attachment.id}
+          type="button"
+          onClick={() => handleOpen(attachment)}
+          disabled={openingId === attachment.id}
+          title={`${attachment.filename} (${formatFileSize(attachment.size_bytes)})`}
+          className="inline-flex items-center gap-1.5 max-w-[220px] px-2 py-1 rounded border border-zinc-200 bg-zinc-50 text-xs text-zinc-700 hover:bg-zinc-100"
+        >
+          {openingId === attachment.id ? (
+            <Loader2 className="w-3.5 h-3.5 animate-spin flex-shrink-0" />
+          ) : (
+            <FileTypeIcon contentType={attachment.content_type} />
+          )}
+          <span className="truncate">{attachment.filename}</span>
+        </button>
+      ))}
+    </div>
+  );
+}
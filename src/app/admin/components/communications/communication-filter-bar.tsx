@@ -0,0 +1,293 @@
+'use client';
+
+/**
+ * Communication Filter Bar
+ *
+ * @file src/components/communications/communication-filter-bar.tsx
+ *
+ * Filters shared by the customer communications list and communication
+ * search: free text, several types and directions, who logged the entry,
+ * customer tags, attachments and call outcome.
+ */
+
+import * as React from 'react';
+import { ChevronDown, Loader2, Search, X } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import {
+  DropdownMenu,
+  DropdownMenuCheckboxItem,
+  DropdownMenuContent,
+  DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { cn } from '@/lib/utils';
+import type {
+  CommunicationFilterOptions,
+  CommunicationFilters,
+} from '@/lib/types/communication';
+import {
+  callOutcomeOptions,
+  communicationDirectionOptions,
+  communicationTypeOptions,
+} from '@/lib/validations/communication';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Whether any filter is set
+ */
+export function hasActiveFilters(filters: CommunicationFilters): boolean {
+  return Object.values(filters).some(
+    (value) =>
+      value !== undefined && value !== '' && !(Array.isArray(value) && value.length === 0)
+  );
+}
+
+function toggle<T>(values: T[] | undefined, value: T): T[] {
+  const current = values ?? [];
+  return current.includes(value)
+    ? current.filter((item) => item !== value)
+    : [...current, value];
+}
+
+// =============================================================================
+// Multi-Select Filter
+// =============================================================================
+
+interface MultiSelectFilterProps<T extends string> {
+  label: string;
+  options: readonly { value: T; label: string }[];
+  selected: T[] | undefined;
+  onChange: (selected: T[]) => void;
+}
+
+export function MultiSelectFilter<T extends string>({
+  label,
+  options,
+  selected,
+  onChange,
+}: MultiSelectFilterProps<T>) {
+  const count = selected?.length ?? 0;
+  const summary =
+    count === 0
+      ? label
+      : count === 1
+        ? options.find((option) => option.value === selected?.[0])?.label ?? label
+        : `${label} (${count})`;
+
+  return (
+    <DropdownMenu>
+      <DropdownMenuTrigger asChild>
+        <Button
+          variant="secondary"
+          size="sm"
+          className={cn('h-10 justify-between gap-1.5', count > 0 && 'text-zinc-900')}
+        >
+          <span className="truncate max-w-[140px]">{summary}</span>
+          <ChevronDown className="w-4 h-4 text-zinc-400" />
+        </Button>
+      </DropdownMenuTrigger>
+      <DropdownMenuContent align="start" className="max-h-72 overflow-y-auto">
+        {options.length === 0 ? (
+          <p className="px-2 py-1.5 text-sm text-zinc-500">Nothing to filter by</p>
+        ) : (
+          options.map((option) => (
+            <DropdownMenuCheckboxItem
+              key={option.value}
+              checked={selected?.includes(option.value) ?? false}
+              onCheckedChange={() => onChange(toggle(selected, option.value))}
+              // Keep the menu open while picking several values
+              onSelect={(event) => event.preventDefault()}
+            >
+              {option.label}
+            </DropdownMenuCheckboxItem>
+          ))
+        )}
+      </DropdownMenuContent>
+    </DropdownMenu>
+  );
+}
+
+// =============================================================================
+// Filter Bar
+// =============================================================================
+
+interface CommunicationFilterBarProps {
+  filters: CommunicationFilters;
+  onFiltersChange: (filters: CommunicationFilters) => void;
+  onClear: () => void;
+  isFiltering: boolean;
+  /** Admins and tags; their filters are hidden until loaded */
+  options: CommunicationFilterOptions | null;
+  /** Show the free-text box (search pages supply their own query) */
+  showSearch?: boolean;
+  /** Show the customer tag filter (pointless within one customer) */
+  showTags?: boolean;
+  /** Extra controls, e.g. the saved views menu */
+  children?: React.ReactNode;
+}
+
+export function CommunicationFilterBar({
+  filters,
+  onFiltersChange,
+  onClear,
+  isFiltering,
+  options,
+  showSearch = true,
+  showTags = true,
+  children,
+}: CommunicationFilterBarProps) {
+  const types = [...new Set([...(filters.types ?? []), ...(filters.type ? [filters.type] : [])])];
+  const directions = [
+    ...new Set([...(filters.directions ?? []), ...(filters.direction ? [filters.direction] : [])]),
+  ];
+
+  const adminOptions = (options?.admins ?? []).map((admin) => ({
+    value: admin.id,
+    label: admin.full_name || admin.email,
+  }));
+  const tagOptions = (options?.tags ?? []).map((tag) => ({ value: tag.id, label: tag.name }));
+
+  const attachmentValue =
+    filters.hasAttachments === undefined ? 'any' : filters.hasAttachments ? 'with' : 'without';
+
+  return (
+    <div className="flex flex-wrap items-center gap-3 mb-4">
+      {/* Search */}
+      {showSearch && (
+        <div className="relative flex-1 min-w-[200px]">
+          <Search className="absolute left-3 top-1/2 -translate-y-1/2 w-4 h-4 text-zinc-400" />
+          <Input
+            placeholder="Search communications..."
+            value={filters.search ?? ''}
+            onChange={(e) => onFiltersChange({ ...filters, search: e.target.value })}
+            className="pl-9"
+          />
+        </div>
+      )}
+
+      {/* Type Filter */}
+      <MultiSelectFilter
+        label="All Types"
+        options={communicationTypeOptions}
+        selected={types}
+        onChange={(selected) =>
+          onFiltersChange({
+            ...filters,
+            type: undefined,
+            types: selected,
+            // Outcome only applies to calls
+            callOutcome: selected.includes('call') ? filters.callOutcome : undefined,
+          })
+        }
+      />
+
+      {/* Direction Filter */}
+      <MultiSelectFilter
+        label="All Directions"
+        options={communicationDirectionOptions}
+        selected={directions}
+        onChange={(selected) =>
+          onFiltersChange({ ...filters, direction: undefined, directions: selected })
+        }
+      />
+
+      {/* Logged By Filter */}
+      {options && (
+        <MultiSelectFilter
+          label="Anyone"
+          options={adminOptions}
+          selected={filters.loggedBy}
+          onChange={(selected) => onFiltersChange({ ...filters, loggedBy: selected })}
+        />
+      )}
+
+      {/* Customer Tag Filter */}
+      {options && showTags && (
+        <MultiSelectFilter
+          label="All Tags"
+          options={tagOptions}
+          selected={filters.tagIds}
+          onChange={(selected) => onFiltersChange({ ...filters, tagIds: selected })}
+        />
+      )}
+
+      {/* Attachments Filter */}
+      <Select
+        value={attachmentValue}
+        onValueChange={(value) =>
+          onFiltersChange({
+            ...filters,
+            hasAttachments: value === 'any' ? undefined : value === 'with',
+          })
+        }
+      >
+        <SelectTrigger className="w-[170px]">
+          <SelectValue placeholder="Attachments" />
+        </SelectTrigger>
+        <SelectContent>
+          <SelectItem value="any">Any Attachments</SelectItem>
+          <SelectItem value="with">With Attachments</SelectItem>
+          <SelectItem value="without">Without Attachments</SelectItem>
+        </SelectContent>
+      </Select>
+
+      {/* Call Outcome Filter */}
+      {types.includes('call') && (
+        <Select
+          value={filters.callOutcome ?? 'all'}
+          onValueChange={(value) =>
+            onFiltersChange({
+              ...filters,
+              callOutcome:
+                value === 'all' ? undefined : (value as CommunicationFilters['callOutcome']),
+            })
+          }
+        >
+          <SelectTrigger className="w-[160px]">
+            <SelectValue placeholder="Outcome" />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="all">All Outcomes</SelectItem>
+            {callOutcomeOptions.map((option) => (
+              <SelectItem key={option.value} value={option.value}>
+                {option.label}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      )}
+
+      {children}
+
+      {/* Clear Filters */}
+      {hasActiveFilters(filters) && (
+        <Button
+          variant="ghost"
+          size="sm"
+          onClick={onClear}
+          className="text-zinc-500 hover:text-zinc-900"
+        >
+          <X className="w-4 h-4 mr-1" />
+          Clear
+        </Button>
+      )}
+
+      {/* Loading Indicator */}
+      {isFiltering && (
+        <div className="flex items-center text-zinc-400">
+          <Loader2 className="w-4 h-4 animate-spin" />
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,212 @@
+'use client';
+
+/**
+ * Communication History Modal
+ *
+ * @file src/components/communications/communication-history-modal.tsx
+ *
+ * Shows a communication's edit history newest first: who edited it, when,
+ * and what changed, with a word-level diff for the summary. Any revision's
+ * previous values can be restored.
+ */
+
+import * as React from 'react';
+import { History, Loader2, RotateCcw } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '@/components/ui/dialog';
+import { cn } from '@/lib/utils';
+import { diffWords } from '@/lib/utils/diff';
+import { formatDateTime } from '@/lib/utils/timezone';
+import {
+  listCommunicationRevisions,
+  restoreCommunicationRevision,
+} from '@/app/actions/communications';
+import type {
+  CommunicationRevisionEntry,
+  CommunicationRevisionFieldChange,
+  CommunicationWithLogger,
+} from '@/lib/types/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface CommunicationHistoryModalProps {
+  communication: CommunicationWithLogger;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  onRestored: (communication: CommunicationWithLogger) => void;
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function CommunicationHistoryModal({
+  communication,
+  open,
+  onOpenChange,
+  onRestored,
+}: CommunicationHistoryModalProps) {
+  const [revisions, setRevisions] = React.useState<CommunicationRevisionEntry[] | null>(null);
+  const [restoringId, setRestoringId] = React.useState<string | null>(null);
+
+  const loadRevisions = React.useCallback(async () => {
+    try {
+      const result = await listCommunicationRevisions(communication.id);
+
+      if (result.success) {
+        setRevisions(result.data);
+      } else {
+        toast.error(result.error || 'Failed to load edit history');
+      }
+    } catch (error) {
+      console.error('Failed to load edit history:', error);
+      toast.error('Failed to load edit history');
+    }
+  }, [communication.id]);
+
+  React.useEffect(() => {
+    if (open) {
+      setRevisions(null);
+      loadRevisions();
+    }
+  }, [open, loadRevisions]);
+
+  const handleRestore = async (revision: CommunicationRevisionEntry) => {
+    setRestoringId(revision.id);
+
+    try {
+      const result = await restoreCommunicationRevision({ revisionId: revision.id });
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to restore revision');
+        return;
+      }
+
+      toast.success('Previous version restored');
+      onRestored(result.data);
+      loadRevisions();
+    } catch (error) {
+      console.error('Failed to restore revision:', error);
+      toast.error('Failed to restore revision');
+    } finally {
+      setRestoringId(null);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[600px] max-h-[85vh] overflow-y-auto">
+        <DialogHeader>
+          <DialogTitle>Edit History</DialogTitle>
+          <DialogDescription>
+            Logged by {communication.logged_by_admin?.full_name ?? 'Unknown'} on{' '}
+            {formatDateTime(communication.created_at, 'mediumWithTime')}
+          </DialogDescription>
+        </DialogHeader>
+
+        {revisions === null ? (
+          <div className="flex justify-center py-8 text-zinc-400">
+            <Loader2 className="w-5 h-5 animate-spin" />
+          </div>
+        ) : revisions.length === 0 ? (
+          <div className="flex flex-col items-center py-8 text-center">
+            <History className="w-6 h-6 text-zinc-300" />
+            <p className="text-sm text-zinc-500 mt-2">This communication has not been edited.</p>
+          </div>
+        ) : (
+          <ol className="space-y-4">
+            {revisions.map((revision) => (
+              <li key={revision.id} className="border border-zinc-200 rounded-lg p-4 space-y-3">
+                {/* Header */}
+                <div className="flex items-start justify-between gap-4">
+                  <div className="text-sm">
+                    <p className="font-medium text-zinc-900">
+                      {revision.editor?.full_name || revision.editor?.email || 'System'}
+                      {revision.restored_from && (
+                        <span className="font-normal text-zinc-500"> restored a version</span>
+                      )}
+                    </p>
+                    <p className="text-xs text-zinc-500">
+                      Edit #{revision.revision_number} ·{' '}
+                      {formatDateTime(revision.created_at, 'mediumWithTime')}
+                    </p>
+                  </div>
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => handleRestore(revision)}
+                    disabled={restoringId !== null}
+                    title="Put back the values this edit replaced"
+                  >
+                    {restoringId === revision.id ? (
+                      <Loader2 className="w-4 h-4 mr-1.5 animate-spin" />
+                    ) : (
+                      <RotateCcw className="w-4 h-4 mr-1.5" />
+                    )}
+                    Restore previous
+                  </Button>
+                </div>
+
+                {/* Changes */}
+                <dl className="space-y-2">
+                  {revision.changes.map((change) => (
+                    <RevisionChange key={change.field} change={change} />
+                  ))}
+                </dl>
+              </li>
+            ))}
+          </ol>
+        )}
+      </DialogContent>
+    </Dialog>
+  );
+}
+
+// =============================================================================
+// Revision Change
+// =============================================================================
+
+function RevisionChange({ change }: { change: CommunicationRevisionFieldChange }) {
+  if (change.field === 'summary') {
+    const segments = diffWords(String(change.from ?? ''), String(change.to ?? ''));
+
+    return (
+      <div>
+        <dt className="text-xs font-medium text-zinc-500">{change.label}</dt>
+        <dd className="mt-1 text-sm text-zinc-700 whitespace-pre-wrap break-words">
+          {segments.map((segment, i) => (
+            <span
+              key={i}
+              className={cn(
+                segment.type === 'added' && 'bg-emerald-50 text-emerald-800',
+                segment.type === 'removed' && 'bg-red-50 text-red-700 line-through'
+              )}
+            >
+              {segment.text}
+            </span>
+          ))}
+        </dd>
+      </div>
+    );
+  }
+
+  return (
+    <div>
+      <dt className="text-xs font-medium text-zinc-500">{change.label}</dt>
+      <dd className="mt-1 text-sm">
+        <span className="text-red-700 line-through">{change.fromFormatted}</span>
+        <span className="text-zinc-400"> → </span>
+        <span className="text-emerald-800">{change.toFormatted}</span>
+      </dd>
+    </div>
+  );
+}
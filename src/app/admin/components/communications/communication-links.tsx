@@ -0,0 +1,281 @@
+'use client';
+
+/**
+ * Communication Link Components
+ *
+ * @file src/components/communications/communication-links.tsx
+ *
+ * CommunicationLinkFields picks the estimate, appointment and property or
+ * pool a communication is about; CommunicationLinkList shows those links
+ * on a logged communication.
+ */
+
+import * as React from 'react';
+import Link from 'next/link';
+import { Calendar, Droplets, FileText, Home, Loader2 } from 'lucide-react';
+import { Label } from '@/components/ui/label';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { cn } from '@/lib/utils';
+import { formatDateTime } from '@/lib/utils/timezone';
+import { getCommunicationLinkOptions } from '@/app/actions/communications';
+import {
+  linkedPoolTypeLabels,
+  type CommunicationLinkOptions,
+  type CommunicationLinkedPool,
+  type CommunicationWithLogger,
+} from '@/lib/types/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/**
+ * Linked record IDs as edited in the form; null means not linked
+ */
+export interface CommunicationLinkValues {
+  estimateId: string | null;
+  calendarEventId: string | null;
+  propertyId: string | null;
+  poolId: string | null;
+}
+
+export const EMPTY_COMMUNICATION_LINKS: CommunicationLinkValues = {
+  estimateId: null,
+  calendarEventId: null,
+  propertyId: null,
+  poolId: null,
+};
+
+const NONE = 'none';
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * The links a communication was saved with, for its edit form
+ */
+export function getCommunicationLinkValues(
+  communication: CommunicationWithLogger
+): CommunicationLinkValues {
+  return {
+    estimateId: communication.estimate_id,
+    calendarEventId: communication.calendar_event_id,
+    propertyId: communication.property_id,
+    poolId: communication.pool_id,
+  };
+}
+
+function formatPool(pool: Pick<CommunicationLinkedPool, 'type'>): string {
+  return `${linkedPoolTypeLabels[pool.type] ?? 'Other'} pool`;
+}
+
+// =============================================================================
+// Link Fields
+// =============================================================================
+
+interface CommunicationLinkFieldsProps {
+  customerId: string;
+  value: CommunicationLinkValues;
+  onChange: (value: CommunicationLinkValues) => void;
+  disabled?: boolean;
+}
+
+export function CommunicationLinkFields({
+  customerId,
+  value,
+  onChange,
+  disabled,
+}: CommunicationLinkFieldsProps) {
+  const [options, setOptions] = React.useState<CommunicationLinkOptions | null>(null);
+
+  React.useEffect(() => {
+    let cancelled = false;
+
+    getCommunicationLinkOptions(customerId)
+      .then((result) => {
+        if (!cancelled && result.success) setOptions(result.data);
+      })
+      .catch((error) => console.error('Failed to load link options:', error));
+
+    return () => {
+      cancelled = true;
+    };
+  }, [customerId]);
+
+  if (!options) {
+    return (
+      <div className="flex items-center gap-2 text-sm text-zinc-500">
+        <Loader2 className="w-4 h-4 animate-spin" />
+        Loading related records...
+      </div>
+    );
+  }
+
+  const { estimates, events, properties } = options;
+
+  if (estimates.length === 0 && events.length === 0 && properties.length === 0) {
+    return <p className="text-sm text-zinc-500">This customer has nothing to link yet.</p>;
+  }
+
+  // Property and pool share one select; choosing a pool also sets its property
+  const placeValue = value.poolId
+    ? `pool:${value.poolId}`
+    : value.propertyId
+      ? `property:${value.propertyId}`
+      : NONE;
+
+  const handlePlaceChange = (next: string) => {
+    if (next === NONE) {
+      onChange({ ...value, propertyId: null, poolId: null });
+      return;
+    }
+
+    const [kind, id] = next.split(':');
+    if (kind === 'pool') {
+      const property = properties.find((p) => p.pools.some((pool) => pool.id === id));
+      onChange({ ...value, propertyId: property?.id ?? null, poolId: id });
+    } else {
+      onChange({ ...value, propertyId: id, poolId: null });
+    }
+  };
+
+  return (
+    <div className="grid grid-cols-1 gap-3">
+      {/* Estimate */}
+      <div className="space-y-2">
+        <Label>Estimate</Label>
+        <Select
+          value={value.estimateId ?? NONE}
+          onValueChange={(next) => onChange({ ...value, estimateId: next === NONE ? null : next })}
+          disabled={disabled || estimates.length === 0}
+        >
+          <SelectTrigger>
+            <SelectValue placeholder="No estimate" />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value={NONE}>No estimate</SelectItem>
+            {estimates.map((estimate) => (
+              <SelectItem key={estimate.id} value={estimate.id}>
+                {estimate.estimate_number}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+
+      {/* Appointment */}
+      <div className="space-y-2">
+        <Label>Appointment</Label>
+        <Select
+          value={value.calendarEventId ?? NONE}
+          onValueChange={(next) =>
+            onChange({ ...value, calendarEventId: next === NONE ? null : next })
+          }
+          disabled={disabled || events.length === 0}
+        >
+          <SelectTrigger>
+            <SelectValue placeholder="No appointment" />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value={NONE}>No appointment</SelectItem>
+            {events.map((event) => (
+              <SelectItem key={event.id} value={event.id}>
+                {event.title} · {formatDateTime(event.start_datetime, 'medium')}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+
+      {/* Property / Pool */}
+      <div className="space-y-2">
+        <Label>Property</Label>
+        <Select
+          value={placeValue}
+          onValueChange={handlePlaceChange}
+          disabled={disabled || properties.length === 0}
+        >
+          <SelectTrigger>
+            <SelectValue placeholder="No property" />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value={NONE}>No property</SelectItem>
+            {properties.map((property) => (
+              <React.Fragment key={property.id}>
+                <SelectItem value={`property:${property.id}`}>
+                  {property.address_line1}, {property.city}
+                </SelectItem>
+                {property.pools.map((pool) => (
+                  <SelectItem key={pool.id} value={`pool:${pool.id}`} className="pl-8">
+                    {formatPool(pool)}
+                  </SelectItem>
+                ))}
+              </React.Fragment>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+    </div>
+  );
+}
+
+// =============================================================================
+// Link List
+// =============================================================================
+
+interface CommunicationLinkListProps {
+  communication: CommunicationWithLogger;
+  className?: string;
+}
+
+export function CommunicationLinkList({ communication, className }: CommunicationLinkListProps) {
+  const { estimate, calendar_event: event, property, pool } = communication;
+
+  if (!estimate && !event && !property && !pool) {
+    return null;
+  }
+
+  const chipClassName =
+    'inline-flex items-center gap-1.5 max-w-[240px] px-2 py-1 rounded border border-zinc-200 bg-white text-xs text-zinc-700';
+
+  return (
+    <div className={cn('flex flex-wrap gap-1.5', className)}>
+      {estimate && (
+        <Link
+          href={`/admin/estimates/${estimate.id}`}
+          className={cn(chipClassName, 'hover:bg-zinc-50')}
+        >
+          <FileText className="w-3.5 h-3.5 flex-shrink-0" />
+          <span className="truncate">Estimate {estimate.estimate_number}</span>
+        </Link>
+      )}
+      {event && (
+        <span className={chipClassName} title={event.title}>
+          <Calendar className="w-3.5 h-3.5 flex-shrink-0" />
+          <span className="truncate">
+            {event.title} · {formatDateTime(event.start_datetime, 'medium')}
+          </span>
+        </span>
+      )}
+      {property && (
+        <span className={chipClassName} title={`${property.address_line1}, ${property.city}`}>
+          <Home className="w-3.5 h-3.5 flex-shrink-0" />
+          <span className="truncate">{property.address_line1}</span>
+        </span>
+      )}
+      {pool && (
+        <span className={chipClassName}>
+          <Droplets className="w-3.5 h-3.5 flex-shrink-0" />
+          <span className="truncate">{formatPool(pool)}</span>
+        </span>
+      )}
+    </div>
+  );
+}
@@ -5,28 +5,45 @@
  *
  * @file src/components/communications/communication-list.tsx
  *
- * Displays a paginated, filterable list of communications for a customer.
- * Includes type/direction filters, search, and date range filtering.
+ * Displays a paginated, filterable list of conversation threads for a customer.
+ * Uses the shared communication filter bar and saved views; a `view` query
+ * parameter opens a saved view. Each thread shows its communications
+ * oldest first with reply actions.
  */
 
 import * as React from 'react';
+import { useSearchParams } from 'next/navigation';
 import { format, formatDistanceToNow } from 'date-fns';
 import {
   Phone,
   MessageSquare,
   Mail,
+  Voicemail,
+  Users,
+  Globe,
+  AtSign,
   ArrowDownLeft,
   ArrowUpRight,
-  Search,
   Filter,
   MoreHorizontal,
   Pencil,
   Trash2,
   Loader2,
-  X,
+  Reply,
+  CheckCircle2,
+  CircleDot,
+  PhoneMissed,
+  PhoneCall,
+  Send,
+  AlertCircle,
+  Paperclip,
+  CalendarClock,
+  CalendarX,
+  Download,
+  History,
+  ArchiveRestore,
 } from 'lucide-react';
 import { Button } from '@/components/ui/button';
-import { Input } from '@/components/ui/input';
 import {
   DropdownMenu,
   DropdownMenuContent,
@@ -34,23 +51,43 @@ import {
   DropdownMenuSeparator,
   DropdownMenuTrigger,
 } from '@/components/ui/dropdown-menu';
-import {
-  Select,
-  SelectContent,
-  SelectItem,
-  SelectTrigger,
-  SelectValue,
-} from '@/components/ui/select';
 import { cn } from '@/lib/utils';
 import { toast } from 'sonner';
 import {
   listCommunications,
   deleteCommunication,
+  restoreCommunication,
+  updateThreadStatus,
+  completeCallback,
 } from '@/app/actions/communications';
-import type { CommunicationWithLogger } from '@/lib/types/communication';
+import {
+  getCommunicationFilterOptions,
+  getCommunicationView,
+} from '@/app/actions/communication-views';
+import {
+  communicationThreadStatusColors,
+  communicationTypeColors,
+  communicationTypeLabels,
+  callOutcomeColors,
+  deliveryStatusColors,
+  toCommunicationDisplay,
+  type CommunicationFilterOptions,
+  type CommunicationFilters,
+  type CommunicationWithLogger,
+  type CommunicationThread,
+  type SavedCommunicationView,
+} from '@/lib/types/communication';
 import type { Communication } from '@/lib/types/database';
 import { LogCommunicationModal } from './log-communication-modal';
 import { EditCommunicationModal } from './edit-communication-modal';
+import { CommunicationHistoryModal } from './communication-history-modal';
+import { CommunicationTrashModal } from './communication-trash-modal';
+import { SendMessageModal } from './send-message-modal';
+import { AttachmentList } from './communication-attachments';
+import { CommunicationLinkList } from './communication-links';
+import { CommunicationFilterBar, hasActiveFilters } from './communication-filter-bar';
+import { SavedViewsMenu } from './saved-views-menu';
+import { ExportCommunicationsDialog } from './export-communications-dialog';
 import {
   AlertDialog,
   AlertDialogAction,
@@ -69,14 +106,11 @@ import {
 interface CommunicationListProps {
   customerId: string;
   customerName: string;
-  initialCommunications?: CommunicationWithLogger[];
+  customerEmail: string | null;
+  initialThreads?: CommunicationThread[];
 }
 
-interface Filters {
-  type: Communication['type'] | 'all';
-  direction: Communication['direction'] | 'all';
-  search: string;
-}
+const EMPTY_FILTERS: CommunicationFilters = {};
 
 // =============================================================================
 // Helper Functions
@@ -90,18 +124,20 @@ function getTypeIcon(type: Communication['type']) {
       return <MessageSquare className="w-4 h-4" />;
     case 'email':
       return <Mail className="w-4 h-4" />;
+    case 'voicemail':
+      return <Voicemail className="w-4 h-4" />;
+    case 'in_person':
+      return <Users className="w-4 h-4" />;
+    case 'web_form':
+      return <Globe className="w-4 h-4" />;
+    case 'social':
+      return <AtSign className="w-4 h-4" />;
   }
 }
 
 function getTypeColors(type: Communication['type']) {
-  switch (type) {
-    case 'call':
-      return 'bg-blue-50 text-blue-700 border-blue-200';
-    case 'text':
-      return 'bg-emerald-50 text-emerald-700 border-emerald-200';
-    case 'email':
-      return 'bg-amber-50 text-amber-700 border-amber-200';
-  }
+  const colors = communicationTypeColors[type];
+  return cn(colors.bg, colors.text, colors.border);
 }
 
 function getDirectionIcon(direction: Communication['direction']) {
@@ -120,13 +156,25 @@ interface CommunicationItemProps {
   communication: CommunicationWithLogger;
   onEdit: (communication: CommunicationWithLogger) => void;
   onDelete: (communication: CommunicationWithLogger) => void;
+  onReply: (communication: CommunicationWithLogger) => void;
+  onCompleteCallback: (communication: CommunicationWithLogger) => void;
+  onViewHistory: (communication: CommunicationWithLogger) => void;
 }
 
-function CommunicationItem({ communication, onEdit, onDelete }: CommunicationItemProps) {
+function CommunicationItem({
+  communication,
+  onEdit,
+  onDelete,
+  onReply,
+  onCompleteCallback,
+  onViewHistory,
+}: CommunicationItemProps) {
   const occurredDate = new Date(communication.occurred_at);
   const formattedDate = format(occurredDate, 'MMM d, yyyy');
   const formattedTime = format(occurredDate, 'h:mm a');
   const relativeTime = formatDistanceToNow(occurredDate, { addSuffix: true });
+  const { call, delivery, attachmentCount, followUp, edited } =
+    toCommunicationDisplay(communication);
 
   return (
     <div className="group p-4 bg-white border border-zinc-200 rounded-lg hover:border-zinc-300 transition-colors">
@@ -147,8 +195,8 @@ function CommunicationItem({ communication, onEdit, onDelete }: CommunicationIte
           <div className="min-w-0 flex-1">
             {/* Header row */}
             <div className="flex items-center gap-2 mb-1">
-              <span className="text-sm font-medium text-zinc-900 capitalize">
-                {communication.type}
+              <span className="text-sm font-medium text-zinc-900">
+                {communicationTypeLabels[communication.type]}
               </span>
               <span
                 className={cn(
@@ -159,6 +207,70 @@ function CommunicationItem({ communication, onEdit, onDelete }: CommunicationIte
                 {getDirectionIcon(communication.direction)}
                 <span className="capitalize">{communication.direction}</span>
               </span>
+              {call?.outcome && call.outcomeLabel && (
+                <span
+                  className={cn(
+                    'inline-flex items-center px-1.5 py-0.5 text-xs rounded',
+                    callOutcomeColors[call.outcome].bg,
+                    callOutcomeColors[call.outcome].text
+                  )}
+                >
+                  {call.outcomeLabel}
+                </span>
+              )}
+              {call?.durationFormatted && (
+                <span className="text-xs text-zinc-500">{call.durationFormatted}</span>
+              )}
+              {call?.numberFormatted && (
+                <span className="text-xs text-zinc-400">{call.numberFormatted}</span>
+              )}
+              {call?.needsCallback && (
+                <span className="inline-flex items-center gap-1 px-1.5 py-0.5 text-xs font-medium rounded bg-red-50 text-red-700">
+                  <PhoneMissed className="w-3 h-3" />
+                  Needs callback
+                </span>
+              )}
+              {delivery && (
+                <span
+                  className={cn(
+                    'inline-flex items-center gap-1 px-1.5 py-0.5 text-xs rounded',
+                    deliveryStatusColors[delivery.status].bg,
+                    deliveryStatusColors[delivery.status].text
+                  )}
+                  title={delivery.error ?? undefined}
+                >
+                  {delivery.status === 'failed' && <AlertCircle className="w-3 h-3" />}
+                  {delivery.statusLabel}
+                </span>
+              )}
+              {followUp && (
+                <span
+                  className={cn(
+                    'inline-flex items-center gap-1 px-1.5 py-0.5 text-xs rounded',
+                    followUp.isCompleted
+                      ? 'bg-green-50 text-green-700'
+                      : followUp.status === 'canceled'
+                        ? 'bg-zinc-100 text-zinc-500 line-through'
+                        : 'bg-blue-50 text-blue-700'
+                  )}
+                  title={`${followUp.title} · ${followUp.statusLabel}`}
+                >
+                  {followUp.isCompleted ? (
+                    <CheckCircle2 className="w-3 h-3" />
+                  ) : followUp.status === 'canceled' ? (
+                    <CalendarX className="w-3 h-3" />
+                  ) : (
+                    <CalendarClock className="w-3 h-3" />
+                  )}
+                  {followUp.isCompleted ? 'Follow-up done' : `Follow-up ${followUp.startFormatted}`}
+                </span>
+              )}
+              {attachmentCount > 0 && (
+                <span className="inline-flex items-center gap-1 text-xs text-zinc-500">
+                  <Paperclip className="w-3 h-3" />
+                  {attachmentCount}
+                </span>
+              )}
             </div>
 
             {/* Summary */}
@@ -166,6 +278,12 @@ function CommunicationItem({ communication, onEdit, onDelete }: CommunicationIte
               {communication.summary}
             </p>
 
+            {/* Attachments */}
+            <AttachmentList attachments={communication.attachments ?? []} className="mt-2" />
+
+            {/* Linked Records */}
+            <CommunicationLinkList communication={communication} className="mt-2" />
+
             {/* Footer row */}
             <div className="flex items-center gap-3 mt-2 text-xs text-zinc-400">
               <span title={`${formattedDate} at ${formattedTime}`}>
@@ -177,6 +295,19 @@ function CommunicationItem({ communication, onEdit, onDelete }: CommunicationIte
                   <span>Logged by {communication.logged_by_admin.full_name}</span>
                 </>
               )}
+              {edited && (
+                <>
+                  <span>•</span>
+                  <button
+                    type="button"
+                    onClick={() => onViewHistory(communication)}
+                    className="hover:text-zinc-600 hover:underline"
+                    title={`Edited${edited.by ? ` by ${edited.by}` : ''} on ${edited.atFormatted}`}
+                  >
+                    Edited
+                  </button>
+                </>
+              )}
             </div>
           </div>
         </div>
@@ -191,10 +322,26 @@ function CommunicationItem({ communication, onEdit, onDelete }: CommunicationIte
               </Button>
             </DropdownMenuTrigger>
             <DropdownMenuContent align="end">
+              <DropdownMenuItem onClick={() => onReply(communication)}>
+                <Reply className="w-4 h-4 mr-2" />
+                Reply
+              </DropdownMenuItem>
+              {call?.needsCallback && (
+                <DropdownMenuItem onClick={() => onCompleteCallback(communication)}>
+                  <PhoneCall className="w-4 h-4 mr-2" />
+                  Mark called back
+                </DropdownMenuItem>
+              )}
               <DropdownMenuItem onClick={() => onEdit(communication)}>
                 <Pencil className="w-4 h-4 mr-2" />
                 Edit
               </DropdownMenuItem>
+              {communication.revision_count > 0 && (
+                <DropdownMenuItem onClick={() => onViewHistory(communication)}>
+                  <History className="w-4 h-4 mr-2" />
+                  View history
+                </DropdownMenuItem>
+              )}
               <DropdownMenuSeparator />
               <DropdownMenuItem
                 onClick={() => onDelete(communication)}
@@ -212,87 +359,97 @@ function CommunicationItem({ communication, onEdit, onDelete }: CommunicationIte
 }
 
 // =============================================================================
-// Filter Bar Component
+// Thread Component
 // =============================================================================
 
-interface FilterBarProps {
-  filters: Filters;
-  onFiltersChange: (filters: Filters) => void;
-  onClear: () => void;
-  isFiltering: boolean;
+interface ThreadItemProps {
+  thread: CommunicationThread;
+  onEdit: (communication: CommunicationWithLogger) => void;
+  onDelete: (communication: CommunicationWithLogger) => void;
+  onReply: (communication: CommunicationWithLogger) => void;
+  onCompleteCallback: (communication: CommunicationWithLogger) => void;
+  onViewHistory: (communication: CommunicationWithLogger) => void;
+  onToggleStatus: (thread: CommunicationThread) => void;
 }
 
-function FilterBar({ filters, onFiltersChange, onClear, isFiltering }: FilterBarProps) {
-  const hasFilters =
-    filters.type !== 'all' || filters.direction !== 'all' || filters.search !== '';
+function ThreadItem({
+  thread,
+  onEdit,
+  onDelete,
+  onReply,
+  onCompleteCallback,
+  onViewHistory,
+  onToggleStatus,
+}: ThreadItemProps) {
+  const statusColors = communicationThreadStatusColors[thread.status];
+  const lastActivity = formatDistanceToNow(new Date(thread.last_activity_at), {
+    addSuffix: true,
+  });
+  const latest = thread.communications[thread.communications.length - 1];
 
   return (
-    <div className="flex flex-col sm:flex-row gap-3 mb-4">
-      {/* Search */}
-      <div className="relative flex-1">
-        <Search className="absolute left-3 top-1/2 -translate-y-1/2 w-4 h-4 text-zinc-400" />
-        <Input
-          placeholder="Search communications..."
-          value={filters.search}
-          onChange={(e) => onFiltersChange({ ...filters, search: e.target.value })}
-          className="pl-9"
-        />
-      </div>
-
-      {/* Type Filter */}
-      <Select
-        value={filters.type}
-        onValueChange={(value) =>
-          onFiltersChange({ ...filters, type: value as Filters['type'] })
-        }
-      >
-        <SelectTrigger className="w-[140px]">
-          <SelectValue placeholder="Type" />
-        </SelectTrigger>
-        <SelectContent>
-          <SelectItem value="all">All Types</SelectItem>
-          <SelectItem value="call">Calls</SelectItem>
-          <SelectItem value="text">Texts</SelectItem>
-          <SelectItem value="email">Emails</SelectItem>
-        </SelectContent>
-      </Select>
-
-      {/* Direction Filter */}
-      <Select
-        value={filters.direction}
-        onValueChange={(value) =>
-          onFiltersChange({ ...filters, direction: value as Filters['direction'] })
-        }
-      >
-        <SelectTrigger className="w-[140px]">
-          <SelectValue placeholder="Direction" />
-        </SelectTrigger>
-        <SelectContent>
-          <SelectItem value="all">All Directions</SelectItem>
-          <SelectItem value="inbound">Inbound</SelectItem>
-          <SelectItem value="outbound">Outbound</SelectItem>
-        </SelectContent>
-      </Select>
-
-      {/* Clear Filters */}
-      {hasFilters && (
-        <Button
-          variant="ghost"
-          size="sm"
-          onClick={onClear}
-          className="text-zinc-500 hover:text-zinc-900"
-        >
-          <X className="w-4 h-4 mr-1" />
-          Clear
-        </Button>
-      )}
-
-      {/* Loading Indicator */}
-      {isFiltering && (
-        <div className="flex items-center text-zinc-400">
-          <Loader2 className="w-4 h-4 animate-spin" />
+    <div className="space-y-2">
+      {/* Thread header */}
+      {thread.communications.length > 1 || thread.status === 'open' ? (
+        <div className="flex items-center justify-between gap-2 text-xs text-zinc-500">
+          <div className="flex items-center gap-2">
+            <span
+              className={cn(
+                'inline-flex items-center gap-1 px-1.5 py-0.5 rounded font-medium',
+                statusColors.bg,
+                statusColors.text
+              )}
+            >
+              {thread.status === 'open' ? (
+                <CircleDot className="w-3 h-3" />
+              ) : (
+                <CheckCircle2 className="w-3 h-3" />
+              )}
+              <span className="capitalize">{thread.status}</span>
+            </span>
+            {thread.message_count > 1 && <span>{thread.message_count} messages</span>}
+            <span>•</span>
+            <span>Last activity {lastActivity}</span>
+          </div>
+          <div className="flex items-center gap-1">
+            {latest && (
+              <Button
+                variant="ghost"
+                size="sm"
+                className="h-7 px-2 text-xs"
+                onClick={() => onReply(latest)}
+              >
+                <Reply className="w-3.5 h-3.5 mr-1" />
+                Reply
+              </Button>
+            )}
+            <Button
+              variant="ghost"
+              size="sm"
+              className="h-7 px-2 text-xs"
+              onClick={() => onToggleStatus(thread)}
+            >
+              {thread.status === 'open' ? 'Mark answered' : 'Reopen'}
+            </Button>
+          </div>
         </div>
-      )}
+      ) : null}
+
+      {/* Thread communications, replies indented */}
+      <div className="space-y-2">
+        {thread.communications.map((communication) => (
+          <div key={communication.id} className={cn(communication.parent_id && 'ml-6')}>
+            <CommunicationItem
+              communication={communication}
+              onEdit={onEdit}
+              onDelete={onDelete}
+              onReply={onReply}
+              onCompleteCallback={onCompleteCallback}
+              onViewHistory={onViewHistory}
+            />
+          </div>
+        ))}
+      </div>
     </div>
   );
 }
@@ -334,7 +491,7 @@ function EmptyState({ hasFilters, onClearFilters, onLogCommunication }: EmptySta
         No communications logged
       </h3>
       <p className="text-sm text-zinc-500 mb-4">
-        Log calls, texts, and emails to keep track of customer interactions.
+        Log calls, texts, emails, and visits to keep track of customer interactions.
       </p>
       <Button onClick={onLogCommunication}>Log Communication</Button>
     </div>
@@ -348,12 +505,11 @@ function EmptyState({ hasFilters, onClearFilters, onLogCommunication }: EmptySta
 export function CommunicationList({
   customerId,
   customerName,
-  initialCommunications = [],
+  customerEmail,
+  initialThreads = [],
 }: CommunicationListProps) {
   // State
-  const [communications, setCommunications] = React.useState<CommunicationWithLogger[]>(
-    initialCommunications
-  );
+  const [threads, setThreads] = React.useState<CommunicationThread[]>(initialThreads);
   const [isLoading, setIsLoading] = React.useState(false);
   const [isFiltering, setIsFiltering] = React.useState(false);
   const [hasMore, setHasMore] = React.useState(false);
@@ -361,18 +517,26 @@ export function CommunicationList({
   const [total, setTotal] = React.useState<number | undefined>();
 
   // Filters
-  const [filters, setFilters] = React.useState<Filters>({
-    type: 'all',
-    direction: 'all',
-    search: '',
-  });
+  const searchParams = useSearchParams();
+  const viewParam = searchParams.get('view');
+  const [filters, setFilters] = React.useState<CommunicationFilters>(EMPTY_FILTERS);
+  const [activeViewId, setActiveViewId] = React.useState<string | null>(null);
+  const [filterOptions, setFilterOptions] = React.useState<CommunicationFilterOptions | null>(
+    null
+  );
 
   // Modals
   const [showLogModal, setShowLogModal] = React.useState(false);
+  const [showSendModal, setShowSendModal] = React.useState(false);
+  const [showExportDialog, setShowExportDialog] = React.useState(false);
+  const [showTrash, setShowTrash] = React.useState(false);
   const [editingCommunication, setEditingCommunication] =
     React.useState<CommunicationWithLogger | null>(null);
   const [deletingCommunication, setDeletingCommunication] =
     React.useState<CommunicationWithLogger | null>(null);
+  const [replyingTo, setReplyingTo] = React.useState<CommunicationWithLogger | null>(null);
+  const [historyCommunication, setHistoryCommunication] =
+    React.useState<CommunicationWithLogger | null>(null);
 
   // Debounced search
   const searchTimeoutRef = React.useRef<NodeJS.Timeout>();
@@ -391,8 +555,7 @@ export function CommunicationList({
           customerId,
           limit: 25,
           cursor: cursor ?? undefined,
-          type: filters.type === 'all' ? undefined : filters.type,
-          direction: filters.direction === 'all' ? undefined : filters.direction,
+          ...filters,
           search: filters.search || undefined,
         });
 
@@ -404,9 +567,9 @@ export function CommunicationList({
         const { items, hasMore: more, nextCursor: next, total: count } = result.data;
 
         if (append) {
-          setCommunications((prev) => [...prev, ...items]);
+          setThreads((prev) => [...prev, ...items]);
         } else {
-          setCommunications(items);
+          setThreads(items);
         }
         setHasMore(more);
         setNextCursor(next);
@@ -422,6 +585,29 @@ export function CommunicationList({
     [customerId, filters]
   );
 
+  // Admins and tags for the filter bar
+  React.useEffect(() => {
+    getCommunicationFilterOptions()
+      .then((result) => result.success && setFilterOptions(result.data))
+      .catch((error) => console.error('Failed to load filter options:', error));
+  }, []);
+
+  // Open the saved view from a shared link
+  React.useEffect(() => {
+    if (!viewParam) return;
+
+    getCommunicationView(viewParam)
+      .then((result) => {
+        if (result.success) {
+          setFilters(result.data.filters);
+          setActiveViewId(result.data.id);
+        } else {
+          toast.error(result.error || 'Failed to open saved view');
+        }
+      })
+      .catch((error) => console.error('Failed to open saved view:', error));
+  }, [viewParam]);
+
   // Initial load and filter changes
   React.useEffect(() => {
     // Debounce search
@@ -450,8 +636,18 @@ export function CommunicationList({
     }
   };
 
+  const handleFiltersChange = (next: CommunicationFilters) => {
+    setFilters(next);
+    setActiveViewId(null);
+  };
+
   const handleClearFilters = () => {
-    setFilters({ type: 'all', direction: 'all', search: '' });
+    handleFiltersChange(EMPTY_FILTERS);
+  };
+
+  const handleApplyView = (view: SavedCommunicationView) => {
+    setFilters(view.filters);
+    setActiveViewId(view.id);
   };
 
   const handleEdit = (communication: CommunicationWithLogger) => {
@@ -462,21 +658,81 @@ export function CommunicationList({
     setDeletingCommunication(communication);
   };
 
+  const handleReply = (communication: CommunicationWithLogger) => {
+    setReplyingTo(communication);
+  };
+
+  const handleToggleStatus = async (thread: CommunicationThread) => {
+    const status = thread.status === 'open' ? 'answered' : 'open';
+
+    try {
+      const result = await updateThreadStatus({ threadId: thread.id, status });
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to update conversation');
+        return;
+      }
+
+      setThreads((prev) =>
+        prev.map((t) => (t.id === thread.id ? { ...t, status: result.data.status } : t))
+      );
+    } catch (error) {
+      console.error('Failed to update thread status:', error);
+      toast.error('Failed to update conversation');
+    }
+  };
+
+  const handleCompleteCallback = async (communication: CommunicationWithLogger) => {
+    try {
+      const result = await completeCallback(communication.id);
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to update call');
+        return;
+      }
+
+      setThreads((prev) =>
+        prev.map((t) => ({
+          ...t,
+          communications: t.communications.map((c) =>
+            c.id === communication.id ? { ...c, needs_callback: false } : c
+          ),
+        }))
+      );
+    } catch (error) {
+      console.error('Failed to complete callback:', error);
+      toast.error('Failed to update call');
+    }
+  };
+
+  const handleUndoDelete = async (id: string) => {
+    const result = await restoreCommunication(id, customerId);
+
+    if (!result.success) {
+      toast.error(result.error || 'Failed to restore communication');
+      return;
+    }
+
+    fetchCommunications();
+  };
+
   const confirmDelete = async () => {
     if (!deletingCommunication) return;
+    const { id } = deletingCommunication;
 
     try {
-      const result = await deleteCommunication(deletingCommunication.id, customerId);
+      const result = await deleteCommunication(id, customerId);
 
       if (!result.success) {
         toast.error(result.error || 'Failed to delete communication');
         return;
       }
 
-      toast.success('Communication deleted');
-      setCommunications((prev) =>
-        prev.filter((c) => c.id !== deletingCommunication.id)
-      );
+      toast.success('Communication moved to trash', {
+        action: { label: 'Undo', onClick: () => handleUndoDelete(id) },
+      });
+      // Thread aggregates change server-side, so reload rather than patch locally
+      fetchCommunications();
     } catch (error) {
       console.error('Failed to delete communication:', error);
       toast.error('Failed to delete communication');
@@ -493,8 +749,7 @@ export function CommunicationList({
   // Render
   // ==========================================================================
 
-  const hasFilters =
-    filters.type !== 'all' || filters.direction !== 'all' || filters.search !== '';
+  const hasFilters = hasActiveFilters(filters);
 
   return (
     <div>
@@ -508,26 +763,48 @@ export function CommunicationList({
             )}
           </h3>
         </div>
-        <Button onClick={() => setShowLogModal(true)} size="sm">
-          <Phone className="w-4 h-4 mr-2" />
-          Log Communication
-        </Button>
+        <div className="flex items-center gap-2">
+          <Button onClick={() => setShowTrash(true)} variant="ghost" size="sm">
+            <ArchiveRestore className="w-4 h-4 mr-2" />
+            Trash
+          </Button>
+          <Button onClick={() => setShowExportDialog(true)} variant="ghost" size="sm">
+            <Download className="w-4 h-4 mr-2" />
+            Export
+          </Button>
+          <Button onClick={() => setShowSendModal(true)} variant="secondary" size="sm">
+            <Send className="w-4 h-4 mr-2" />
+            Send Message
+          </Button>
+          <Button onClick={() => setShowLogModal(true)} size="sm">
+            <Phone className="w-4 h-4 mr-2" />
+            Log Communication
+          </Button>
+        </div>
       </div>
 
       {/* Filters */}
-      <FilterBar
+      <CommunicationFilterBar
         filters={filters}
-        onFiltersChange={setFilters}
+        onFiltersChange={handleFiltersChange}
         onClear={handleClearFilters}
         isFiltering={isFiltering}
-      />
+        options={filterOptions}
+        showTags={false}
+      >
+        <SavedViewsMenu
+          filters={filters}
+          activeViewId={activeViewId}
+          onApply={handleApplyView}
+        />
+      </CommunicationFilterBar>
 
       {/* Content */}
       {isLoading && !isFiltering ? (
         <div className="flex items-center justify-center py-12">
           <Loader2 className="w-6 h-6 animate-spin text-zinc-400" />
         </div>
-      ) : communications.length === 0 ? (
+      ) : threads.length === 0 ? (
         <EmptyState
           hasFilters={hasFilters}
           onClearFilters={handleClearFilters}
@@ -535,14 +812,18 @@ export function CommunicationList({
         />
       ) : (
         <>
-          {/* Communication Items */}
-          <div className="space-y-3">
-            {communications.map((communication) => (
-              <CommunicationItem
-                key={communication.id}
-                communication={communication}
+          {/* Threads */}
+          <div className="space-y-5">
+            {threads.map((thread) => (
+              <ThreadItem
+                key={thread.id}
+                thread={thread}
                 onEdit={handleEdit}
                 onDelete={handleDelete}
+                onReply={handleReply}
+                onCompleteCallback={handleCompleteCallback}
+                onViewHistory={setHistoryCommunication}
+                onToggleStatus={handleToggleStatus}
               />
             ))}
           </div>
@@ -574,6 +855,46 @@ export function CommunicationList({
         onSuccess={handleSuccess}
       />
 
+      {/* Send Message Modal */}
+      <SendMessageModal
+        customerId={customerId}
+        customerName={customerName}
+        hasEmail={!!customerEmail}
+        open={showSendModal}
+        onOpenChange={setShowSendModal}
+        onSuccess={handleSuccess}
+      />
+
+      {/* Export Dialog */}
+      <ExportCommunicationsDialog
+        customerId={customerId}
+        filters={filters}
+        open={showExportDialog}
+        onOpenChange={setShowExportDialog}
+      />
+
+      {/* Trash Modal */}
+      <CommunicationTrashModal
+        customerId={customerId}
+        open={showTrash}
+        onOpenChange={setShowTrash}
+        onRestored={handleSuccess}
+      />
+
+      {/* Reply Modal */}
+      {replyingTo && (
+        <LogCommunicationModal
+          customerId={customerId}
+          customerName={customerName}
+          open={!!replyingTo}
+          onOpenChange={(open) => !open && setReplyingTo(null)}
+          onSuccess={handleSuccess}
+          parentId={replyingTo.id}
+          defaultType={replyingTo.type}
+          defaultDirection={replyingTo.direction === 'inbound' ? 'outbound' : 'inbound'}
+        />
+      )}
+
       {/* Edit Communication Modal */}
       {editingCommunication && (
         <EditCommunicationModal
@@ -584,6 +905,19 @@ export function CommunicationList({
         />
       )}
 
+      {/* Edit History Modal */}
+      {historyCommunication && (
+        <CommunicationHistoryModal
+          communication={historyCommunication}
+          open={!!historyCommunication}
+          onOpenChange={(open) => !open && setHistoryCommunication(null)}
+          onRestored={(communication) => {
+            setHistoryCommunication(communication);
+            fetchCommunications();
+          }}
+        />
+      )}
+
       {/* Delete Confirmation Dialog */}
       <AlertDialog
         open={!!deletingCommunication}
@@ -593,8 +927,12 @@ export function CommunicationList({
           <AlertDialogHeader>
             <AlertDialogTitle>Delete Communication</AlertDialogTitle>
             <AlertDialogDescription>
-              Are you sure you want to delete this {deletingCommunication?.type}? This
-              action cannot be undone.
+              Are you sure you want to delete this{' '}
+              {deletingCommunication
+                ? communicationTypeLabels[deletingCommunication.type].toLowerCase()
+                : 'communication'}
+              ? It moves to the trash, where it can be restored until it is permanently
+              deleted.
             </AlertDialogDescription>
           </AlertDialogHeader>
           <AlertDialogFooter>
@@ -0,0 +1,329 @@
+'use client';
+
+/**
+ * Communication Search
+ *
+ * @file src/components/communications/communication-search.tsx
+ *
+ * Search results across all customers with the shared filter bar, saved
+ * views, relevance or date sorting and cursor-based "load more". The
+ * query is kept in the filters' search field so saved views include it.
+ */
+
+import * as React from 'react';
+import Link from 'next/link';
+import { Download, Loader2, Search } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { cn } from '@/lib/utils';
+import { formatDateTime } from '@/lib/utils/timezone';
+import { searchCommunications } from '@/app/actions/communications';
+import {
+  getCommunicationFilterOptions,
+  getCommunicationView,
+} from '@/app/actions/communication-views';
+import {
+  communicationTypeColors,
+  communicationTypeLabels,
+  type CommunicationFilterOptions,
+  type CommunicationFilters,
+  type CommunicationSearchHit,
+  type SavedCommunicationView,
+} from '@/lib/types/communication';
+import type { CommunicationSearchSort } from '@/lib/validations/communication';
+import { CommunicationFilterBar } from './communication-filter-bar';
+import { SavedViewsMenu } from './saved-views-menu';
+import { ExportCommunicationsDialog } from './export-communications-dialog';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface CommunicationSearchProps {
+  initialQuery: string;
+  viewId: string | null;
+}
+
+const PAGE_SIZE = 25;
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function CommunicationSearch({ initialQuery, viewId }: CommunicationSearchProps) {
+  const [filters, setFilters] = React.useState<CommunicationFilters>(
+    initialQuery ? { search: initialQuery } : {}
+  );
+  const [sort, setSort] = React.useState<CommunicationSearchSort>('relevance');
+  const [activeViewId, setActiveViewId] = React.useState<string | null>(null);
+  const [filterOptions, setFilterOptions] = React.useState<CommunicationFilterOptions | null>(
+    null
+  );
+
+  const [hits, setHits] = React.useState<CommunicationSearchHit[]>([]);
+  const [total, setTotal] = React.useState<number | undefined>();
+  const [nextCursor, setNextCursor] = React.useState<string | null>(null);
+  const [isSearching, setIsSearching] = React.useState(false);
+  const [isLoadingMore, setIsLoadingMore] = React.useState(false);
+  const [showExportDialog, setShowExportDialog] = React.useState(false);
+
+  const searchTimeoutRef = React.useRef<NodeJS.Timeout>();
+  const query = filters.search?.trim() ?? '';
+
+  // ==========================================================================
+  // Data Fetching
+  // ==========================================================================
+
+  const runSearch = React.useCallback(
+    async (cursor?: string) => {
+      const { search: _search, ...rest } = filters;
+      const result = await searchCommunications({
+        ...rest,
+        query,
+        sort,
+        limit: PAGE_SIZE,
+        offset: 0,
+        cursor,
+      });
+
+      if (!result.success) {
+        toast.error(result.error || 'Search failed');
+        return null;
+      }
+
+      return result.data;
+    },
+    [filters, query, sort]
+  );
+
+  React.useEffect(() => {
+    getCommunicationFilterOptions()
+      .then((result) => result.success && setFilterOptions(result.data))
+      .catch((error) => console.error('Failed to load filter options:', error));
+  }, []);
+
+  // Open the saved view from a shared link
+  React.useEffect(() => {
+    if (!viewId) return;
+
+    getCommunicationView(viewId)
+      .then((result) => {
+        if (result.success) {
+          setFilters(result.data.filters);
+          setActiveViewId(result.data.id);
+        } else {
+          toast.error(result.error || 'Failed to open saved view');
+        }
+      })
+      .catch((error) => console.error('Failed to open saved view:', error));
+  }, [viewId]);
+
+  // Search as the query, filters or sort change
+  React.useEffect(() => {
+    if (searchTimeoutRef.current) {
+      clearTimeout(searchTimeoutRef.current);
+    }
+
+    if (!query) {
+      setHits([]);
+      setTotal(undefined);
+      setNextCursor(null);
+      return;
+    }
+
+    searchTimeoutRef.current = setTimeout(async () => {
+      setIsSearching(true);
+      try {
+        const data = await runSearch();
+        if (data) {
+          setHits(data.items);
+          setTotal(data.total);
+          setNextCursor(data.hasMore ? data.nextCursor : null);
+        }
+      } catch (error) {
+        console.error('Failed to search communications:', error);
+        toast.error('Search failed');
+      } finally {
+        setIsSearching(false);
+      }
+    }, 300);
+
+    return () => {
+      if (searchTimeoutRef.current) {
+        clearTimeout(searchTimeoutRef.current);
+      }
+    };
+  }, [query, runSearch]);
+
+  // ==========================================================================
+  // Event Handlers
+  // ==========================================================================
+
+  const handleLoadMore = async () => {
+    if (!nextCursor) return;
+
+    setIsLoadingMore(true);
+    try {
+      const data = await runSearch(nextCursor);
+      if (data) {
+        setHits((prev) => [...prev, ...data.items]);
+        setNextCursor(data.hasMore ? data.nextCursor : null);
+      }
+    } catch (error) {
+      console.error('Failed to load more results:', error);
+      toast.error('Failed to load more results');
+    } finally {
+      setIsLoadingMore(false);
+    }
+  };
+
+  const handleFiltersChange = (next: CommunicationFilters) => {
+    setFilters(next);
+    setActiveViewId(null);
+  };
+
+  const handleApplyView = (view: SavedCommunicationView) => {
+    setFilters(view.filters);
+    setActiveViewId(view.id);
+  };
+
+  // ==========================================================================
+  // Render
+  // ==========================================================================
+
+  return (
+    <div>
+      <CommunicationFilterBar
+        filters={filters}
+        onFiltersChange={handleFiltersChange}
+        onClear={() => handleFiltersChange({})}
+        isFiltering={isSearching}
+        options={filterOptions}
+      >
+        <Select value={sort} onValueChange={(value) => setSort(value as CommunicationSearchSort)}>
+          <SelectTrigger className="w-[150px]">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="relevance">Best Match</SelectItem>
+            <SelectItem value="date">Newest First</SelectItem>
+          </SelectContent>
+        </Select>
+        <SavedViewsMenu
+          filters={filters}
+          activeViewId={activeViewId}
+          onApply={handleApplyView}
+        />
+        <Button
+          variant="secondary"
+          size="sm"
+          className="h-10"
+          onClick={() => setShowExportDialog(true)}
+          disabled={!query}
+        >
+          <Download className="w-4 h-4 mr-1.5" />
+          Export
+        </Button>
+      </CommunicationFilterBar>
+
+      <ExportCommunicationsDialog
+        filters={filters}
+        open={showExportDialog}
+        onOpenChange={setShowExportDialog}
+      />
+
+      {!query ? (
+        <div className="flex flex-col items-center justify-center py-12 text-center">
+          <div className="w-12 h-12 rounded-full bg-zinc-100 flex items-center justify-center mb-4">
+            <Search className="w-6 h-6 text-zinc-400" />
+          </div>
+          <p className="text-sm text-zinc-500">
+            Search by words, names or phone numbers, then narrow with filters
+          </p>
+        </div>
+      ) : hits.length === 0 && !isSearching ? (
+        <p className="py-12 text-center text-sm text-zinc-500">
+          No communications match &quot;{query}&quot;
+        </p>
+      ) : (
+        <>
+          {total !== undefined && (
+            <p className="text-sm text-zinc-500 mb-3">
+              {total} {total === 1 ? 'result' : 'results'}
+            </p>
+          )}
+
+          <div className="space-y-3">
+            {hits.map((hit) => (
+              <SearchHit key={hit.id} hit={hit} />
+            ))}
+          </div>
+
+          {nextCursor && (
+            <div className="flex justify-center mt-6">
+              <Button variant="secondary" onClick={handleLoadMore} disabled={isLoadingMore}>
+                {isLoadingMore ? (
+                  <>
+                    <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                    Loading...
+                  </>
+                ) : (
+                  'Load More'
+                )}
+              </Button>
+            </div>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+// =============================================================================
+// Search Hit
+// =============================================================================
+
+function SearchHit({ hit }: { hit: CommunicationSearchHit }) {
+  const colors = communicationTypeColors[hit.type];
+
+  return (
+    <Link
+      href={`/admin/customers/${hit.customer_id}?tab=communications`}
+      className="block p-4 bg-white border border-zinc-200 rounded-lg hover:border-zinc-300 transition-colors"
+    >
+      <div className="flex items-center gap-2 mb-1 text-xs">
+        <span className="text-sm font-medium text-zinc-900">{hit.customer.name}</span>
+        <span
+          className={cn('px-1.5 py-0.5 rounded border', colors.bg, colors.text, colors.border)}
+        >
+          {communicationTypeLabels[hit.type]}
+        </span>
+        <span className="capitalize text-zinc-500">{hit.direction}</span>
+        <span className="ml-auto text-zinc-400">{formatDateTime(hit.occurred_at)}</span>
+      </div>
+      <p className="text-sm text-zinc-600 line-clamp-3">
+        {hit.snippet.map((segment, i) =>
+          segment.highlighted ? (
+            <mark key={i} className="bg-yellow-100 text-zinc-900 rounded-sm">
+              {segment.text}
+            </mark>
+          ) : (
+            <span key={i}>{segment.text}</span>
+          )
+        )}
+      </p>
+      {hit.logged_by_admin && (
+        <p className="mt-2 text-xs text-zinc-400">
+          Logged by {hit.logged_by_admin.full_name || hit.logged_by_admin.email}
+        </p>
+      )}
+    </Link>
+  );
+}
@@ -0,0 +1,284 @@
+'use client';
+
+/**
+ * Communication Trash Modal
+ *
+ * @file src/components/communications/communication-trash-modal.tsx
+ *
+ * Lists a customer's deleted communications, most recently deleted first,
+ * with when each will be permanently deleted. Communications can be
+ * restored or deleted for good straight away.
+ */
+
+import * as React from 'react';
+import { formatDistanceToNow } from 'date-fns';
+import { Loader2, RotateCcw, Trash2 } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '@/components/ui/dialog';
+import {
+  AlertDialog,
+  AlertDialogAction,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from '@/components/ui/alert-dialog';
+import { formatDateTime } from '@/lib/utils/timezone';
+import {
+  deleteCommunicationPermanently,
+  listTrashedCommunications,
+  restoreCommunication,
+} from '@/app/actions/communications';
+import {
+  communicationTypeLabels,
+  type TrashedCommunication,
+} from '@/lib/types/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface CommunicationTrashModalProps {
+  customerId: string;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  /** Called after a communication is restored */
+  onRestored: () => void;
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function CommunicationTrashModal({
+  customerId,
+  open,
+  onOpenChange,
+  onRestored,
+}: CommunicationTrashModalProps) {
+  const [items, setItems] = React.useState<TrashedCommunication[] | null>(null);
+  const [retentionDays, setRetentionDays] = React.useState<number | null>(null);
+  const [nextCursor, setNextCursor] = React.useState<string | null>(null);
+  const [isLoadingMore, setIsLoadingMore] = React.useState(false);
+  const [pendingId, setPendingId] = React.useState<string | null>(null);
+  const [purging, setPurging] = React.useState<TrashedCommunication | null>(null);
+
+  const loadTrash = React.useCallback(
+    async (cursor?: string) => {
+      try {
+        const result = await listTrashedCommunications({ customerId, limit: 25, cursor });
+
+        if (!result.success) {
+          toast.error(result.error || 'Failed to load trash');
+          return;
+        }
+
+        setItems((prev) =>
+          cursor && prev ? [...prev, ...result.data.items] : result.data.items
+        );
+        setRetentionDays(result.data.retentionDays);
+        setNextCursor(result.data.nextCursor);
+      } catch (error) {
+        console.error('Failed to load trash:', error);
+        toast.error('Failed to load trash');
+      }
+    },
+    [customerId]
+  );
+
+  React.useEffect(() => {
+    if (open) {
+      setItems(null);
+      loadTrash();
+    }
+  }, [open, loadTrash]);
+
+  const removeItem = (id: string) => {
+    setItems((prev) => prev?.filter((item) => item.id !== id) ?? null);
+  };
+
+  const handleLoadMore = async () => {
+    if (!nextCursor) return;
+    setIsLoadingMore(true);
+    await loadTrash(nextCursor);
+    setIsLoadingMore(false);
+  };
+
+  const handleRestore = async (communication: TrashedCommunication) => {
+    setPendingId(communication.id);
+
+    try {
+      const result = await restoreCommunication(communication.id, customerId);
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to restore communication');
+        return;
+      }
+
+      toast.success('Communication restored');
+      removeItem(communication.id);
+      onRestored();
+    } catch (error) {
+      console.error('Failed to restore communication:', error);
+      toast.error('Failed to restore communication');
+    } finally {
+      setPendingId(null);
+    }
+  };
+
+  const confirmPurge = async () => {
+    if (!purging) return;
+    setPendingId(purging.id);
+
+    try {
+      const result = await deleteCommunicationPermanently(purging.id, customerId);
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to delete communication');
+        return;
+      }
+
+      toast.success('Communication permanently deleted');
+      removeItem(purging.id);
+    } catch (error) {
+      console.error('Failed to permanently delete communication:', error);
+      toast.error('Failed to delete communication');
+    } finally {
+      setPendingId(null);
+      setPurging(null);
+    }
+  };
+
+  return (
+    <>
+      <Dialog open={open} onOpenChange={onOpenChange}>
+        <DialogContent className="sm:max-w-[600px] max-h-[85vh] overflow-y-auto">
+          <DialogHeader>
+            <DialogTitle>Trash</DialogTitle>
+            <DialogDescription>
+              {retentionDays
+                ? `Deleted communications are permanently removed after ${retentionDays} days.`
+                : 'Deleted communications are permanently removed after a while.'}
+            </DialogDescription>
+          </DialogHeader>
+
+          {items === null ? (
+            <div className="flex justify-center py-8 text-zinc-400">
+              <Loader2 className="w-5 h-5 animate-spin" />
+            </div>
+          ) : items.length === 0 ? (
+            <div className="flex flex-col items-center py-8 text-center">
+              <Trash2 className="w-6 h-6 text-zinc-300" />
+              <p className="text-sm text-zinc-500 mt-2">The trash is empty.</p>
+            </div>
+          ) : (
+            <ul className="space-y-3">
+              {items.map((communication) => (
+                <li
+                  key={communication.id}
+                  className="border border-zinc-200 rounded-lg p-4 space-y-2"
+                >
+                  <div className="flex items-start justify-between gap-4">
+                    <div className="min-w-0 text-sm">
+                      <p className="font-medium text-zinc-900">
+                        {communicationTypeLabels[communication.type]}
+                        <span className="ml-2 font-normal text-zinc-500 capitalize">
+                          {communication.direction}
+                        </span>
+                      </p>
+                      <p className="text-xs text-zinc-500">
+                        {formatDateTime(communication.occurred_at, 'mediumWithTime')}
+                      </p>
+                    </div>
+                    <div className="flex items-center gap-1 flex-shrink-0">
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        onClick={() => handleRestore(communication)}
+                        disabled={pendingId !== null}
+                      >
+                        {pendingId === communication.id && !purging ? (
+                          <Loader2 className="w-4 h-4 mr-1.5 animate-spin" />
+                        ) : (
+                          <RotateCcw className="w-4 h-4 mr-1.5" />
+                        )}
+                        Restore
+                      </Button>
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        onClick={() => setPurging(communication)}
+                        disabled={pendingId !== null}
+                        className="text-red-600 hover:text-red-700"
+                      >
+                        <Trash2 className="w-4 h-4 mr-1.5" />
+                        Delete forever
+                      </Button>
+                    </div>
+                  </div>
+
+                  <p className="text-sm text-zinc-600 whitespace-pre-wrap break-words line-clamp-3">
+                    {communication.summary}
+                  </p>
+
+                  <p className="text-xs text-zinc-400">
+                    Deleted{' '}
+                    {formatDistanceToNow(new Date(communication.deleted_at), { addSuffix: true })}
+                    {communication.deleted_by_admin &&
+                      ` by ${communication.deleted_by_admin.full_name}`}
+                    {' · '}
+                    Permanently deleted{' '}
+                    {formatDistanceToNow(new Date(communication.purge_at), { addSuffix: true })}
+                  </p>
+                </li>
+              ))}
+            </ul>
+          )}
+
+          {nextCursor && (
+            <div className="flex justify-center">
+              <Button variant="secondary" onClick={handleLoadMore} disabled={isLoadingMore}>
+                {isLoadingMore ? (
+                  <>
+                    <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                    Loading...
+                  </>
+                ) : (
+                  'Load More'
+                )}
+              </Button>
+            </div>
+          )}
+        </DialogContent>
+      </Dialog>
+
+      {/* Permanent Delete Confirmation */}
+      <AlertDialog open={!!purging} onOpenChange={(open) => !open && setPurging(null)}>
+        <AlertDialogContent>
+          <AlertDialogHeader>
+            <AlertDialogTitle>Delete Forever</AlertDialogTitle>
+            <AlertDialogDescription>
+              This permanently deletes the communication and its attachments. This action
+              cannot be undone.
+            </AlertDialogDescription>
+          </AlertDialogHeader>
+          <AlertDialogFooter>
+            <AlertDialogCancel>Cancel</AlertDialogCancel>
+            <AlertDialogAction onClick={confirmPurge} className="bg-red-600 hover:bg-red-700">
+              Delete Forever
+            </AlertDialogAction>
+          </AlertDialogFooter>
+        </AlertDialogContent>
+      </AlertDialog>
+    </>
+  );
+}
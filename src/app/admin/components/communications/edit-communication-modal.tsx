@@ -6,13 +6,29 @@
  * @file src/components/communications/edit-communication-modal.tsx
  *
  * Modal dialog for editing an existing communication record.
+ *
+ * Edits are saved against the version the form was opened with. If someone
+ * else saved first, their copy is shown field by field so their values can
+ * be taken over before saving again.
  */
 
 import * as React from 'react';
 import { useForm } from 'react-hook-form';
 import { zodResolver } from '@hookform/resolvers/zod';
 import { z } from 'zod';
-import { Phone, MessageSquare, Mail, ArrowDownLeft, ArrowUpRight, Loader2 } from 'lucide-react';
+import {
+  Phone,
+  MessageSquare,
+  Mail,
+  Voicemail,
+  Users,
+  Globe,
+  AtSign,
+  ArrowDownLeft,
+  ArrowUpRight,
+  Loader2,
+  AlertTriangle,
+} from 'lucide-react';
 import {
   Dialog,
   DialogContent,
@@ -28,10 +44,27 @@ import { Textarea } from '@/components/ui/textarea';
 import { cn } from '@/lib/utils';
 import { updateCommunication } from '@/app/actions/communications';
 import { toast } from 'sonner';
-import type { CommunicationWithLogger } from '@/lib/types/communication';
+import {
+  buildLinkLabels,
+  communicationRevisionFieldLabels,
+  communicationTypeLabels,
+  formatRevisionValue,
+  type CommunicationLinkField,
+  type CommunicationRevisionField,
+  type CommunicationWithLogger,
+} from '@/lib/types/communication';
+import { CallDetailsFields } from './call-details-fields';
+import {
+  CommunicationLinkFields,
+  getCommunicationLinkValues,
+  type CommunicationLinkValues,
+} from './communication-links';
 import {
   COMMUNICATION_TYPES,
   COMMUNICATION_DIRECTIONS,
+  CALL_OUTCOMES,
+  parseCallDuration,
+  formatCallDurationInput,
   type CommunicationType,
   type CommunicationDirection,
 } from '@/lib/validations/communication';
@@ -56,10 +89,166 @@ const formSchema = z.object({
   direction: z.enum(COMMUNICATION_DIRECTIONS),
   summary: z.string().min(1, 'Please enter a summary').max(5000),
   occurredAt: z.string().min(1, 'Please select when this occurred'),
+  callOutcome: z.enum(CALL_OUTCOMES).optional(),
+  callDuration: z
+    .string()
+    .optional()
+    .refine((val) => !val?.trim() || parseCallDuration(val) !== null, {
+      message: 'Use m:ss or minutes',
+    }),
+  callNumber: z.string().max(30).optional(),
 });
 
 type FormData = z.infer<typeof formSchema>;
 
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+// Convert ISO datetime to local datetime-local input format
+function toLocalDateTimeString(isoString: string): string {
+  const date = new Date(isoString);
+  const offset = date.getTimezoneOffset();
+  const localDate = new Date(date.getTime() - offset * 60 * 1000);
+  return localDate.toISOString().slice(0, 16);
+}
+
+function toFormValues(communication: CommunicationWithLogger): FormData {
+  return {
+    type: communication.type,
+    direction: communication.direction,
+    summary: communication.summary,
+    occurredAt: toLocalDateTimeString(communication.occurred_at),
+    callOutcome: communication.call_outcome ?? undefined,
+    callDuration: formatCallDurationInput(communication.call_duration_seconds),
+    callNumber: communication.call_number ?? '',
+  };
+}
+
+/**
+ * Form fields that differ from the server copy, in form order
+ */
+const CONFLICT_FIELDS: { field: CommunicationRevisionField; formField: keyof FormData }[] = [
+  { field: 'type', formField: 'type' },
+  { field: 'direction', formField: 'direction' },
+  { field: 'occurred_at', formField: 'occurredAt' },
+  { field: 'call_outcome', formField: 'callOutcome' },
+  { field: 'call_duration_seconds', formField: 'callDuration' },
+  { field: 'call_number', formField: 'callNumber' },
+  { field: 'summary', formField: 'summary' },
+];
+
+/**
+ * Linked records that differ from the server copy
+ */
+const LINK_CONFLICT_FIELDS: {
+  field: CommunicationLinkField;
+  linkField: keyof CommunicationLinkValues;
+}[] = [
+  { field: 'estimate_id', linkField: 'estimateId' },
+  { field: 'calendar_event_id', linkField: 'calendarEventId' },
+  { field: 'property_id', linkField: 'propertyId' },
+  { field: 'pool_id', linkField: 'poolId' },
+];
+
+// =============================================================================
+// Conflict Panel Component
+// =============================================================================
+
+interface ConflictPanelProps {
+  current: CommunicationWithLogger;
+  draft: FormData;
+  draftLinks: CommunicationLinkValues;
+  onUseTheirs: (formField: keyof FormData) => void;
+  onUseTheirLink: (linkField: keyof CommunicationLinkValues) => void;
+  onUseAllTheirs: () => void;
+}
+
+function ConflictPanel({
+  current,
+  draft,
+  draftLinks,
+  onUseTheirs,
+  onUseTheirLink,
+  onUseAllTheirs,
+}: ConflictPanelProps) {
+  const theirs = toFormValues(current);
+  const theirLinks = getCommunicationLinkValues(current);
+  const linkLabels = buildLinkLabels({
+    estimates: current.estimate ? [current.estimate] : [],
+    events: current.calendar_event ? [current.calendar_event] : [],
+    properties: current.property ? [current.property] : [],
+    pools: current.pool ? [current.pool] : [],
+  });
+
+  const differences = [
+    ...CONFLICT_FIELDS.filter(
+      ({ formField }) => (draft[formField] ?? '') !== (theirs[formField] ?? '')
+    ).map(({ field, formField }) => ({
+      field,
+      onUse: () => onUseTheirs(formField),
+    })),
+    ...LINK_CONFLICT_FIELDS.filter(
+      ({ linkField }) => draftLinks[linkField] !== theirLinks[linkField]
+    ).map(({ field, linkField }) => ({
+      field,
+      onUse: () => onUseTheirLink(linkField),
+    })),
+  ];
+  const editor = current.edited_by_admin?.full_name ?? 'Someone else';
+
+  return (
+    <div className="rounded-lg border border-amber-200 bg-amber-50 p-3 space-y-3">
+      <div className="flex items-start gap-2">
+        <AlertTriangle className="w-4 h-4 mt-0.5 text-amber-600 flex-shrink-0" />
+        <div className="text-sm text-amber-900">
+          <p className="font-medium">{editor} saved changes while you were editing</p>
+          <p className="text-amber-800">
+            {differences.length > 0
+              ? 'Take any of their values you want to keep, then save again.'
+              : 'Their copy matches yours. Save again to confirm.'}
+          </p>
+        </div>
+      </div>
+
+      {differences.length > 0 && (
+        <ul className="space-y-2">
+          {differences.map(({ field, onUse }) => (
+            <li
+              key={field}
+              className="flex items-start justify-between gap-3 rounded-md bg-white px-3 py-2"
+            >
+              <div className="min-w-0 text-sm">
+                <p className="text-xs font-medium text-zinc-500">
+                  {communicationRevisionFieldLabels[field]}
+                </p>
+                <p className="text-zinc-700 whitespace-pre-wrap break-words line-clamp-4">
+                  {formatRevisionValue(field, current[field], linkLabels)}
+                </p>
+              </div>
+              <Button
+                type="button"
+                variant="ghost"
+                size="sm"
+                className="flex-shrink-0"
+                onClick={onUse}
+              >
+                Use theirs
+              </Button>
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {differences.length > 1 && (
+        <Button type="button" variant="secondary" size="sm" onClick={onUseAllTheirs}>
+          Use all of theirs
+        </Button>
+      )}
+    </div>
+  );
+}
+
 // =============================================================================
 // Type Selector Component
 // =============================================================================
@@ -74,10 +263,14 @@ function TypeSelector({ value, onChange }: TypeSelectorProps) {
     { value: 'call', label: 'Call', icon: <Phone className="w-4 h-4" /> },
     { value: 'text', label: 'Text', icon: <MessageSquare className="w-4 h-4" /> },
     { value: 'email', label: 'Email', icon: <Mail className="w-4 h-4" /> },
+    { value: 'voicemail', label: 'Voicemail', icon: <Voicemail className="w-4 h-4" /> },
+    { value: 'in_person', label: 'In Person', icon: <Users className="w-4 h-4" /> },
+    { value: 'web_form', label: 'Web Form', icon: <Globe className="w-4 h-4" /> },
+    { value: 'social', label: 'Social', icon: <AtSign className="w-4 h-4" /> },
   ];
 
   return (
-    <div className="flex gap-2">
+    <div className="grid grid-cols-2 sm:grid-cols-4 gap-2">
       {types.map((type) => (
         <button
           key={type.value}
@@ -146,35 +339,50 @@ export function EditCommunicationModal({
   onSuccess,
 }: EditCommunicationModalProps) {
   const [isSubmitting, setIsSubmitting] = React.useState(false);
-
-  // Convert ISO datetime to local datetime-local input format
-  const toLocalDateTimeString = (isoString: string) => {
-    const date = new Date(isoString);
-    const offset = date.getTimezoneOffset();
-    const localDate = new Date(date.getTime() - offset * 60 * 1000);
-    return localDate.toISOString().slice(0, 16);
-  };
+  // Version the next save is checked against; moves to the server copy's
+  // version once a conflict has been shown
+  const [version, setVersion] = React.useState(communication.version);
+  const [conflict, setConflict] = React.useState<CommunicationWithLogger | null>(null);
+  const [links, setLinks] = React.useState<CommunicationLinkValues>(
+    getCommunicationLinkValues(communication)
+  );
 
   const form = useForm<FormData>({
     resolver: zodResolver(formSchema),
-    defaultValues: {
-      type: communication.type,
-      direction: communication.direction,
-      summary: communication.summary,
-      occurredAt: toLocalDateTimeString(communication.occurred_at),
-    },
+    defaultValues: toFormValues(communication),
   });
 
   // Reset form when communication changes
   React.useEffect(() => {
-    form.reset({
-      type: communication.type,
-      direction: communication.direction,
-      summary: communication.summary,
-      occurredAt: toLocalDateTimeString(communication.occurred_at),
-    });
+    form.reset(toFormValues(communication));
+    setVersion(communication.version);
+    setConflict(null);
+    setLinks(getCommunicationLinkValues(communication));
   }, [communication, form]);
 
+  const handleUseTheirs = (formField: keyof FormData) => {
+    if (!conflict) return;
+    form.setValue(formField, toFormValues(conflict)[formField], { shouldValidate: true });
+  };
+
+  const handleUseTheirLink = (linkField: keyof CommunicationLinkValues) => {
+    if (!conflict) return;
+    const theirLinks = getCommunicationLinkValues(conflict);
+
+    // Property and pool go together so the pool stays at the property
+    setLinks((prev) =>
+      linkField === 'propertyId' || linkField === 'poolId'
+        ? { ...prev, propertyId: theirLinks.propertyId, poolId: theirLinks.poolId }
+        : { ...prev, [linkField]: theirLinks[linkField] }
+    );
+  };
+
+  const handleUseAllTheirs = () => {
+    if (!conflict) return;
+    form.reset(toFormValues(conflict));
+    setLinks(getCommunicationLinkValues(conflict));
+  };
+
   const onSubmit = async (data: FormData) => {
     setIsSubmitting(true);
 
@@ -182,19 +390,34 @@ export function EditCommunicationModal({
       // Convert local datetime to ISO string
       const occurredAt = new Date(data.occurredAt).toISOString();
 
+      const isCall = data.type === 'call';
       const result = await updateCommunication({
         id: communication.id,
+        version,
         type: data.type,
         direction: data.direction,
         summary: data.summary,
         occurredAt,
+        callOutcome: isCall ? data.callOutcome ?? null : null,
+        callDurationSeconds: isCall ? parseCallDuration(data.callDuration) : null,
+        callNumber: isCall && data.callNumber?.trim() ? data.callNumber : null,
+        ...links,
       });
 
       if (!result.success) {
+        if (result.code === 'CONFLICT' && 'current' in result) {
+          // Save against their version next time, once the user has merged
+          setConflict(result.current);
+          setVersion(result.current.version);
+          toast.error('Someone else changed this communication');
+          return;
+        }
+
         toast.error(result.error || 'Failed to update communication');
         return;
       }
 
+      setConflict(null);
       toast.success('Communication updated');
       onOpenChange(false);
       onSuccess?.();
@@ -211,7 +434,7 @@ export function EditCommunicationModal({
 
   return (
     <Dialog open={open} onOpenChange={onOpenChange}>
-      <DialogContent className="sm:max-w-[480px]">
+      <DialogContent className="sm:max-w-[480px] max-h-[90vh] overflow-y-auto">
         <DialogHeader>
           <DialogTitle>Edit Communication</DialogTitle>
           <DialogDescription>
@@ -220,6 +443,18 @@ export function EditCommunicationModal({
         </DialogHeader>
 
         <form onSubmit={form.handleSubmit(onSubmit)} className="space-y-5">
+          {/* Conflict */}
+          {conflict && (
+            <ConflictPanel
+              current={conflict}
+              draft={form.watch()}
+              draftLinks={links}
+              onUseTheirs={handleUseTheirs}
+              onUseTheirLink={handleUseTheirLink}
+              onUseAllTheirs={handleUseAllTheirs}
+            />
+          )}
+
           {/* Communication Type */}
           <div className="space-y-2">
             <Label>Type</Label>
@@ -254,12 +489,28 @@ export function EditCommunicationModal({
             )}
           </div>
 
+          {/* Call Details */}
+          {watchType === 'call' && (
+            <CallDetailsFields
+              outcome={form.watch('callOutcome')}
+              onOutcomeChange={(value) => form.setValue('callOutcome', value)}
+              durationInput={form.watch('callDuration') ?? ''}
+              onDurationInputChange={(value) =>
+                form.setValue('callDuration', value, { shouldValidate: true })
+              }
+              durationError={form.formState.errors.callDuration?.message}
+              callNumber={form.watch('callNumber') ?? ''}
+              onCallNumberChange={(value) => form.setValue('callNumber', value)}
+              direction={watchDirection}
+            />
+          )}
+
           {/* Summary */}
           <div className="space-y-2">
             <Label htmlFor="summary">Summary</Label>
             <Textarea
               id="summary"
-              placeholder={`What was discussed during this ${watchType}?`}
+              placeholder={`What was discussed during this ${communicationTypeLabels[watchType].toLowerCase()}?`}
               rows={4}
               {...form.register('summary')}
               className={cn(
@@ -277,6 +528,17 @@ export function EditCommunicationModal({
             </p>
           </div>
 
+          {/* Related To */}
+          <div className="space-y-2">
+            <Label>Related to</Label>
+            <CommunicationLinkFields
+              customerId={communication.customer_id}
+              value={links}
+              onChange={setLinks}
+              disabled={isSubmitting}
+            />
+          </div>
+
           <DialogFooter>
             <Button
               type="button"
@@ -292,6 +554,8 @@ export function EditCommunicationModal({
                   <Loader2 className="w-4 h-4 mr-2 animate-spin" />
                   Saving...
                 </>
+              ) : conflict ? (
+                'Save Merged Changes'
               ) : (
                 'Save Changes'
               )}
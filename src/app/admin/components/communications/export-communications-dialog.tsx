@@ -0,0 +1,230 @@
+'use client';
+
+/**
+ * Export Communications Dialog
+ *
+ * @file src/components/communications/export-communications-dialog.tsx
+ *
+ * Downloads communication history as a PDF transcript, CSV or JSON Lines
+ * in a chosen timezone. On a customer it exports their full history or
+ * just what the current filters show; elsewhere it exports the filtered
+ * set across customers.
+ */
+
+import * as React from 'react';
+import { Download, Loader2 } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import { Label } from '@/components/ui/label';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '@/components/ui/dialog';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { DEFAULT_TIMEZONE, TIMEZONE_OPTIONS, getBrowserTimezone } from '@/lib/utils/timezone';
+import type { CommunicationFilters } from '@/lib/types/communication';
+import {
+  communicationExportFormatOptions,
+  type CommunicationExportFormat,
+} from '@/lib/validations/export';
+import { hasActiveFilters } from './communication-filter-bar';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface ExportCommunicationsDialogProps {
+  /** Limit the export to one customer */
+  customerId?: string;
+  filters: CommunicationFilters;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function getTimezoneOptions(): { value: string; label: string }[] {
+  const browser = getBrowserTimezone();
+  const options: { value: string; label: string }[] = [...TIMEZONE_OPTIONS];
+
+  if (browser && !options.some((option) => option.value === browser)) {
+    options.unshift({ value: browser, label: browser.replace(/_/g, ' ') });
+  }
+  if (!options.some((option) => option.value === 'UTC')) {
+    options.push({ value: 'UTC', label: 'UTC' });
+  }
+
+  return options;
+}
+
+function getFilename(response: Response, fallback: string): string {
+  const disposition = response.headers.get('Content-Disposition') ?? '';
+  return disposition.match(/filename="([^"]+)"/)?.[1] ?? fallback;
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function ExportCommunicationsDialog({
+  customerId,
+  filters,
+  open,
+  onOpenChange,
+}: ExportCommunicationsDialogProps) {
+  const timezoneOptions = React.useMemo(getTimezoneOptions, []);
+  const [format, setFormat] = React.useState<CommunicationExportFormat>('pdf');
+  const [timezone, setTimezone] = React.useState(getBrowserTimezone() ?? DEFAULT_TIMEZONE);
+  const [applyFilters, setApplyFilters] = React.useState(true);
+  const [isExporting, setIsExporting] = React.useState(false);
+
+  const filtered = hasActiveFilters(filters);
+
+  const handleExport = async () => {
+    setIsExporting(true);
+
+    try {
+      const params = new URLSearchParams({ format, timezone });
+      if (customerId) {
+        params.set('customerId', customerId);
+      }
+      if (filtered && (applyFilters || !customerId)) {
+        params.set('filters', JSON.stringify(filters));
+      }
+
+      const response = await fetch(`/api/communications/export?${params}`);
+
+      if (!response.ok) {
+        const body = await response.json().catch(() => null);
+        toast.error(body?.error || 'Failed to export communications');
+        return;
+      }
+
+      const blob = await response.blob();
+      const url = URL.createObjectURL(blob);
+      const link = document.createElement('a');
+      link.href = url;
+      link.download = getFilename(response, `communications.${format}`);
+      link.click();
+      URL.revokeObjectURL(url);
+
+      const count = Number(response.headers.get('X-Export-Count') ?? 0);
+      toast.success(`Exported ${count} ${count === 1 ? 'communication' : 'communications'}`);
+      onOpenChange(false);
+    } catch (error) {
+      console.error('Failed to export communications:', error);
+      toast.error('Failed to export communications');
+    } finally {
+      setIsExporting(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[440px]">
+        <DialogHeader>
+          <DialogTitle>Export Communications</DialogTitle>
+          <DialogDescription>
+            Includes who logged each entry, timestamps and attachment names
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-5">
+          {/* Format */}
+          <div className="space-y-2">
+            <Label>Format</Label>
+            <Select
+              value={format}
+              onValueChange={(value) => setFormat(value as CommunicationExportFormat)}
+            >
+              <SelectTrigger>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {communicationExportFormatOptions.map((option) => (
+                  <SelectItem key={option.value} value={option.value}>
+                    {option.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          {/* Timezone */}
+          <div className="space-y-2">
+            <Label>Timezone</Label>
+            <Select value={timezone} onValueChange={setTimezone}>
+              <SelectTrigger>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {timezoneOptions.map((option) => (
+                  <SelectItem key={option.value} value={option.value}>
+                    {option.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          {/* Scope */}
+          {customerId && filtered && (
+            <div className="flex items-center gap-3">
+              <input
+                type="checkbox"
+                id="export-apply-filters"
+                checked={applyFilters}
+                onChange={(e) => setApplyFilters(e.target.checked)}
+                className="h-4 w-4 rounded border-zinc-300 text-blue-600 focus:ring-blue-500"
+              />
+              <Label htmlFor="export-apply-filters" className="font-normal cursor-pointer">
+                Only communications matching the current filters
+              </Label>
+            </div>
+          )}
+          {!customerId && !filtered && (
+            <p className="text-sm text-amber-700">
+              No filters are set, so this exports every customer&apos;s communications.
+            </p>
+          )}
+        </div>
+
+        <DialogFooter>
+          <Button
+            type="button"
+            variant="secondary"
+            onClick={() => onOpenChange(false)}
+            disabled={isExporting}
+          >
+            Cancel
+          </Button>
+          <Button onClick={handleExport} disabled={isExporting}>
+            {isExporting ? (
+              <>
+                <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                Exporting...
+              </>
+            ) : (
+              <>
+                <Download className="w-4 h-4 mr-2" />
+                Export
+              </>
+            )}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,260 @@
+'use client';
+
+/**
+ * Import Review Queue
+ *
+ * @file src/components/communications/import-review-queue.tsx
+ *
+ * Lists imported messages that could not be matched to exactly one
+ * customer. Staff pick a customer to log the message against, or dismiss it.
+ */
+
+import * as React from 'react';
+import { useRouter } from 'next/navigation';
+import { Search, Loader2, ArrowDownLeft, ArrowUpRight, X, Inbox } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { cn } from '@/lib/utils';
+import { formatDateTime } from '@/lib/utils/timezone';
+import { searchCustomers } from '@/app/actions/customers';
+import { resolveImportQueueItem, dismissImportQueueItem } from '@/app/actions/imports';
+import { communicationTypeLabels } from '@/lib/types/communication';
+import {
+  importQueueReasonLabels,
+  importSourceLabels,
+  type ImportQueueItemWithAdmins,
+} from '@/lib/types/import';
+import type { CommunicationDirection } from '@/lib/validations/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface ImportReviewQueueProps {
+  items: ImportQueueItemWithAdmins[];
+  total: number;
+}
+
+interface CustomerOption {
+  id: string;
+  name: string;
+  phone: string;
+  email: string | null;
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function ImportReviewQueue({ items, total }: ImportReviewQueueProps) {
+  if (items.length === 0) {
+    return (
+      <div className="bg-white border border-zinc-200 rounded-lg p-8 text-center">
+        <Inbox className="w-8 h-8 mx-auto text-zinc-300" />
+        <p className="text-sm text-zinc-500 mt-2">No messages waiting for review.</p>
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-3">
+      <p className="text-sm text-zinc-500">
+        {total} {total === 1 ? 'message' : 'messages'} waiting for review
+      </p>
+      {items.map((item) => (
+        <ReviewQueueItem key={item.id} item={item} />
+      ))}
+    </div>
+  );
+}
+
+// =============================================================================
+// Queue Item
+// =============================================================================
+
+function ReviewQueueItem({ item }: { item: ImportQueueItemWithAdmins }) {
+  const router = useRouter();
+  const [query, setQuery] = React.useState('');
+  const [results, setResults] = React.useState<CustomerOption[]>([]);
+  const [isSearching, setIsSearching] = React.useState(false);
+  // A single matched customer is preselected; the reviewer only confirms direction
+  const [customer, setCustomer] = React.useState<CustomerOption | null>(
+    item.candidate_customers.length === 1 ? item.candidate_customers[0] : null
+  );
+  const [direction, setDirection] = React.useState<CommunicationDirection>(
+    item.direction ?? 'inbound'
+  );
+  const [isSaving, setIsSaving] = React.useState(false);
+
+  // Debounced customer search
+  React.useEffect(() => {
+    if (query.length < 2) {
+      setResults([]);
+      return;
+    }
+
+    const timer = setTimeout(async () => {
+      setIsSearching(true);
+      try {
+        const result = await searchCustomers(query, 5);
+        setResults(result.success ? result.data : []);
+      } catch {
+        setResults([]);
+      } finally {
+        setIsSearching(false);
+      }
+    }, 300);
+
+    return () => clearTimeout(timer);
+  }, [query]);
+
+  const handleAssign = async () => {
+    if (!customer) return;
+
+    setIsSaving(true);
+    try {
+      const result = await resolveImportQueueItem({
+        id: item.id,
+        customerId: customer.id,
+        direction,
+      });
+
+      if (result.success) {
+        toast.success(`Logged for ${customer.name}`);
+        router.refresh();
+      } else {
+        toast.error(result.error);
+      }
+    } catch {
+      toast.error('An unexpected error occurred');
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const handleDismiss = async () => {
+    setIsSaving(true);
+    try {
+      const result = await dismissImportQueueItem(item.id);
+
+      if (result.success) {
+        toast.success('Message dismissed');
+        router.refresh();
+      } else {
+        toast.error(result.error);
+      }
+    } catch {
+      toast.error('An unexpected error occurred');
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <div className="bg-white border border-zinc-200 rounded-lg p-4 space-y-3">
+      {/* Header */}
+      <div className="flex items-start justify-between gap-4">
+        <div className="min-w-0">
+          <p className="text-sm font-medium text-zinc-900 truncate">
+            {item.counterparty ?? 'Unknown sender'}
+          </p>
+          <p className="text-xs text-zinc-500 mt-0.5">
+            {importSourceLabels[item.source]} · {communicationTypeLabels[item.type]} ·{' '}
+            {formatDateTime(item.occurred_at)}
+          </p>
+        </div>
+        <span className="shrink-0 rounded-full bg-amber-50 px-2 py-0.5 text-xs font-medium text-amber-700">
+          {importQueueReasonLabels[item.reason]}
+        </span>
+      </div>
+
+      {/* Summary */}
+      <p className="text-sm text-zinc-700 whitespace-pre-wrap line-clamp-4">{item.summary}</p>
+
+      {/* Assignment */}
+      <div className="flex flex-wrap items-center gap-2 pt-2 border-t border-zinc-100">
+        {customer ? (
+          <div className="flex items-center gap-2 rounded-md border border-zinc-300 px-3 h-9 text-sm">
+            <span className="font-medium text-zinc-900">{customer.name}</span>
+            <button
+              type="button"
+              onClick={() => setCustomer(null)}
+              className="text-zinc-400 hover:text-zinc-600"
+              aria-label="Clear customer"
+            >
+              <X className="w-4 h-4" />
+            </button>
+          </div>
+        ) : (
+          <div className="relative w-64">
+            <Search className="absolute left-3 top-1/2 -translate-y-1/2 w-4 h-4 text-zinc-400" />
+            <Input
+              placeholder="Find customer..."
+              value={query}
+              onChange={(e) => setQuery(e.target.value)}
+              className="pl-9"
+            />
+            {isSearching && (
+              <Loader2 className="absolute right-3 top-1/2 -translate-y-1/2 w-4 h-4 text-zinc-400 animate-spin" />
+            )}
+            {results.length > 0 && (
+              <div className="absolute z-10 mt-1 w-full rounded-md border border-zinc-200 bg-white shadow-lg">
+                {results.map((option) => (
+                  <button
+                    key={option.id}
+                    type="button"
+                    onClick={() => {
+                      setCustomer(option);
+                      setQuery('');
+                      setResults([]);
+                    }}
+                    className="block w-full px-3 py-2 text-left text-sm hover:bg-zinc-50"
+                  >
+                    <span className="font-medium text-zinc-900">{option.name}</span>
+                    {option.email && (
+                      <span className="block text-xs text-zinc-500">{option.email}</span>
+                    )}
+                  </button>
+                ))}
+              </div>
+            )}
+          </div>
+        )}
+
+        {/* Direction */}
+        <div className="flex gap-1">
+          {(['inbound', 'outbound'] as const).map((value) => {
+            const Icon = value === 'inbound' ? ArrowDownLeft : ArrowUpRight;
+            return (
+              <button
+                key={value}
+                type="button"
+                onClick={() => setDirection(value)}
+                className={cn(
+                  'inline-flex items-center gap-1 px-2.5 h-9 rounded-md border text-xs font-medium transition-colors',
+                  direction === value
+                    ? 'bg-zinc-900 text-white border-zinc-900'
+                    : 'bg-white text-zinc-700 border-zinc-300 hover:bg-zinc-50'
+                )}
+              >
+                <Icon className="w-3.5 h-3.5" />
+                {value === 'inbound' ? 'Inbound' : 'Outbound'}
+              </button>
+            );
+          })}
+        </div>
+
+        <div className="ml-auto flex gap-2">
+          <Button variant="ghost" size="sm" onClick={handleDismiss} disabled={isSaving}>
+            Dismiss
+          </Button>
+          <Button size="sm" onClick={handleAssign} disabled={!customer || isSaving}>
+            {isSaving && <Loader2 className="w-4 h-4 mr-2 animate-spin" />}
+            Assign
+          </Button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,166 @@
+'use client';
+
+/**
+ * Import Upload Card
+ *
+ * @file src/components/communications/import-upload-card.tsx
+ *
+ * File picker and result summary for a communication importer.
+ * The import action is passed in so each source (email, SMS, ...) shares
+ * the same upload flow.
+ */
+
+import * as React from 'react';
+import { useRouter } from 'next/navigation';
+import { Upload, Eye, Loader2, CheckCircle2, AlertCircle } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import type { ActionResult } from '@/lib/types/api';
+import type { ImportResult } from '@/lib/types/import';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface ImportUploadCardProps {
+  title: string;
+  description: string;
+  /** Accepted file extensions, e.g. ".eml,.mbox" */
+  accept: string;
+  action: (formData: FormData) => Promise<ActionResult<ImportResult>>;
+  /** Show a Preview button that runs the import as a dry run */
+  supportsDryRun?: boolean;
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function ImportUploadCard({
+  title,
+  description,
+  accept,
+  action,
+  supportsDryRun = false,
+}: ImportUploadCardProps) {
+  const router = useRouter();
+  const inputRef = React.useRef<HTMLInputElement>(null);
+  const [files, setFiles] = React.useState<File[]>([]);
+  const [running, setRunning] = React.useState<'import' | 'preview' | null>(null);
+  const [result, setResult] = React.useState<ImportResult | null>(null);
+
+  const handleRun = async (dryRun: boolean) => {
+    if (files.length === 0) return;
+
+    setRunning(dryRun ? 'preview' : 'import');
+    setResult(null);
+
+    try {
+      const formData = new FormData();
+      files.forEach((file) => formData.append('files', file));
+      if (dryRun) formData.append('dryRun', 'true');
+
+      const response = await action(formData);
+
+      if (response.success) {
+        setResult(response.data);
+        // Keep the files selected after a preview so they can be imported
+        if (!dryRun) {
+          setFiles([]);
+          if (inputRef.current) inputRef.current.value = '';
+          toast.success(`Imported ${response.data.imported} messages`);
+          router.refresh();
+        }
+      } else {
+        toast.error(response.error);
+      }
+    } catch {
+      toast.error('An unexpected error occurred');
+    } finally {
+      setRunning(null);
+    }
+  };
+
+  return (
+    <div className="bg-white border border-zinc-200 rounded-lg p-6 space-y-4">
+      <div>
+        <h2 className="text-base font-semibold text-zinc-900">{title}</h2>
+        <p className="text-sm text-zinc-500 mt-1">{description}</p>
+      </div>
+
+      <div className="flex items-center gap-3">
+        <input
+          ref={inputRef}
+          type="file"
+          multiple
+          accept={accept}
+          onChange={(e) => setFiles(Array.from(e.target.files ?? []))}
+          className="block w-full text-sm text-zinc-700 file:mr-3 file:rounded-md file:border file:border-zinc-300 file:bg-white file:px-3 file:py-1.5 file:text-sm file:font-medium hover:file:bg-zinc-50"
+        />
+        {supportsDryRun && (
+          <Button
+            variant="secondary"
+            onClick={() => handleRun(true)}
+            disabled={files.length === 0 || running !== null}
+          >
+            {running === 'preview' ? (
+              <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+            ) : (
+              <Eye className="w-4 h-4 mr-2" />
+            )}
+            Preview
+          </Button>
+        )}
+        <Button onClick={() => handleRun(false)} disabled={files.length === 0 || running !== null}>
+          {running === 'import' ? (
+            <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+          ) : (
+            <Upload className="w-4 h-4 mr-2" />
+          )}
+          Import
+        </Button>
+      </div>
+
+      {result && <ImportResultSummary result={result} />}
+    </div>
+  );
+}
+
+// =============================================================================
+// Result Summary
+// =============================================================================
+
+function ImportResultSummary({ result }: { result: ImportResult }) {
+  return (
+    <div className="rounded-md border border-zinc-200 bg-zinc-50 p-4 space-y-3">
+      <div className="flex items-center gap-2 text-sm text-zinc-900">
+        <CheckCircle2 className="w-4 h-4 text-green-600" />
+        {result.dryRun ? (
+          <span>
+            Preview: {result.imported} matched · {result.queued} unmatched ·{' '}
+            {result.duplicates} duplicates · {result.failed} unreadable. Nothing has been saved.
+          </span>
+        ) : (
+          <span>
+            {result.imported} imported · {result.duplicates} already imported · {result.queued}{' '}
+            sent to review · {result.failed} failed
+          </span>
+        )}
+      </div>
+
+      {result.errors.length > 0 && (
+        <ul className="space-y-1">
+          {result.errors.map((failure, index) => (
+            <li key={index} className="flex items-start gap-2 text-sm text-red-700">
+              <AlertCircle className="w-4 h-4 mt-0.5 shrink-0" />
+              <span>
+                {failure.file}
+                {failure.reference && ` (${failure.reference})`}: {failure.error}
+              </span>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
@@ -6,14 +6,27 @@
  * @file src/components/communications/log-communication-modal.tsx
  *
  * Modal dialog for logging a new communication (call, text, email).
- * Includes type selector, direction toggle, datetime picker, and summary input.
+ * Includes type selector, direction toggle, datetime picker, summary input,
+ * optional photo/PDF attachments, an optional follow-up on the calendar and
+ * the estimate, appointment or property it is about.
  */
 
 import * as React from 'react';
 import { useForm } from 'react-hook-form';
 import { zodResolver } from '@hookform/resolvers/zod';
 import { z } from 'zod';
-import { Phone, MessageSquare, Mail, ArrowDownLeft, ArrowUpRight, Loader2 } from 'lucide-react';
+import {
+  Phone,
+  MessageSquare,
+  Mail,
+  Voicemail,
+  Users,
+  Globe,
+  AtSign,
+  ArrowDownLeft,
+  ArrowUpRight,
+  Loader2,
+} from 'lucide-react';
 import {
   Dialog,
   DialogContent,
@@ -27,11 +40,24 @@ import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import { Textarea } from '@/components/ui/textarea';
 import { cn } from '@/lib/utils';
-import { createCommunication } from '@/app/actions/communications';
+import {
+  createCommunication,
+  uploadCommunicationAttachments,
+} from '@/app/actions/communications';
+import { communicationTypeLabels } from '@/lib/types/communication';
+import { CallDetailsFields } from './call-details-fields';
+import { AttachmentPicker } from './communication-attachments';
+import {
+  CommunicationLinkFields,
+  EMPTY_COMMUNICATION_LINKS,
+  type CommunicationLinkValues,
+} from './communication-links';
 import { toast } from 'sonner';
 import {
   COMMUNICATION_TYPES,
   COMMUNICATION_DIRECTIONS,
+  CALL_OUTCOMES,
+  parseCallDuration,
   type CommunicationType,
   type CommunicationDirection,
 } from '@/lib/validations/communication';
@@ -50,18 +76,50 @@ interface LogCommunicationModalProps {
    * Pre-select communication type when opening modal
    */
   defaultType?: CommunicationType;
+  /**
+   * Pre-select direction when opening modal
+   */
+  defaultDirection?: CommunicationDirection;
+  /**
+   * Communication being replied to; the new entry joins its thread
+   */
+  parentId?: string;
+  /**
+   * Pre-link an estimate when logging from its page
+   */
+  defaultEstimateId?: string;
+  /**
+   * Pre-link a calendar event when logging from it
+   */
+  defaultCalendarEventId?: string;
 }
 
 // =============================================================================
 // Form Schema
 // =============================================================================
 
-const formSchema = z.object({
-  type: z.enum(COMMUNICATION_TYPES),
-  direction: z.enum(COMMUNICATION_DIRECTIONS),
-  summary: z.string().min(1, 'Please enter a summary').max(5000),
-  occurredAt: z.string().min(1, 'Please select when this occurred'),
-});
+const formSchema = z
+  .object({
+    type: z.enum(COMMUNICATION_TYPES),
+    direction: z.enum(COMMUNICATION_DIRECTIONS),
+    summary: z.string().min(1, 'Please enter a summary').max(5000),
+    occurredAt: z.string().min(1, 'Please select when this occurred'),
+    callOutcome: z.enum(CALL_OUTCOMES).optional(),
+    callDuration: z
+      .string()
+      .optional()
+      .refine((val) => !val?.trim() || parseCallDuration(val) !== null, {
+        message: 'Use m:ss or minutes',
+      }),
+    callNumber: z.string().max(30).optional(),
+    scheduleFollowUp: z.boolean(),
+    followUpAt: z.string().optional(),
+    followUpTitle: z.string().max(200).optional(),
+  })
+  .refine((data) => !data.scheduleFollowUp || !!data.followUpAt, {
+    message: 'Please choose when to follow up',
+    path: ['followUpAt'],
+  });
 
 type FormData = z.infer<typeof formSchema>;
 
@@ -79,10 +137,14 @@ function TypeSelector({ value, onChange }: TypeSelectorProps) {
     { value: 'call', label: 'Call', icon: <Phone className="w-4 h-4" /> },
     { value: 'text', label: 'Text', icon: <MessageSquare className="w-4 h-4" /> },
     { value: 'email', label: 'Email', icon: <Mail className="w-4 h-4" /> },
+    { value: 'voicemail', label: 'Voicemail', icon: <Voicemail className="w-4 h-4" /> },
+    { value: 'in_person', label: 'In Person', icon: <Users className="w-4 h-4" /> },
+    { value: 'web_form', label: 'Web Form', icon: <Globe className="w-4 h-4" /> },
+    { value: 'social', label: 'Social', icon: <AtSign className="w-4 h-4" /> },
   ];
 
   return (
-    <div className="flex gap-2">
+    <div className="grid grid-cols-2 sm:grid-cols-4 gap-2">
       {types.map((type) => (
         <button
           key={type.value}
@@ -151,24 +213,39 @@ export function LogCommunicationModal({
   onOpenChange,
   onSuccess,
   defaultType = 'call',
+  defaultDirection = 'outbound',
+  parentId,
+  defaultEstimateId,
+  defaultCalendarEventId,
 }: LogCommunicationModalProps) {
   const [isSubmitting, setIsSubmitting] = React.useState(false);
+  const [files, setFiles] = React.useState<File[]>([]);
+  const [links, setLinks] = React.useState<CommunicationLinkValues>(EMPTY_COMMUNICATION_LINKS);
 
   // Get current datetime in local timezone for default value
-  const getLocalDateTimeString = () => {
-    const now = new Date();
-    const offset = now.getTimezoneOffset();
-    const localDate = new Date(now.getTime() - offset * 60 * 1000);
+  const getLocalDateTimeString = (date = new Date()) => {
+    const offset = date.getTimezoneOffset();
+    const localDate = new Date(date.getTime() - offset * 60 * 1000);
     return localDate.toISOString().slice(0, 16);
   };
 
+  // Follow-ups default to the same time tomorrow
+  const getDefaultFollowUpString = () =>
+    getLocalDateTimeString(new Date(Date.now() + 24 * 60 * 60 * 1000));
+
   const form = useForm<FormData>({
     resolver: zodResolver(formSchema),
     defaultValues: {
       type: defaultType,
-      direction: 'outbound',
+      direction: defaultDirection,
       summary: '',
       occurredAt: getLocalDateTimeString(),
+      callOutcome: undefined,
+      callDuration: '',
+      callNumber: '',
+      scheduleFollowUp: false,
+      followUpAt: getDefaultFollowUpString(),
+      followUpTitle: '',
     },
   });
 
@@ -177,12 +254,24 @@ export function LogCommunicationModal({
     if (open) {
       form.reset({
         type: defaultType,
-        direction: 'outbound',
+        direction: defaultDirection,
         summary: '',
         occurredAt: getLocalDateTimeString(),
+        callOutcome: undefined,
+        callDuration: '',
+        callNumber: '',
+        scheduleFollowUp: false,
+        followUpAt: getDefaultFollowUpString(),
+        followUpTitle: '',
+      });
+      setFiles([]);
+      setLinks({
+        ...EMPTY_COMMUNICATION_LINKS,
+        estimateId: defaultEstimateId ?? null,
+        calendarEventId: defaultCalendarEventId ?? null,
       });
     }
-  }, [open, defaultType, form]);
+  }, [open, defaultType, defaultDirection, defaultEstimateId, defaultCalendarEventId, form]);
 
   const onSubmit = async (data: FormData) => {
     setIsSubmitting(true);
@@ -191,12 +280,31 @@ export function LogCommunicationModal({
       // Convert local datetime to ISO string
       const occurredAt = new Date(data.occurredAt).toISOString();
 
+      const isCall = data.type === 'call';
       const result = await createCommunication({
         customerId,
         type: data.type,
         direction: data.direction,
         summary: data.summary,
         occurredAt,
+        parentId,
+        callOutcome: isCall ? data.callOutcome : undefined,
+        callDurationSeconds: isCall
+          ? parseCallDuration(data.callDuration) ?? undefined
+          : undefined,
+        callNumber: isCall && data.callNumber?.trim() ? data.callNumber : undefined,
+        estimateId: links.estimateId ?? undefined,
+        calendarEventId: links.calendarEventId ?? undefined,
+        propertyId: links.propertyId ?? undefined,
+        poolId: links.poolId ?? undefined,
+        followUp:
+          data.scheduleFollowUp && data.followUpAt
+            ? {
+                title: data.followUpTitle?.trim() || undefined,
+                startDatetime: new Date(data.followUpAt).toISOString(),
+                allDay: false,
+              }
+            : undefined,
       });
 
       if (!result.success) {
@@ -204,7 +312,27 @@ export function LogCommunicationModal({
         return;
       }
 
-      toast.success(`${data.type.charAt(0).toUpperCase() + data.type.slice(1)} logged successfully`);
+      if (files.length > 0) {
+        const formData = new FormData();
+        formData.set('communicationId', result.data.id);
+        formData.set('customerId', customerId);
+        files.forEach((file) => formData.append('files', file));
+
+        const upload = await uploadCommunicationAttachments(formData);
+        if (!upload.success) {
+          // The communication is saved; don't lose it over the files
+          toast.error(`Logged, but attachments failed to upload: ${upload.error}`);
+          onOpenChange(false);
+          onSuccess?.();
+          return;
+        }
+      }
+
+      toast.success(
+        data.scheduleFollowUp
+          ? `${communicationTypeLabels[data.type]} logged and follow-up scheduled`
+          : `${communicationTypeLabels[data.type]} logged successfully`
+      );
       onOpenChange(false);
       onSuccess?.();
     } catch (error) {
@@ -217,14 +345,17 @@ export function LogCommunicationModal({
 
   const watchType = form.watch('type');
   const watchDirection = form.watch('direction');
+  const watchScheduleFollowUp = form.watch('scheduleFollowUp');
 
   return (
     <Dialog open={open} onOpenChange={onOpenChange}>
-      <DialogContent className="sm:max-w-[480px]">
+      <DialogContent className="sm:max-w-[480px] max-h-[90vh] overflow-y-auto">
         <DialogHeader>
-          <DialogTitle>Log Communication</DialogTitle>
+          <DialogTitle>{parentId ? 'Log Reply' : 'Log Communication'}</DialogTitle>
           <DialogDescription>
-            Record an interaction with {customerName}
+            {parentId
+              ? `Add a reply to this conversation with ${customerName}`
+              : `Record an interaction with ${customerName}`}
           </DialogDescription>
         </DialogHeader>
 
@@ -263,12 +394,28 @@ export function LogCommunicationModal({
             )}
           </div>
 
+          {/* Call Details */}
+          {watchType === 'call' && (
+            <CallDetailsFields
+              outcome={form.watch('callOutcome')}
+              onOutcomeChange={(value) => form.setValue('callOutcome', value)}
+              durationInput={form.watch('callDuration') ?? ''}
+              onDurationInputChange={(value) =>
+                form.setValue('callDuration', value, { shouldValidate: true })
+              }
+              durationError={form.formState.errors.callDuration?.message}
+              callNumber={form.watch('callNumber') ?? ''}
+              onCallNumberChange={(value) => form.setValue('callNumber', value)}
+              direction={watchDirection}
+            />
+          )}
+
           {/* Summary */}
           <div className="space-y-2">
             <Label htmlFor="summary">Summary</Label>
             <Textarea
               id="summary"
-              placeholder={`What was discussed during this ${watchType}?`}
+              placeholder={`What was discussed during this ${communicationTypeLabels[watchType].toLowerCase()}?`}
               rows={4}
               {...form.register('summary')}
               className={cn(
@@ -286,6 +433,65 @@ export function LogCommunicationModal({
             </p>
           </div>
 
+          {/* Follow-up */}
+          <div className="space-y-3">
+            <div className="flex items-center gap-3">
+              <input
+                type="checkbox"
+                id="scheduleFollowUp"
+                {...form.register('scheduleFollowUp')}
+                className="h-4 w-4 rounded border-zinc-300 text-blue-600 focus:ring-blue-500"
+              />
+              <Label htmlFor="scheduleFollowUp" className="font-normal cursor-pointer">
+                Schedule a follow-up
+              </Label>
+            </div>
+
+            {watchScheduleFollowUp && (
+              <div className="grid grid-cols-2 gap-3 pl-7">
+                <div className="space-y-2">
+                  <Label htmlFor="followUpAt">Follow up on</Label>
+                  <Input
+                    id="followUpAt"
+                    type="datetime-local"
+                    {...form.register('followUpAt')}
+                    className={cn(form.formState.errors.followUpAt && 'border-red-500')}
+                  />
+                  {form.formState.errors.followUpAt && (
+                    <p className="text-sm text-red-600">
+                      {form.formState.errors.followUpAt.message}
+                    </p>
+                  )}
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="followUpTitle">Title</Label>
+                  <Input
+                    id="followUpTitle"
+                    placeholder={`Follow up with ${customerName}`}
+                    {...form.register('followUpTitle')}
+                  />
+                </div>
+              </div>
+            )}
+          </div>
+
+          {/* Related To */}
+          <div className="space-y-2">
+            <Label>Related to</Label>
+            <CommunicationLinkFields
+              customerId={customerId}
+              value={links}
+              onChange={setLinks}
+              disabled={isSubmitting}
+            />
+          </div>
+
+          {/* Attachments */}
+          <div className="space-y-2">
+            <Label>Attachments</Label>
+            <AttachmentPicker files={files} onChange={setFiles} disabled={isSubmitting} />
+          </div>
+
           <DialogFooter>
             <Button
               type="button"
@@ -0,0 +1,226 @@
+'use client';
+
+/**
+ * Needs Response Queue
+ *
+ * @file src/components/communications/needs-response-queue.tsx
+ *
+ * Customers whose inbound messages have not been answered yet, oldest
+ * first. Staff open the customer to reply, snooze the entry until later,
+ * or dismiss it when no reply is needed.
+ */
+
+import * as React from 'react';
+import Link from 'next/link';
+import { useRouter } from 'next/navigation';
+import { formatDistanceToNow } from 'date-fns';
+import { AlarmClock, ArrowRight, CheckCheck, Clock, Inbox } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu';
+import { cn } from '@/lib/utils';
+import { formatPhone } from '@/lib/utils/phone';
+import { formatDateTime } from '@/lib/utils/timezone';
+import { snoozeNeedsResponse, dismissNeedsResponse } from '@/app/actions/communications';
+import {
+  communicationTypeLabels,
+  type NeedsResponseEntry,
+  type NeedsResponseQueue as NeedsResponseQueueData,
+} from '@/lib/types/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface NeedsResponseQueueProps {
+  queue: NeedsResponseQueueData;
+}
+
+// =============================================================================
+// Snooze Options
+// =============================================================================
+
+/**
+ * Snooze presets, computed in the browser's time zone
+ */
+function getSnoozeOptions(now = new Date()): { label: string; until: Date }[] {
+  const tomorrowMorning = new Date(now);
+  tomorrowMorning.setDate(now.getDate() + 1);
+  tomorrowMorning.setHours(8, 0, 0, 0);
+
+  const nextMonday = new Date(now);
+  nextMonday.setDate(now.getDate() + ((8 - now.getDay()) % 7 || 7));
+  nextMonday.setHours(8, 0, 0, 0);
+
+  return [
+    { label: '1 hour', until: new Date(now.getTime() + 60 * 60 * 1000) },
+    { label: '4 hours', until: new Date(now.getTime() + 4 * 60 * 60 * 1000) },
+    { label: 'Tomorrow morning', until: tomorrowMorning },
+    { label: 'Next Monday', until: nextMonday },
+  ];
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function NeedsResponseQueue({ queue }: NeedsResponseQueueProps) {
+  if (queue.entries.length === 0) {
+    return (
+      <div className="bg-white border border-zinc-200 rounded-lg p-8 text-center">
+        <Inbox className="w-8 h-8 mx-auto text-zinc-300" />
+        <p className="text-sm text-zinc-500 mt-2">Everyone has been answered.</p>
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-3">
+      <p className="text-sm text-zinc-500">
+        {queue.total} {queue.total === 1 ? 'customer' : 'customers'} waiting
+        {queue.overdue > 0 && (
+          <span className="text-red-600">
+            {' '}
+            · {queue.overdue} past the {queue.slaHours} business hour target
+          </span>
+        )}
+      </p>
+      {queue.entries.map((entry) => (
+        <NeedsResponseItem key={entry.communication.customer_id} entry={entry} />
+      ))}
+    </div>
+  );
+}
+
+// =============================================================================
+// Queue Item
+// =============================================================================
+
+function NeedsResponseItem({ entry }: { entry: NeedsResponseEntry }) {
+  const router = useRouter();
+  const [isSaving, setIsSaving] = React.useState(false);
+  const { communication } = entry;
+
+  const handleSnooze = async (until: Date) => {
+    setIsSaving(true);
+    try {
+      const result = await snoozeNeedsResponse({
+        customerId: communication.customer_id,
+        until: until.toISOString(),
+      });
+
+      if (result.success) {
+        toast.success(`Snoozed until ${formatDateTime(until, 'shortWithTime')}`);
+        router.refresh();
+      } else {
+        toast.error(result.error);
+      }
+    } catch {
+      toast.error('An unexpected error occurred');
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const handleDismiss = async () => {
+    setIsSaving(true);
+    try {
+      const result = await dismissNeedsResponse({ customerId: communication.customer_id });
+
+      if (result.success) {
+        toast.success('Removed from queue');
+        router.refresh();
+      } else {
+        toast.error(result.error);
+      }
+    } catch {
+      toast.error('An unexpected error occurred');
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <div
+      className={cn(
+        'bg-white border rounded-lg p-4 space-y-3',
+        entry.isOverdue ? 'border-red-200' : 'border-zinc-200'
+      )}
+    >
+      {/* Header */}
+      <div className="flex items-start justify-between gap-4">
+        <div className="min-w-0">
+          <Link
+            href={`/admin/customers/${communication.customer_id}?tab=communications`}
+            className="text-sm font-medium text-zinc-900 hover:underline truncate"
+          >
+            {communication.customer.name}
+          </Link>
+          <p className="text-xs text-zinc-500 mt-0.5">
+            {formatPhone(communication.customer.phone)} ·{' '}
+            {communicationTypeLabels[communication.type]} ·{' '}
+            {formatDateTime(communication.occurred_at)}
+            {entry.unansweredCount > 1 && ` · ${entry.unansweredCount} messages`}
+          </p>
+        </div>
+        <span
+          className={cn(
+            'shrink-0 inline-flex items-center gap-1 rounded-full px-2 py-0.5 text-xs font-medium',
+            entry.isOverdue ? 'bg-red-50 text-red-700' : 'bg-amber-50 text-amber-700'
+          )}
+          title={`Reply due ${formatDateTime(entry.dueAt)}`}
+        >
+          <Clock className="w-3 h-3" />
+          {entry.isOverdue
+            ? `Overdue · waiting ${formatDistanceToNow(new Date(entry.waitingSince))}`
+            : `Due ${formatDateTime(entry.dueAt, 'shortWithTime')}`}
+        </span>
+      </div>
+
+      {/* Summary */}
+      <p className="text-sm text-zinc-700 whitespace-pre-wrap line-clamp-3">
+        {communication.summary}
+      </p>
+
+      {/* Actions */}
+      <div className="flex items-center gap-2 pt-2 border-t border-zinc-100">
+        <DropdownMenu>
+          <DropdownMenuTrigger asChild>
+            <Button variant="ghost" size="sm" disabled={isSaving}>
+              <AlarmClock className="w-4 h-4 mr-1.5" />
+              Snooze
+            </Button>
+          </DropdownMenuTrigger>
+          <DropdownMenuContent align="start">
+            {getSnoozeOptions().map((option) => (
+              <DropdownMenuItem
+                key={option.label}
+                onClick={() => handleSnooze(option.until)}
+              >
+                {option.label}
+              </DropdownMenuItem>
+            ))}
+          </DropdownMenuContent>
+        </DropdownMenu>
+        <Button variant="ghost" size="sm" onClick={handleDismiss} disabled={isSaving}>
+          <CheckCheck className="w-4 h-4 mr-1.5" />
+          No reply needed
+        </Button>
+
+        <div className="ml-auto">
+          <Button size="sm" asChild>
+            <Link href={`/admin/customers/${communication.customer_id}?tab=communications`}>
+              Reply
+              <ArrowRight className="w-4 h-4 ml-1" />
+            </Link>
+          </Button>
+        </div>
+      </div>
+    </div>
+  );
+}
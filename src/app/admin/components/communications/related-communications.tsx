@@ -0,0 +1,159 @@
+'use client';
+
+/**
+ * Related Communications
+ *
+ * @file src/components/communications/related-communications.tsx
+ *
+ * Compact list of the communications linked to an estimate or calendar
+ * event, newest first. Used on the estimate detail page and in the event
+ * popover.
+ */
+
+import * as React from 'react';
+import { formatDistanceToNow } from 'date-fns';
+import {
+  Globe,
+  Loader2,
+  Mail,
+  MessageSquare,
+  Phone,
+  Share2,
+  Users,
+  Voicemail,
+  type LucideIcon,
+} from 'lucide-react';
+import { cn } from '@/lib/utils';
+import { listLinkedCommunications } from '@/app/actions/communications';
+import {
+  communicationTypeLabels,
+  type CommunicationWithLogger,
+} from '@/lib/types/communication';
+import type { Communication } from '@/lib/types/database';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface RelatedCommunicationsProps {
+  estimateId?: string;
+  calendarEventId?: string;
+  /** Change to reload, e.g. after logging a new communication */
+  refreshKey?: number;
+  /** Number of communications to show */
+  limit?: number;
+  emptyMessage?: string;
+  className?: string;
+}
+
+const communicationIcons: Record<Communication['type'], LucideIcon> = {
+  call: Phone,
+  text: MessageSquare,
+  email: Mail,
+  voicemail: Voicemail,
+  in_person: Users,
+  web_form: Globe,
+  social: Share2,
+};
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function RelatedCommunications({
+  estimateId,
+  calendarEventId,
+  refreshKey = 0,
+  limit = 50,
+  emptyMessage = 'No communications linked yet.',
+  className,
+}: RelatedCommunicationsProps) {
+  const [communications, setCommunications] = React.useState<CommunicationWithLogger[]>([]);
+  const [isLoading, setIsLoading] = React.useState(true);
+  const [error, setError] = React.useState<string | null>(null);
+
+  React.useEffect(() => {
+    let cancelled = false;
+    setIsLoading(true);
+
+    listLinkedCommunications({ estimateId, calendarEventId, limit })
+      .then((result) => {
+        if (cancelled) return;
+        if (result.success) {
+          setCommunications(result.data);
+          setError(null);
+        } else {
+          setError(result.error || 'Failed to load related communications');
+        }
+      })
+      .catch((err) => {
+        console.error('Failed to load related communications:', err);
+        if (!cancelled) setError('Failed to load related communications');
+      })
+      .finally(() => {
+        if (!cancelled) setIsLoading(false);
+      });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [estimateId, calendarEventId, limit, refreshKey]);
+
+  if (isLoading && communications.length === 0) {
+    return (
+      <div className={cn('flex items-center justify-center py-4', className)}>
+        <Loader2 className="w-4 h-4 animate-spin text-zinc-400" />
+      </div>
+    );
+  }
+
+  if (error) {
+    return <p className={cn('text-sm text-red-600', className)}>{error}</p>;
+  }
+
+  if (communications.length === 0) {
+    return <p className={cn('text-sm text-zinc-500', className)}>{emptyMessage}</p>;
+  }
+
+  return (
+    <ul className={cn('space-y-3', isLoading && 'opacity-60', className)}>
+      {communications.map((communication) => {
+        const Icon = communicationIcons[communication.type] ?? MessageSquare;
+
+        return (
+          <li key={communication.id} className="flex gap-3">
+            <div className="w-7 h-7 rounded-full bg-zinc-100 flex items-center justify-center flex-shrink-0">
+              <Icon className="w-3.5 h-3.5 text-zinc-600" />
+            </div>
+            <div className="flex-1 min-w-0">
+              <div className="flex items-baseline justify-between gap-2">
+                <p className="text-sm font-medium text-zinc-900 truncate">
+                  {communicationTypeLabels[communication.type]}
+                  <span className="ml-1.5 font-normal text-zinc-500 capitalize">
+                    {communication.direction}
+                  </span>
+                </p>
+                <span
+                  className="text-xs text-zinc-400 flex-shrink-0"
+                  title={new Date(communication.occurred_at).toLocaleString()}
+                >
+                  {formatDistanceToNow(new Date(communication.occurred_at), {
+                    addSuffix: true,
+                  })}
+                </span>
+              </div>
+              <p className="text-sm text-zinc-600 whitespace-pre-wrap break-words line-clamp-2">
+                {communication.summary}
+              </p>
+              {communication.logged_by_admin && (
+                <p className="text-xs text-zinc-400 mt-0.5">
+                  {communication.logged_by_admin.full_name}
+                </p>
+              )}
+            </div>
+          </li>
+        );
+      })}
+    </ul>
+  );
+}
@@ -0,0 +1,313 @@
+'use client';
+
+/**
+ * Saved Views Menu
+ *
+ * @file src/components/communications/saved-views-menu.tsx
+ *
+ * Lists saved communication filter sets, applies one, saves the current
+ * filters under a name and copies a link that opens a view.
+ */
+
+import * as React from 'react';
+import { Bookmark, Link2, Loader2, Lock, Plus, Trash2 } from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '@/components/ui/dialog';
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuLabel,
+  DropdownMenuSeparator,
+  DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu';
+import {
+  deleteCommunicationView,
+  listCommunicationViews,
+  saveCommunicationView,
+} from '@/app/actions/communication-views';
+import type {
+  CommunicationFilters,
+  SavedCommunicationView,
+} from '@/lib/types/communication';
+import { hasActiveFilters } from './communication-filter-bar';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface SavedViewsMenuProps {
+  filters: CommunicationFilters;
+  activeViewId: string | null;
+  onApply: (view: SavedCommunicationView) => void;
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * The current page's URL with the view parameter set
+ */
+function getViewUrl(viewId: string): string {
+  const url = new URL(window.location.href);
+  url.searchParams.set('view', viewId);
+  return url.toString();
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function SavedViewsMenu({ filters, activeViewId, onApply }: SavedViewsMenuProps) {
+  const [views, setViews] = React.useState<SavedCommunicationView[] | null>(null);
+  const [isLoading, setIsLoading] = React.useState(false);
+  const [showSaveDialog, setShowSaveDialog] = React.useState(false);
+
+  const activeView = views?.find((view) => view.id === activeViewId);
+
+  const loadViews = async () => {
+    setIsLoading(true);
+    try {
+      const result = await listCommunicationViews();
+
+      if (result.success) {
+        setViews(result.data);
+      } else {
+        toast.error(result.error || 'Failed to load saved views');
+      }
+    } catch (error) {
+      console.error('Failed to load saved views:', error);
+      toast.error('Failed to load saved views');
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  const handleCopyLink = async (view: SavedCommunicationView) => {
+    try {
+      await navigator.clipboard.writeText(getViewUrl(view.id));
+      toast.success(
+        view.is_shared ? 'Link copied' : 'Link copied — only you can open this private view'
+      );
+    } catch {
+      toast.error('Failed to copy link');
+    }
+  };
+
+  const handleDelete = async (view: SavedCommunicationView) => {
+    try {
+      const result = await deleteCommunicationView(view.id);
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to delete view');
+        return;
+      }
+
+      setViews((prev) => prev?.filter((item) => item.id !== view.id) ?? null);
+      toast.success(`Deleted "${view.name}"`);
+    } catch (error) {
+      console.error('Failed to delete view:', error);
+      toast.error('Failed to delete view');
+    }
+  };
+
+  const handleSaved = (view: SavedCommunicationView) => {
+    setViews((prev) =>
+      [...(prev ?? []), view].sort((a, b) => a.name.localeCompare(b.name))
+    );
+    onApply(view);
+  };
+
+  return (
+    <>
+      <DropdownMenu onOpenChange={(open) => open && views === null && loadViews()}>
+        <DropdownMenuTrigger asChild>
+          <Button variant="secondary" size="sm" className="h-10 gap-1.5">
+            <Bookmark className="w-4 h-4" />
+            <span className="truncate max-w-[140px]">{activeView?.name ?? 'Views'}</span>
+          </Button>
+        </DropdownMenuTrigger>
+        <DropdownMenuContent align="end" className="w-64">
+          <DropdownMenuLabel>Saved views</DropdownMenuLabel>
+          {isLoading ? (
+            <div className="flex justify-center py-2 text-zinc-400">
+              <Loader2 className="w-4 h-4 animate-spin" />
+            </div>
+          ) : views && views.length > 0 ? (
+            views.map((view) => (
+              <DropdownMenuItem
+                key={view.id}
+                onClick={() => onApply(view)}
+                className="group flex items-center gap-2"
+              >
+                {!view.is_shared && <Lock className="w-3.5 h-3.5 text-zinc-400" />}
+                <span className="flex-1 truncate">{view.name}</span>
+                <button
+                  type="button"
+                  onClick={(event) => {
+                    event.stopPropagation();
+                    handleCopyLink(view);
+                  }}
+                  className="text-zinc-400 hover:text-zinc-700"
+                  aria-label={`Copy link to ${view.name}`}
+                >
+                  <Link2 className="w-3.5 h-3.5" />
+                </button>
+                {view.canEdit && (
+                  <button
+                    type="button"
+                    onClick={(event) => {
+                      event.stopPropagation();
+                      handleDelete(view);
+                    }}
+                    className="text-zinc-400 hover:text-red-600"
+                    aria-label={`Delete ${view.name}`}
+                  >
+                    <Trash2 className="w-3.5 h-3.5" />
+                  </button>
+                )}
+              </DropdownMenuItem>
+            ))
+          ) : (
+            <p className="px-2 py-1.5 text-sm text-zinc-500">No saved views yet</p>
+          )}
+          <DropdownMenuSeparator />
+          <DropdownMenuItem
+            disabled={!hasActiveFilters(filters)}
+            onClick={() => setShowSaveDialog(true)}
+          >
+            <Plus className="w-4 h-4 mr-2" />
+            Save current view…
+          </DropdownMenuItem>
+        </DropdownMenuContent>
+      </DropdownMenu>
+
+      <SaveViewDialog
+        filters={filters}
+        open={showSaveDialog}
+        onOpenChange={setShowSaveDialog}
+        onSaved={handleSaved}
+      />
+    </>
+  );
+}
+
+// =============================================================================
+// Save View Dialog
+// =============================================================================
+
+interface SaveViewDialogProps {
+  filters: CommunicationFilters;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  onSaved: (view: SavedCommunicationView) => void;
+}
+
+function SaveViewDialog({ filters, open, onOpenChange, onSaved }: SaveViewDialogProps) {
+  const [name, setName] = React.useState('');
+  const [isShared, setIsShared] = React.useState(true);
+  const [isSubmitting, setIsSubmitting] = React.useState(false);
+
+  React.useEffect(() => {
+    if (open) {
+      setName('');
+      setIsShared(true);
+    }
+  }, [open]);
+
+  const handleSubmit = async (event: React.FormEvent) => {
+    event.preventDefault();
+    setIsSubmitting(true);
+
+    try {
+      const result = await saveCommunicationView({ name, filters, isShared });
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to save view');
+        return;
+      }
+
+      toast.success(`Saved "${result.data.name}"`);
+      onSaved(result.data);
+      onOpenChange(false);
+    } catch (error) {
+      console.error('Failed to save view:', error);
+      toast.error('Failed to save view');
+    } finally {
+      setIsSubmitting(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[420px]">
+        <DialogHeader>
+          <DialogTitle>Save View</DialogTitle>
+          <DialogDescription>
+            Save the current filters to open them again later
+          </DialogDescription>
+        </DialogHeader>
+
+        <form onSubmit={handleSubmit} className="space-y-5">
+          {/* Name */}
+          <div className="space-y-2">
+            <Label htmlFor="view-name">Name</Label>
+            <Input
+              id="view-name"
+              placeholder="e.g. Unanswered web forms"
+              value={name}
+              maxLength={100}
+              onChange={(e) => setName(e.target.value)}
+            />
+          </div>
+
+          {/* Sharing */}
+          <div className="flex items-center gap-3">
+            <input
+              type="checkbox"
+              id="view-shared"
+              checked={isShared}
+              onChange={(e) => setIsShared(e.target.checked)}
+              className="h-4 w-4 rounded border-zinc-300 text-blue-600 focus:ring-blue-500"
+            />
+            <Label htmlFor="view-shared" className="font-normal cursor-pointer">
+              Share with the team
+            </Label>
+          </div>
+
+          <DialogFooter>
+            <Button
+              type="button"
+              variant="secondary"
+              onClick={() => onOpenChange(false)}
+              disabled={isSubmitting}
+            >
+              Cancel
+            </Button>
+            <Button type="submit" disabled={isSubmitting || !name.trim()}>
+              {isSubmitting ? (
+                <>
+                  <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                  Saving...
+                </>
+              ) : (
+                'Save View'
+              )}
+            </Button>
+          </DialogFooter>
+        </form>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,439 @@
+'use client';
+
+/**
+ * Send Message Modal
+ *
+ * @file src/components/communications/send-message-modal.tsx
+ *
+ * Modal dialog for emailing or texting a customer from the CRM.
+ * The message is sent through the configured provider and logged
+ * as an outbound communication. A saved template can be chosen instead
+ * of writing the message; it is previewed for this customer before sending.
+ */
+
+import * as React from 'react';
+import { useForm } from 'react-hook-form';
+import { zodResolver } from '@hookform/resolvers/zod';
+import { z } from 'zod';
+import { Mail, MessageSquare, Loader2, AlertCircle } from 'lucide-react';
+import {
+  Dialog,
+  DialogContent,
+  DialogHeader,
+  DialogTitle,
+  DialogDescription,
+  DialogFooter,
+} from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { Textarea } from '@/components/ui/textarea';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { cn } from '@/lib/utils';
+import { sendMessage } from '@/app/actions/notifications';
+import { listTemplates, previewTemplate, sendTemplate } from '@/app/actions/templates';
+import type { MessageTemplateWithAuthor, RenderedTemplate } from '@/lib/types/template';
+import type { CommunicationWithLogger } from '@/lib/types/communication';
+import { toast } from 'sonner';
+import {
+  NOTIFICATION_CHANNELS,
+  MAX_EMAIL_BODY_LENGTH,
+  MAX_SMS_BODY_LENGTH,
+  type NotificationChannel,
+} from '@/lib/validations/notification';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface SendMessageModalProps {
+  customerId: string;
+  customerName: string;
+  /** Whether the customer has an email address on file */
+  hasEmail: boolean;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  onSuccess?: () => void;
+  /**
+   * Pre-select channel when opening modal
+   */
+  defaultChannel?: NotificationChannel;
+  /**
+   * Communication being replied to; the sent message joins its thread
+   */
+  parentId?: string;
+}
+
+// =============================================================================
+// Form Schema
+// =============================================================================
+
+const formSchema = z
+  .object({
+    channel: z.enum(NOTIFICATION_CHANNELS),
+    subject: z.string().max(200, 'Subject must be 200 characters or less'),
+    body: z.string().trim().min(1, 'Please enter a message'),
+  })
+  .superRefine((data, ctx) => {
+    if (data.channel === 'email' && !data.subject.trim()) {
+      ctx.addIssue({ code: 'custom', path: ['subject'], message: 'Please enter a subject' });
+    }
+
+    const max = data.channel === 'email' ? MAX_EMAIL_BODY_LENGTH : MAX_SMS_BODY_LENGTH;
+    if (data.body.length > max) {
+      ctx.addIssue({
+        code: 'custom',
+        path: ['body'],
+        message: `Message must be ${max} characters or less`,
+      });
+    }
+  });
+
+type FormData = z.infer<typeof formSchema>;
+
+/** Select value for writing a message without a template */
+const NO_TEMPLATE = 'none';
+
+// =============================================================================
+// Channel Toggle Component
+// =============================================================================
+
+interface ChannelToggleProps {
+  value: NotificationChannel;
+  onChange: (value: NotificationChannel) => void;
+  emailDisabled: boolean;
+}
+
+function ChannelToggle({ value, onChange, emailDisabled }: ChannelToggleProps) {
+  const channels: { value: NotificationChannel; label: string; icon: React.ReactNode }[] = [
+    { value: 'email', label: 'Email', icon: <Mail className="w-4 h-4" /> },
+    { value: 'sms', label: 'Text', icon: <MessageSquare className="w-4 h-4" /> },
+  ];
+
+  return (
+    <div className="flex gap-2">
+      {channels.map((channel) => {
+        const disabled = channel.value === 'email' && emailDisabled;
+
+        return (
+          <button
+            key={channel.value}
+            type="button"
+            disabled={disabled}
+            title={disabled ? 'No email address on file' : undefined}
+            onClick={() => onChange(channel.value)}
+            className={cn(
+              'flex-1 flex items-center justify-center gap-2 px-3 py-2.5 rounded-md border text-sm font-medium transition-colors',
+              value === channel.value
+                ? 'bg-zinc-900 text-white border-zinc-900'
+                : 'bg-white text-zinc-700 border-zinc-300 hover:bg-zinc-50 hover:border-zinc-400',
+              disabled && 'opacity-50 cursor-not-allowed hover:bg-white hover:border-zinc-300'
+            )}
+          >
+            {channel.icon}
+            {channel.label}
+          </button>
+        );
+      })}
+    </div>
+  );
+}
+
+// =============================================================================
+// Main Component
+// =============================================================================
+
+export function SendMessageModal({
+  customerId,
+  customerName,
+  hasEmail,
+  open,
+  onOpenChange,
+  onSuccess,
+  defaultChannel = 'sms',
+  parentId,
+}: SendMessageModalProps) {
+  const [isSubmitting, setIsSubmitting] = React.useState(false);
+  const [templates, setTemplates] = React.useState<MessageTemplateWithAuthor[]>([]);
+  const [templateId, setTemplateId] = React.useState(NO_TEMPLATE);
+  const [preview, setPreview] = React.useState<RenderedTemplate | null>(null);
+  const [previewError, setPreviewError] = React.useState<string | null>(null);
+  const [isPreviewing, setIsPreviewing] = React.useState(false);
+
+  const initialChannel = defaultChannel === 'email' && !hasEmail ? 'sms' : defaultChannel;
+
+  const form = useForm<FormData>({
+    resolver: zodResolver(formSchema),
+    defaultValues: {
+      channel: initialChannel,
+      subject: '',
+      body: '',
+    },
+  });
+
+  // Reset form when modal opens with new defaults
+  React.useEffect(() => {
+    if (open) {
+      form.reset({ channel: initialChannel, subject: '', body: '' });
+      setTemplateId(NO_TEMPLATE);
+    }
+  }, [open, initialChannel, form]);
+
+  // Load templates whenever the modal opens
+  React.useEffect(() => {
+    if (!open) return;
+
+    listTemplates()
+      .then((result) => setTemplates(result.success ? result.data : []))
+      .catch(() => setTemplates([]));
+  }, [open]);
+
+  // Fill the selected template in for this customer
+  React.useEffect(() => {
+    setPreview(null);
+    setPreviewError(null);
+
+    if (templateId === NO_TEMPLATE) return;
+
+    let cancelled = false;
+    setIsPreviewing(true);
+
+    previewTemplate({ templateId, customerId })
+      .then((result) => {
+        if (cancelled) return;
+        if (result.success) {
+          setPreview(result.data);
+        } else {
+          setPreviewError(result.error);
+        }
+      })
+      .catch(() => {
+        if (!cancelled) setPreviewError('Failed to preview template');
+      })
+      .finally(() => {
+        if (!cancelled) setIsPreviewing(false);
+      });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [templateId, customerId]);
+
+  const handleSent = (communication: CommunicationWithLogger, channel: NotificationChannel) => {
+    // The attempt is logged either way; surface provider failures
+    if (communication.delivery_status === 'failed') {
+      toast.error(
+        `Message logged but not delivered: ${communication.delivery_error ?? 'Unknown error'}`
+      );
+    } else {
+      toast.success(channel === 'email' ? 'Email sent' : 'Text message sent');
+    }
+
+    onOpenChange(false);
+    onSuccess?.();
+  };
+
+  /**
+   * Copy the filled-in template into the editor so it can be changed.
+   * The message is then sent as a regular message, not from the template.
+   */
+  const handleCustomize = () => {
+    if (!preview) return;
+
+    form.setValue('subject', preview.subject ?? '');
+    form.setValue('body', preview.body);
+    setTemplateId(NO_TEMPLATE);
+  };
+
+  const handleSendTemplate = async () => {
+    if (!preview) return;
+
+    setIsSubmitting(true);
+
+    try {
+      const result = await sendTemplate({ templateId: preview.templateId, customerId, parentId });
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to send message');
+        return;
+      }
+
+      handleSent(result.data, preview.channel);
+    } catch (error) {
+      console.error('Failed to send template:', error);
+      toast.error('Failed to send message');
+    } finally {
+      setIsSubmitting(false);
+    }
+  };
+
+  const onSubmit = async (data: FormData) => {
+    setIsSubmitting(true);
+
+    try {
+      const result = await sendMessage(
+        data.channel === 'email'
+          ? { channel: 'email', customerId, parentId, subject: data.subject, body: data.body }
+          : { channel: 'sms', customerId, parentId, body: data.body }
+      );
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to send message');
+        return;
+      }
+
+      handleSent(result.data, data.channel);
+    } catch (error) {
+      console.error('Failed to send message:', error);
+      toast.error('Failed to send message');
+    } finally {
+      setIsSubmitting(false);
+    }
+  };
+
+  const watchChannel = form.watch('channel');
+  const maxLength = watchChannel === 'email' ? MAX_EMAIL_BODY_LENGTH : MAX_SMS_BODY_LENGTH;
+  const channelTemplates = templates.filter((template) => template.channel === watchChannel);
+  const usingTemplate = templateId !== NO_TEMPLATE;
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[480px]">
+        <DialogHeader>
+          <DialogTitle>{parentId ? 'Send Reply' : 'Send Message'}</DialogTitle>
+          <DialogDescription>
+            Send an email or text message to {customerName}
+          </DialogDescription>
+        </DialogHeader>
+
+        <form onSubmit={form.handleSubmit(onSubmit)} className="space-y-5">
+          {/* Channel */}
+          <div className="space-y-2">
+            <Label>Send as</Label>
+            <ChannelToggle
+              value={watchChannel}
+              onChange={(value) => {
+                form.setValue('channel', value, { shouldValidate: false });
+                setTemplateId(NO_TEMPLATE);
+              }}
+              emailDisabled={!hasEmail}
+            />
+          </div>
+
+          {/* Template */}
+          {channelTemplates.length > 0 && (
+            <div className="space-y-2">
+              <Label>Template</Label>
+              <Select value={templateId} onValueChange={setTemplateId}>
+                <SelectTrigger>
+                  <SelectValue placeholder="Write a new message" />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value={NO_TEMPLATE}>Write a new message</SelectItem>
+                  {channelTemplates.map((template) => (
+                    <SelectItem key={template.id} value={template.id}>
+                      {template.name}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+          )}
+
+          {/* Template Preview */}
+          {usingTemplate && (
+            <div className="space-y-2">
+              {isPreviewing ? (
+                <div className="flex items-center justify-center py-6">
+                  <Loader2 className="w-5 h-5 animate-spin text-zinc-400" />
+                </div>
+              ) : previewError ? (
+                <div className="flex gap-2 p-3 rounded-md bg-red-50 border border-red-200 text-sm text-red-700">
+                  <AlertCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+                  <p>{previewError}</p>
+                </div>
+              ) : preview ? (
+                <>
+                  <div className="rounded-md border border-zinc-200 bg-zinc-50 p-4 space-y-2 max-h-64 overflow-y-auto">
+                    {preview.subject && (
+                      <p className="text-sm font-medium text-zinc-900">{preview.subject}</p>
+                    )}
+                    <p className="text-sm text-zinc-700 whitespace-pre-wrap">{preview.body}</p>
+                  </div>
+                  <Button type="button" variant="link" size="sm" onClick={handleCustomize}>
+                    Edit before sending
+                  </Button>
+                </>
+              ) : null}
+            </div>
+          )}
+
+          {/* Subject */}
+          {!usingTemplate && watchChannel === 'email' && (
+            <div className="space-y-2">
+              <Label htmlFor="subject">Subject</Label>
+              <Input
+                id="subject"
+                {...form.register('subject')}
+                className={cn(form.formState.errors.subject && 'border-red-500')}
+              />
+              {form.formState.errors.subject && (
+                <p className="text-sm text-red-600">
+                  {form.formState.errors.subject.message}
+                </p>
+              )}
+            </div>
+          )}
+
+          {/* Message */}
+          <div className={cn('space-y-2', usingTemplate && 'hidden')}>
+            <Label htmlFor="body">Message</Label>
+            <Textarea
+              id="body"
+              rows={6}
+              {...form.register('body')}
+              className={cn('resize-none', form.formState.errors.body && 'border-red-500')}
+            />
+            {form.formState.errors.body && (
+              <p className="text-sm text-red-600">{form.formState.errors.body.message}</p>
+            )}
+            <p className="text-xs text-zinc-500">
+              {form.watch('body')?.length || 0} / {maxLength} characters
+            </p>
+          </div>
+
+          <DialogFooter>
+            <Button
+              type="button"
+              variant="secondary"
+              onClick={() => onOpenChange(false)}
+              disabled={isSubmitting}
+            >
+              Cancel
+            </Button>
+            <Button
+              type={usingTemplate ? 'button' : 'submit'}
+              onClick={usingTemplate ? handleSendTemplate : undefined}
+              disabled={isSubmitting || (usingTemplate && !preview)}
+            >
+              {isSubmitting ? (
+                <>
+                  <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                  Sending...
+                </>
+              ) : (
+                'Send'
+              )}
+            </Button>
+          </DialogFooter>
+        </form>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,318 @@
+'use client';
+
+/**
+ * Template Form Modal
+ *
+ * @file src/components/communications/template-form-modal.tsx
+ *
+ * Modal dialog for creating or editing a message template.
+ * Merge fields can be inserted at the cursor from the variable list.
+ */
+
+import * as React from 'react';
+import { useForm } from 'react-hook-form';
+import { zodResolver } from '@hookform/resolvers/zod';
+import { z } from 'zod';
+import { Mail, MessageSquare, Loader2 } from 'lucide-react';
+import {
+  Dialog,
+  DialogContent,
+  DialogHeader,
+  DialogTitle,
+  DialogDescription,
+  DialogFooter,
+} from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { Textarea } from '@/components/ui/textarea';
+import { cn } from '@/lib/utils';
+import { toast } from 'sonner';
+import { createTemplate, updateTemplate } from '@/app/actions/templates';
+import { TEMPLATE_VARIABLES, findUnknownVariables } from '@/lib/notifications/templates';
+import {
+  NOTIFICATION_CHANNELS,
+  MAX_EMAIL_BODY_LENGTH,
+  MAX_SMS_BODY_LENGTH,
+  type NotificationChannel,
+} from '@/lib/validations/notification';
+import type { MessageTemplateWithAuthor } from '@/lib/types/template';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface TemplateFormModalProps {
+  /** Template to edit; omit to create a new one */
+  template?: MessageTemplateWithAuthor;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  onSuccess?: (template: MessageTemplateWithAuthor) => void;
+}
+
+// =============================================================================
+// Form Schema
+// =============================================================================
+
+const unknownVariablesMessage = (text: string) => {
+  const unknown = findUnknownVariables(text);
+  return unknown.length > 0
+    ? `Unknown variables: ${unknown.map((name) => `{{${name}}}`).join(', ')}`
+    : null;
+};
+
+const formSchema = z
+  .object({
+    name: z.string().trim().min(1, 'Please enter a name').max(100),
+    channel: z.enum(NOTIFICATION_CHANNELS),
+    subject: z.string().max(200, 'Subject must be 200 characters or less'),
+    body: z.string().trim().min(1, 'Please enter a message'),
+  })
+  .superRefine((data, ctx) => {
+    if (data.channel === 'email' && !data.subject.trim()) {
+      ctx.addIssue({ code: 'custom', path: ['subject'], message: 'Please enter a subject' });
+    }
+
+    const max = data.channel === 'email' ? MAX_EMAIL_BODY_LENGTH : MAX_SMS_BODY_LENGTH;
+    if (data.body.length > max) {
+      ctx.addIssue({
+        code: 'custom',
+        path: ['body'],
+        message: `Message must be ${max} characters or less`,
+      });
+    }
+
+    for (const field of ['subject', 'body'] as const) {
+      const message = unknownVariablesMessage(data[field]);
+      if (message) {
+        ctx.addIssue({ code: 'custom', path: [field], message });
+      }
+    }
+  });
+
+type FormData = z.infer<typeof formSchema>;
+
+// =============================================================================
+// Main Component
+// =============================================================================
+
+export function TemplateFormModal({
+  template,
+  open,
+  onOpenChange,
+  onSuccess,
+}: TemplateFormModalProps) {
+  const [isSubmitting, setIsSubmitting] = React.useState(false);
+  const bodyRef = React.useRef<HTMLTextAreaElement | null>(null);
+
+  const defaults = React.useMemo<FormData>(
+    () => ({
+      name: template?.name ?? '',
+      channel: template?.channel ?? 'email',
+      subject: template?.subject ?? '',
+      body: template?.body ?? '',
+    }),
+    [template]
+  );
+
+  const form = useForm<FormData>({
+    resolver: zodResolver(formSchema),
+    defaultValues: defaults,
+  });
+
+  // Reset form when modal opens
+  React.useEffect(() => {
+    if (open) {
+      form.reset(defaults);
+    }
+  }, [open, defaults, form]);
+
+  const insertVariable = (key: string) => {
+    const token = `{{${key}}}`;
+    const body = form.getValues('body');
+    const textarea = bodyRef.current;
+    const start = textarea?.selectionStart ?? body.length;
+    const end = textarea?.selectionEnd ?? body.length;
+
+    form.setValue('body', body.slice(0, start) + token + body.slice(end), {
+      shouldValidate: form.formState.isSubmitted,
+    });
+
+    // Restore the cursor after the inserted token
+    requestAnimationFrame(() => {
+      textarea?.focus();
+      textarea?.setSelectionRange(start + token.length, start + token.length);
+    });
+  };
+
+  const onSubmit = async (data: FormData) => {
+    setIsSubmitting(true);
+
+    try {
+      const fields = {
+        name: data.name,
+        channel: data.channel,
+        subject: data.channel === 'email' ? data.subject : null,
+        body: data.body,
+      };
+      const result = template
+        ? await updateTemplate({ id: template.id, ...fields })
+        : await createTemplate(fields);
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to save template');
+        return;
+      }
+
+      toast.success(template ? 'Template updated' : 'Template created');
+      onOpenChange(false);
+      onSuccess?.(result.data);
+    } catch (error) {
+      console.error('Failed to save template:', error);
+      toast.error('Failed to save template');
+    } finally {
+      setIsSubmitting(false);
+    }
+  };
+
+  const watchChannel = form.watch('channel');
+  const maxLength = watchChannel === 'email' ? MAX_EMAIL_BODY_LENGTH : MAX_SMS_BODY_LENGTH;
+  const { ref: bodyRegisterRef, ...bodyRegister } = form.register('body');
+
+  const channels: { value: NotificationChannel; label: string; icon: React.ReactNode }[] = [
+    { value: 'email', label: 'Email', icon: <Mail className="w-4 h-4" /> },
+    { value: 'sms', label: 'Text', icon: <MessageSquare className="w-4 h-4" /> },
+  ];
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[560px]">
+        <DialogHeader>
+          <DialogTitle>{template ? 'Edit Template' : 'New Template'}</DialogTitle>
+          <DialogDescription>
+            Use merge fields like {'{{customer.first_name}}'} to personalize each message
+          </DialogDescription>
+        </DialogHeader>
+
+        <form onSubmit={form.handleSubmit(onSubmit)} className="space-y-5">
+          {/* Name */}
+          <div className="space-y-2">
+            <Label htmlFor="template-name">Name</Label>
+            <Input
+              id="template-name"
+              placeholder="e.g. Appointment reminder"
+              {...form.register('name')}
+              className={cn(form.formState.errors.name && 'border-red-500')}
+            />
+            {form.formState.errors.name && (
+              <p className="text-sm text-red-600">{form.formState.errors.name.message}</p>
+            )}
+          </div>
+
+          {/* Channel */}
+          <div className="space-y-2">
+            <Label>Channel</Label>
+            <div className="flex gap-2">
+              {channels.map((channel) => (
+                <button
+                  key={channel.value}
+                  type="button"
+                  onClick={() => form.setValue('channel', channel.value)}
+                  className={cn(
+                    'flex-1 flex items-center justify-center gap-2 px-3 py-2.5 rounded-md border text-sm font-medium transition-colors',
+                    watchChannel === channel.value
+                      ? 'bg-zinc-900 text-white border-zinc-900'
+                      : 'bg-white text-zinc-700 border-zinc-300 hover:bg-zinc-50 hover:border-zinc-400'
+                  )}
+                >
+                  {channel.icon}
+                  {channel.label}
+                </button>
+              ))}
+            </div>
+          </div>
+
+          {/* Subject */}
+          {watchChannel === 'email' && (
+            <div className="space-y-2">
+              <Label htmlFor="template-subject">Subject</Label>
+              <Input
+                id="template-subject"
+                {...form.register('subject')}
+                className={cn(form.formState.errors.subject && 'border-red-500')}
+              />
+              {form.formState.errors.subject && (
+                <p className="text-sm text-red-600">{form.formState.errors.subject.message}</p>
+              )}
+            </div>
+          )}
+
+          {/* Body */}
+          <div className="space-y-2">
+            <Label htmlFor="template-body">Message</Label>
+            <Textarea
+              id="template-body"
+              rows={7}
+              {...bodyRegister}
+              ref={(element) => {
+                bodyRegisterRef(element);
+                bodyRef.current = element;
+              }}
+              className={cn(
+                'resize-none font-mono text-xs',
+                form.formState.errors.body && 'border-red-500'
+              )}
+            />
+            {form.formState.errors.body && (
+              <p className="text-sm text-red-600">{form.formState.errors.body.message}</p>
+            )}
+            <p className="text-xs text-zinc-500">
+              {form.watch('body')?.length || 0} / {maxLength} characters before merge fields
+            </p>
+          </div>
+
+          {/* Variables */}
+          <div className="space-y-2">
+            <Label>Insert merge field</Label>
+            <div className="flex flex-wrap gap-1.5">
+              {TEMPLATE_VARIABLES.map((variable) => (
+                <button
+                  key={variable.key}
+                  type="button"
+                  title={`e.g. ${variable.example}`}
+                  onClick={() => insertVariable(variable.key)}
+                  className="px-2 py-1 text-xs rounded border border-zinc-200 bg-zinc-50 text-zinc-700 hover:bg-zinc-100"
+                >
+                  {variable.label}
+                </button>
+              ))}
+            </div>
+          </div>
+
+          <DialogFooter>
+            <Button
+              type="button"
+              variant="secondary"
+              onClick={() => onOpenChange(false)}
+              disabled={isSubmitting}
+            >
+              Cancel
+            </Button>
+            <Button type="submit" disabled={isSubmitting}>
+              {isSubmitting ? (
+                <>
+                  <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                  Saving...
+                </>
+              ) : template ? (
+                'Save Changes'
+              ) : (
+                'Create Template'
+              )}
+            </Button>
+          </DialogFooter>
+        </form>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,415 @@
+'use client';
+
+/**
+ * Template Manager Component
+ *
+ * @file src/components/communications/template-manager.tsx
+ *
+ * Lists message templates with create, edit and delete actions, and
+ * previews a template filled in for a real customer.
+ */
+
+import * as React from 'react';
+import { useRouter } from 'next/navigation';
+import {
+  Mail,
+  MessageSquare,
+  MoreHorizontal,
+  Pencil,
+  Trash2,
+  Eye,
+  Plus,
+  Search,
+  Loader2,
+  X,
+  AlertCircle,
+} from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuSeparator,
+  DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu';
+import {
+  Dialog,
+  DialogContent,
+  DialogHeader,
+  DialogTitle,
+  DialogDescription,
+} from '@/components/ui/dialog';
+import {
+  AlertDialog,
+  AlertDialogAction,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from '@/components/ui/alert-dialog';
+import { searchCustomers } from '@/app/actions/customers';
+import { deleteTemplate, previewTemplate } from '@/app/actions/templates';
+import {
+  templateChannelLabels,
+  type MessageTemplateWithAuthor,
+  type RenderedTemplate,
+} from '@/lib/types/template';
+import { TemplateFormModal } from './template-form-modal';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface TemplateManagerProps {
+  templates: MessageTemplateWithAuthor[];
+}
+
+interface CustomerOption {
+  id: string;
+  name: string;
+  email: string | null;
+}
+
+// =============================================================================
+// Main Component
+// =============================================================================
+
+export function TemplateManager({ templates }: TemplateManagerProps) {
+  const router = useRouter();
+  const [showCreate, setShowCreate] = React.useState(false);
+  const [editing, setEditing] = React.useState<MessageTemplateWithAuthor | null>(null);
+  const [deleting, setDeleting] = React.useState<MessageTemplateWithAuthor | null>(null);
+  const [previewing, setPreviewing] = React.useState<MessageTemplateWithAuthor | null>(null);
+
+  const confirmDelete = async () => {
+    if (!deleting) return;
+
+    try {
+      const result = await deleteTemplate(deleting.id);
+
+      if (!result.success) {
+        toast.error(result.error || 'Failed to delete template');
+        return;
+      }
+
+      toast.success('Template deleted');
+      router.refresh();
+    } catch (error) {
+      console.error('Failed to delete template:', error);
+      toast.error('Failed to delete template');
+    } finally {
+      setDeleting(null);
+    }
+  };
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between">
+        <p className="text-sm text-zinc-500">
+          {templates.length} {templates.length === 1 ? 'template' : 'templates'}
+        </p>
+        <Button size="sm" onClick={() => setShowCreate(true)}>
+          <Plus className="w-4 h-4 mr-2" />
+          New Template
+        </Button>
+      </div>
+
+      {templates.length === 0 ? (
+        <div className="p-8 text-center bg-white border border-dashed border-zinc-300 rounded-lg">
+          <p className="text-sm text-zinc-600">No templates yet</p>
+          <p className="text-xs text-zinc-400 mt-1">
+            Save messages you send often, like appointment reminders or estimate follow-ups
+          </p>
+        </div>
+      ) : (
+        <div className="space-y-3">
+          {templates.map((template) => (
+            <TemplateItem
+              key={template.id}
+              template={template}
+              onEdit={setEditing}
+              onDelete={setDeleting}
+              onPreview={setPreviewing}
+            />
+          ))}
+        </div>
+      )}
+
+      {/* Create Modal */}
+      <TemplateFormModal
+        open={showCreate}
+        onOpenChange={setShowCreate}
+        onSuccess={() => router.refresh()}
+      />
+
+      {/* Edit Modal */}
+      {editing && (
+        <TemplateFormModal
+          template={editing}
+          open={!!editing}
+          onOpenChange={(open) => !open && setEditing(null)}
+          onSuccess={() => router.refresh()}
+        />
+      )}
+
+      {/* Preview Dialog */}
+      {previewing && (
+        <TemplatePreviewDialog
+          template={previewing}
+          open={!!previewing}
+          onOpenChange={(open) => !open && setPreviewing(null)}
+        />
+      )}
+
+      {/* Delete Confirmation Dialog */}
+      <AlertDialog open={!!deleting} onOpenChange={(open) => !open && setDeleting(null)}>
+        <AlertDialogContent>
+          <AlertDialogHeader>
+            <AlertDialogTitle>Delete Template</AlertDialogTitle>
+            <AlertDialogDescription>
+              Delete &quot;{deleting?.name}&quot;? Messages already sent from it are kept.
+            </AlertDialogDescription>
+          </AlertDialogHeader>
+          <AlertDialogFooter>
+            <AlertDialogCancel>Cancel</AlertDialogCancel>
+            <AlertDialogAction onClick={confirmDelete} className="bg-red-600 hover:bg-red-700">
+              Delete
+            </AlertDialogAction>
+          </AlertDialogFooter>
+        </AlertDialogContent>
+      </AlertDialog>
+    </div>
+  );
+}
+
+// =============================================================================
+// Template Item
+// =============================================================================
+
+interface TemplateItemProps {
+  template: MessageTemplateWithAuthor;
+  onEdit: (template: MessageTemplateWithAuthor) => void;
+  onDelete: (template: MessageTemplateWithAuthor) => void;
+  onPreview: (template: MessageTemplateWithAuthor) => void;
+}
+
+function TemplateItem({ template, onEdit, onDelete, onPreview }: TemplateItemProps) {
+  const Icon = template.channel === 'email' ? Mail : MessageSquare;
+
+  return (
+    <div className="group p-4 bg-white border border-zinc-200 rounded-lg hover:border-zinc-300 transition-colors">
+      <div className="flex items-start justify-between gap-4">
+        <div className="min-w-0 flex-1">
+          <div className="flex items-center gap-2 mb-1">
+            <span className="text-sm font-medium text-zinc-900">{template.name}</span>
+            <span className="inline-flex items-center gap-1 px-1.5 py-0.5 text-xs rounded bg-zinc-100 text-zinc-600">
+              <Icon className="w-3 h-3" />
+              {templateChannelLabels[template.channel]}
+            </span>
+          </div>
+          {template.subject && (
+            <p className="text-sm text-zinc-700 truncate">{template.subject}</p>
+          )}
+          <p className="text-sm text-zinc-500 whitespace-pre-wrap line-clamp-3">{template.body}</p>
+          {template.created_by_admin && (
+            <p className="mt-2 text-xs text-zinc-400">
+              Created by {template.created_by_admin.full_name}
+            </p>
+          )}
+        </div>
+
+        <div className="flex items-center gap-1">
+          <Button variant="ghost" size="sm" onClick={() => onPreview(template)}>
+            <Eye className="w-4 h-4 mr-1.5" />
+            Preview
+          </Button>
+          <DropdownMenu>
+            <DropdownMenuTrigger asChild>
+              <Button variant="ghost" size="sm" className="h-8 w-8 p-0">
+                <MoreHorizontal className="w-4 h-4" />
+                <span className="sr-only">Open menu</span>
+              </Button>
+            </DropdownMenuTrigger>
+            <DropdownMenuContent align="end">
+              <DropdownMenuItem onClick={() => onEdit(template)}>
+                <Pencil className="w-4 h-4 mr-2" />
+                Edit
+              </DropdownMenuItem>
+              <DropdownMenuSeparator />
+              <DropdownMenuItem
+                onClick={() => onDelete(template)}
+                className="text-red-600 focus:text-red-600"
+              >
+                <Trash2 className="w-4 h-4 mr-2" />
+                Delete
+              </DropdownMenuItem>
+            </DropdownMenuContent>
+          </DropdownMenu>
+        </div>
+      </div>
+    </div>
+  );
+}
+
+// =============================================================================
+// Preview Dialog
+// =============================================================================
+
+interface TemplatePreviewDialogProps {
+  template: MessageTemplateWithAuthor;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+}
+
+function TemplatePreviewDialog({ template, open, onOpenChange }: TemplatePreviewDialogProps) {
+  const [query, setQuery] = React.useState('');
+  const [results, setResults] = React.useState<CustomerOption[]>([]);
+  const [isSearching, setIsSearching] = React.useState(false);
+  const [customer, setCustomer] = React.useState<CustomerOption | null>(null);
+  const [preview, setPreview] = React.useState<RenderedTemplate | null>(null);
+  const [error, setError] = React.useState<string | null>(null);
+  const [isRendering, setIsRendering] = React.useState(false);
+
+  // Debounced customer search
+  React.useEffect(() => {
+    if (query.length < 2) {
+      setResults([]);
+      return;
+    }
+
+    const timer = setTimeout(async () => {
+      setIsSearching(true);
+      try {
+        const result = await searchCustomers(query, 5);
+        setResults(result.success ? result.data : []);
+      } catch {
+        setResults([]);
+      } finally {
+        setIsSearching(false);
+      }
+    }, 300);
+
+    return () => clearTimeout(timer);
+  }, [query]);
+
+  // Render for the selected customer
+  React.useEffect(() => {
+    if (!customer) {
+      setPreview(null);
+      setError(null);
+      return;
+    }
+
+    let cancelled = false;
+    setIsRendering(true);
+
+    previewTemplate({ templateId: template.id, customerId: customer.id })
+      .then((result) => {
+        if (cancelled) return;
+        if (result.success) {
+          setPreview(result.data);
+          setError(null);
+        } else {
+          setPreview(null);
+          setError(result.error);
+        }
+      })
+      .catch(() => {
+        if (!cancelled) setError('Failed to preview template');
+      })
+      .finally(() => {
+        if (!cancelled) setIsRendering(false);
+      });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [customer, template.id]);
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-[560px]">
+        <DialogHeader>
+          <DialogTitle>Preview: {template.name}</DialogTitle>
+          <DialogDescription>
+            Choose a customer to see the message with their details filled in
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-4">
+          {/* Customer */}
+          {customer ? (
+            <div className="flex items-center justify-between rounded-md border border-zinc-300 px-3 h-9 text-sm">
+              <span className="font-medium text-zinc-900">{customer.name}</span>
+              <button
+                type="button"
+                onClick={() => setCustomer(null)}
+                className="text-zinc-400 hover:text-zinc-600"
+                aria-label="Clear customer"
+              >
+                <X className="w-4 h-4" />
+              </button>
+            </div>
+          ) : (
+            <div className="relative">
+              <Search className="absolute left-3 top-1/2 -translate-y-1/2 w-4 h-4 text-zinc-400" />
+              <Input
+                placeholder="Find customer..."
+                value={query}
+                onChange={(e) => setQuery(e.target.value)}
+                className="pl-9"
+              />
+              {isSearching && (
+                <Loader2 className="absolute right-3 top-1/2 -translate-y-1/2 w-4 h-4 text-zinc-400 animate-spin" />
+              )}
+              {results.length > 0 && (
+                <div className="absolute z-10 mt-1 w-full rounded-md border border-zinc-200 bg-white shadow-lg">
+                  {results.map((option) => (
+                    <button
+                      key={option.id}
+                      type="button"
+                      onClick={() => {
+                        setCustomer(option);
+                        setQuery('');
+                        setResults([]);
+                      }}
+                      className="block w-full px-3 py-2 text-left text-sm hover:bg-zinc-50"
+                    >
+                      <span className="font-medium text-zinc-900">{option.name}</span>
+                      {option.email && (
+                        <span className="block text-xs text-zinc-500">{option.email}</span>
+                      )}
+                    </button>
+                  ))}
+                </div>
+              )}
+            </div>
+          )}
+
+          {/* Result */}
+          {isRendering ? (
+            <div className="flex items-center justify-center py-8">
+              <Loader2 className="w-5 h-5 animate-spin text-zinc-400" />
+            </div>
+          ) : error ? (
+            <div className="flex gap-2 p-3 rounded-md bg-red-50 border border-red-200 text-sm text-red-700">
+              <AlertCircle className="w-4 h-4 mt-0.5 flex-shrink-0" />
+              <p>{error}</p>
+            </div>
+          ) : preview ? (
+            <div className="rounded-md border border-zinc-200 bg-zinc-50 p-4 space-y-2">
+              {preview.subject && (
+                <p className="text-sm font-medium text-zinc-900">{preview.subject}</p>
+              )}
+              <p className="text-sm text-zinc-700 whitespace-pre-wrap">{preview.body}</p>
+            </div>
+          ) : null}
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
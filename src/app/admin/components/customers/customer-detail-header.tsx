@@ -15,6 +15,7 @@ import {
   Trash2,
   Copy,
   ExternalLink,
+  Clock,
 } from 'lucide-react';
 import { Button } from '@/components/ui/button';
 import {
@@ -26,6 +27,8 @@ import {
 } from '@/components/ui/dropdown-menu';
 import { Badge } from '@/components/ui/badge';
 import { formatPhone } from '@/lib/utils/phone';
+import { formatDateTime } from '@/lib/utils/timezone';
+import { differenceInCalendarDays } from 'date-fns';
 import { cn } from '@/lib/utils';
 import type { CustomerWithDetails } from '@/lib/types/customer';
 import { LogCommunicationModal } from '../communications/log-communication-modal';
@@ -41,6 +44,7 @@ import { deleteCustomer } from '@/app/actions/customers';
  * - Breadcrumb navigation back to customers list
  * - Customer avatar with initials
  * - Contact information (phone, email)
+ * - When the customer was last contacted
  * - Tags
  * - Quick action buttons (Log Call, New Estimate, Schedule Event)
  * - More actions dropdown (Edit, Delete)
@@ -48,9 +52,26 @@ import { deleteCustomer } from '@/app/actions/customers';
 
 interface CustomerDetailHeaderProps {
   customer: CustomerWithDetails;
+  /** Most recent communication; null when never contacted, undefined when unknown */
+  lastContactAt?: string | null;
 }
 
-export function CustomerDetailHeader({ customer }: CustomerDetailHeaderProps) {
+/**
+ * "Last contacted 42 days ago"
+ */
+function formatLastContact(lastContactAt: string | null): string {
+  if (!lastContactAt) {
+    return 'No communications logged yet';
+  }
+
+  const days = differenceInCalendarDays(new Date(), new Date(lastContactAt));
+
+  if (days <= 0) return 'Last contacted today';
+  if (days === 1) return 'Last contacted yesterday';
+  return `Last contacted ${days} days ago`;
+}
+
+export function CustomerDetailHeader({ customer, lastContactAt }: CustomerDetailHeaderProps) {
   const router = useRouter();
 
   // Communication modal state
@@ -167,6 +188,19 @@ export function CustomerDetailHeader({ customer }: CustomerDetailHeaderProps) {
               </div>
             )}
 
+            {/* Last Contact */}
+            {lastContactAt !== undefined && (
+              <div className="flex items-center gap-2">
+                <Clock className="w-4 h-4 text-zinc-400" aria-hidden="true" />
+                <span
+                  className="text-sm text-zinc-500"
+                  title={lastContactAt ? formatDateTime(lastContactAt) : undefined}
+                >
+                  {formatLastContact(lastContactAt)}
+                </span>
+              </div>
+            )}
+
             {/* Source (if present) */}
             {customer.source && (
               <div className="flex items-center gap-2">
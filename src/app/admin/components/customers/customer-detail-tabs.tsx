@@ -1,14 +1,16 @@
 'use client';
 
 import * as React from 'react';
+import { useSearchParams } from 'next/navigation';
 import { Tabs, TabsContent, TabsList, TabsTrigger } from '@/components/ui/tabs';
-import { Building2, MessageSquare, FileText, StickyNote } from 'lucide-react';
+import { Building2, MessageSquare, FileText, StickyNote, History } from 'lucide-react';
 import { PropertyList } from '@/components/properties/property-list';
 import { NoteList } from '@/components/notes/note-list';
 import { EstimateList } from '@/components/estimates/estimate-list';
 import type { CustomerWithDetails, EstimateWithDetails } from '@/lib/types/customer';
 import type { Property, Pool, CustomerNote } from '@/lib/types/database';
 import { CommunicationList } from '../communications/communication-list';
+import { CustomerTimeline } from './customer-timeline';
 
 /**
  * Customer Detail Tabs Component
@@ -18,8 +20,11 @@ import { CommunicationList } from '../communications/communication-list';
  * - Communications: Communication history log (placeholder)
  * - Estimates: List of estimates for this customer (fully implemented)
  * - Notes: Customer notes with author tracking (fully implemented)
+ * - Timeline: All of the above plus events and attachments, newest first
  */
 
+const CUSTOMER_TABS = ['properties', 'communications', 'estimates', 'notes', 'timeline'];
+
 interface PropertyWithPool extends Property {
   pool: Pool | null;
 }
@@ -37,6 +42,11 @@ interface CustomerDetailTabsProps {
 }
 
 export function CustomerDetailTabs({ customer }: CustomerDetailTabsProps) {
+  // Links can open a specific tab with ?tab=communications
+  const requestedTab = useSearchParams().get('tab');
+  const defaultTab =
+    requestedTab && CUSTOMER_TABS.includes(requestedTab) ? requestedTab : 'properties';
+
   // Count items for tab badges
   const propertiesCount = customer.properties?.length ?? 0;
   const communicationsCount = customer.communications?.length ?? 0;
@@ -44,7 +54,7 @@ export function CustomerDetailTabs({ customer }: CustomerDetailTabsProps) {
   const notesCount = customer.notes?.length ?? 0;
 
   return (
-    <Tabs defaultValue="properties" className="w-full">
+    <Tabs defaultValue={defaultTab} className="w-full">
       <TabsList className="w-full justify-start border-b border-zinc-200 bg-transparent p-0 h-auto">
         <TabsTrigger
           value="properties"
@@ -97,6 +107,14 @@ export function CustomerDetailTabs({ customer }: CustomerDetailTabsProps) {
             </span>
           )}
         </TabsTrigger>
+
+        <TabsTrigger
+          value="timeline"
+          className="relative px-4 py-3 text-sm font-medium text-zinc-600 hover:text-zinc-900 data-[state=active]:text-zinc-900 data-[state=active]:bg-transparent rounded-none border-b-2 border-transparent data-[state=active]:border-zinc-900 transition-colors"
+        >
+          <History className="w-4 h-4 mr-2" />
+          Timeline
+        </TabsTrigger>
       </TabsList>
 
       {/* Properties Tab Content - Fully Implemented with Pool Management */}
@@ -112,9 +130,7 @@ export function CustomerDetailTabs({ customer }: CustomerDetailTabsProps) {
       <CommunicationList
           customerId={customer.id}
           customerName={customer.name}
-          initialCommunications={
-            (customer.communications ?? []) as CommunicationWithLogger[]
-          }
+          customerEmail={customer.email}
         />
       </TabsContent>
 
@@ -135,6 +151,11 @@ export function CustomerDetailTabs({ customer }: CustomerDetailTabsProps) {
           initialNotes={(customer.notes ?? []) as NoteWithAuthor[]}
         />
       </TabsContent>
+
+      {/* Timeline Tab Content */}
+      <TabsContent value="timeline" className="mt-6">
+        <CustomerTimeline customerId={customer.id} />
+      </TabsContent>
     </Tabs>
   );
 }
@@ -0,0 +1,348 @@
+'use client';
+
+/**
+ * Customer Timeline
+ *
+ * @file src/components/customers/customer-timeline.tsx
+ *
+ * A customer's communications, notes, estimates, calendar events and
+ * attachments in one list, newest first, with the communication filters
+ * and a filter for what to show.
+ */
+
+import * as React from 'react';
+import Link from 'next/link';
+import {
+  Calendar,
+  FileText,
+  Globe,
+  Loader2,
+  Mail,
+  MessageSquare,
+  Paperclip,
+  Phone,
+  Share2,
+  StickyNote,
+  Users,
+  Voicemail,
+  type LucideIcon,
+} from 'lucide-react';
+import { toast } from 'sonner';
+import { Button } from '@/components/ui/button';
+import {
+  CommunicationFilterBar,
+  MultiSelectFilter,
+  hasActiveFilters,
+} from '@/components/communications/communication-filter-bar';
+import { AttachmentList } from '@/components/communications/communication-attachments';
+import { CommunicationLinkList } from '@/components/communications/communication-links';
+import { EstimateStatusBadge } from '@/components/estimates/estimate-status-badge';
+import { cn } from '@/lib/utils';
+import { formatCents } from '@/lib/utils/currency';
+import { formatDateTime } from '@/lib/utils/timezone';
+import { getCustomerTimeline } from '@/app/actions/timeline';
+import { getCommunicationFilterOptions } from '@/app/actions/communication-views';
+import {
+  communicationTypeLabels,
+  type CommunicationFilterOptions,
+  type CommunicationFilters,
+} from '@/lib/types/communication';
+import type { Communication } from '@/lib/types/database';
+import type { TimelineFilters, TimelineItem, TimelineItemKind } from '@/lib/types/timeline';
+import { timelineKindOptions } from '@/lib/validations/timeline';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+interface CustomerTimelineProps {
+  customerId: string;
+}
+
+const EMPTY_FILTERS: TimelineFilters = {};
+
+const communicationIcons: Record<Communication['type'], LucideIcon> = {
+  call: Phone,
+  text: MessageSquare,
+  email: Mail,
+  voicemail: Voicemail,
+  in_person: Users,
+  web_form: Globe,
+  social: Share2,
+};
+
+// =============================================================================
+// Timeline Entry
+// =============================================================================
+
+function getEntryIcon(item: TimelineItem): LucideIcon {
+  switch (item.kind) {
+    case 'communication':
+      return communicationIcons[item.communication.type] ?? MessageSquare;
+    case 'note':
+      return StickyNote;
+    case 'estimate':
+      return FileText;
+    case 'event':
+      return Calendar;
+    case 'attachment':
+      return Paperclip;
+  }
+}
+
+function TimelineEntry({ item }: { item: TimelineItem }) {
+  const Icon = getEntryIcon(item);
+
+  let title: React.ReactNode;
+  let byline: string | null = null;
+  let body: React.ReactNode = null;
+
+  switch (item.kind) {
+    case 'communication': {
+      const { communication } = item;
+      title = (
+        <>
+          {communicationTypeLabels[communication.type]}
+          <span className="ml-2 font-normal text-zinc-500 capitalize">
+            {communication.direction}
+          </span>
+        </>
+      );
+      byline = communication.logged_by_admin?.full_name ?? null;
+      body = (
+        <>
+          <p className="text-sm text-zinc-600 whitespace-pre-wrap break-words line-clamp-4">
+            {communication.summary}
+          </p>
+          <AttachmentList attachments={communication.attachments ?? []} className="mt-2" />
+          <CommunicationLinkList communication={communication} className="mt-2" />
+        </>
+      );
+      break;
+    }
+    case 'note': {
+      const { note } = item;
+      title = 'Note';
+      byline = note.author?.full_name ?? null;
+      body = (
+        <>
+          <p className="text-sm text-zinc-600 whitespace-pre-wrap break-words line-clamp-4">
+            {note.content}
+          </p>
+          <AttachmentList attachments={note.attachments} className="mt-2" />
+        </>
+      );
+      break;
+    }
+    case 'estimate': {
+      const { estimate } = item;
+      title = (
+        <Link href={`/admin/estimates/${estimate.id}`} className="hover:underline">
+          Estimate {estimate.estimate_number}
+        </Link>
+      );
+      byline = estimate.created_by_admin?.full_name ?? null;
+      body = (
+        <div className="flex items-center gap-2 text-sm text-zinc-600">
+          <EstimateStatusBadge status={estimate.status} />
+          <span>{formatCents(estimate.total_cents)}</span>
+        </div>
+      );
+      break;
+    }
+    case 'event': {
+      const { event } = item;
+      title = event.title;
+      byline = event.created_by_admin?.full_name ?? null;
+      body = (
+        <p className="text-sm text-zinc-600">
+          <span className="capitalize">{event.status}</span>
+          {event.description && ` · ${event.description}`}
+        </p>
+      );
+      break;
+    }
+    case 'attachment': {
+      const { attachment } = item;
+      title = 'Attachment';
+      byline = attachment.uploaded_by_admin?.full_name ?? null;
+      body = <AttachmentList attachments={[attachment]} />;
+      break;
+    }
+  }
+
+  return (
+    <li className="relative flex gap-4">
+      <div className="flex flex-col items-center">
+        <div className="w-8 h-8 rounded-full bg-zinc-100 flex items-center justify-center flex-shrink-0">
+          <Icon className="w-4 h-4 text-zinc-600" />
+        </div>
+        <div className="flex-1 w-px bg-zinc-200 mt-1" />
+      </div>
+      <div className="flex-1 min-w-0 pb-6 space-y-1">
+        <div className="flex items-baseline justify-between gap-4">
+          <p className="text-sm font-medium text-zinc-900 truncate">{title}</p>
+          <p className="text-xs text-zinc-500 flex-shrink-0">
+            {formatDateTime(item.occurredAt, 'mediumWithTime')}
+          </p>
+        </div>
+        {byline && <p className="text-xs text-zinc-500">{byline}</p>}
+        {body}
+      </div>
+    </li>
+  );
+}
+
+// =============================================================================
+// Component
+// =============================================================================
+
+export function CustomerTimeline({ customerId }: CustomerTimelineProps) {
+  const [items, setItems] = React.useState<TimelineItem[]>([]);
+  const [isLoading, setIsLoading] = React.useState(true);
+  const [isFiltering, setIsFiltering] = React.useState(false);
+  const [isLoadingMore, setIsLoadingMore] = React.useState(false);
+  const [nextCursor, setNextCursor] = React.useState<string | null>(null);
+  const [total, setTotal] = React.useState<number | undefined>();
+  const [filters, setFilters] = React.useState<TimelineFilters>(EMPTY_FILTERS);
+  const [filterOptions, setFilterOptions] = React.useState<CommunicationFilterOptions | null>(
+    null
+  );
+
+  // Debounced search
+  const searchTimeoutRef = React.useRef<NodeJS.Timeout>();
+
+  const fetchTimeline = React.useCallback(
+    async (cursor?: string) => {
+      try {
+        const result = await getCustomerTimeline({
+          customerId,
+          limit: 25,
+          cursor,
+          ...filters,
+          search: filters.search || undefined,
+        });
+
+        if (!result.success) {
+          toast.error(result.error || 'Failed to load timeline');
+          return;
+        }
+
+        setItems((prev) => (cursor ? [...prev, ...result.data.items] : result.data.items));
+        setNextCursor(result.data.nextCursor);
+        setTotal(result.data.total);
+      } catch (error) {
+        console.error('Failed to fetch timeline:', error);
+        toast.error('Failed to load timeline');
+      }
+    },
+    [customerId, filters]
+  );
+
+  // Admins for the filter bar
+  React.useEffect(() => {
+    getCommunicationFilterOptions()
+      .then((result) => result.success && setFilterOptions(result.data))
+      .catch((error) => console.error('Failed to load filter options:', error));
+  }, []);
+
+  // Initial load and filter changes
+  React.useEffect(() => {
+    if (searchTimeoutRef.current) {
+      clearTimeout(searchTimeoutRef.current);
+    }
+
+    searchTimeoutRef.current = setTimeout(async () => {
+      setIsFiltering(true);
+      await fetchTimeline();
+      setIsFiltering(false);
+      setIsLoading(false);
+    }, 300);
+
+    return () => {
+      if (searchTimeoutRef.current) {
+        clearTimeout(searchTimeoutRef.current);
+      }
+    };
+  }, [fetchTimeline]);
+
+  const handleLoadMore = async () => {
+    if (!nextCursor) return;
+    setIsLoadingMore(true);
+    await fetchTimeline(nextCursor);
+    setIsLoadingMore(false);
+  };
+
+  const handleFiltersChange = (next: CommunicationFilters) => {
+    setFilters((prev) => ({ ...next, kinds: prev.kinds }));
+  };
+
+  const handleKindsChange = (kinds: TimelineItemKind[]) => {
+    setFilters((prev) => ({ ...prev, kinds }));
+  };
+
+  return (
+    <div>
+      {/* Header */}
+      <div className="flex items-center justify-between mb-4">
+        <h3 className="text-sm font-medium text-zinc-900">
+          Timeline
+          {total !== undefined && <span className="ml-2 text-zinc-500">({total})</span>}
+        </h3>
+      </div>
+
+      {/* Filters */}
+      <CommunicationFilterBar
+        filters={filters}
+        onFiltersChange={handleFiltersChange}
+        onClear={() => setFilters(EMPTY_FILTERS)}
+        isFiltering={isFiltering}
+        options={filterOptions}
+        showTags={false}
+      >
+        <MultiSelectFilter
+          label="Everything"
+          options={timelineKindOptions}
+          selected={filters.kinds}
+          onChange={handleKindsChange}
+        />
+      </CommunicationFilterBar>
+
+      {/* Content */}
+      {isLoading ? (
+        <div className="flex items-center justify-center py-12">
+          <Loader2 className="w-6 h-6 animate-spin text-zinc-400" />
+        </div>
+      ) : items.length === 0 ? (
+        <div className="flex flex-col items-center justify-center py-12 text-center">
+          <p className="text-sm text-zinc-500">
+            {hasActiveFilters(filters)
+              ? 'Nothing matches these filters.'
+              : 'No activity for this customer yet.'}
+          </p>
+        </div>
+      ) : (
+        <ul className={cn('mt-2', isFiltering && 'opacity-60')}>
+          {items.map((item) => (
+            <TimelineEntry key={`${item.kind}:${item.id}`} item={item} />
+          ))}
+        </ul>
+      )}
+
+      {nextCursor && (
+        <div className="flex justify-center mt-4">
+          <Button variant="secondary" onClick={handleLoadMore} disabled={isLoadingMore}>
+            {isLoadingMore ? (
+              <>
+                <Loader2 className="w-4 h-4 mr-2 animate-spin" />
+                Loading...
+              </>
+            ) : (
+              'Load More'
+            )}
+          </Button>
+        </div>
+      )}
+    </div>
+  );
+}
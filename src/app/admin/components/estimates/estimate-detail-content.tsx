@@ -5,7 +5,7 @@
  *
  * Main content component for the estimate detail page.
  * Displays estimate information, customer details, pool details (if any),
- * line items, and action buttons.
+ * line items, communications about the estimate, and action buttons.
  */
 
 import { useState } from 'react';
@@ -24,10 +24,14 @@ import {
   Trash2,
   ChevronRight,
   AlertCircle,
+  MessageSquare,
+  Plus,
 } from 'lucide-react';
 import { EstimateStatusBadge } from './estimate-status-badge';
 import { EstimateLineItemsTable } from './estimate-line-items';
 import { EstimateStatusActions } from './estimate-status-actions';
+import { RelatedCommunications } from '@/components/communications/related-communications';
+import { LogCommunicationModal } from '@/components/communications/log-communication-modal';
 import type { EstimateWithDetails } from '@/lib/types/estimate';
 import { formatCents } from '@/lib/utils/currency';
 import { duplicateEstimate, deleteEstimate } from '@/app/actions/estimates';
@@ -102,6 +106,8 @@ export function EstimateDetailContent({ estimate }: EstimateDetailContentProps)
   const [isDeleting, setIsDeleting] = useState(false);
   const [isDuplicating, setIsDuplicating] = useState(false);
   const [error, setError] = useState<string | null>(null);
+  const [showLogModal, setShowLogModal] = useState(false);
+  const [communicationsKey, setCommunicationsKey] = useState(0);
 
   const handleDuplicate = async () => {
     setIsDuplicating(true);
@@ -422,6 +428,28 @@ export function EstimateDetailContent({ estimate }: EstimateDetailContentProps)
               </p>
             </div>
           )}
+
+          {/* Communications */}
+          <div className="bg-white border border-zinc-200 rounded-lg p-4 print:hidden">
+            <div className="flex items-center justify-between mb-3">
+              <h2 className="text-sm font-semibold text-zinc-900 flex items-center gap-2">
+                <MessageSquare className="w-4 h-4 text-zinc-500" />
+                Communications
+              </h2>
+              <button
+                onClick={() => setShowLogModal(true)}
+                className="inline-flex items-center text-sm text-zinc-600 hover:text-zinc-900"
+              >
+                <Plus className="w-4 h-4 mr-1" />
+                Log Communication
+              </button>
+            </div>
+            <RelatedCommunications
+              estimateId={estimate.id}
+              refreshKey={communicationsKey}
+              emptyMessage="No communications about this estimate yet."
+            />
+          </div>
         </div>
       </div>
 
@@ -446,7 +474,24 @@ export function EstimateDetailContent({ estimate }: EstimateDetailContentProps)
             </Link>
           </>
         )}
+        <span className="text-zinc-300">|</span>
+        <Link
+          href={`/admin/audit?table=estimates&record=${estimate.id}`}
+          className="inline-flex items-center text-sm text-zinc-600 hover:text-zinc-900"
+        >
+          View History
+          <ChevronRight className="w-4 h-4 ml-1" />
+        </Link>
       </div>
+
+      <LogCommunicationModal
+        customerId={estimate.customer.id}
+        customerName={estimate.customer.name}
+        open={showLogModal}
+        onOpenChange={setShowLogModal}
+        onSuccess={() => setCommunicationsKey((key) => key + 1)}
+        defaultEstimateId={estimate.id}
+      />
     </div>
   );
 }
@@ -3,29 +3,42 @@
 import * as React from 'react';
 import Link from 'next/link';
 import { useRouter } from 'next/navigation';
-import { Search, Command, User, Loader2 } from 'lucide-react';
-import { globalSearchCustomers } from '@/app/actions/customers';
-import { formatPhone } from '@/lib/utils/phone';
+import {
+  Search,
+  Command,
+  User,
+  Loader2,
+  MessageSquare,
+  StickyNote,
+  FileText,
+  MapPin,
+  type LucideIcon,
+} from 'lucide-react';
+import { globalSearch } from '@/app/actions/search';
+import type { GlobalSearchGroup, GlobalSearchResult } from '@/lib/types/search';
+import type { SearchEntityType } from '@/lib/validations/search';
 import { cn } from '@/lib/utils';
 
 /**
  * Header Component
  *
- * Sticky header with global search bar.
+ * Sticky header with global search bar. Searches customers,
+ * communications, notes, estimates and properties at once.
  * Height: 56px (h-14)
  */
 
-interface SearchResult {
-  id: string;
-  name: string;
-  phone: string;
-  email: string | null;
-}
+const RESULT_ICONS: Record<SearchEntityType, LucideIcon> = {
+  customer: User,
+  communication: MessageSquare,
+  note: StickyNote,
+  estimate: FileText,
+  property: MapPin,
+};
 
 export function Header() {
   const router = useRouter();
   const [searchValue, setSearchValue] = React.useState('');
-  const [results, setResults] = React.useState<SearchResult[]>([]);
+  const [groups, setGroups] = React.useState<GlobalSearchGroup[]>([]);
   const [isLoading, setIsLoading] = React.useState(false);
   const [isOpen, setIsOpen] = React.useState(false);
   const [selectedIndex, setSelectedIndex] = React.useState(-1);
@@ -52,18 +65,21 @@ export function Header() {
     return () => document.removeEventListener('keydown', handleKeyDown);
   }, []);
 
+  // Flattened in display order for keyboard navigation
+  const results = React.useMemo(() => groups.flatMap((group) => group.results), [groups]);
+
   // Search when value changes
   React.useEffect(() => {
-    if (searchValue.length < 2) {
-      setResults([]);
+    if (searchValue.trim().length < 2) {
+      setGroups([]);
       return;
     }
 
     const timer = setTimeout(async () => {
       setIsLoading(true);
-      const result = await globalSearchCustomers(searchValue, 8);
+      const result = await globalSearch({ query: searchValue, limit: 4 });
       if (result.success) {
-        setResults(result.data);
+        setGroups(result.data.groups);
       }
       setIsLoading(false);
     }, 200);
@@ -106,18 +122,18 @@ export function Header() {
       case 'Enter':
         e.preventDefault();
         if (selectedIndex >= 0 && results[selectedIndex]) {
-          navigateToCustomer(results[selectedIndex].id);
+          navigateToResult(results[selectedIndex]);
         }
         break;
     }
   };
 
-  const navigateToCustomer = (id: string) => {
+  const navigateToResult = (result: GlobalSearchResult) => {
     setIsOpen(false);
     setSearchValue('');
-    setResults([]);
+    setGroups([]);
     setSelectedIndex(-1);
-    router.push(`/admin/customers/${id}`);
+    router.push(result.href);
   };
 
   const showDropdown = isOpen && (searchValue.length >= 2 || isLoading);
@@ -131,7 +147,7 @@ export function Header() {
           <input
             ref={inputRef}
             type="search"
-            placeholder="Search customers..."
+            placeholder="Search customers, phones, estimates, addresses..."
             value={searchValue}
             onChange={(e) => {
               setSearchValue(e.target.value);
@@ -177,7 +193,7 @@ export function Header() {
             className={cn(
               'absolute top-full left-0 right-0 mt-1',
               'bg-white border border-zinc-200 rounded-lg shadow-lg',
-              'max-h-80 overflow-y-auto',
+              'max-h-[28rem] overflow-y-auto',
               'z-50'
             )}
           >
@@ -189,42 +205,65 @@ export function Header() {
 
             {!isLoading && searchValue.length >= 2 && results.length === 0 && (
               <div className="px-4 py-3 text-sm text-zinc-500">
-                No customers found for "{searchValue}"
+                No results for "{searchValue}"
               </div>
             )}
 
-            {results.length > 0 && (
-              <ul className="py-1">
-                {results.map((result, index) => (
-                  <li key={result.id}>
-                    <button
-                      onClick={() => navigateToCustomer(result.id)}
-                      className={cn(
-                        'w-full px-4 py-2 text-left',
-                        'flex items-center gap-3',
-                        'transition-colors duration-100',
-                        index === selectedIndex
-                          ? 'bg-zinc-100'
-                          : 'hover:bg-zinc-50'
-                      )}
-                    >
-                      <div className="w-8 h-8 rounded-full bg-zinc-100 flex items-center justify-center shrink-0">
-                        <User className="w-4 h-4 text-zinc-500" />
-                      </div>
-                      <div className="min-w-0 flex-1">
-                        <p className="text-sm font-medium text-zinc-900 truncate">
-                          {result.name}
-                        </p>
-                        <p className="text-xs text-zinc-500 truncate">
-                          {formatPhone(result.phone)}
-                          {result.email && ` • ${result.email}`}
-                        </p>
-                      </div>
-                    </button>
-                  </li>
-                ))}
-              </ul>
-            )}
+            {groups.map((group) => (
+              <div key={group.type} className="py-1 border-b border-zinc-100 last:border-0">
+                <p className="px-4 pt-1 pb-0.5 text-[11px] font-medium uppercase tracking-wide text-zinc-400">
+                  {group.label}
+                </p>
+                <ul>
+                  {group.results.map((result) => {
+                    const index = results.indexOf(result);
+                    const Icon = RESULT_ICONS[result.type];
+
+                    return (
+                      <li key={`${result.type}-${result.id}`}>
+                        <button
+                          onClick={() => navigateToResult(result)}
+                          className={cn(
+                            'w-full px-4 py-2 text-left',
+                            'flex items-center gap-3',
+                            'transition-colors duration-100',
+                            index === selectedIndex
+                              ? 'bg-zinc-100'
+                              : 'hover:bg-zinc-50'
+                          )}
+                        >
+                          <div className="w-8 h-8 rounded-full bg-zinc-100 flex items-center justify-center shrink-0">
+                            <Icon className="w-4 h-4 text-zinc-500" />
+                          </div>
+                          <div className="min-w-0 flex-1">
+                            <p className="text-sm font-medium text-zinc-900 truncate">
+                              {result.title}
+                            </p>
+                            {result.snippet ? (
+                              <p className="text-xs text-zinc-500 truncate">
+                                {result.snippet.map((segment, i) =>
+                                  segment.highlighted ? (
+                                    <mark key={i} className="bg-yellow-100 text-zinc-900 rounded-sm">
+                                      {segment.text}
+                                    </mark>
+                                  ) : (
+                                    <span key={i}>{segment.text}</span>
+                                  )
+                                )}
+                              </p>
+                            ) : (
+                              result.subtitle && (
+                                <p className="text-xs text-zinc-500 truncate">{result.subtitle}</p>
+                              )
+                            )}
+                          </div>
+                        </button>
+                      </li>
+                    );
+                  })}
+                </ul>
+              </div>
+            ))}
 
             {/* View all link */}
             {results.length > 0 && (
@@ -237,7 +276,7 @@ export function Header() {
                   }}
                   className="text-sm text-blue-600 hover:text-blue-700"
                 >
-                  View all results →
+                  View all matching customers →
                 </Link>
               </div>
             )}
@@ -6,9 +6,14 @@ import {
   Users,
   Calendar,
   FileText,
+  Inbox,
+  LayoutTemplate,
+  MessageCircleReply,
   Settings,
   LayoutDashboard,
   LogOut,
+  TextSearch,
+  ScrollText,
   Loader2,
 } from 'lucide-react';
 import { cn } from '@/lib/utils';
@@ -93,6 +98,21 @@ export function Sidebar() {
         <NavItem href="/admin/estimates" icon={FileText}>
           Estimates
         </NavItem>
+        <NavItem href="/admin/communications/needs-response" icon={MessageCircleReply}>
+          Needs Response
+        </NavItem>
+        <NavItem href="/admin/communications/search" icon={TextSearch}>
+          Search
+        </NavItem>
+        <NavItem href="/admin/communications/import" icon={Inbox}>
+          Import
+        </NavItem>
+        <NavItem href="/admin/communications/templates" icon={LayoutTemplate}>
+          Templates
+        </NavItem>
+        <NavItem href="/admin/audit" icon={ScrollText}>
+          Audit Log
+        </NavItem>
 
         {/* Divider
         <div className="my-4 border-t border-zinc-200" />
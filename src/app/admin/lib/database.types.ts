@@ -13,8 +13,25 @@
 // Enums
 // =============================================================================
 
-export type CommunicationType = 'call' | 'text' | 'email';
+export type CommunicationType =
+  | 'call'
+  | 'text'
+  | 'email'
+  | 'voicemail'
+  | 'in_person'
+  | 'web_form'
+  | 'social';
 export type CommunicationDirection = 'inbound' | 'outbound';
+export type CommunicationThreadStatus = 'open' | 'answered';
+export type CallOutcome = 'connected' | 'no_answer' | 'busy' | 'left_voicemail' | 'wrong_number';
+export type CommunicationImportSource = 'email' | 'sms' | 'cdr';
+export type ImportQueueStatus = 'pending' | 'resolved' | 'dismissed';
+export type ImportQueueReason =
+  | 'no_customer_match'
+  | 'multiple_customer_matches'
+  | 'ambiguous_direction';
+export type DeliveryStatus = 'pending' | 'sent' | 'delivered' | 'failed';
+export type MessageTemplateChannel = 'email' | 'sms';
 export type PoolType = 'inground' | 'above_ground' | 'spa' | 'other';
 export type PoolSurfaceType = 'plaster' | 'pebble' | 'tile' | 'vinyl' | 'fiberglass';
 export type CalendarEventType = 'consultation' | 'estimate_visit' | 'follow_up' | 'other';
@@ -67,6 +84,7 @@ export interface CustomerNote {
   created_by: string;
   created_at: string;
   updated_at: string;
+  search_vector: unknown; // tsvector - typically not used directly
 }
 
 export interface CustomerAttachment {
@@ -77,6 +95,7 @@ export interface CustomerAttachment {
   content_type: string;
   size_bytes: number;
   note_id: string | null;
+  communication_id: string | null;
   uploaded_by: string;
   created_at: string;
 }
@@ -90,7 +109,116 @@ export interface Communication {
   occurred_at: string;
   logged_by: string;
   created_at: string;
+  thread_id: string;
+  parent_id: string | null;
+  call_duration_seconds: number | null;
+  call_outcome: CallOutcome | null;
+  call_number: string | null;
+  needs_callback: boolean;
+  delivery_status: DeliveryStatus | null;
+  delivery_provider: string | null;
+  delivery_provider_message_id: string | null;
+  delivery_error: string | null;
+  delivery_updated_at: string | null;
+  template_id: string | null;
+  import_source: CommunicationImportSource | null;
+  external_message_id: string | null;
+  response_snoozed_until: string | null;
+  response_dismissed_at: string | null;
+  response_dismissed_by: string | null;
   search_vector: unknown; // tsvector - typically not used directly
+  summary_digits: string; // Generated: digit runs in summary for number search
+  edited_at: string | null; // Set by trigger when content fields change
+  edited_by: string | null;
+  revision_count: number;
+  deleted_at: string | null; // Soft delete; set while in the trash
+  deleted_by: string | null;
+  version: number; // Optimistic locking, incremented on content and link edits
+  estimate_id: string | null; // What the communication is about (optional)
+  calendar_event_id: string | null;
+  property_id: string | null;
+  pool_id: string | null; // Fills property_id by trigger when that is null
+}
+
+/**
+ * Row returned by get_needs_response_queue: a customer waiting on a reply
+ */
+export interface NeedsResponseQueueRow {
+  customer_id: string;
+  /** Oldest unanswered inbound communication */
+  communication_id: string;
+  waiting_since: string;
+  unanswered_count: number;
+  communication_ids: string[];
+}
+
+export interface CommunicationThreadRow {
+  id: string;
+  customer_id: string;
+  status: CommunicationThreadStatus;
+  last_activity_at: string;
+  message_count: number;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface CommunicationImportQueueItem {
+  id: string;
+  source: CommunicationImportSource;
+  external_message_id: string;
+  type: CommunicationType;
+  direction: CommunicationDirection | null;
+  summary: string;
+  occurred_at: string;
+  counterparty: string | null;
+  reason: ImportQueueReason;
+  payload: Record<string, unknown>;
+  status: ImportQueueStatus;
+  communication_id: string | null;
+  resolved_by: string | null;
+  resolved_at: string | null;
+  imported_by: string | null;
+  created_at: string;
+}
+
+export interface MessageTemplate {
+  id: string;
+  name: string;
+  channel: MessageTemplateChannel;
+  subject: string | null;
+  body: string;
+  created_by: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface CommunicationSavedView {
+  id: string;
+  name: string;
+  filters: Record<string, unknown>; // CommunicationFilters, validated by the application
+  is_shared: boolean;
+  created_by: string;
+  created_at: string;
+  updated_at: string;
+}
+
+/**
+ * A tracked field's value before and after an edit
+ */
+export interface CommunicationRevisionChange {
+  from: unknown;
+  to: unknown;
+}
+
+export interface CommunicationRevision {
+  id: string;
+  communication_id: string;
+  revision_number: number;
+  edited_by: string | null;
+  changes: Record<string, CommunicationRevisionChange>; // Changed fields only
+  previous: Record<string, unknown>; // Every tracked field before the edit
+  restored_from: string | null;
+  created_at: string;
 }
 
 export interface Property {
@@ -135,6 +263,7 @@ export interface CalendarEvent {
   end_datetime: string;
   all_day: boolean;
   location_url: string | null;
+  communication_id: string | null;
   reminder_24h_sent: boolean;
   reminder_2h_sent: boolean;
   created_by: string;
@@ -167,6 +296,29 @@ export interface Estimate {
   created_by: string;
   created_at: string;
   updated_at: string;
+  search_vector: unknown; // tsvector - typically not used directly
+}
+
+/**
+ * Row of the customer_timeline view (00036_customer_timeline.sql);
+ * communication columns are null for other kinds
+ */
+export interface CustomerTimelineRow {
+  kind: 'communication' | 'note' | 'estimate' | 'event' | 'attachment';
+  id: string;
+  customer_id: string;
+  occurred_at: string;
+  actor_id: string;
+  communication_type: CommunicationType | null;
+  direction: CommunicationDirection | null;
+  call_outcome: CallOutcome | null;
+  call_duration_seconds: number | null;
+  has_attachments: boolean;
+  search_vector: unknown; // tsvector - typically not used directly
+  estimate_id: string | null; // Communication links; an estimate or event's own ID
+  calendar_event_id: string | null;
+  property_id: string | null;
+  pool_id: string | null;
 }
 
 export interface AuditLog {
@@ -228,6 +380,7 @@ export interface CustomerAttachmentInsert {
   content_type: string;
   size_bytes: number;
   note_id?: string | null;
+  communication_id?: string | null;
   uploaded_by: string;
 }
 
@@ -239,6 +392,55 @@ export interface CommunicationInsert {
   summary: string;
   occurred_at: string;
   logged_by: string;
+  thread_id?: string; // Assigned by trigger if omitted
+  parent_id?: string | null;
+  call_duration_seconds?: number | null;
+  call_outcome?: CallOutcome | null;
+  call_number?: string | null;
+  needs_callback?: boolean;
+  delivery_status?: DeliveryStatus | null;
+  delivery_provider?: string | null;
+  delivery_provider_message_id?: string | null;
+  delivery_error?: string | null;
+  delivery_updated_at?: string | null;
+  template_id?: string | null;
+  import_source?: CommunicationImportSource | null;
+  external_message_id?: string | null;
+  estimate_id?: string | null;
+  calendar_event_id?: string | null;
+  property_id?: string | null;
+  pool_id?: string | null;
+}
+
+export interface CommunicationImportQueueInsert {
+  id?: string;
+  source: CommunicationImportSource;
+  external_message_id: string;
+  type: CommunicationType;
+  direction?: CommunicationDirection | null;
+  summary: string;
+  occurred_at: string;
+  counterparty?: string | null;
+  reason: ImportQueueReason;
+  payload?: Record<string, unknown>;
+  imported_by?: string | null;
+}
+
+export interface MessageTemplateInsert {
+  id?: string;
+  name: string;
+  channel: MessageTemplateChannel;
+  subject?: string | null;
+  body: string;
+  created_by?: string | null;
+}
+
+export interface CommunicationSavedViewInsert {
+  id?: string;
+  name: string;
+  filters?: Record<string, unknown>;
+  is_shared?: boolean;
+  created_by: string;
 }
 
 export interface PropertyInsert {
@@ -279,6 +481,7 @@ export interface CalendarEventInsert {
   end_datetime: string;
   all_day?: boolean;
   location_url?: string | null;
+  communication_id?: string | null;
   created_by: string;
 }
 
@@ -319,6 +522,47 @@ export interface CustomerNoteUpdate {
 export interface CommunicationUpdate {
   summary?: string;
   occurred_at?: string;
+  call_duration_seconds?: number | null;
+  call_outcome?: CallOutcome | null;
+  call_number?: string | null;
+  needs_callback?: boolean;
+  delivery_status?: DeliveryStatus | null;
+  delivery_provider_message_id?: string | null;
+  delivery_error?: string | null;
+  delivery_updated_at?: string | null;
+  response_snoozed_until?: string | null;
+  response_dismissed_at?: string | null;
+  response_dismissed_by?: string | null;
+  deleted_at?: string | null;
+  deleted_by?: string | null;
+  estimate_id?: string | null;
+  calendar_event_id?: string | null;
+  property_id?: string | null;
+  pool_id?: string | null;
+}
+
+export interface CommunicationThreadUpdate {
+  status?: CommunicationThreadStatus;
+}
+
+export interface CommunicationImportQueueUpdate {
+  status?: ImportQueueStatus;
+  communication_id?: string | null;
+  resolved_by?: string | null;
+  resolved_at?: string | null;
+}
+
+export interface MessageTemplateUpdate {
+  name?: string;
+  channel?: MessageTemplateChannel;
+  subject?: string | null;
+  body?: string;
+}
+
+export interface CommunicationSavedViewUpdate {
+  name?: string;
+  filters?: Record<string, unknown>;
+  is_shared?: boolean;
 }
 
 export interface PropertyUpdate {
@@ -407,6 +651,31 @@ export interface Database {
         Insert: CommunicationInsert;
         Update: CommunicationUpdate;
       };
+      communication_threads: {
+        Row: CommunicationThreadRow;
+        Insert: never; // Created by trigger on communications
+        Update: CommunicationThreadUpdate;
+      };
+      communication_import_queue: {
+        Row: CommunicationImportQueueItem;
+        Insert: CommunicationImportQueueInsert;
+        Update: CommunicationImportQueueUpdate;
+      };
+      message_templates: {
+        Row: MessageTemplate;
+        Insert: MessageTemplateInsert;
+        Update: MessageTemplateUpdate;
+      };
+      communication_saved_views: {
+        Row: CommunicationSavedView;
+        Insert: CommunicationSavedViewInsert;
+        Update: CommunicationSavedViewUpdate;
+      };
+      communication_revisions: {
+        Row: CommunicationRevision;
+        Insert: never; // Written by trigger on communications
+        Update: never;
+      };
       properties: {
         Row: Property;
         Insert: PropertyInsert;
@@ -433,9 +702,20 @@ export interface Database {
         Update: never; // Immutable
       };
     };
+    Views: {
+      customer_timeline: {
+        Row: CustomerTimelineRow;
+      };
+    };
     Enums: {
       communication_type: CommunicationType;
       communication_direction: CommunicationDirection;
+      communication_thread_status: CommunicationThreadStatus;
+      call_outcome: CallOutcome;
+      communication_import_source: CommunicationImportSource;
+      import_queue_status: ImportQueueStatus;
+      delivery_status: DeliveryStatus;
+      message_template_channel: MessageTemplateChannel;
       pool_type: PoolType;
       pool_surface_type: PoolSurfaceType;
       calendar_event_type: CalendarEventType;
@@ -452,6 +732,119 @@ export interface Database {
         Args: Record<string, never>;
         Returns: string;
       };
+      find_customers_by_emails: {
+        Args: { emails: string[] };
+        Returns: { id: string; email: string }[];
+      };
+      find_customers_by_phones: {
+        Args: { phones: string[] };
+        Returns: { phone: string; id: string }[];
+      };
+      restore_communication_revision: {
+        Args: { p_revision_id: string };
+        Returns: string;
+      };
+      get_needs_response_queue: {
+        Args: { p_customer_id?: string | null };
+        Returns: NeedsResponseQueueRow[];
+      };
+      get_communication_response_times: {
+        Args: { p_from: string; p_to: string };
+        Returns: {
+          admin_id: string | null;
+          responses: number;
+          median_seconds: number | null;
+          average_seconds: number | null;
+        }[];
+      };
+      get_communication_volume: {
+        Args: { p_from: string; p_to: string };
+        Returns: {
+          admin_id: string;
+          type: CommunicationType;
+          direction: CommunicationDirection;
+          total: number;
+        }[];
+      };
+      get_communication_stats: {
+        Args: {
+          p_customer_id: string;
+          p_from?: string | null;
+          p_to?: string | null;
+          p_include_deleted?: boolean;
+        };
+        Returns: {
+          type: CommunicationType;
+          direction: CommunicationDirection;
+          call_outcome: CallOutcome | null;
+          total: number;
+          needs_callback: number;
+          connected_duration_sum: number;
+          connected_duration_count: number;
+          last_occurred_at: string;
+        }[];
+      };
+      global_search: {
+        Args: {
+          p_query: string;
+          p_digits?: string | null;
+          p_estimate_number?: number | null;
+          p_limit?: number;
+        };
+        Returns: {
+          entity_type: 'customer' | 'communication' | 'note' | 'estimate' | 'property';
+          entity_id: string;
+          customer_id: string;
+          customer_name: string;
+          label: string | null;
+          headline: string | null;
+          score: number;
+          occurred_at: string;
+        }[];
+      };
+      search_communications: {
+        Args: {
+          p_query: string;
+          p_customer_id?: string | null;
+          p_sort?: string;
+          p_limit?: number;
+          p_offset?: number;
+          p_after_rank?: number | null;
+          p_after_occurred_at?: string | null;
+          p_after_id?: string | null;
+          p_digits?: string | null;
+          p_types?: CommunicationType[] | null;
+          p_directions?: CommunicationDirection[] | null;
+          p_logged_by?: string[] | null;
+          p_tag_ids?: string[] | null;
+          p_has_attachments?: boolean | null;
+          p_from?: string | null;
+          p_to?: string | null;
+          p_call_outcome?: CallOutcome | null;
+          p_min_call_duration?: number | null;
+          p_max_call_duration?: number | null;
+          p_include_deleted?: boolean;
+          p_estimate_id?: string | null;
+          p_calendar_event_id?: string | null;
+          p_property_id?: string | null;
+          p_pool_id?: string | null;
+        };
+        Returns: { id: string; rank: number; headline: string; total_count: number }[];
+      };
+      get_communication_monthly_counts: {
+        Args: {
+          p_customer_id: string;
+          p_from?: string | null;
+          p_to?: string | null;
+          p_timezone?: string;
+          p_include_deleted?: boolean;
+        };
+        Returns: { month: string; direction: CommunicationDirection; total: number }[];
+      };
+      get_communication_hours: {
+        Args: { p_from: string; p_to: string; p_timezone?: string };
+        Returns: { admin_id: string; day_of_week: number; hour: number; total: number }[];
+      };
     };
   };
 }
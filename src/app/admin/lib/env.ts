@@ -9,14 +9,36 @@ const serverEnvSchema = z.object({
   NEXT_PUBLIC_SUPABASE_URL: z.string().url(),
   NEXT_PUBLIC_SUPABASE_ANON_KEY: z.string().min(1),
   SUPABASE_SERVICE_ROLE_KEY: z.string().min(1),
+  CURSOR_SECRET: z.string().min(32).optional(),
 
   // Application
   NEXT_PUBLIC_APP_URL: z.string().url(),
   DEFAULT_TIMEZONE: z.string().default('America/New_York'),
+  BUSINESS_HOURS_START: z.coerce.number().int().min(0).max(23).optional(),
+  BUSINESS_HOURS_END: z.coerce.number().int().min(1).max(24).optional(),
+  RESPONSE_SLA_BUSINESS_HOURS: z.coerce.number().positive().optional(),
+  COMMUNICATION_TRASH_RETENTION_DAYS: z.coerce.number().int().positive().optional(),
+  CRON_SECRET: z.string().min(16).optional(),
 
   // Email (optional for development)
   RESEND_API_KEY: z.string().optional(),
-  EMAIL_FROM: z.string().email().optional(),
+  EMAIL_FROM: z.string().optional(),
+  NOTIFICATION_EMAIL_PROVIDER: z.enum(['smtp', 'outbox']).optional(),
+  SMTP_HOST: z.string().optional(),
+  SMTP_PORT: z.coerce.number().int().positive().optional(),
+  SMTP_SECURE: z.enum(['true', 'false']).optional(),
+  SMTP_USER: z.string().optional(),
+  SMTP_PASSWORD: z.string().optional(),
+
+  // SMS (optional for development)
+  NOTIFICATION_SMS_PROVIDER: z.enum(['http', 'outbox']).optional(),
+  SMS_HTTP_URL: z.string().url().optional(),
+  SMS_HTTP_TOKEN: z.string().optional(),
+  SMS_FROM_NUMBER: z.string().optional(),
+  SMS_WEBHOOK_SECRET: z.string().optional(),
+
+  // Development outbox for notifications
+  NOTIFICATION_OUTBOX_DIR: z.string().optional(),
 
   // Node environment
   NODE_ENV: z.enum(['development', 'production', 'test']).default('development'),
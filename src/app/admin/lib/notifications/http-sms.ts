@@ -0,0 +1,113 @@
+/**
+ * HTTP SMS Provider
+ *
+ * @file src/lib/notifications/http-sms.ts
+ *
+ * Sends text messages through a generic HTTP/JSON gateway. The gateway
+ * receives `{ to, from, body }` with a bearer token and should answer with
+ * a JSON object containing a message ID (`id`, `sid` or `message_id`) and
+ * optionally a `status`. Delivery updates arrive later through
+ * /api/webhooks/sms-status.
+ */
+
+import type { SmsMessage, SmsProvider, DeliveryResult } from './types';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+export interface HttpSmsConfig {
+  url: string;
+  token?: string;
+  /** Sending number in E.164 */
+  from: string;
+  timeoutMs?: number;
+}
+
+// =============================================================================
+// Provider
+// =============================================================================
+
+export class HttpSmsProvider implements SmsProvider {
+  readonly name = 'http-sms';
+  readonly channel = 'sms' as const;
+
+  private config: HttpSmsConfig;
+
+  constructor(config: HttpSmsConfig) {
+    this.config = config;
+  }
+
+  async send(message: SmsMessage): Promise<DeliveryResult> {
+    try {
+      const response = await fetch(this.config.url, {
+        method: 'POST',
+        headers: {
+          'Content-Type': 'application/json',
+          ...(this.config.token ? { Authorization: `Bearer ${this.config.token}` } : {}),
+        },
+        body: JSON.stringify({ to: message.to, from: this.config.from, body: message.body }),
+        signal: AbortSignal.timeout(this.config.timeoutMs ?? 15_000),
+      });
+
+      const payload = (await response.json().catch(() => ({}))) as Record<string, unknown>;
+
+      if (!response.ok) {
+        const detail = payload.error ?? payload.message ?? response.statusText;
+        return {
+          status: 'failed',
+          providerMessageId: null,
+          error: `SMS gateway returned ${response.status}: ${String(detail)}`,
+        };
+      }
+
+      const id = payload.id ?? payload.sid ?? payload.message_id;
+
+      return {
+        status: mapSmsStatus(payload.status) ?? 'sent',
+        providerMessageId: id != null ? String(id) : null,
+        error: null,
+      };
+    } catch (error) {
+      return {
+        status: 'failed',
+        providerMessageId: null,
+        error: error instanceof Error ? error.message : 'SMS send failed',
+      };
+    }
+  }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/**
+ * Map a gateway status string to a delivery status. Shared with the
+ * status webhook so both paths agree.
+ *
+ * @example
+ * mapSmsStatus('undelivered') // 'failed'
+ * mapSmsStatus('queued') // 'sent'
+ */
+export function mapSmsStatus(status: unknown): DeliveryResult['status'] | null {
+  if (typeof status !== 'string') return null;
+
+  switch (status.trim().toLowerCase()) {
+    case 'delivered':
+    case 'read':
+      return 'delivered';
+    case 'failed':
+    case 'undelivered':
+    case 'rejected':
+    case 'canceled':
+      return 'failed';
+    case 'accepted':
+    case 'queued':
+    case 'sending':
+    case 'sent':
+      return 'sent';
+    default:
+      return null;
+  }
+}
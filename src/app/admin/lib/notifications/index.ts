@@ -2,13 +2,120 @@
  * Notification Services
  *
  * This directory contains notification infrastructure:
- * - Email provider (Resend)
- * - Future: SMS provider (Twilio)
- * - Notification templates
- * - Reminder processing logic
+ * - Email provider (SMTP)
+ * - SMS provider (generic HTTP gateway)
+ * - Outbox provider that writes messages to disk (development/tests)
+ * - Message template merge fields
  *
  * The NotificationProvider interface allows adding new
- * providers without changing reminder logic.
+ * providers without changing sending logic. Providers are chosen from
+ * environment variables:
+ *
+ * - NOTIFICATION_EMAIL_PROVIDER: 'smtp' | 'outbox'
+ *   (default: smtp when SMTP_HOST is set, otherwise outbox)
+ * - SMTP_HOST, SMTP_PORT (587), SMTP_SECURE ('true' for port 465),
+ *   SMTP_USER, SMTP_PASSWORD, EMAIL_FROM
+ * - SMTP_ALLOW_INSECURE: 'true' to send without STARTTLS (local relays only)
+ * - NOTIFICATION_SMS_PROVIDER: 'http' | 'outbox'
+ *   (default: http when SMS_HTTP_URL is set, otherwise outbox)
+ * - SMS_HTTP_URL, SMS_HTTP_TOKEN, SMS_FROM_NUMBER
+ * - SMS_WEBHOOK_SECRET: shared secret for /api/webhooks/sms-status
+ * - NOTIFICATION_OUTBOX_DIR (default: .outbox in the project root)
+ *
+ * The outbox is refused in production so messages are never silently dropped.
+ */
+
+import path from 'path';
+import { SmtpEmailProvider } from './smtp-email';
+import { HttpSmsProvider } from './http-sms';
+import { OutboxProvider } from './outbox';
+import type { EmailProvider, SmsProvider } from './types';
+
+export type {
+  NotificationChannel,
+  EmailMessage,
+  SmsMessage,
+  DeliveryResult,
+  NotificationProvider,
+  EmailProvider,
+  SmsProvider,
+} from './types';
+export { SmtpEmailProvider } from './smtp-email';
+export { HttpSmsProvider, mapSmsStatus } from './http-sms';
+export { OutboxProvider } from './outbox';
+export {
+  TEMPLATE_VARIABLES,
+  extractVariables,
+  findUnknownVariables,
+  renderTemplate,
+  type TemplateVariable,
+  type TemplateValues,
+} from './templates';
+
+// =============================================================================
+// Provider Factories
+// =============================================================================
+
+/**
+ * Email provider configured for this environment
  */
+export function getEmailProvider(): EmailProvider {
+  const env = process.env;
+  const choice = env.NOTIFICATION_EMAIL_PROVIDER ?? (env.SMTP_HOST ? 'smtp' : 'outbox');
+
+  if (choice === 'smtp') {
+    if (!env.SMTP_HOST || !env.EMAIL_FROM) {
+      throw new Error('SMTP_HOST and EMAIL_FROM must be set to send email');
+    }
+
+    const secure = env.SMTP_SECURE === 'true';
+
+    return new SmtpEmailProvider(
+      {
+        host: env.SMTP_HOST,
+        port: Number(env.SMTP_PORT ?? (secure ? 465 : 587)),
+        secure,
+        allowInsecure: env.SMTP_ALLOW_INSECURE === 'true',
+        user: env.SMTP_USER,
+        password: env.SMTP_PASSWORD,
+        clientName: env.NEXT_PUBLIC_APP_URL ? new URL(env.NEXT_PUBLIC_APP_URL).hostname : undefined,
+      },
+      env.EMAIL_FROM
+    );
+  }
+
+  return getOutbox('email');
+}
+
+/**
+ * SMS provider configured for this environment
+ */
+export function getSmsProvider(): SmsProvider {
+  const env = process.env;
+  const choice = env.NOTIFICATION_SMS_PROVIDER ?? (env.SMS_HTTP_URL ? 'http' : 'outbox');
+
+  if (choice === 'http') {
+    if (!env.SMS_HTTP_URL || !env.SMS_FROM_NUMBER) {
+      throw new Error('SMS_HTTP_URL and SMS_FROM_NUMBER must be set to send text messages');
+    }
+
+    return new HttpSmsProvider({
+      url: env.SMS_HTTP_URL,
+      token: env.SMS_HTTP_TOKEN,
+      from: env.SMS_FROM_NUMBER,
+    });
+  }
+
+  return getOutbox('sms');
+}
+
+function getOutbox<C extends 'email' | 'sms'>(channel: C): OutboxProvider<C> {
+  if (process.env.NODE_ENV === 'production') {
+    throw new Error(`No ${channel} provider is configured`);
+  }
 
-export {};
+  return new OutboxProvider(
+    channel,
+    process.env.NOTIFICATION_OUTBOX_DIR ?? path.join(process.cwd(), '.outbox')
+  );
+}
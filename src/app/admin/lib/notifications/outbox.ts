@@ -0,0 +1,56 @@
+/**
+ * Outbox Provider
+ *
+ * @file src/lib/notifications/outbox.ts
+ *
+ * Development/test provider that writes each message to a JSON file
+ * instead of sending it. Messages are reported as delivered immediately.
+ */
+
+import { randomUUID } from 'crypto';
+import { mkdir, writeFile } from 'fs/promises';
+import path from 'path';
+import type {
+  NotificationChannel,
+  NotificationMessageMap,
+  NotificationProvider,
+  DeliveryResult,
+} from './types';
+
+// =============================================================================
+// Provider
+// =============================================================================
+
+export class OutboxProvider<C extends NotificationChannel> implements NotificationProvider<C> {
+  readonly name = 'outbox';
+  readonly channel: C;
+
+  private directory: string;
+
+  constructor(channel: C, directory: string) {
+    this.channel = channel;
+    this.directory = directory;
+  }
+
+  async send(message: NotificationMessageMap[C]): Promise<DeliveryResult> {
+    const id = randomUUID();
+    const sentAt = new Date().toISOString();
+    const fileName = `${sentAt.replace(/[:.]/g, '-')}-${this.channel}-${id}.json`;
+
+    try {
+      await mkdir(this.directory, { recursive: true });
+      await writeFile(
+        path.join(this.directory, fileName),
+        JSON.stringify({ id, channel: this.channel, sentAt, message }, null, 2)
+      );
+
+      return { status: 'delivered', providerMessageId: id, error: null };
+    } catch (error) {
+      return {
+        status: 'failed',
+        providerMessageId: null,
+        error: error instanceof Error ? error.message : 'Could not write to outbox',
+      };
+    }
+  }
+}
@@ -0,0 +1,121 @@
+/**
+ * SMTP Email Provider
+ *
+ * @file src/lib/notifications/smtp-email.ts
+ *
+ * Sends plain-text email through any SMTP relay (Google Workspace,
+ * Microsoft 365, SES, Postmark, etc.) using nodemailer.
+ *
+ * A plain connection must be upgraded with STARTTLS before credentials or
+ * mail are sent, unless the config sets allowInsecure (local relays only).
+ */
+
+import { randomUUID } from 'crypto';
+import nodemailer, { type Transporter } from 'nodemailer';
+import type { EmailMessage, EmailProvider, DeliveryResult } from './types';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+export interface SmtpConfig {
+  host: string;
+  port: number;
+  /** true = implicit TLS; false = plain connection upgraded with STARTTLS */
+  secure: boolean;
+  /** Carry on in plaintext when STARTTLS is not offered (never with a remote relay) */
+  allowInsecure?: boolean;
+  user?: string;
+  password?: string;
+  /** Name sent in EHLO */
+  clientName?: string;
+  /** Connection, greeting and idle socket timeout */
+  timeoutMs?: number;
+}
+
+const DEFAULT_TIMEOUT_MS = 30_000;
+
+// =============================================================================
+// Provider
+// =============================================================================
+
+export class SmtpEmailProvider implements EmailProvider {
+  readonly name = 'smtp';
+  readonly channel = 'email' as const;
+
+  private transport: Transporter;
+  private defaultFrom: string;
+
+  constructor(config: SmtpConfig, defaultFrom: string) {
+    const timeoutMs = config.timeoutMs ?? DEFAULT_TIMEOUT_MS;
+
+    this.transport = nodemailer.createTransport({
+      host: config.host,
+      port: config.port,
+      secure: config.secure,
+      requireTLS: !config.secure && !config.allowInsecure,
+      auth: config.user ? { user: config.user, pass: config.password } : undefined,
+      name: config.clientName,
+      connectionTimeout: timeoutMs,
+      greetingTimeout: timeoutMs,
+      socketTimeout: timeoutMs,
+    });
+    this.defaultFrom = defaultFrom;
+  }
+
+  async send(message: EmailMessage): Promise<DeliveryResult> {
+    const from = message.from ?? this.defaultFrom;
+    const messageId = `<${randomUUID()}@${domainOf(from)}>`;
+
+    try {
+      assertSingleLine(from, 'From header');
+      assertSingleLine(message.to, 'To header');
+      if (message.replyTo) assertSingleLine(message.replyTo, 'Reply-To header');
+      assertSingleLine(message.subject, 'Subject header');
+
+      await this.transport.sendMail({
+        from,
+        to: message.to,
+        replyTo: message.replyTo,
+        subject: message.subject,
+        text: message.text,
+        messageId,
+      });
+
+      // The relay accepted it; SMTP gives no delivery confirmation beyond this
+      return { status: 'sent', providerMessageId: messageId, error: null };
+    } catch (error) {
+      return {
+        status: 'failed',
+        providerMessageId: null,
+        error: error instanceof Error ? error.message : 'SMTP send failed',
+      };
+    }
+  }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/**
+ * Throw if a header value contains CR or LF, so none can add headers of
+ * its own
+ */
+function assertSingleLine(value: string, label: string): void {
+  if (/[\r\n]/.test(value)) {
+    throw new Error(`SMTP ${label} must not contain line breaks`);
+  }
+}
+
+/**
+ * Bare address from "Name <address>" or "address"
+ */
+function addressOf(value: string): string {
+  const match = value.match(/<([^>]+)>/);
+  return (match ? match[1] : value).trim();
+}
+
+function domainOf(value: string): string {
+  return addressOf(value).split('@')[1] ?? 'localhost';
+}
@@ -0,0 +1,136 @@
+/**
+ * Message Template Rendering
+ *
+ * @file src/lib/notifications/templates.ts
+ *
+ * Merge fields are written as {{group.field}} (whitespace inside the braces
+ * is ignored). Only the variables listed in TEMPLATE_VARIABLES are allowed;
+ * anything else is rejected when the template is saved, and rendering fails
+ * if a variable has no value for the chosen customer rather than sending a
+ * message with a blank in it.
+ */
+
+import { ValidationError } from '@/lib/utils/errors';
+
+// =============================================================================
+// Variables
+// =============================================================================
+
+/**
+ * Merge fields available to templates
+ */
+export const TEMPLATE_VARIABLES = [
+  { key: 'customer.name', label: 'Customer name', example: 'Jane Smith' },
+  { key: 'customer.first_name', label: 'Customer first name', example: 'Jane' },
+  { key: 'customer.phone', label: 'Customer phone', example: '(555) 123-4567' },
+  { key: 'customer.email', label: 'Customer email', example: 'jane@example.com' },
+  { key: 'property.address', label: 'Property address', example: '123 Main St, Tampa, FL 33601' },
+  { key: 'property.city', label: 'Property city', example: 'Tampa' },
+  { key: 'appointment.title', label: 'Appointment title', example: 'Estimate visit' },
+  { key: 'appointment.date', label: 'Appointment date', example: 'Monday, January 15, 2025' },
+  { key: 'appointment.time', label: 'Appointment time', example: 'Monday, January 15, 2025 at 2:30 PM' },
+  { key: 'estimate.number', label: 'Estimate number', example: 'EST-0042' },
+  { key: 'estimate.total', label: 'Estimate total', example: '$4,250.00' },
+  { key: 'estimate.valid_until', label: 'Estimate valid until', example: 'Feb 15, 2025' },
+] as const;
+
+export type TemplateVariable = (typeof TEMPLATE_VARIABLES)[number]['key'];
+
+/**
+ * Values for a render; absent or null means "not available for this customer"
+ */
+export type TemplateValues = Partial<Record<TemplateVariable, string | null>>;
+
+const KNOWN_VARIABLES = new Set<string>(TEMPLATE_VARIABLES.map((v) => v.key));
+
+const VARIABLE_PATTERN = /\{\{\s*([^{}]*?)\s*\}\}/g;
+
+// =============================================================================
+// Parsing
+// =============================================================================
+
+/**
+ * Variables referenced in a template, in first-use order without duplicates
+ */
+export function extractVariables(text: string): string[] {
+  const found = new Set<string>();
+  for (const match of text.matchAll(VARIABLE_PATTERN)) {
+    found.add(match[1]);
+  }
+  return [...found];
+}
+
+/**
+ * Variables referenced in a template that are not merge fields
+ */
+export function findUnknownVariables(text: string): string[] {
+  return extractVariables(text).filter((name) => !KNOWN_VARIABLES.has(name));
+}
+
+export function isTemplateVariable(name: string): name is TemplateVariable {
+  return KNOWN_VARIABLES.has(name);
+}
+
+// =============================================================================
+// Rendering
+// =============================================================================
+
+/**
+ * Fill in a template's merge fields
+ *
+ * @param parts - Template text to render together (e.g. subject and body) so
+ *   every problem is reported at once
+ * @param values - Merge field values for the recipient
+ * @returns Rendered parts in the same shape
+ * @throws ValidationError with `details.unknown` and `details.missing`
+ */
+export function renderTemplate<K extends string>(
+  parts: Record<K, string>,
+  values: TemplateValues
+): Record<K, string> {
+  const unknown = new Set<string>();
+  const missing = new Set<string>();
+
+  for (const text of Object.values<string>(parts)) {
+    for (const name of extractVariables(text)) {
+      if (!isTemplateVariable(name)) {
+        unknown.add(name);
+      } else if (!values[name]) {
+        missing.add(name);
+      }
+    }
+  }
+
+  if (unknown.size > 0 || missing.size > 0) {
+    throw new ValidationError(describeProblems([...unknown], [...missing]), {
+      unknown: [...unknown],
+      missing: [...missing],
+    });
+  }
+
+  const rendered = {} as Record<K, string>;
+  for (const key of Object.keys(parts) as K[]) {
+    rendered[key] = parts[key].replace(
+      VARIABLE_PATTERN,
+      (_, name: TemplateVariable) => values[name] as string
+    );
+  }
+
+  return rendered;
+}
+
+function describeProblems(unknown: string[], missing: string[]): string {
+  const problems: string[] = [];
+
+  if (unknown.length > 0) {
+    problems.push(`Unknown variables: ${unknown.map((name) => `{{${name}}}`).join(', ')}`);
+  }
+
+  if (missing.length > 0) {
+    problems.push(
+      `No value for this customer: ${missing.map((name) => `{{${name}}}`).join(', ')}`
+    );
+  }
+
+  return problems.join('. ');
+}
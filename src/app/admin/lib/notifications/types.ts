@@ -0,0 +1,75 @@
+/**
+ * Notification Types
+ *
+ * @file src/lib/notifications/types.ts
+ *
+ * Provider-agnostic message and delivery types. Each provider implements
+ * NotificationProvider for one channel, so the sending logic never depends
+ * on a specific email or SMS vendor.
+ */
+
+import type { DeliveryStatus } from '@/lib/types/database';
+
+// =============================================================================
+// Messages
+// =============================================================================
+
+export type NotificationChannel = 'email' | 'sms';
+
+/**
+ * Plain-text email to a single recipient
+ */
+export interface EmailMessage {
+  to: string;
+  subject: string;
+  text: string;
+  /** Overrides the provider's default sender */
+  from?: string;
+  replyTo?: string;
+}
+
+/**
+ * Text message to a single E.164 number
+ */
+export interface SmsMessage {
+  to: string;
+  body: string;
+}
+
+export interface NotificationMessageMap {
+  email: EmailMessage;
+  sms: SmsMessage;
+}
+
+// =============================================================================
+// Delivery
+// =============================================================================
+
+/**
+ * What the provider reported when the message was handed over
+ */
+export interface DeliveryResult {
+  /** `pending` is never returned; it is the state before sending */
+  status: Exclude<DeliveryStatus, 'pending'>;
+  /** Provider's ID for the message, used to match status callbacks */
+  providerMessageId: string | null;
+  error: string | null;
+}
+
+// =============================================================================
+// Provider Interface
+// =============================================================================
+
+/**
+ * A channel-specific sender. Implementations must not throw for delivery
+ * failures; they return a `failed` result so the attempt is still logged.
+ */
+export interface NotificationProvider<C extends NotificationChannel = NotificationChannel> {
+  /** Stored on the communication as `delivery_provider` */
+  readonly name: string;
+  readonly channel: C;
+  send(message: NotificationMessageMap[C]): Promise<DeliveryResult>;
+}
+
+export type EmailProvider = NotificationProvider<'email'>;
+export type SmsProvider = NotificationProvider<'sms'>;
@@ -0,0 +1,183 @@
+/**
+ * Analytics Service
+ *
+ * @file src/lib/services/analytics.service.ts
+ *
+ * Response-time and workload reporting on communications. Aggregation
+ * happens in the database (get_communication_response_times,
+ * get_communication_volume, get_communication_hours); this service shapes
+ * the rows into team-wide and per-admin metrics.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { Admin, Database } from '@/lib/types/database';
+import type {
+  AdminCommunicationMetrics,
+  CommunicationActivity,
+  CommunicationAnalytics,
+  CommunicationVolume,
+  ResponseTimeMetrics,
+} from '@/lib/types/analytics';
+import { DEFAULT_TIMEZONE } from '@/lib/utils/timezone';
+import {
+  COMMUNICATION_DIRECTIONS,
+  COMMUNICATION_TYPES,
+} from '@/lib/validations/communication';
+import type { CommunicationAnalyticsInput } from '@/lib/validations/analytics';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+type Functions = Database['public']['Functions'];
+type ResponseTimeRow = Functions['get_communication_response_times']['Returns'][number];
+type VolumeRow = Functions['get_communication_volume']['Returns'][number];
+type HourRow = Functions['get_communication_hours']['Returns'][number];
+
+// =============================================================================
+// Analytics Service
+// =============================================================================
+
+export class AnalyticsService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * Communication metrics for a date range, team-wide and per admin
+   *
+   * @param input - Validated range and optional admin filter
+   * @returns Team totals and one entry per admin with activity
+   */
+  async getCommunicationAnalytics(
+    input: CommunicationAnalyticsInput
+  ): Promise<CommunicationAnalytics> {
+    const range = { p_from: input.from, p_to: input.to };
+
+    const [responseTimes, volume, hours] = await Promise.all([
+      this.supabase.rpc('get_communication_response_times', range),
+      this.supabase.rpc('get_communication_volume', range),
+      this.supabase.rpc('get_communication_hours', { ...range, p_timezone: DEFAULT_TIMEZONE }),
+    ]);
+
+    const error = responseTimes.error ?? volume.error ?? hours.error;
+    if (error) {
+      console.error('Failed to load communication analytics:', error);
+      throw new Error(`Failed to load communication analytics: ${error.message}`);
+    }
+
+    const responseRows = (responseTimes.data ?? []) as ResponseTimeRow[];
+    const volumeRows = (volume.data ?? []) as VolumeRow[];
+    const hourRows = (hours.data ?? []) as HourRow[];
+
+    const adminIds = new Set<string>();
+    for (const row of [...responseRows, ...volumeRows, ...hourRows]) {
+      if (row.admin_id) adminIds.add(row.admin_id);
+    }
+    if (input.adminId) {
+      for (const id of [...adminIds]) {
+        if (id !== input.adminId) adminIds.delete(id);
+      }
+    }
+
+    const admins = await this.loadAdmins([...adminIds]);
+
+    const perAdmin: AdminCommunicationMetrics[] = admins.map((admin) => ({
+      admin,
+      responseTime: toResponseTime(responseRows.find((row) => row.admin_id === admin.id)),
+      volume: toVolume(volumeRows.filter((row) => row.admin_id === admin.id)),
+      activity: toActivity(hourRows.filter((row) => row.admin_id === admin.id)),
+    }));
+
+    perAdmin.sort((a, b) => b.volume.total - a.volume.total);
+
+    return {
+      from: input.from,
+      to: input.to,
+      timezone: DEFAULT_TIMEZONE,
+      team: {
+        responseTime: toResponseTime(responseRows.find((row) => row.admin_id === null)),
+        volume: toVolume(volumeRows),
+        activity: toActivity(hourRows),
+      },
+      admins: perAdmin,
+    };
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  private async loadAdmins(ids: string[]): Promise<Pick<Admin, 'id' | 'email' | 'full_name'>[]> {
+    if (ids.length === 0) {
+      return [];
+    }
+
+    const { data, error } = await this.supabase
+      .from('admins')
+      .select('id, email, full_name')
+      .in('id', ids);
+
+    if (error) {
+      console.error('Failed to load admins:', error);
+      throw new Error(`Failed to load admins: ${error.message}`);
+    }
+
+    return data ?? [];
+  }
+}
+
+// =============================================================================
+// Shaping Helpers
+// =============================================================================
+
+function toResponseTime(row: ResponseTimeRow | undefined): ResponseTimeMetrics {
+  return {
+    responses: row?.responses ?? 0,
+    medianSeconds: row?.median_seconds != null ? Math.round(row.median_seconds) : null,
+    averageSeconds: row?.average_seconds != null ? Math.round(row.average_seconds) : null,
+  };
+}
+
+function toVolume(rows: VolumeRow[]): CommunicationVolume {
+  // Start every bucket at zero so new channels always appear in the result
+  const byType = Object.fromEntries(
+    COMMUNICATION_TYPES.map((type) => [type, 0])
+  ) as CommunicationVolume['byType'];
+  const byDirection = Object.fromEntries(
+    COMMUNICATION_DIRECTIONS.map((direction) => [direction, 0])
+  ) as CommunicationVolume['byDirection'];
+  let total = 0;
+
+  for (const row of rows) {
+    byType[row.type] += row.total;
+    byDirection[row.direction] += row.total;
+    total += row.total;
+  }
+
+  return { total, byType, byDirection };
+}
+
+function toActivity(rows: HourRow[]): CommunicationActivity {
+  const byHour = Array.from({ length: 24 }, () => 0);
+  const byDayHour = Array.from({ length: 7 }, () => Array.from({ length: 24 }, () => 0));
+
+  for (const row of rows) {
+    byHour[row.hour] += row.total;
+    byDayHour[row.day_of_week][row.hour] += row.total;
+  }
+
+  const busiestHours = byHour
+    .map((total, hour) => ({ hour, total }))
+    .filter((entry) => entry.total > 0)
+    .sort((a, b) => b.total - a.total || a.hour - b.hour)
+    .slice(0, 3);
+
+  return { byHour, byDayHour, busiestHours };
+}
@@ -0,0 +1,206 @@
+/**
+ * Attachment Service
+ *
+ * @file src/lib/services/attachment.service.ts
+ *
+ * Uploads files to the customer-attachments bucket and records them in
+ * customer_attachments. Storage path convention: {customer_id}/{uuid}.{ext}
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import { randomUUID } from 'crypto';
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { Database } from '@/lib/types/database';
+import type { CommunicationAttachment } from '@/lib/types/communication';
+import { NotFoundError } from '@/lib/utils/errors';
+import { ATTACHMENT_BUCKET } from '@/lib/validations/attachment';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+/** How long download links stay valid, in seconds */
+const SIGNED_URL_TTL_SECONDS = 60 * 5;
+
+const EXTENSIONS: Record<string, string> = {
+  'image/jpeg': 'jpg',
+  'image/png': 'png',
+  'image/webp': 'webp',
+  'application/pdf': 'pdf',
+};
+
+const ATTACHMENT_COLUMNS = 'id, filename, content_type, size_bytes, storage_path, created_at';
+
+// =============================================================================
+// Attachment Service
+// =============================================================================
+
+export class AttachmentService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * Attach files to a communication
+   *
+   * Files are validated by the caller (see validateAttachmentFiles). If any
+   * upload fails, files already uploaded in this call are removed again.
+   *
+   * @param communicationId - Communication to attach to
+   * @param files - Files to upload
+   * @param uploadedBy - Admin ID uploading
+   * @returns The new attachments
+   */
+  async attachToCommunication(
+    communicationId: string,
+    files: File[],
+    uploadedBy: string
+  ): Promise<CommunicationAttachment[]> {
+    const { data: communication, error } = await this.supabase
+      .from('communications')
+      .select('id, customer_id')
+      .eq('id', communicationId)
+      .is('deleted_at', null)
+      .maybeSingle();
+
+    if (error) {
+      throw new Error(`Failed to load communication: ${error.message}`);
+    }
+
+    if (!communication) {
+      throw new NotFoundError('Communication');
+    }
+
+    const attachments: CommunicationAttachment[] = [];
+
+    try {
+      for (const file of files) {
+        attachments.push(
+          await this.upload(file, {
+            customer_id: communication.customer_id,
+            communication_id: communication.id,
+            uploaded_by: uploadedBy,
+          })
+        );
+      }
+    } catch (uploadError) {
+      await this.remove(attachments.map((attachment) => attachment.id));
+      throw uploadError;
+    }
+
+    return attachments;
+  }
+
+  /**
+   * Create a short-lived download link for an attachment
+   *
+   * @param id - Attachment ID
+   * @returns Signed URL and the original filename
+   */
+  async getDownloadUrl(id: string): Promise<{ url: string; filename: string }> {
+    const { data: attachment, error } = await this.supabase
+      .from('customer_attachments')
+      .select('storage_path, filename')
+      .eq('id', id)
+      .maybeSingle();
+
+    if (error) {
+      throw new Error(`Failed to load attachment: ${error.message}`);
+    }
+
+    if (!attachment) {
+      throw new NotFoundError('Attachment');
+    }
+
+    const { data, error: signError } = await this.supabase.storage
+      .from(ATTACHMENT_BUCKET)
+      .createSignedUrl(attachment.storage_path, SIGNED_URL_TTL_SECONDS, {
+        download: attachment.filename,
+      });
+
+    if (signError || !data) {
+      throw new Error(`Failed to create download link: ${signError?.message ?? 'unknown error'}`);
+    }
+
+    return { url: data.signedUrl, filename: attachment.filename };
+  }
+
+  /**
+   * Delete attachments and their files
+   *
+   * @param ids - Attachment IDs
+   */
+  async remove(ids: string[]): Promise<void> {
+    if (ids.length === 0) return;
+
+    const { data: attachments, error } = await this.supabase
+      .from('customer_attachments')
+      .select('id, storage_path')
+      .in('id', ids);
+
+    if (error) {
+      throw new Error(`Failed to load attachments: ${error.message}`);
+    }
+
+    if (!attachments || attachments.length === 0) return;
+
+    await this.supabase.storage
+      .from(ATTACHMENT_BUCKET)
+      .remove(attachments.map((attachment) => attachment.storage_path));
+
+    const { error: deleteError } = await this.supabase
+      .from('customer_attachments')
+      .delete()
+      .in('id', attachments.map((attachment) => attachment.id));
+
+    if (deleteError) {
+      throw new Error(`Failed to delete attachments: ${deleteError.message}`);
+    }
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  /**
+   * Upload one file and record it; the file is removed if the insert fails
+   */
+  private async upload(
+    file: File,
+    link: { customer_id: string; communication_id: string; uploaded_by: string }
+  ): Promise<CommunicationAttachment> {
+    const path = `${link.customer_id}/${randomUUID()}.${EXTENSIONS[file.type] ?? 'bin'}`;
+
+    const { error: uploadError } = await this.supabase.storage
+      .from(ATTACHMENT_BUCKET)
+      .upload(path, file, { contentType: file.type });
+
+    if (uploadError) {
+      throw new Error(`Failed to upload ${file.name}: ${uploadError.message}`);
+    }
+
+    const { data, error } = await this.supabase
+      .from('customer_attachments')
+      .insert({
+        ...link,
+        storage_path: path,
+        filename: file.name.slice(0, 255) || 'attachment',
+        content_type: file.type,
+        size_bytes: file.size,
+      })
+      .select(ATTACHMENT_COLUMNS)
+      .single();
+
+    if (error) {
+      await this.supabase.storage.from(ATTACHMENT_BUCKET).remove([path]);
+      throw new Error(`Failed to save ${file.name}: ${error.message}`);
+    }
+
+    return data as CommunicationAttachment;
+  }
+}
@@ -0,0 +1,167 @@
+/**
+ * Audit Service
+ *
+ * @file src/lib/services/audit.service.ts
+ *
+ * Reads the audit log written by the record_audit_log() trigger (see
+ * 00035_audit_triggers.sql): one record's history, one admin's activity,
+ * and the filterable log across every audited table. Entries are never
+ * written or changed from here.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { AuditLog, Database } from '@/lib/types/database';
+import {
+  getAuditChanges,
+  type AuditFilterOptions,
+  type AuditLogEntry,
+  type AuditLogListResult,
+} from '@/lib/types/audit';
+import { buildKeysetPage, decodeCursor, isReversed, keysetFilter } from '@/lib/utils/cursor';
+import type {
+  AdminAuditActivityInput,
+  ListAuditLogInput,
+  RecordAuditHistoryInput,
+} from '@/lib/validations/audit';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+type AuditLogRow = AuditLog & {
+  changed_by_admin: AuditLogEntry['changed_by_admin'];
+};
+
+const AUDIT_LOG_SELECT = `
+  *,
+  changed_by_admin:admins!audit_log_changed_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+// =============================================================================
+// Audit Service
+// =============================================================================
+
+export class AuditService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * Audit log, newest first, with optional filters
+   *
+   * @param input - Filters and keyset cursor
+   * @returns Paginated entries with their field changes
+   */
+  async list(input: ListAuditLogInput): Promise<AuditLogListResult> {
+    const { tableName, recordId, action, changedBy, from, to, limit = 50, cursor } = input;
+
+    // Bind cursors to the filters so a page cannot be continued under others
+    const scope = `audit_log:${[tableName, recordId, action, changedBy, from, to].join(':')}`;
+    const position = cursor ? decodeCursor(cursor, scope) : null;
+    const reversed = isReversed(position);
+
+    let query = this.supabase
+      .from('audit_log')
+      .select(AUDIT_LOG_SELECT, { count: 'exact' })
+      .order('created_at', { ascending: reversed })
+      .order('id', { ascending: reversed })
+      .limit(limit + 1); // Fetch one extra to check if there are more
+
+    if (tableName) {
+      query = query.eq('table_name', tableName);
+    }
+
+    if (recordId) {
+      query = query.eq('record_id', recordId);
+    }
+
+    if (action) {
+      query = query.eq('action', action);
+    }
+
+    if (changedBy) {
+      query = query.eq('changed_by', changedBy);
+    }
+
+    if (from) {
+      query = query.gte('created_at', from);
+    }
+
+    if (to) {
+      query = query.lte('created_at', to);
+    }
+
+    if (position) {
+      query = query.or(keysetFilter('created_at', position));
+    }
+
+    const { data, error, count } = await query;
+
+    if (error) {
+      console.error('Failed to list audit log:', error);
+      throw new Error(`Failed to list audit log: ${error.message}`);
+    }
+
+    const rows = (data ?? []) as unknown as AuditLogRow[];
+    const { items, ...page } = buildKeysetPage(rows, limit, position, scope, (row) => ({
+      value: row.created_at,
+      id: row.id,
+    }));
+
+    return {
+      items: items.map((row) => ({
+        ...row,
+        changes: getAuditChanges(row.old_values, row.new_values),
+      })),
+      ...page,
+      total: count ?? undefined,
+    };
+  }
+
+  /**
+   * Every change to one record, newest first
+   *
+   * @param input - Table, record ID and keyset cursor
+   */
+  async listByRecord(input: RecordAuditHistoryInput): Promise<AuditLogListResult> {
+    const { tableName, recordId, limit, cursor } = input;
+    return this.list({ tableName, recordId, limit, cursor });
+  }
+
+  /**
+   * Every change one admin made, newest first
+   *
+   * @param input - Admin ID and keyset cursor
+   */
+  async listByAdmin(input: AdminAuditActivityInput): Promise<AuditLogListResult> {
+    const { adminId, limit, cursor } = input;
+    return this.list({ changedBy: adminId, limit, cursor });
+  }
+
+  /**
+   * Options for the audit log filter bar
+   */
+  async getFilterOptions(): Promise<AuditFilterOptions> {
+    const { data, error } = await this.supabase
+      .from('admins')
+      .select('id, email, full_name')
+      .order('full_name', { ascending: true });
+
+    if (error) {
+      console.error('Failed to get audit filter options:', error);
+      throw new Error(`Failed to get audit filter options: ${error.message}`);
+    }
+
+    return { admins: data ?? [] };
+  }
+}
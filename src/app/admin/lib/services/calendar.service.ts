@@ -78,6 +78,7 @@ export class CalendarService {
       end_datetime: input.endDatetime,
       all_day: input.allDay,
       location_url: input.locationUrl || null,
+      communication_id: input.communicationId ?? null,
       created_by: adminId,
     };
 
@@ -543,6 +544,7 @@ export class CalendarService {
       end_datetime: data.end_datetime as string,
       all_day: data.all_day as boolean,
       location_url: data.location_url as string | null,
+      communication_id: data.communication_id as string | null,
       reminder_24h_sent: data.reminder_24h_sent as boolean,
       reminder_2h_sent: data.reminder_2h_sent as boolean,
       created_by: data.created_by as string,
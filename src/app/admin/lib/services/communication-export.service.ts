@@ -0,0 +1,409 @@
+/**
+ * Communication Export Service
+ *
+ * @file src/lib/services/communication-export.service.ts
+ *
+ * Exports communication history, for one customer or any filtered set
+ * across customers, as CSV, JSON Lines or a printable PDF transcript.
+ * Records carry the logger's name, occurred/created timestamps in the
+ * requested timezone and references to attachments (not the files).
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import { formatInTimeZone } from 'date-fns-tz';
+import type { Database } from '@/lib/types/database';
+import {
+  callOutcomeLabels,
+  communicationTypeLabels,
+  deliveryStatusLabels,
+  type CommunicationExportFile,
+  type CommunicationExportRecord,
+  type CommunicationWithCustomer,
+} from '@/lib/types/communication';
+import { toCsv } from '@/lib/utils/csv';
+import { keysetFilter } from '@/lib/utils/cursor';
+import { ValidationError } from '@/lib/utils/errors';
+import { PdfDocument } from '@/lib/utils/pdf';
+import { formatPhone } from '@/lib/utils/phone';
+import { formatSecondsDuration } from '@/lib/utils/timezone';
+import {
+  MAX_EXPORT_COMMUNICATIONS,
+  type CommunicationExportInput,
+} from '@/lib/validations/export';
+import { combineFilter } from './communication.service';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+type ExportRow = CommunicationWithCustomer & {
+  attachments: {
+    id: string;
+    filename: string;
+    content_type: string;
+    size_bytes: number;
+    storage_path: string;
+  }[];
+};
+
+/**
+ * Rows fetched per request; PostgREST caps responses at 1000
+ */
+const EXPORT_BATCH_SIZE = 1000;
+
+const CSV_HEADERS = [
+  'Communication ID',
+  'Customer ID',
+  'Customer',
+  'Customer Phone',
+  'Thread ID',
+  'Reply To',
+  'Type',
+  'Direction',
+  'Occurred At',
+  'Created At',
+  'Timezone',
+  'Logged By',
+  'Logged By Email',
+  'Call Outcome',
+  'Call Duration (s)',
+  'Call Number',
+  'Delivery Status',
+  'Attachments',
+  'Summary',
+];
+
+// =============================================================================
+// Communication Export Service
+// =============================================================================
+
+export class CommunicationExportService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * Render matching communications in the requested format
+   *
+   * @param input - Format, timezone, optional customer and filters
+   * @returns File name, content type and body
+   * @throws ValidationError when more than MAX_EXPORT_COMMUNICATIONS match
+   */
+  async export(input: CommunicationExportInput): Promise<CommunicationExportFile> {
+    const records = await this.loadRecords(input);
+    const stamp = formatInTimeZone(new Date(), input.timezone, 'yyyy-MM-dd');
+    const subject = input.customerId && records[0] ? slugify(records[0].customerName) : 'all';
+    const basename = `communications-${subject}-${stamp}`;
+
+    switch (input.format) {
+      case 'jsonl':
+        return {
+          filename: `${basename}.jsonl`,
+          contentType: 'application/x-ndjson; charset=utf-8',
+          body: records.map((record) => JSON.stringify(record)).join('\n') + '\n',
+          count: records.length,
+        };
+      case 'pdf':
+        return {
+          filename: `${basename}.pdf`,
+          contentType: 'application/pdf',
+          body: renderTranscript(records, input),
+          count: records.length,
+        };
+      default:
+        return {
+          filename: `${basename}.csv`,
+          contentType: 'text/csv; charset=utf-8',
+          // BOM so Excel opens the file as UTF-8
+          body: '\uFEFF' + toCsv(CSV_HEADERS, records.map(toCsvRow)),
+          count: records.length,
+        };
+    }
+  }
+
+  /**
+   * Load matching communications oldest first, in batches
+   *
+   * @param input - Customer and filters
+   * @returns Export records in the input's timezone
+   */
+  async loadRecords(input: CommunicationExportInput): Promise<CommunicationExportRecord[]> {
+    const rows: ExportRow[] = [];
+    let after: ExportRow | undefined;
+
+    do {
+      const batch = await this.loadBatch(input, after);
+      rows.push(...batch);
+      after = batch.length === EXPORT_BATCH_SIZE ? batch[batch.length - 1] : undefined;
+
+      if (rows.length > MAX_EXPORT_COMMUNICATIONS) {
+        throw new ValidationError(
+          `Exports are limited to ${MAX_EXPORT_COMMUNICATIONS.toLocaleString()} communications; narrow the filters and try again`
+        );
+      }
+    } while (after);
+
+    return rows.map((row) => toRecord(row, input.timezone));
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  private async loadBatch(
+    input: CommunicationExportInput,
+    after: ExportRow | undefined
+  ): Promise<ExportRow[]> {
+    const types = combineFilter(input.type, input.types);
+    const directions = combineFilter(input.direction, input.directions);
+    const search = input.search?.trim();
+
+    // Tags belong to the customer: inner-join their links and filter on them
+    const tagEmbed = input.tagIds?.length ? ', tag_links:customer_tag_links!inner(tag_id)' : '';
+    const customerJoin = input.tagIds?.length ? '!inner' : '';
+    const attachmentJoin = input.hasAttachments ? '!inner' : '';
+
+    let query = this.supabase
+      .from('communications')
+      .select(
+        `
+        *,
+        customer:customers!communications_customer_id_fkey${customerJoin}(id, name, phone${tagEmbed}),
+        logged_by_admin:admins!communications_logged_by_fkey(id, email, full_name),
+        attachments:customer_attachments!customer_attachments_communication_id_fkey${attachmentJoin}(
+          id,
+          filename,
+          content_type,
+          size_bytes,
+          storage_path
+        )
+      `
+      )
+      .is('deleted_at', null)
+      .order('occurred_at', { ascending: true })
+      .order('id', { ascending: true })
+      .limit(EXPORT_BATCH_SIZE);
+
+    if (input.customerId) {
+      query = query.eq('customer_id', input.customerId);
+    }
+    if (types) {
+      query = query.in('type', types);
+    }
+    if (directions) {
+      query = query.in('direction', directions);
+    }
+    if (input.loggedBy?.length) {
+      query = query.in('logged_by', input.loggedBy);
+    }
+    if (input.tagIds?.length) {
+      query = query.in('customer.tag_links.tag_id', input.tagIds);
+    }
+    if (input.hasAttachments === false) {
+      query = query.is('attachments', null);
+    }
+    if (input.dateFrom) {
+      query = query.gte('occurred_at', input.dateFrom);
+    }
+    if (input.dateTo) {
+      query = query.lt('occurred_at', input.dateTo);
+    }
+    if (input.callOutcome) {
+      query = query.eq('call_outcome', input.callOutcome);
+    }
+    if (input.minCallDurationSeconds !== undefined) {
+      query = query.gte('call_duration_seconds', input.minCallDurationSeconds);
+    }
+    if (input.maxCallDurationSeconds !== undefined) {
+      query = query.lte('call_duration_seconds', input.maxCallDurationSeconds);
+    }
+    if (input.estimateId) {
+      query = query.eq('estimate_id', input.estimateId);
+    }
+    if (input.calendarEventId) {
+      query = query.eq('calendar_event_id', input.calendarEventId);
+    }
+    if (input.propertyId) {
+      query = query.eq('property_id', input.propertyId);
+    }
+    if (input.poolId) {
+      query = query.eq('pool_id', input.poolId);
+    }
+    if (search) {
+      query = query.textSearch('search_vector', search, { type: 'websearch', config: 'english' });
+    }
+
+    // Oldest first, so continue with the rows after the last one ('prev' selects greater keys)
+    if (after) {
+      query = query.or(
+        keysetFilter('occurred_at', { value: after.occurred_at, id: after.id, direction: 'prev' })
+      );
+    }
+
+    const { data, error } = await query;
+
+    if (error) {
+      console.error('Failed to load communications for export:', error);
+      throw new Error(`Failed to export communications: ${error.message}`);
+    }
+
+    return (data ?? []) as unknown as ExportRow[];
+  }
+}
+
+// =============================================================================
+// Formatting Helpers
+// =============================================================================
+
+function slugify(text: string): string {
+  return (
+    text
+      .toLowerCase()
+      .replace(/[^a-z0-9]+/g, '-')
+      .replace(/^-+|-+$/g, '')
+      .slice(0, 40) || 'customer'
+  );
+}
+
+function toRecord(row: ExportRow, timezone: string): CommunicationExportRecord {
+  return {
+    id: row.id,
+    customerId: row.customer_id,
+    customerName: row.customer.name,
+    customerPhone: row.customer.phone,
+    threadId: row.thread_id,
+    parentId: row.parent_id,
+    type: row.type,
+    direction: row.direction,
+    summary: row.summary,
+    occurredAt: formatInTimeZone(row.occurred_at, timezone, "yyyy-MM-dd'T'HH:mm:ssxxx"),
+    createdAt: formatInTimeZone(row.created_at, timezone, "yyyy-MM-dd'T'HH:mm:ssxxx"),
+    timezone,
+    loggedBy: row.logged_by_admin
+      ? {
+          id: row.logged_by_admin.id,
+          name: row.logged_by_admin.full_name || row.logged_by_admin.email,
+          email: row.logged_by_admin.email,
+        }
+      : null,
+    callOutcome: row.call_outcome,
+    callDurationSeconds: row.call_duration_seconds,
+    callNumber: row.call_number,
+    deliveryStatus: row.delivery_status,
+    attachments: (row.attachments ?? []).map((attachment) => ({
+      id: attachment.id,
+      filename: attachment.filename,
+      contentType: attachment.content_type,
+      sizeBytes: attachment.size_bytes,
+      storagePath: attachment.storage_path,
+    })),
+  };
+}
+
+function toCsvRow(record: CommunicationExportRecord): (string | number | null)[] {
+  return [
+    record.id,
+    record.customerId,
+    record.customerName,
+    formatPhone(record.customerPhone),
+    record.threadId,
+    record.parentId,
+    communicationTypeLabels[record.type],
+    record.direction,
+    record.occurredAt,
+    record.createdAt,
+    record.timezone,
+    record.loggedBy?.name ?? null,
+    record.loggedBy?.email ?? null,
+    record.callOutcome ? callOutcomeLabels[record.callOutcome] : null,
+    record.callDurationSeconds,
+    record.callNumber ? formatPhone(record.callNumber) : null,
+    record.deliveryStatus ? deliveryStatusLabels[record.deliveryStatus] : null,
+    record.attachments
+      .map((attachment) => `${attachment.filename} (${attachment.storagePath})`)
+      .join('; '),
+    record.summary,
+  ];
+}
+
+/**
+ * Printable transcript: a header, then one block per communication with
+ * who logged it, when, call details and attachment names
+ */
+function renderTranscript(
+  records: CommunicationExportRecord[],
+  input: CommunicationExportInput
+): Uint8Array {
+  const singleCustomer = input.customerId ? records[0] : undefined;
+  const title = singleCustomer
+    ? `Communication history: ${singleCustomer.customerName}`
+    : 'Communication history';
+  const pdf = new PdfDocument(title);
+  const now = new Date();
+
+  pdf.text(title, { size: 16, bold: true });
+  if (singleCustomer) {
+    pdf.text(formatPhone(singleCustomer.customerPhone), { muted: true });
+  }
+  pdf
+    .text(
+      `${records.length} ${records.length === 1 ? 'communication' : 'communications'} · ` +
+        `exported ${formatInTimeZone(now, input.timezone, "MMM d, yyyy 'at' h:mm a zzz")} · ` +
+        `times in ${input.timezone}`,
+      { size: 9, muted: true }
+    )
+    .space(4)
+    .rule();
+
+  if (records.length === 0) {
+    pdf.space(8).text('No communications match this export.', { muted: true });
+  }
+
+  for (const record of records) {
+    const heading = [
+      communicationTypeLabels[record.type],
+      record.direction === 'inbound' ? 'Inbound' : 'Outbound',
+      formatInTimeZone(record.occurredAt, input.timezone, "MMM d, yyyy 'at' h:mm a zzz"),
+    ];
+    if (!singleCustomer) {
+      heading.unshift(record.customerName);
+    }
+
+    const details = [
+      record.loggedBy ? `Logged by ${record.loggedBy.name}` : null,
+      `Recorded ${formatInTimeZone(record.createdAt, input.timezone, 'MMM d, yyyy h:mm a')}`,
+      record.callOutcome ? callOutcomeLabels[record.callOutcome] : null,
+      record.callDurationSeconds != null
+        ? formatSecondsDuration(record.callDurationSeconds)
+        : null,
+      record.callNumber ? formatPhone(record.callNumber) : null,
+      record.deliveryStatus ? deliveryStatusLabels[record.deliveryStatus] : null,
+      record.parentId ? 'Reply' : null,
+    ].filter(Boolean);
+
+    pdf
+      .space(8)
+      .keepTogether(48)
+      .text(heading.join(' · '), { bold: true })
+      .text(details.join(' · '), { size: 8, muted: true })
+      .space(2)
+      .text(record.summary, { indent: 8 });
+
+    if (record.attachments.length > 0) {
+      pdf.text(
+        `Attachments: ${record.attachments.map((attachment) => attachment.filename).join(', ')}`,
+        { size: 8, muted: true, indent: 8 }
+      );
+    }
+
+    pdf.space(4).rule();
+  }
+
+  return pdf.toBytes();
+}
@@ -0,0 +1,1018 @@
+/**
+ * Communication Import Service
+ *
+ * @file src/lib/services/communication-import.service.ts
+ *
+ * Service layer for importing communications from external sources.
+ * Each source parses its files into import messages; this service then
+ * matches them to customers, skips anything already imported, links
+ * replies to their parent, and sends unmatched messages to the review
+ * queue.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import { createHash } from 'crypto';
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type {
+  Database,
+  Communication,
+  CommunicationImportSource,
+  ImportQueueReason,
+} from '@/lib/types/database';
+import type {
+  ImportResult,
+  ImportQueueItemWithAdmins,
+  ImportQueueListResult,
+} from '@/lib/types/import';
+import { callOutcomeLabels, type CommunicationWithLogger } from '@/lib/types/communication';
+import {
+  parseEmail,
+  splitMbox,
+  isMbox,
+  stripQuotedReply,
+  type ParsedEmail,
+} from '@/lib/utils/email-parser';
+import { parseSmsExport, type SmsExportRow } from '@/lib/utils/sms-export';
+import { parseCdrExport, type CdrRow } from '@/lib/utils/cdr';
+import { normalizePhone, formatPhone } from '@/lib/utils/phone';
+import { formatSecondsDuration } from '@/lib/utils/timezone';
+import type {
+  ListImportQueueInput,
+  ResolveImportQueueItemInput,
+} from '@/lib/validations/import';
+import { COMMUNICATION_SELECT } from './communication.service';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+/**
+ * A file uploaded for import
+ */
+export interface ImportFile {
+  name: string;
+  content: string;
+}
+
+/**
+ * A single message prepared by a source parser, ready to be matched and saved
+ */
+interface ImportMessage {
+  file: string;
+  externalId: string;
+  type: Communication['type'];
+  /** Known direction, or null when it depends on which side matched */
+  direction: Communication['direction'] | null;
+  summary: string;
+  occurredAt: string;
+  /**
+   * Customers this message could belong to, with the direction each
+   * implies (null when matching the customer does not tell the direction)
+   */
+  matches: { customerId: string; direction: Communication['direction'] | null }[];
+  /** Address or number shown to the reviewer when matching fails */
+  counterparty: string | null;
+  /** External ID of the message this one replies to */
+  parentExternalId: string | null;
+  payload: Record<string, unknown>;
+}
+
+type ImportedCallFields = Pick<
+  Communication,
+  'call_duration_seconds' | 'call_outcome' | 'call_number' | 'needs_callback'
+>;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+const MAX_SUMMARY_LENGTH = 5000;
+
+/** Longest call the communications table accepts (24 hours) */
+const MAX_CALL_DURATION_SECONDS = 86400;
+
+/** Keep `.in()` filters well under URL length limits */
+const LOOKUP_CHUNK_SIZE = 100;
+
+const QUEUE_SELECT = `
+  *,
+  imported_by_admin:admins!communication_import_queue_imported_by_fkey(
+    id,
+    email,
+    full_name
+  ),
+  resolved_by_admin:admins!communication_import_queue_resolved_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+// =============================================================================
+// Communication Import Service
+// =============================================================================
+
+export class CommunicationImportService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  // ---------------------------------------------------------------------------
+  // Email Import
+  // ---------------------------------------------------------------------------
+
+  /**
+   * Import emails from .eml and .mbox files
+   *
+   * Messages sent by a customer are logged as inbound, messages sent to a
+   * customer (To or Cc) as outbound. Messages matching no customer or
+   * several customers go to the review queue. Messages without a readable
+   * Date header are reported as failed rather than given a made-up time.
+   * Re-importing the same file is a no-op because messages are keyed by
+   * Message-ID.
+   *
+   * @param files - Uploaded file names and contents
+   * @param importedBy - Admin ID running the import
+   * @returns Counts of imported, duplicate, queued and failed messages
+   */
+  async importEmails(files: ImportFile[], importedBy: string): Promise<ImportResult> {
+    const result = emptyResult('email');
+    const parsed: { file: string; email: ParsedEmail }[] = [];
+
+    for (const file of files) {
+      const raws = isMbox(file.content) ? splitMbox(file.content) : [file.content];
+
+      for (const raw of raws) {
+        try {
+          parsed.push({ file: file.name, email: parseEmail(raw) });
+        } catch (error) {
+          result.failed++;
+          result.errors.push({
+            file: file.name,
+            reference: null,
+            error: error instanceof Error ? error.message : 'Could not read message',
+          });
+        }
+      }
+    }
+
+    // Look up every address involved in one round trip
+    const addresses = new Set<string>();
+    for (const { email } of parsed) {
+      if (email.from) addresses.add(email.from.address);
+      for (const recipient of [...email.to, ...email.cc]) addresses.add(recipient.address);
+    }
+    const customersByEmail = await this.findCustomersByEmails([...addresses]);
+
+    const messages: ImportMessage[] = [];
+    for (const { file, email } of parsed) {
+      if (!email.from) {
+        result.failed++;
+        result.errors.push({
+          file,
+          reference: email.messageId,
+          error: 'Message has no From address',
+        });
+        continue;
+      }
+
+      if (!email.date) {
+        result.failed++;
+        result.errors.push({
+          file,
+          reference: email.messageId,
+          error: 'Message has no valid Date header',
+        });
+        continue;
+      }
+
+      messages.push(toEmailImportMessage(file, email, customersByEmail));
+    }
+
+    await this.saveMessages('email', messages, importedBy, result);
+
+    return result;
+  }
+
+  // ---------------------------------------------------------------------------
+  // SMS Import
+  // ---------------------------------------------------------------------------
+
+  /**
+   * Import text message history from CSV or JSON exports
+   *
+   * Counterparty numbers are matched against `customers.phone_normalized`
+   * using the database's `normalize_phone` rules. When the export has no
+   * direction column, a matching sender makes the message inbound and a
+   * matching recipient makes it outbound.
+   *
+   * @param files - Uploaded file names and contents
+   * @param importedBy - Admin ID running the import
+   * @param options - `dryRun` reports matched, unmatched and duplicate rows
+   *   without writing anything
+   * @returns Counts of imported (or matched), duplicate, queued (or
+   *   unmatched) and failed rows
+   */
+  async importSms(
+    files: ImportFile[],
+    importedBy: string,
+    options: { dryRun?: boolean } = {}
+  ): Promise<ImportResult> {
+    const result = emptyResult('sms', options.dryRun);
+    const parsed: { file: string; row: SmsExportRow }[] = [];
+
+    for (const file of files) {
+      try {
+        const { rows, errors } = parseSmsExport(file.name, file.content);
+        rows.forEach((row) => parsed.push({ file: file.name, row }));
+        for (const rowError of errors) {
+          result.failed++;
+          result.errors.push({
+            file: file.name,
+            reference: `row ${rowError.row}`,
+            error: rowError.error,
+          });
+        }
+      } catch (error) {
+        result.failed++;
+        result.errors.push({
+          file: file.name,
+          reference: null,
+          error: error instanceof Error ? error.message : 'Could not read file',
+        });
+      }
+    }
+
+    const numbers = new Set<string>();
+    for (const { row } of parsed) {
+      for (const number of [row.number, row.from, row.to]) {
+        if (number) numbers.add(number);
+      }
+    }
+    const customersByPhone = await this.findCustomersByPhones([...numbers]);
+
+    const messages = parsed.map(({ file, row }) =>
+      toSmsImportMessage(file, row, customersByPhone)
+    );
+
+    await this.saveMessages('sms', messages, importedBy, result);
+
+    return result;
+  }
+
+  // ---------------------------------------------------------------------------
+  // Call-Detail Record Import
+  // ---------------------------------------------------------------------------
+
+  /**
+   * Import call-detail records exported by the phone system
+   *
+   * Calls are matched to customers by `phone_normalized`: a matching caller
+   * makes the call inbound, a matching callee makes it outbound. Missed
+   * inbound calls from known customers are flagged as needing a callback.
+   *
+   * @param files - Uploaded CSV file names and contents
+   * @param importedBy - Admin ID running the import
+   * @param options - `dryRun` reports what would be imported without writing
+   * @returns Counts of imported, duplicate, queued and failed calls
+   */
+  async importCdr(
+    files: ImportFile[],
+    importedBy: string,
+    options: { dryRun?: boolean } = {}
+  ): Promise<ImportResult> {
+    const result = emptyResult('cdr', options.dryRun);
+    const parsed: { file: string; row: CdrRow }[] = [];
+
+    for (const file of files) {
+      try {
+        const { rows, errors } = parseCdrExport(file.content);
+        rows.forEach((row) => parsed.push({ file: file.name, row }));
+        for (const rowError of errors) {
+          result.failed++;
+          result.errors.push({
+            file: file.name,
+            reference: `row ${rowError.row}`,
+            error: rowError.error,
+          });
+        }
+      } catch (error) {
+        result.failed++;
+        result.errors.push({
+          file: file.name,
+          reference: null,
+          error: error instanceof Error ? error.message : 'Could not read file',
+        });
+      }
+    }
+
+    const numbers = new Set<string>();
+    for (const { row } of parsed) {
+      numbers.add(row.caller);
+      numbers.add(row.callee);
+    }
+    const customersByPhone = await this.findCustomersByPhones([...numbers]);
+
+    const messages = parsed.map(({ file, row }) =>
+      toCdrImportMessage(file, row, customersByPhone)
+    );
+
+    await this.saveMessages('cdr', messages, importedBy, result);
+
+    return result;
+  }
+
+  // ---------------------------------------------------------------------------
+  // Review Queue
+  // ---------------------------------------------------------------------------
+
+  /**
+   * List review queue items, oldest message first
+   *
+   * @param input - Status filter and pagination
+   * @returns Paginated queue items
+   */
+  async listQueue(input: ListImportQueueInput): Promise<ImportQueueListResult> {
+    const { status, limit, offset } = input;
+
+    const { data, error, count } = await this.supabase
+      .from('communication_import_queue')
+      .select(QUEUE_SELECT, { count: 'exact' })
+      .eq('status', status)
+      .order('occurred_at', { ascending: true })
+      .range(offset, offset + limit - 1);
+
+    if (error) {
+      console.error('Failed to list import queue:', error);
+      throw new Error(`Failed to list import queue: ${error.message}`);
+    }
+
+    const total = count ?? 0;
+    const items = await this.attachCandidateCustomers(
+      (data ?? []) as Omit<ImportQueueItemWithAdmins, 'candidate_customers'>[]
+    );
+
+    return {
+      items,
+      total,
+      hasMore: offset + limit < total,
+    };
+  }
+
+  /**
+   * Count pending review queue items
+   *
+   * @returns Number of messages awaiting review
+   */
+  async countPending(): Promise<number> {
+    const { count, error } = await this.supabase
+      .from('communication_import_queue')
+      .select('id', { count: 'exact', head: true })
+      .eq('status', 'pending');
+
+    if (error) {
+      console.error('Failed to count import queue:', error);
+      throw new Error(`Failed to count import queue: ${error.message}`);
+    }
+
+    return count ?? 0;
+  }
+
+  /**
+   * Assign a queued message to a customer, creating the communication
+   *
+   * @param input - Queue item, customer and (if unknown) direction
+   * @param resolvedBy - Admin ID resolving the item
+   * @returns The created communication
+   */
+  async resolveQueueItem(
+    input: ResolveImportQueueItemInput,
+    resolvedBy: string
+  ): Promise<CommunicationWithLogger> {
+    const { data: item, error: itemError } = await this.supabase
+      .from('communication_import_queue')
+      .select('*')
+      .eq('id', input.id)
+      .single();
+
+    if (itemError || !item) {
+      throw new Error('Import queue item not found');
+    }
+
+    if (item.status !== 'pending') {
+      throw new Error('This message has already been reviewed');
+    }
+
+    const direction = input.direction ?? item.direction;
+    if (!direction) {
+      throw new Error('Please choose whether this message was inbound or outbound');
+    }
+
+    const parentExternalId =
+      typeof item.payload.parentExternalId === 'string' ? item.payload.parentExternalId : null;
+
+    const { data: communication, error } = await this.supabase
+      .from('communications')
+      .insert({
+        customer_id: input.customerId,
+        type: item.type,
+        direction,
+        summary: item.summary,
+        occurred_at: item.occurred_at,
+        logged_by: resolvedBy,
+        parent_id: await this.findParentId(item.source, input.customerId, parentExternalId),
+        import_source: item.source,
+        external_message_id: item.external_message_id,
+        ...pickCallFields(item.payload, direction),
+      })
+      .select(COMMUNICATION_SELECT)
+      .single();
+
+    if (error) {
+      if (error.code === '23505') {
+        throw new Error('This message has already been imported for that customer');
+      }
+      console.error('Failed to resolve import queue item:', error);
+      throw new Error(`Failed to log communication: ${error.message}`);
+    }
+
+    const { error: updateError } = await this.supabase
+      .from('communication_import_queue')
+      .update({
+        status: 'resolved',
+        communication_id: communication.id,
+        resolved_by: resolvedBy,
+        resolved_at: new Date().toISOString(),
+      })
+      .eq('id', input.id);
+
+    if (updateError) {
+      console.error('Failed to update import queue item:', updateError);
+      throw new Error(`Failed to update import queue item: ${updateError.message}`);
+    }
+
+    return communication as CommunicationWithLogger;
+  }
+
+  /**
+   * Dismiss a queued message without logging it (spam, vendors, etc.)
+   *
+   * @param id - Queue item ID
+   * @param resolvedBy - Admin ID dismissing the item
+   */
+  async dismissQueueItem(id: string, resolvedBy: string): Promise<boolean> {
+    const { error } = await this.supabase
+      .from('communication_import_queue')
+      .update({
+        status: 'dismissed',
+        resolved_by: resolvedBy,
+        resolved_at: new Date().toISOString(),
+      })
+      .eq('id', id)
+      .eq('status', 'pending');
+
+    if (error) {
+      console.error('Failed to dismiss import queue item:', error);
+      throw new Error(`Failed to dismiss import queue item: ${error.message}`);
+    }
+
+    return true;
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  /**
+   * Save prepared messages: skip duplicates, insert matched messages as
+   * communications and queue the rest. Messages are saved oldest first so
+   * a reply's parent already exists when the reply is inserted.
+   *
+   * In a dry run the same counts are produced without writing anything.
+   */
+  private async saveMessages(
+    source: CommunicationImportSource,
+    messages: ImportMessage[],
+    importedBy: string,
+    result: ImportResult
+  ): Promise<void> {
+    const seen = await this.findExistingExternalIds(
+      source,
+      messages.map((message) => message.externalId)
+    );
+
+    const ordered = [...messages].sort((a, b) => a.occurredAt.localeCompare(b.occurredAt));
+
+    for (const message of ordered) {
+      if (seen.has(message.externalId)) {
+        result.duplicates++;
+        continue;
+      }
+      seen.add(message.externalId);
+
+      try {
+        const customerIds = new Set(message.matches.map((match) => match.customerId));
+        const direction = message.direction ?? message.matches[0]?.direction ?? null;
+
+        if (customerIds.size === 1 && direction) {
+          if (!result.dryRun) {
+            await this.insertCommunication(source, message, importedBy);
+          }
+          result.imported++;
+        } else {
+          const reason: ImportQueueReason =
+            customerIds.size === 0
+              ? 'no_customer_match'
+              : customerIds.size > 1
+                ? 'multiple_customer_matches'
+                : 'ambiguous_direction';
+          if (!result.dryRun) {
+            await this.enqueue(source, message, reason, importedBy);
+          }
+          result.queued++;
+        }
+      } catch (error) {
+        result.failed++;
+        result.errors.push({
+          file: message.file,
+          reference: message.externalId,
+          error: error instanceof Error ? error.message : 'Could not save message',
+        });
+      }
+    }
+  }
+
+  private async insertCommunication(
+    source: CommunicationImportSource,
+    message: ImportMessage,
+    loggedBy: string
+  ): Promise<void> {
+    const [match] = message.matches;
+    const direction = message.direction ?? match.direction;
+    if (!direction) {
+      throw new Error('Direction is unknown; queue the message for review instead');
+    }
+
+    const { error } = await this.supabase.from('communications').insert({
+      customer_id: match.customerId,
+      type: message.type,
+      direction,
+      summary: message.summary,
+      occurred_at: message.occurredAt,
+      logged_by: loggedBy,
+      parent_id: await this.findParentId(source, match.customerId, message.parentExternalId),
+      import_source: source,
+      external_message_id: message.externalId,
+      ...pickCallFields(message.payload, direction),
+    });
+
+    if (error) {
+      console.error('Failed to insert imported communication:', error);
+      throw new Error(`Failed to log communication: ${error.message}`);
+    }
+  }
+
+  private async enqueue(
+    source: CommunicationImportSource,
+    message: ImportMessage,
+    reason: ImportQueueReason,
+    importedBy: string
+  ): Promise<void> {
+    const directions = new Set(
+      message.matches.flatMap((match) => (match.direction ? [match.direction] : []))
+    );
+
+    const { error } = await this.supabase.from('communication_import_queue').insert({
+      source,
+      external_message_id: message.externalId,
+      type: message.type,
+      direction: message.direction ?? (directions.size === 1 ? [...directions][0] : null),
+      summary: message.summary,
+      occurred_at: message.occurredAt,
+      counterparty: message.counterparty,
+      reason,
+      payload: {
+        ...message.payload,
+        file: message.file,
+        parentExternalId: message.parentExternalId,
+        candidateCustomerIds: [...new Set(message.matches.map((match) => match.customerId))],
+      },
+      imported_by: importedBy,
+    });
+
+    if (error) {
+      console.error('Failed to queue imported message:', error);
+      throw new Error(`Failed to queue message for review: ${error.message}`);
+    }
+  }
+
+  /**
+   * Map lowercased email address to the IDs of customers using it
+   */
+  private async findCustomersByEmails(emails: string[]): Promise<Map<string, string[]>> {
+    const byEmail = new Map<string, string[]>();
+    if (emails.length === 0) return byEmail;
+
+    const { data, error } = await this.supabase.rpc('find_customers_by_emails', { emails });
+
+    if (error) {
+      console.error('Failed to match customers by email:', error);
+      throw new Error(`Failed to match customers: ${error.message}`);
+    }
+
+    for (const row of data ?? []) {
+      byEmail.set(row.email, [...(byEmail.get(row.email) ?? []), row.id]);
+    }
+
+    return byEmail;
+  }
+
+  /**
+   * Map raw phone number (as it appears in the export) to the IDs of
+   * customers whose normalized phone matches it
+   */
+  private async findCustomersByPhones(phones: string[]): Promise<Map<string, string[]>> {
+    const byPhone = new Map<string, string[]>();
+    if (phones.length === 0) return byPhone;
+
+    const { data, error } = await this.supabase.rpc('find_customers_by_phones', { phones });
+
+    if (error) {
+      console.error('Failed to match customers by phone:', error);
+      throw new Error(`Failed to match customers: ${error.message}`);
+    }
+
+    for (const row of data ?? []) {
+      byPhone.set(row.phone, [...(byPhone.get(row.phone) ?? []), row.id]);
+    }
+
+    return byPhone;
+  }
+
+  /**
+   * Load the customers each queued message was matched to, so the review
+   * queue can preselect a single candidate
+   */
+  private async attachCandidateCustomers(
+    items: Omit<ImportQueueItemWithAdmins, 'candidate_customers'>[]
+  ): Promise<ImportQueueItemWithAdmins[]> {
+    const candidateIds = (item: { payload: Record<string, unknown> }): string[] =>
+      Array.isArray(item.payload.candidateCustomerIds)
+        ? item.payload.candidateCustomerIds.filter((id): id is string => typeof id === 'string')
+        : [];
+
+    const ids = [...new Set(items.flatMap(candidateIds))];
+    const customers = new Map<string, ImportQueueItemWithAdmins['candidate_customers'][number]>();
+
+    if (ids.length > 0) {
+      const { data, error } = await this.supabase
+        .from('customers')
+        .select('id, name, phone, email')
+        .in('id', ids);
+
+      if (error) {
+        console.error('Failed to load candidate customers:', error);
+        throw new Error(`Failed to load import queue: ${error.message}`);
+      }
+
+      for (const customer of data ?? []) {
+        customers.set(customer.id, customer);
+      }
+    }
+
+    return items.map((item) => ({
+      ...item,
+      candidate_customers: candidateIds(item).flatMap((id) => {
+        const customer = customers.get(id);
+        return customer ? [customer] : [];
+      }),
+    }));
+  }
+
+  /**
+   * External IDs already imported or queued for a source
+   */
+  private async findExistingExternalIds(
+    source: CommunicationImportSource,
+    externalIds: string[]
+  ): Promise<Set<string>> {
+    const existing = new Set<string>();
+    const unique = [...new Set(externalIds)];
+
+    for (let i = 0; i < unique.length; i += LOOKUP_CHUNK_SIZE) {
+      const chunk = unique.slice(i, i + LOOKUP_CHUNK_SIZE);
+
+      const [communications, queued] = await Promise.all([
+        this.supabase
+          .from('communications')
+          .select('external_message_id')
+          .eq('import_source', source)
+          .in('external_message_id', chunk),
+        this.supabase
+          .from('communication_import_queue')
+          .select('external_message_id')
+          .eq('source', source)
+          .in('external_message_id', chunk),
+      ]);
+
+      const error = communications.error ?? queued.error;
+      if (error) {
+        console.error('Failed to check for duplicate imports:', error);
+        throw new Error(`Failed to check for duplicates: ${error.message}`);
+      }
+
+      for (const row of communications.data ?? []) {
+        if (row.external_message_id) existing.add(row.external_message_id);
+      }
+      for (const row of queued.data ?? []) {
+        existing.add(row.external_message_id);
+      }
+    }
+
+    return existing;
+  }
+
+  /**
+   * Find the customer's communication a reply belongs under
+   */
+  private async findParentId(
+    source: CommunicationImportSource,
+    customerId: string,
+    parentExternalId: string | null
+  ): Promise<string | null> {
+    if (!parentExternalId) return null;
+
+    const { data } = await this.supabase
+      .from('communications')
+      .select('id')
+      .eq('customer_id', customerId)
+      .eq('import_source', source)
+      .eq('external_message_id', parentExternalId)
+      .maybeSingle();
+
+    return data?.id ?? null;
+  }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function emptyResult(source: CommunicationImportSource, dryRun = false): ImportResult {
+  return { source, dryRun, imported: 0, duplicates: 0, queued: 0, failed: 0, errors: [] };
+}
+
+/**
+ * Truncate a summary to the communications length limit
+ */
+function truncateSummary(summary: string): string {
+  return summary.length > MAX_SUMMARY_LENGTH
+    ? `${summary.slice(0, MAX_SUMMARY_LENGTH - 1)}…`
+    : summary;
+}
+
+/**
+ * Call metadata carried in the payload by call-based importers. Only
+ * inbound calls can be flagged as needing a callback.
+ */
+function pickCallFields(
+  payload: Record<string, unknown>,
+  direction: Communication['direction']
+): Partial<ImportedCallFields> {
+  const call = payload.call as ImportedCallFields | undefined;
+  if (!call) return {};
+
+  return { ...call, needs_callback: direction === 'inbound' && call.needs_callback };
+}
+
+/**
+ * Build an import message from a parsed email. The sender matching a
+ * customer makes it inbound; otherwise recipients matching make it outbound.
+ */
+function toEmailImportMessage(
+  file: string,
+  email: ParsedEmail,
+  customersByEmail: Map<string, string[]>
+): ImportMessage {
+  const from = email.from!;
+  const date = email.date!;
+  const recipients = [...email.to, ...email.cc];
+
+  const senderMatches = customersByEmail.get(from.address) ?? [];
+  const recipientMatches = recipients.flatMap(
+    (recipient) => customersByEmail.get(recipient.address) ?? []
+  );
+
+  const matches =
+    senderMatches.length > 0
+      ? senderMatches.map((customerId) => ({ customerId, direction: 'inbound' as const }))
+      : recipientMatches.map((customerId) => ({ customerId, direction: 'outbound' as const }));
+
+  const occurredAt = date.toISOString();
+  const body = stripQuotedReply(email.text) || email.text;
+  const subject = email.subject || '(no subject)';
+
+  return {
+    file,
+    externalId: email.messageId ?? fallbackMessageId(email),
+    type: 'email',
+    direction: null,
+    summary: truncateSummary(`Subject: ${subject}\n\n${body}`.trim()),
+    occurredAt,
+    matches,
+    counterparty: senderMatches.length > 0 || recipients.length === 0
+      ? from.address
+      : recipients.map((recipient) => recipient.address).join(', '),
+    parentExternalId: email.inReplyTo,
+    payload: {
+      from: from.address,
+      to: email.to.map((recipient) => recipient.address),
+      cc: email.cc.map((recipient) => recipient.address),
+      subject: email.subject,
+    },
+  };
+}
+
+/**
+ * Stable ID for messages without a Message-ID header, so re-imports
+ * still deduplicate
+ */
+function fallbackMessageId(email: ParsedEmail): string {
+  const hash = createHash('sha256')
+    .update(
+      [email.from?.address ?? '', email.date?.toISOString() ?? '', email.subject, email.text].join(
+        '\n'
+      )
+    )
+    .digest('hex');
+  return `sha256:${hash}`;
+}
+
+/**
+ * Build an import message from an SMS export row. An explicit direction
+ * picks the customer side; otherwise whichever number matches decides it.
+ * A lone number with no direction is matched but its direction is left
+ * for review.
+ */
+function toSmsImportMessage(
+  file: string,
+  row: SmsExportRow,
+  customersByPhone: Map<string, string[]>
+): ImportMessage {
+  const lookup = (number: string | null) => (number ? customersByPhone.get(number) ?? [] : []);
+
+  let counterparty: string | null;
+  let matches: ImportMessage['matches'];
+
+  if (row.direction) {
+    const direction = row.direction;
+    counterparty = row.number ?? (direction === 'inbound' ? row.from : row.to);
+    matches = lookup(counterparty).map((customerId) => ({ customerId, direction }));
+  } else if (row.number) {
+    // A single number with no direction: the number is the customer's, but
+    // not who sent it. A matching customer is kept as the candidate and
+    // the reviewer picks the direction.
+    counterparty = row.number;
+    matches = lookup(counterparty).map((customerId) => ({ customerId, direction: null }));
+  } else {
+    const senderMatches = lookup(row.from);
+    counterparty = senderMatches.length > 0 ? row.from : row.to;
+    matches =
+      senderMatches.length > 0
+        ? senderMatches.map((customerId) => ({ customerId, direction: 'inbound' as const }))
+        : lookup(row.to).map((customerId) => ({ customerId, direction: 'outbound' as const }));
+  }
+
+  const normalizedCounterparty = counterparty ? normalizePhone(counterparty) : null;
+  const occurredAt = row.sentAt.toISOString();
+
+  return {
+    file,
+    externalId: row.id ?? fallbackSmsId(normalizedCounterparty, row, occurredAt),
+    type: 'text',
+    direction: row.direction,
+    summary: truncateSummary(row.body),
+    occurredAt,
+    matches,
+    counterparty: normalizedCounterparty,
+    parentExternalId: null,
+    payload: {
+      row: row.row,
+      from: row.from,
+      to: row.to,
+      number: row.number,
+    },
+  };
+}
+
+/**
+ * Stable ID for SMS rows without a message ID, so re-imports still
+ * deduplicate
+ */
+function fallbackSmsId(
+  counterparty: string | null,
+  row: SmsExportRow,
+  occurredAt: string
+): string {
+  const hash = createHash('sha256')
+    .update([counterparty ?? '', row.direction ?? '', occurredAt, row.body].join('\n'))
+    .digest('hex');
+  return `sha256:${hash}`;
+}
+
+/**
+ * Build an import message from a CDR row. A matching caller makes the call
+ * inbound; otherwise a matching callee makes it outbound.
+ */
+function toCdrImportMessage(
+  file: string,
+  row: CdrRow,
+  customersByPhone: Map<string, string[]>
+): ImportMessage {
+  const callerMatches = customersByPhone.get(row.caller) ?? [];
+  const calleeMatches = customersByPhone.get(row.callee) ?? [];
+
+  const direction: Communication['direction'] | null =
+    callerMatches.length > 0 ? 'inbound' : calleeMatches.length > 0 ? 'outbound' : null;
+  const matches =
+    direction === 'inbound'
+      ? callerMatches.map((customerId) => ({ customerId, direction }))
+      : calleeMatches.map((customerId) => ({ customerId, direction: 'outbound' as const }));
+
+  const counterparty = direction === 'outbound' ? row.callee : row.caller;
+  const normalized = normalizePhone(counterparty);
+  const duration =
+    row.durationSeconds != null ? Math.min(row.durationSeconds, MAX_CALL_DURATION_SECONDS) : null;
+
+  // Unknown dispositions fall back to "nobody talked"
+  const missed = row.outcome ? row.outcome !== 'connected' : duration === 0;
+
+  const occurredAt = row.startedAt.toISOString();
+
+  return {
+    file,
+    externalId: row.id ?? fallbackCdrId(row, occurredAt),
+    type: 'call',
+    direction,
+    summary: truncateSummary(describeCall(row, direction, missed, duration)),
+    occurredAt,
+    matches,
+    counterparty: normalized,
+    parentExternalId: null,
+    payload: {
+      row: row.row,
+      caller: row.caller,
+      callee: row.callee,
+      disposition: row.disposition,
+      call: {
+        call_duration_seconds: duration,
+        call_outcome: row.outcome,
+        call_number: /^\+?[0-9]+$/.test(normalized) ? normalized : null,
+        needs_callback: missed,
+      },
+    },
+  };
+}
+
+/**
+ * Generated summary for an imported call, e.g.
+ * "Missed call from (555) 123-4567 (No Answer)"
+ */
+function describeCall(
+  row: CdrRow,
+  direction: Communication['direction'] | null,
+  missed: boolean,
+  duration: number | null
+): string {
+  const outcome = row.outcome ? callOutcomeLabels[row.outcome] : row.disposition;
+  const details = [
+    outcome,
+    duration && !missed ? formatSecondsDuration(duration) : null,
+  ].filter(Boolean);
+  const suffix = details.length > 0 ? ` (${details.join(', ')})` : '';
+
+  if (direction === 'inbound') {
+    const label = missed ? 'Missed call' : 'Call';
+    return `${label} from ${formatPhone(row.caller)}${suffix}`;
+  }
+  if (direction === 'outbound') {
+    return `Call to ${formatPhone(row.callee)}${suffix}`;
+  }
+  return `Call from ${formatPhone(row.caller)} to ${formatPhone(row.callee)}${suffix}`;
+}
+
+/**
+ * Stable ID for CDR rows without a unique call ID, so re-imports still
+ * deduplicate
+ */
+function fallbackCdrId(row: CdrRow, occurredAt: string): string {
+  const hash = createHash('sha256')
+    .update([normalizePhone(row.caller), normalizePhone(row.callee), occurredAt].join('\n'))
+    .digest('hex');
+  return `sha256:${hash}`;
+}
@@ -0,0 +1,212 @@
+/**
+ * Communication Revision Service
+ *
+ * @file src/lib/services/communication-revision.service.ts
+ *
+ * Edit history for communications. Revisions are written by a database
+ * trigger whenever type, direction, summary, occurred_at, call details or
+ * linked records change, recording who edited, when, and a field-level diff. This service
+ * reads them for display and restores earlier values.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { CommunicationRevision, Database } from '@/lib/types/database';
+import {
+  COMMUNICATION_LINK_FIELDS,
+  buildLinkLabels,
+  communicationRevisionFieldLabels,
+  formatRevisionValue,
+  type CommunicationLinkField,
+  type CommunicationLinkLabels,
+  type CommunicationRevisionEntry,
+  type CommunicationRevisionField,
+  type CommunicationLinkedEstimate,
+  type CommunicationLinkedEvent,
+  type CommunicationLinkedPool,
+  type CommunicationLinkedProperty,
+  type CommunicationWithLogger,
+} from '@/lib/types/communication';
+import { NotFoundError } from '@/lib/utils/errors';
+import { COMMUNICATION_SELECT } from './communication.service';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+type LinkTable = 'estimates' | 'calendar_events' | 'properties' | 'pools';
+
+type RevisionRow = CommunicationRevision & {
+  editor: CommunicationRevisionEntry['editor'];
+};
+
+const REVISION_SELECT = `
+  *,
+  editor:admins!communication_revisions_edited_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+/**
+ * Display order of changed fields
+ */
+const FIELD_ORDER = Object.keys(communicationRevisionFieldLabels) as CommunicationRevisionField[];
+
+// =============================================================================
+// Communication Revision Service
+// =============================================================================
+
+export class CommunicationRevisionService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * A communication's edit history, newest first
+   *
+   * @param communicationId - Communication ID
+   * @returns Revisions with their editor and formatted changes
+   */
+  async listByCommunication(communicationId: string): Promise<CommunicationRevisionEntry[]> {
+    const { data, error } = await this.supabase
+      .from('communication_revisions')
+      .select(REVISION_SELECT)
+      .eq('communication_id', communicationId)
+      .order('revision_number', { ascending: false });
+
+    if (error) {
+      console.error('Failed to list communication revisions:', error);
+      throw new Error(`Failed to list revisions: ${error.message}`);
+    }
+
+    const rows = (data ?? []) as unknown as RevisionRow[];
+    const linkLabels = await this.loadLinkLabels(rows);
+
+    return rows.map((row) => toRevisionEntry(row, linkLabels));
+  }
+
+  /**
+   * Put back the values a revision replaced
+   *
+   * The restore is recorded as a new revision, so it can be undone the
+   * same way. Restoring values that already match changes nothing.
+   *
+   * @param revisionId - Revision to restore
+   * @returns The communication after the restore
+   */
+  async restore(revisionId: string): Promise<CommunicationWithLogger> {
+    const { data: communicationId, error } = await this.supabase.rpc(
+      'restore_communication_revision',
+      { p_revision_id: revisionId }
+    );
+
+    if (error) {
+      if (error.code === 'P0002') {
+        throw new NotFoundError('Revision');
+      }
+      console.error('Failed to restore communication revision:', error);
+      throw new Error(`Failed to restore revision: ${error.message}`);
+    }
+
+    const { data: communication, error: loadError } = await this.supabase
+      .from('communications')
+      .select(COMMUNICATION_SELECT)
+      .eq('id', communicationId as string)
+      .single();
+
+    if (loadError) {
+      console.error('Failed to load restored communication:', loadError);
+      throw new Error(`Failed to load communication: ${loadError.message}`);
+    }
+
+    return communication as CommunicationWithLogger;
+  }
+
+  /**
+   * Names of the estimates, events, properties and pools the revisions
+   * link to or from. Records deleted since are left out.
+   */
+  private async loadLinkLabels(rows: RevisionRow[]): Promise<CommunicationLinkLabels> {
+    const ids: Record<CommunicationLinkField, Set<string>> = {
+      estimate_id: new Set(),
+      calendar_event_id: new Set(),
+      property_id: new Set(),
+      pool_id: new Set(),
+    };
+
+    for (const row of rows) {
+      for (const field of COMMUNICATION_LINK_FIELDS) {
+        const change = row.changes[field];
+        if (!change) continue;
+        [change.from, change.to].forEach((id) => typeof id === 'string' && ids[field].add(id));
+      }
+    }
+
+    const select = async <T>(table: LinkTable, columns: string, idSet: Set<string>) => {
+      if (idSet.size === 0) return [] as T[];
+
+      const { data, error } = await this.supabase
+        .from(table)
+        .select(columns)
+        .in('id', [...idSet]);
+
+      if (error) {
+        console.error(`Failed to load revision ${table}:`, error);
+        throw new Error(`Failed to list revisions: ${error.message}`);
+      }
+
+      return (data ?? []) as unknown as T[];
+    };
+
+    const [estimates, events, properties, pools] = await Promise.all([
+      select<CommunicationLinkedEstimate>(
+        'estimates',
+        'id, estimate_number, status, total_cents',
+        ids.estimate_id
+      ),
+      select<CommunicationLinkedEvent>(
+        'calendar_events',
+        'id, title, start_datetime, all_day, status',
+        ids.calendar_event_id
+      ),
+      select<CommunicationLinkedProperty>(
+        'properties',
+        'id, address_line1, city, state',
+        ids.property_id
+      ),
+      select<CommunicationLinkedPool>('pools', 'id, type, property_id', ids.pool_id),
+    ]);
+
+    return buildLinkLabels({ estimates, events, properties, pools });
+  }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function toRevisionEntry(
+  row: RevisionRow,
+  linkLabels: CommunicationLinkLabels
+): CommunicationRevisionEntry {
+  const changes = FIELD_ORDER.filter((field) => field in row.changes).map((field) => {
+    const { from, to } = row.changes[field];
+    return {
+      field,
+      label: communicationRevisionFieldLabels[field],
+      from,
+      to,
+      fromFormatted: formatRevisionValue(field, from, linkLabels),
+      toFormatted: formatRevisionValue(field, to, linkLabels),
+    };
+  });
+
+  return { ...row, changes };
+}
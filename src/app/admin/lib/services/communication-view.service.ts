@@ -0,0 +1,271 @@
+/**
+ * Communication View Service
+ *
+ * @file src/lib/services/communication-view.service.ts
+ *
+ * Saved communication filter sets ("views") and the options the filter bar
+ * offers (admins, customer tags). Views are private to the admin who saved
+ * them unless shared; only that admin can change or delete them.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { CommunicationSavedView, Database } from '@/lib/types/database';
+import type {
+  CommunicationFilterOptions,
+  CommunicationFilters,
+  SavedCommunicationView,
+} from '@/lib/types/communication';
+import { ConflictError, ForbiddenError, NotFoundError } from '@/lib/utils/errors';
+import {
+  communicationFiltersSchema,
+  type SaveCommunicationViewInput,
+  type UpdateCommunicationViewInput,
+} from '@/lib/validations/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+type SavedViewRow = CommunicationSavedView & {
+  creator: SavedCommunicationView['creator'];
+};
+
+const VIEW_SELECT = `
+  *,
+  creator:admins!communication_saved_views_created_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+// =============================================================================
+// Communication View Service
+// =============================================================================
+
+export class CommunicationViewService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * Views an admin can open: everything shared plus their own
+   *
+   * @param adminId - Admin listing views
+   * @returns Views ordered by name
+   */
+  async list(adminId: string): Promise<SavedCommunicationView[]> {
+    const { data, error } = await this.supabase
+      .from('communication_saved_views')
+      .select(VIEW_SELECT)
+      .or(`is_shared.eq.true,created_by.eq.${adminId}`)
+      .order('name', { ascending: true });
+
+    if (error) {
+      console.error('Failed to list saved views:', error);
+      throw new Error(`Failed to list saved views: ${error.message}`);
+    }
+
+    return ((data ?? []) as unknown as SavedViewRow[]).map((row) =>
+      toSavedView(row, adminId)
+    );
+  }
+
+  /**
+   * Open a view, e.g. from a shared link
+   *
+   * @param id - View ID
+   * @param adminId - Admin opening the view
+   * @throws NotFoundError when missing or private to another admin
+   */
+  async getById(id: string, adminId: string): Promise<SavedCommunicationView> {
+    const row = await this.load(id);
+
+    if (!row.is_shared && row.created_by !== adminId) {
+      throw new NotFoundError('Saved view');
+    }
+
+    return toSavedView(row, adminId);
+  }
+
+  /**
+   * Save the current filters under a name
+   *
+   * @param input - Validated name, filters and sharing
+   * @param adminId - Admin saving the view
+   */
+  async create(
+    input: SaveCommunicationViewInput,
+    adminId: string
+  ): Promise<SavedCommunicationView> {
+    const { data, error } = await this.supabase
+      .from('communication_saved_views')
+      .insert({
+        name: input.name,
+        filters: compactFilters(input.filters),
+        is_shared: input.isShared,
+        created_by: adminId,
+      })
+      .select(VIEW_SELECT)
+      .single();
+
+    if (error) {
+      console.error('Failed to save view:', error);
+      throw toViewError('save', error);
+    }
+
+    return toSavedView(data as unknown as SavedViewRow, adminId);
+  }
+
+  /**
+   * Rename, re-share or replace the filters of one of the admin's views
+   *
+   * @param input - View ID and fields to change
+   * @param adminId - Admin making the change
+   * @throws ForbiddenError when the view belongs to another admin
+   */
+  async update(
+    input: UpdateCommunicationViewInput,
+    adminId: string
+  ): Promise<SavedCommunicationView> {
+    await this.assertOwner(input.id, adminId);
+
+    const { data, error } = await this.supabase
+      .from('communication_saved_views')
+      .update({
+        ...(input.name !== undefined && { name: input.name }),
+        ...(input.filters !== undefined && { filters: compactFilters(input.filters) }),
+        ...(input.isShared !== undefined && { is_shared: input.isShared }),
+      })
+      .eq('id', input.id)
+      .select(VIEW_SELECT)
+      .single();
+
+    if (error) {
+      console.error('Failed to update view:', error);
+      throw toViewError('update', error);
+    }
+
+    return toSavedView(data as unknown as SavedViewRow, adminId);
+  }
+
+  /**
+   * Delete one of the admin's views
+   *
+   * @param id - View ID
+   * @param adminId - Admin deleting the view
+   */
+  async delete(id: string, adminId: string): Promise<void> {
+    await this.assertOwner(id, adminId);
+
+    const { error } = await this.supabase
+      .from('communication_saved_views')
+      .delete()
+      .eq('id', id);
+
+    if (error) {
+      console.error('Failed to delete view:', error);
+      throw new Error(`Failed to delete saved view: ${error.message}`);
+    }
+  }
+
+  /**
+   * Admins and customer tags for the filter bar
+   */
+  async getFilterOptions(): Promise<CommunicationFilterOptions> {
+    const [admins, tags] = await Promise.all([
+      this.supabase
+        .from('admins')
+        .select('id, email, full_name')
+        .order('full_name', { ascending: true }),
+      this.supabase
+        .from('customer_tags')
+        .select('id, name, color')
+        .order('name', { ascending: true }),
+    ]);
+
+    const error = admins.error ?? tags.error;
+    if (error) {
+      console.error('Failed to load filter options:', error);
+      throw new Error(`Failed to load filter options: ${error.message}`);
+    }
+
+    return { admins: admins.data ?? [], tags: tags.data ?? [] };
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  private async load(id: string): Promise<SavedViewRow> {
+    const { data, error } = await this.supabase
+      .from('communication_saved_views')
+      .select(VIEW_SELECT)
+      .eq('id', id)
+      .maybeSingle();
+
+    if (error) {
+      console.error('Failed to load saved view:', error);
+      throw new Error(`Failed to load saved view: ${error.message}`);
+    }
+
+    if (!data) {
+      throw new NotFoundError('Saved view');
+    }
+
+    return data as unknown as SavedViewRow;
+  }
+
+  private async assertOwner(id: string, adminId: string): Promise<void> {
+    const row = await this.load(id);
+
+    if (row.created_by !== adminId) {
+      throw new ForbiddenError('Only the admin who saved this view can change it');
+    }
+  }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Drop empty values so saved filters stay minimal
+ */
+function compactFilters(filters: CommunicationFilters): Record<string, unknown> {
+  return Object.fromEntries(
+    Object.entries(filters).filter(
+      ([, value]) =>
+        value !== undefined && value !== '' && !(Array.isArray(value) && value.length === 0)
+    )
+  );
+}
+
+/**
+ * Stored filters are re-validated on read; a view whose filters no longer
+ * validate (a removed type, a malformed date) opens unfiltered rather
+ * than failing
+ */
+function toSavedView(row: SavedViewRow, adminId: string): SavedCommunicationView {
+  const parsed = communicationFiltersSchema.safeParse(row.filters);
+
+  return {
+    ...row,
+    filters: parsed.success ? parsed.data : {},
+    canEdit: row.created_by === adminId,
+  };
+}
+
+function toViewError(action: 'save' | 'update', error: { code?: string; message: string }) {
+  if (error.code === '23505') {
+    return new ConflictError('You already have a view with this name');
+  }
+
+  return new Error(`Failed to ${action} view: ${error.message}`);
+}
@@ -4,24 +4,59 @@
  * @file src/lib/services/communication.service.ts
  *
  * Service layer for communication-related database operations.
- * Handles CRUD operations, full-text search, and filtering for communications.
+ * Handles CRUD operations, full-text search, and filtering for communications,
+ * and groups communications into conversation threads.
  *
  * All methods receive a Supabase client instance for proper auth context.
  */
 
 import type { SupabaseClient } from '@supabase/supabase-js';
-import type { Database, Communication } from '@/lib/types/database';
+import type {
+  Database,
+  Communication,
+  CommunicationThreadRow,
+  CommunicationThreadStatus,
+} from '@/lib/types/database';
 import type {
   CommunicationWithLogger,
   CommunicationWithCustomer,
-  CommunicationListResult,
+  CommunicationLinkOptions,
+  CommunicationThread,
+  CommunicationThreadListResult,
+  CommunicationSearchHit,
   CommunicationSearchResult,
+  CommunicationStats,
+  CommunicationTrashListResult,
+  MonthlyCommunicationCount,
+  TrashedCommunication,
 } from '@/lib/types/communication';
-import type {
-  CreateCommunicationInput,
-  UpdateCommunicationInput,
-  ListCommunicationsInput,
-  SearchCommunicationsInput,
+import {
+  buildKeysetPage,
+  decodeCursor,
+  encodeCursor,
+  isReversed,
+  keysetFilter,
+} from '@/lib/utils/cursor';
+import { parseHighlights } from '@/lib/utils/highlight';
+import { ConflictError, NotFoundError } from '@/lib/utils/errors';
+import { extractDigits, normalizePhone } from '@/lib/utils/phone';
+import { DEFAULT_TIMEZONE } from '@/lib/utils/timezone';
+import { AttachmentService } from './attachment.service';
+import { CalendarService } from './calendar.service';
+import {
+  COMMUNICATION_TYPES,
+  COMMUNICATION_DIRECTIONS,
+  CALL_OUTCOMES,
+  DEFAULT_FOLLOW_UP_MINUTES,
+  DEFAULT_COMMUNICATION_TRASH_RETENTION_DAYS,
+  type CreateCommunicationInput,
+  type FollowUpInput,
+  type UpdateCommunicationInput,
+  type ListCommunicationsInput,
+  type ListLinkedCommunicationsInput,
+  type SearchCommunicationsInput,
+  type CommunicationStatsInput,
+  type ListCommunicationTrashInput,
 } from '@/lib/validations/communication';
 
 // =============================================================================
@@ -30,6 +65,99 @@ import type {
 
 type SupabaseClientType = SupabaseClient<Database>;
 
+type SearchCommunicationRow =
+  Database['public']['Functions']['search_communications']['Returns'][number];
+
+/**
+ * Fewest digits in a search query before they are matched against numbers
+ * mentioned in summaries and call numbers
+ */
+const MIN_SEARCH_DIGITS = 3;
+
+/**
+ * Configured days a deleted communication stays in the trash
+ */
+export const COMMUNICATION_TRASH_RETENTION_DAYS =
+  Number(process.env.COMMUNICATION_TRASH_RETENTION_DAYS) > 0
+    ? Number(process.env.COMMUNICATION_TRASH_RETENTION_DAYS)
+    : DEFAULT_COMMUNICATION_TRASH_RETENTION_DAYS;
+
+/**
+ * Communications permanently deleted per batch when purging the trash
+ */
+const PURGE_BATCH_SIZE = 100;
+
+/**
+ * Columns for CommunicationWithLogger: the row, who logged and last edited
+ * it, its attachments, any follow-ups scheduled from it and the estimate,
+ * event, property and pool it is about
+ */
+export const COMMUNICATION_SELECT = `
+  *,
+  logged_by_admin:admins!communications_logged_by_fkey(
+    id,
+    email,
+    full_name
+  ),
+  edited_by_admin:admins!communications_edited_by_fkey(
+    id,
+    email,
+    full_name
+  ),
+  attachments:customer_attachments!customer_attachments_communication_id_fkey(
+    id,
+    filename,
+    content_type,
+    size_bytes,
+    storage_path,
+    created_at
+  ),
+  follow_ups:calendar_events!calendar_events_communication_id_fkey(
+    id,
+    title,
+    start_datetime,
+    end_datetime,
+    all_day,
+    status
+  ),
+  estimate:estimates!communications_estimate_id_fkey(
+    id,
+    estimate_number,
+    status,
+    total_cents
+  ),
+  calendar_event:calendar_events!communications_calendar_event_id_fkey(
+    id,
+    title,
+    start_datetime,
+    all_day,
+    status
+  ),
+  property:properties!communications_property_id_fkey(
+    id,
+    address_line1,
+    city,
+    state
+  ),
+  pool:pools!communications_pool_id_fkey(
+    id,
+    type,
+    property_id
+  )
+`;
+
+/**
+ * Columns for TrashedCommunication: COMMUNICATION_SELECT plus who deleted it
+ */
+const TRASHED_COMMUNICATION_SELECT = `
+  ${COMMUNICATION_SELECT.trim()},
+  deleted_by_admin:admins!communications_deleted_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
 interface CreateCommunicationData {
   customer_id: string;
   type: Communication['type'];
@@ -37,6 +165,37 @@ interface CreateCommunicationData {
   summary: string;
   occurred_at: string;
   logged_by: string;
+  parent_id: string | null;
+  call_duration_seconds: number | null;
+  call_outcome: Communication['call_outcome'];
+  call_number: string | null;
+  delivery_status?: Communication['delivery_status'];
+  delivery_provider?: string;
+  delivery_updated_at?: string;
+  template_id?: string | null;
+  estimate_id: string | null;
+  calendar_event_id: string | null;
+  property_id: string | null;
+  pool_id: string | null;
+}
+
+/**
+ * Extra fields set when a communication is created by sending a message
+ */
+export interface CreateCommunicationOptions {
+  /** Provider name; the communication starts with delivery_status 'pending' */
+  deliveryProvider?: string;
+  /** Template the message was rendered from */
+  templateId?: string;
+}
+
+/**
+ * Delivery state reported by a provider
+ */
+export interface DeliveryStatusUpdate {
+  status: NonNullable<Communication['delivery_status']>;
+  providerMessageId?: string | null;
+  error?: string | null;
 }
 
 interface UpdateCommunicationData {
@@ -44,6 +203,13 @@ interface UpdateCommunicationData {
   direction?: Communication['direction'];
   summary?: string;
   occurred_at?: string;
+  call_duration_seconds?: number | null;
+  call_outcome?: Communication['call_outcome'];
+  call_number?: string | null;
+  estimate_id?: string | null;
+  calendar_event_id?: string | null;
+  property_id?: string | null;
+  pool_id?: string | null;
 }
 
 // =============================================================================
@@ -64,13 +230,20 @@ export class CommunicationService {
   /**
    * Log a new communication for a customer
    *
+   * When `parentId` is set the communication joins the parent's thread;
+   * otherwise the database starts a new thread for it. When `followUp` is
+   * set a follow_up calendar event is scheduled for the same customer; if
+   * that fails the communication is removed again.
+   *
    * @param input - Validated communication data
    * @param loggedBy - Admin ID who is logging this communication
+   * @param options - Delivery tracking for messages sent from the CRM
    * @returns The created communication with logger info
    */
   async create(
     input: CreateCommunicationInput,
-    loggedBy: string
+    loggedBy: string,
+    options: CreateCommunicationOptions = {}
   ): Promise<CommunicationWithLogger> {
     const data: CreateCommunicationData = {
       customer_id: input.customerId,
@@ -79,21 +252,29 @@ export class CommunicationService {
       summary: input.summary,
       occurred_at: input.occurredAt,
       logged_by: loggedBy,
+      parent_id: input.parentId ?? null,
+      call_duration_seconds: input.type === 'call' ? input.callDurationSeconds ?? null : null,
+      call_outcome: input.type === 'call' ? input.callOutcome ?? null : null,
+      call_number:
+        input.type === 'call' && input.callNumber ? normalizePhone(input.callNumber) : null,
+      ...(options.deliveryProvider
+        ? {
+            delivery_status: 'pending' as const,
+            delivery_provider: options.deliveryProvider,
+            delivery_updated_at: new Date().toISOString(),
+          }
+        : {}),
+      template_id: options.templateId ?? null,
+      estimate_id: input.estimateId ?? null,
+      calendar_event_id: input.calendarEventId ?? null,
+      property_id: input.propertyId ?? null,
+      pool_id: input.poolId ?? null,
     };
 
     const { data: communication, error } = await this.supabase
       .from('communications')
       .insert(data)
-      .select(
-        `
-        *,
-        logged_by_admin:admins!communications_logged_by_fkey(
-          id,
-          email,
-          full_name
-        )
-      `
-      )
+      .select(COMMUNICATION_SELECT)
       .single();
 
     if (error) {
@@ -101,7 +282,23 @@ export class CommunicationService {
       throw new Error(`Failed to log communication: ${error.message}`);
     }
 
-    return communication as CommunicationWithLogger;
+    if (!input.followUp) {
+      return communication as CommunicationWithLogger;
+    }
+
+    try {
+      await this.scheduleFollowUp(communication as CommunicationWithLogger, input.followUp, loggedBy);
+    } catch (followUpError) {
+      await this.supabase.from('communications').delete().eq('id', communication.id);
+      throw followUpError;
+    }
+
+    const created = await this.getById(communication.id);
+    if (!created) {
+      throw new Error('Failed to load communication after scheduling follow-up');
+    }
+
+    return created;
   }
 
   // ---------------------------------------------------------------------------
@@ -117,16 +314,7 @@ export class CommunicationService {
   async getById(id: string): Promise<CommunicationWithLogger | null> {
     const { data, error } = await this.supabase
       .from('communications')
-      .select(
-        `
-        *,
-        logged_by_admin:admins!communications_logged_by_fkey(
-          id,
-          email,
-          full_name
-        )
-      `
-      )
+      .select(COMMUNICATION_SELECT)
       .eq('id', id)
       .single();
 
@@ -143,106 +331,393 @@ export class CommunicationService {
   }
 
   /**
-   * List communications for a customer with filters and pagination
+   * List conversation threads for a customer with filters and pagination
+   *
+   * Filters apply to the communications inside a thread: a thread is
+   * returned when at least one of its communications matches, and it is
+   * always returned whole so staff keep the surrounding context. Trashed
+   * communications are left out unless `includeDeleted` is set.
    *
    * @param input - Filter and pagination options
-   * @returns Paginated list of communications
+   * @returns Paginated list of threads, most recently active first
    */
   async listByCustomer(
     input: ListCommunicationsInput
-  ): Promise<CommunicationListResult> {
-    const { customerId, limit = 25, cursor, type, direction, search, dateFrom, dateTo } = input;
+  ): Promise<CommunicationThreadListResult> {
+    const {
+      customerId,
+      limit = 25,
+      cursor,
+      search,
+      dateFrom,
+      dateTo,
+      callOutcome,
+      minCallDurationSeconds,
+      maxCallDurationSeconds,
+      loggedBy,
+      tagIds,
+      hasAttachments,
+      estimateId,
+      calendarEventId,
+      propertyId,
+      poolId,
+      includeDeleted = false,
+    } = input;
+    const types = combineFilter(input.type, input.types);
+    const directions = combineFilter(input.direction, input.directions);
+
+    const hasFilters = !!(
+      types ||
+      directions ||
+      loggedBy?.length ||
+      hasAttachments !== undefined ||
+      dateFrom ||
+      dateTo ||
+      (search && search.trim()) ||
+      callOutcome ||
+      minCallDurationSeconds !== undefined ||
+      maxCallDurationSeconds !== undefined ||
+      estimateId ||
+      calendarEventId ||
+      propertyId ||
+      poolId
+    );
+
+    // Keyset on (last_activity_at, id): threads sharing a timestamp are never skipped
+    const scope = `communication_threads:${customerId}`;
+    const position = cursor ? decodeCursor(cursor, scope) : null;
+    const reversed = isReversed(position);
+
+    // Tags belong to the customer, so the whole list matches or none of it does
+    if (tagIds?.length && !(await this.customerHasAnyTag(customerId, tagIds))) {
+      return {
+        items: [],
+        hasMore: false,
+        hasPrevious: false,
+        nextCursor: null,
+        prevCursor: null,
+        total: 0,
+      };
+    }
 
     let query = this.supabase
-      .from('communications')
-      .select(
-        `
-        *,
-        logged_by_admin:admins!communications_logged_by_fkey(
-          id,
-          email,
-          full_name
-        )
-      `,
-        { count: 'exact' }
-      )
+      .from('communication_threads')
+      .select(hasFilters ? `*, ${filteredEmbed(hasAttachments)}` : '*', { count: 'exact' })
       .eq('customer_id', customerId)
-      .order('occurred_at', { ascending: false })
+      .order('last_activity_at', { ascending: reversed })
+      .order('id', { ascending: reversed })
       .limit(limit + 1); // Fetch one extra to check if there are more
 
+    // Skip trashed communications, and threads with nothing else left
+    if (!includeDeleted) {
+      query = query.gt('message_count', 0);
+      if (hasFilters) {
+        query = query.is('communications.deleted_at', null);
+      }
+    }
+
     // Apply type filter
-    if (type) {
-      query = query.eq('type', type);
+    if (types) {
+      query = query.in('communications.type', types);
     }
 
     // Apply direction filter
-    if (direction) {
-      query = query.eq('direction', direction);
+    if (directions) {
+      query = query.in('communications.direction', directions);
+    }
+
+    // Apply logged-by filter
+    if (loggedBy?.length) {
+      query = query.in('communications.logged_by', loggedBy);
+    }
+
+    // Without attachments: the left-joined embed is empty
+    if (hasAttachments === false) {
+      query = query.is('communications.attachments', null);
     }
 
-    // Apply date range filters
+    // Apply date range filters ([dateFrom, dateTo), as in the search RPC)
     if (dateFrom) {
-      query = query.gte('occurred_at', dateFrom);
+      query = query.gte('communications.occurred_at', dateFrom);
     }
     if (dateTo) {
-      query = query.lte('occurred_at', dateTo);
+      query = query.lt('communications.occurred_at', dateTo);
+    }
+
+    // Apply call metadata filters
+    if (callOutcome) {
+      query = query.eq('communications.call_outcome', callOutcome);
+    }
+    if (minCallDurationSeconds !== undefined) {
+      query = query.gte('communications.call_duration_seconds', minCallDurationSeconds);
+    }
+    if (maxCallDurationSeconds !== undefined) {
+      query = query.lte('communications.call_duration_seconds', maxCallDurationSeconds);
+    }
+
+    // Apply linked record filters
+    if (estimateId) {
+      query = query.eq('communications.estimate_id', estimateId);
+    }
+    if (calendarEventId) {
+      query = query.eq('communications.calendar_event_id', calendarEventId);
+    }
+    if (propertyId) {
+      query = query.eq('communications.property_id', propertyId);
+    }
+    if (poolId) {
+      query = query.eq('communications.pool_id', poolId);
     }
 
     // Apply full-text search
     if (search && search.trim()) {
-      query = query.textSearch('search_vector', search.trim(), {
+      query = query.textSearch('communications.search_vector', search.trim(), {
         type: 'websearch',
         config: 'english',
       });
     }
 
     // Apply cursor-based pagination
-    if (cursor) {
-      const decoded = this.decodeCursor(cursor);
-      if (decoded) {
-        query = query.lt('occurred_at', decoded.occurredAt);
-      }
+    if (position) {
+      query = query.or(keysetFilter('last_activity_at', position));
     }
 
     const { data, error, count } = await query;
 
     if (error) {
-      console.error('Failed to list communications:', error);
+      console.error('Failed to list communication threads:', error);
       throw new Error(`Failed to list communications: ${error.message}`);
     }
 
-    const items = data as CommunicationWithLogger[];
-    const hasMore = items.length > limit;
-    const resultItems = hasMore ? items.slice(0, limit) : items;
+    const rows = (data ?? []) as unknown as CommunicationThreadRow[];
+    const { items: threadRows, ...page } = buildKeysetPage(
+      rows,
+      limit,
+      position,
+      scope,
+      (thread) => ({ value: thread.last_activity_at, id: thread.id })
+    );
 
-    // Create cursor for next page
-    let nextCursor: string | null = null;
-    if (hasMore && resultItems.length > 0) {
-      const lastItem = resultItems[resultItems.length - 1];
-      nextCursor = this.encodeCursor({
-        occurredAt: lastItem.occurred_at,
-        id: lastItem.id,
-      });
-    }
+    const items = await this.attachCommunications(threadRows, includeDeleted);
 
     return {
-      items: resultItems,
-      hasMore,
-      nextCursor,
+      items,
+      ...page,
       total: count ?? undefined,
     };
   }
 
+  /**
+   * Communications linked to an estimate, calendar event, property or pool,
+   * newest first. Trashed communications are left out.
+   *
+   * @param input - Linked record IDs (all given must match) and limit
+   * @returns Linked communications with logger info
+   */
+  async listLinked(input: ListLinkedCommunicationsInput): Promise<CommunicationWithLogger[]> {
+    const { estimateId, calendarEventId, propertyId, poolId, limit = 50 } = input;
+
+    let query = this.supabase
+      .from('communications')
+      .select(COMMUNICATION_SELECT)
+      .is('deleted_at', null)
+      .order('occurred_at', { ascending: false })
+      .order('id', { ascending: false })
+      .limit(limit);
+
+    if (estimateId) {
+      query = query.eq('estimate_id', estimateId);
+    }
+    if (calendarEventId) {
+      query = query.eq('calendar_event_id', calendarEventId);
+    }
+    if (propertyId) {
+      query = query.eq('property_id', propertyId);
+    }
+    if (poolId) {
+      query = query.eq('pool_id', poolId);
+    }
+
+    const { data, error } = await query;
+
+    if (error) {
+      console.error('Failed to list linked communications:', error);
+      throw new Error(`Failed to list linked communications: ${error.message}`);
+    }
+
+    return (data ?? []) as unknown as CommunicationWithLogger[];
+  }
+
+  /**
+   * A customer's estimates, calendar events and properties (with their
+   * pools) that a communication can be linked to, newest first
+   *
+   * @param customerId - Customer ID
+   * @returns Link options for the customer
+   */
+  async getLinkOptions(customerId: string): Promise<CommunicationLinkOptions> {
+    const [estimates, events, properties] = await Promise.all([
+      this.supabase
+        .from('estimates')
+        .select('id, estimate_number, status, total_cents')
+        .eq('customer_id', customerId)
+        .order('created_at', { ascending: false })
+        .limit(50),
+      this.supabase
+        .from('calendar_events')
+        .select('id, title, start_datetime, all_day, status')
+        .eq('customer_id', customerId)
+        .order('start_datetime', { ascending: false })
+        .limit(50),
+      this.supabase
+        .from('properties')
+        .select('id, address_line1, city, state, pools(id, type, property_id)')
+        .eq('customer_id', customerId)
+        .order('created_at', { ascending: true }),
+    ]);
+
+    const error = estimates.error ?? events.error ?? properties.error;
+    if (error) {
+      console.error('Failed to get communication link options:', error);
+      throw new Error(`Failed to get link options: ${error.message}`);
+    }
+
+    return {
+      estimates: estimates.data ?? [],
+      events: events.data ?? [],
+      properties: (properties.data ?? []) as unknown as CommunicationLinkOptions['properties'],
+    };
+  }
+
+  /**
+   * Get a single thread with all of its communications
+   *
+   * @param threadId - Thread ID
+   * @returns The thread, or null if not found
+   */
+  async getThread(threadId: string): Promise<CommunicationThread | null> {
+    const { data, error } = await this.supabase
+      .from('communication_threads')
+      .select('*')
+      .eq('id', threadId)
+      .single();
+
+    if (error) {
+      if (error.code === 'PGRST116') {
+        return null;
+      }
+      console.error('Failed to get communication thread:', error);
+      throw new Error(`Failed to get communication thread: ${error.message}`);
+    }
+
+    const [thread] = await this.attachCommunications([data as CommunicationThreadRow]);
+    return thread;
+  }
+
   /**
    * Search communications using full-text search
    *
-   * @param input - Search query and options
-   * @returns Search results with customer info
+   * Ranking and snippets come from search_communications: full-text,
+   * typo-tolerant trigram and digit matches (phone numbers, gate codes,
+   * estimate numbers) are blended into one ranking, or results are ordered
+   * newest first with sort 'date'. Each carries the parts of its summary
+   * around the matched words.
+   *
+   * @param input - Search query, sort and paging options
+   * @returns Ranked results with customer info and highlighted snippets
    */
   async search(input: SearchCommunicationsInput): Promise<CommunicationSearchResult> {
-    const { customerId, query, limit = 10 } = input;
+    const { customerId, query, limit = 10, offset = 0, cursor, sort = 'relevance' } = input;
+
+    // Cursors only replay the search they came from
+    const scope = `communication_search:${customerId ?? 'all'}:${sort}:${query.trim()}`;
+    const position = cursor ? decodeCursor(cursor, scope) : null;
 
-    let dbQuery = this.supabase
+    const digits = extractDigits(query);
+
+    const { data, error } = await this.supabase.rpc('search_communications', {
+      p_query: query.trim(),
+      p_customer_id: customerId ?? null,
+      p_sort: sort,
+      p_limit: limit + 1, // Fetch one extra to check if there are more
+      p_offset: offset,
+      p_after_rank: position?.score ?? null,
+      p_after_occurred_at: position?.value ?? null,
+      p_after_id: position?.id ?? null,
+      p_digits: digits.length >= MIN_SEARCH_DIGITS ? digits : null,
+      p_types: combineFilter(input.type, input.types),
+      p_directions: combineFilter(input.direction, input.directions),
+      p_logged_by: input.loggedBy?.length ? input.loggedBy : null,
+      p_tag_ids: input.tagIds?.length ? input.tagIds : null,
+      p_has_attachments: input.hasAttachments ?? null,
+      p_from: input.dateFrom ?? null,
+      p_to: input.dateTo ?? null,
+      p_call_outcome: input.callOutcome ?? null,
+      p_min_call_duration: input.minCallDurationSeconds ?? null,
+      p_max_call_duration: input.maxCallDurationSeconds ?? null,
+      p_include_deleted: input.includeDeleted ?? false,
+      p_estimate_id: input.estimateId ?? null,
+      p_calendar_event_id: input.calendarEventId ?? null,
+      p_property_id: input.propertyId ?? null,
+      p_pool_id: input.poolId ?? null,
+    });
+
+    if (error) {
+      console.error('Failed to search communications:', error);
+      throw new Error(`Failed to search communications: ${error.message}`);
+    }
+
+    const rows = (data ?? []) as SearchCommunicationRow[];
+    const hasMore = rows.length > limit;
+    const matches = hasMore ? rows.slice(0, limit) : rows;
+
+    const communications = await this.loadSearchMatches(matches.map((row) => row.id));
+
+    const items: CommunicationSearchHit[] = [];
+    for (const row of matches) {
+      const communication = communications.get(row.id);
+      if (!communication) continue;
+
+      items.push({
+        ...communication,
+        rank: row.rank,
+        snippet: parseHighlights(row.headline),
+      });
+    }
+
+    const last = items[items.length - 1];
+
+    return {
+      items,
+      total: rows[0]?.total_count ?? 0,
+      sort,
+      hasMore,
+      nextCursor:
+        hasMore && last
+          ? encodeCursor(
+              {
+                value: last.occurred_at,
+                id: last.id,
+                score: sort === 'relevance' ? last.rank : undefined,
+                direction: 'next',
+              },
+              scope
+            )
+          : null,
+    };
+  }
+
+  /**
+   * Load matched communications with customer and logger, keyed by ID
+   */
+  private async loadSearchMatches(
+    ids: string[]
+  ): Promise<Map<string, CommunicationWithCustomer>> {
+    if (ids.length === 0) {
+      return new Map();
+    }
+
+    const { data, error } = await this.supabase
       .from('communications')
       .select(
         `
@@ -257,32 +732,21 @@ export class CommunicationService {
           email,
           full_name
         )
-      `,
-        { count: 'exact' }
+      `
       )
-      .textSearch('search_vector', query.trim(), {
-        type: 'websearch',
-        config: 'english',
-      })
-      .order('occurred_at', { ascending: false })
-      .limit(limit);
-
-    // Optionally filter by customer
-    if (customerId) {
-      dbQuery = dbQuery.eq('customer_id', customerId);
-    }
-
-    const { data, error, count } = await dbQuery;
+      .in('id', ids);
 
     if (error) {
-      console.error('Failed to search communications:', error);
+      console.error('Failed to load search matches:', error);
       throw new Error(`Failed to search communications: ${error.message}`);
     }
 
-    return {
-      items: data as CommunicationWithCustomer[],
-      total: count ?? 0,
-    };
+    return new Map(
+      ((data ?? []) as CommunicationWithCustomer[]).map((communication) => [
+        communication.id,
+        communication,
+      ])
+    );
   }
 
   // ---------------------------------------------------------------------------
@@ -290,13 +754,17 @@ export class CommunicationService {
   // ---------------------------------------------------------------------------
 
   /**
-   * Update an existing communication
+   * Update an existing communication with optimistic locking
    *
-   * @param input - Communication ID and fields to update
+   * The update only applies if the communication is still at
+   * `input.version`. Otherwise a ConflictError is thrown whose details carry
+   * the current server copy (`current`) so the caller can merge.
+   *
+   * @param input - Communication ID, expected version and fields to update
    * @returns The updated communication
    */
   async update(input: UpdateCommunicationInput): Promise<CommunicationWithLogger> {
-    const { id, ...fields } = input;
+    const { id, version, ...fields } = input;
 
     // Build update data object with only provided fields
     const data: UpdateCommunicationData = {};
@@ -304,46 +772,258 @@ export class CommunicationService {
     if (fields.direction !== undefined) data.direction = fields.direction;
     if (fields.summary !== undefined) data.summary = fields.summary;
     if (fields.occurredAt !== undefined) data.occurred_at = fields.occurredAt;
+    if (fields.callDurationSeconds !== undefined) {
+      data.call_duration_seconds = fields.callDurationSeconds;
+    }
+    if (fields.callOutcome !== undefined) data.call_outcome = fields.callOutcome;
+    if (fields.callNumber !== undefined) {
+      data.call_number = fields.callNumber ? normalizePhone(fields.callNumber) : null;
+    }
+    if (fields.estimateId !== undefined) data.estimate_id = fields.estimateId;
+    if (fields.calendarEventId !== undefined) data.calendar_event_id = fields.calendarEventId;
+    if (fields.propertyId !== undefined) data.property_id = fields.propertyId;
+    if (fields.poolId !== undefined) data.pool_id = fields.poolId;
+
+    // Changing away from a call drops the call-only fields
+    if (fields.type !== undefined && fields.type !== 'call') {
+      data.call_duration_seconds = null;
+      data.call_outcome = null;
+      data.call_number = null;
+    }
 
     const { data: communication, error } = await this.supabase
       .from('communications')
       .update(data)
       .eq('id', id)
-      .select(
-        `
-        *,
-        logged_by_admin:admins!communications_logged_by_fkey(
-          id,
-          email,
-          full_name
-        )
-      `
-      )
-      .single();
+      .eq('version', version)
+      .is('deleted_at', null)
+      .select(COMMUNICATION_SELECT)
+      .maybeSingle();
 
     if (error) {
       console.error('Failed to update communication:', error);
       throw new Error(`Failed to update communication: ${error.message}`);
     }
 
+    if (!communication) {
+      // No row matched: gone, trashed, or changed since the edit started
+      const current = await this.getById(id);
+
+      if (!current || current.deleted_at) {
+        throw new NotFoundError('Communication');
+      }
+
+      throw new ConflictError(
+        'This communication was changed by someone else. Review their changes and save again.',
+        { current }
+      );
+    }
+
     return communication as CommunicationWithLogger;
   }
 
+  /**
+   * Manually set a thread's status
+   *
+   * The status is recalculated automatically whenever a communication is
+   * added, so this is only needed to close out a conversation that needs
+   * no reply.
+   *
+   * @param threadId - Thread ID
+   * @param status - New status
+   * @returns The updated thread record
+   */
+  async updateThreadStatus(
+    threadId: string,
+    status: CommunicationThreadStatus
+  ): Promise<CommunicationThreadRow> {
+    const { data, error } = await this.supabase
+      .from('communication_threads')
+      .update({ status })
+      .eq('id', threadId)
+      .select()
+      .single();
+
+    if (error) {
+      console.error('Failed to update thread status:', error);
+      throw new Error(`Failed to update thread status: ${error.message}`);
+    }
+
+    return data as CommunicationThreadRow;
+  }
+
+  /**
+   * Clear the needs-callback flag on a missed call
+   *
+   * Logging an outbound call to the customer clears it automatically;
+   * this covers callbacks made some other way (text, email, in person).
+   *
+   * @param id - Communication ID
+   * @returns The updated communication with logger info
+   */
+  async completeCallback(id: string): Promise<CommunicationWithLogger> {
+    const { data, error } = await this.supabase
+      .from('communications')
+      .update({ needs_callback: false })
+      .eq('id', id)
+      .select(COMMUNICATION_SELECT)
+      .single();
+
+    if (error) {
+      console.error('Failed to complete callback:', error);
+      throw new Error(`Failed to complete callback: ${error.message}`);
+    }
+
+    return data as CommunicationWithLogger;
+  }
+
+  /**
+   * Record the delivery state of a sent message
+   *
+   * A message already marked delivered or failed (e.g. by a callback that
+   * beat the send result) keeps that state; only the provider's message ID
+   * is stored.
+   *
+   * @param id - Communication ID
+   * @param update - Status reported by the provider
+   * @returns The updated communication with logger info
+   */
+  async updateDeliveryStatus(
+    id: string,
+    update: DeliveryStatusUpdate
+  ): Promise<CommunicationWithLogger> {
+    let query = this.supabase
+      .from('communications')
+      .update({
+        delivery_status: update.status,
+        ...(update.providerMessageId !== undefined
+          ? { delivery_provider_message_id: update.providerMessageId }
+          : {}),
+        delivery_error: update.error?.slice(0, 1000) ?? null,
+        delivery_updated_at: new Date().toISOString(),
+      })
+      .eq('id', id);
+
+    if (update.status === 'sent') {
+      query = query.in('delivery_status', ['pending', 'sent']);
+    }
+
+    const { data, error } = await query.select(COMMUNICATION_SELECT).maybeSingle();
+
+    if (error) {
+      console.error('Failed to update delivery status:', error);
+      throw new Error(`Failed to update delivery status: ${error.message}`);
+    }
+
+    if (data) {
+      return data as CommunicationWithLogger;
+    }
+
+    if (update.providerMessageId === undefined) {
+      const communication = await this.getById(id);
+      if (!communication) {
+        throw new NotFoundError('Communication');
+      }
+      return communication;
+    }
+
+    const { data: current, error: currentError } = await this.supabase
+      .from('communications')
+      .update({ delivery_provider_message_id: update.providerMessageId })
+      .eq('id', id)
+      .select(COMMUNICATION_SELECT)
+      .maybeSingle();
+
+    if (currentError) {
+      console.error('Failed to update delivery status:', currentError);
+      throw new Error(`Failed to update delivery status: ${currentError.message}`);
+    }
+
+    if (!current) {
+      throw new NotFoundError('Communication');
+    }
+
+    return current as CommunicationWithLogger;
+  }
+
+  /**
+   * Apply a provider status callback to the matching communication
+   *
+   * A message already marked delivered or failed is not moved back to
+   * sent by a late, out-of-order callback.
+   *
+   * @param provider - Provider name stored on the communication
+   * @param providerMessageId - Provider's message ID
+   * @param update - Reported status and error
+   * @returns True if a communication has this message ID, false if none
+   *   does yet (the send may not have stored it)
+   */
+  async applyDeliveryCallback(
+    provider: string,
+    providerMessageId: string,
+    update: Omit<DeliveryStatusUpdate, 'providerMessageId'>
+  ): Promise<boolean> {
+    let query = this.supabase
+      .from('communications')
+      .update({
+        delivery_status: update.status,
+        delivery_error: update.error?.slice(0, 1000) ?? null,
+        delivery_updated_at: new Date().toISOString(),
+      })
+      .eq('delivery_provider', provider)
+      .eq('delivery_provider_message_id', providerMessageId);
+
+    if (update.status === 'sent') {
+      query = query.in('delivery_status', ['pending', 'sent']);
+    }
+
+    const { data, error } = await query.select('id');
+
+    if (error) {
+      console.error('Failed to apply delivery callback:', error);
+      throw new Error(`Failed to apply delivery callback: ${error.message}`);
+    }
+
+    if ((data ?? []).length > 0 || update.status !== 'sent') {
+      return (data ?? []).length > 0;
+    }
+
+    // A late "sent" for a message already delivered or failed still matched
+    const { count, error: lookupError } = await this.supabase
+      .from('communications')
+      .select('id', { count: 'exact', head: true })
+      .eq('delivery_provider', provider)
+      .eq('delivery_provider_message_id', providerMessageId);
+
+    if (lookupError) {
+      console.error('Failed to apply delivery callback:', lookupError);
+      throw new Error(`Failed to apply delivery callback: ${lookupError.message}`);
+    }
+
+    return (count ?? 0) > 0;
+  }
+
   // ---------------------------------------------------------------------------
   // Delete
   // ---------------------------------------------------------------------------
 
   /**
-   * Delete a communication (hard delete)
+   * Move a communication to the trash (soft delete)
+   *
+   * It disappears from lists, search and stats and is permanently deleted
+   * with its attachments once it has been in the trash for
+   * COMMUNICATION_TRASH_RETENTION_DAYS.
    *
    * @param id - Communication ID to delete
+   * @param deletedBy - Admin ID deleting it
    * @returns True if deleted successfully
    */
-  async delete(id: string): Promise<boolean> {
+  async delete(id: string, deletedBy: string): Promise<boolean> {
     const { error } = await this.supabase
       .from('communications')
-      .delete()
-      .eq('id', id);
+      .update({ deleted_at: new Date().toISOString(), deleted_by: deletedBy })
+      .eq('id', id)
+      .is('deleted_at', null);
 
     if (error) {
       console.error('Failed to delete communication:', error);
@@ -353,69 +1033,454 @@ export class CommunicationService {
     return true;
   }
 
+  /**
+   * Restore a communication from the trash
+   *
+   * @param id - Communication ID
+   * @returns The restored communication with logger info
+   */
+  async restore(id: string): Promise<CommunicationWithLogger> {
+    const { data, error } = await this.supabase
+      .from('communications')
+      .update({ deleted_at: null, deleted_by: null })
+      .eq('id', id)
+      .not('deleted_at', 'is', null)
+      .select(COMMUNICATION_SELECT)
+      .maybeSingle();
+
+    if (error) {
+      console.error('Failed to restore communication:', error);
+      throw new Error(`Failed to restore communication: ${error.message}`);
+    }
+
+    if (!data) {
+      throw new NotFoundError('Communication');
+    }
+
+    return data as CommunicationWithLogger;
+  }
+
+  /**
+   * List trashed communications, most recently deleted first
+   *
+   * @param input - Optional customer and pagination options
+   * @returns Paginated trash with each communication's purge date
+   */
+  async listTrash(input: ListCommunicationTrashInput): Promise<CommunicationTrashListResult> {
+    const { customerId, limit = 25, cursor } = input;
+
+    const scope = `communication_trash:${customerId ?? 'all'}`;
+    const position = cursor ? decodeCursor(cursor, scope) : null;
+    const reversed = isReversed(position);
+
+    let query = this.supabase
+      .from('communications')
+      .select(TRASHED_COMMUNICATION_SELECT, { count: 'exact' })
+      .not('deleted_at', 'is', null)
+      .order('deleted_at', { ascending: reversed })
+      .order('id', { ascending: reversed })
+      .limit(limit + 1); // Fetch one extra to check if there are more
+
+    if (customerId) {
+      query = query.eq('customer_id', customerId);
+    }
+
+    if (position) {
+      query = query.or(keysetFilter('deleted_at', position));
+    }
+
+    const { data, error, count } = await query;
+
+    if (error) {
+      console.error('Failed to list trashed communications:', error);
+      throw new Error(`Failed to list trashed communications: ${error.message}`);
+    }
+
+    const rows = (data ?? []) as unknown as Omit<TrashedCommunication, 'purge_at'>[];
+    const { items, ...page } = buildKeysetPage(rows, limit, position, scope, (row) => ({
+      value: row.deleted_at,
+      id: row.id,
+    }));
+
+    const retentionMs = COMMUNICATION_TRASH_RETENTION_DAYS * 24 * 60 * 60 * 1000;
+
+    return {
+      items: items.map((row) => ({
+        ...row,
+        purge_at: new Date(new Date(row.deleted_at).getTime() + retentionMs).toISOString(),
+      })),
+      ...page,
+      total: count ?? undefined,
+      retentionDays: COMMUNICATION_TRASH_RETENTION_DAYS,
+    };
+  }
+
+  /**
+   * Permanently delete a trashed communication
+   *
+   * Also deletes any attachments linked to the communication. Only
+   * communications already in the trash can be deleted this way.
+   *
+   * @param id - Communication ID to delete
+   * @returns True if deleted successfully
+   */
+  async deletePermanently(id: string): Promise<boolean> {
+    const { data, error } = await this.supabase
+      .from('communications')
+      .select('id')
+      .eq('id', id)
+      .not('deleted_at', 'is', null)
+      .maybeSingle();
+
+    if (error) {
+      console.error('Failed to load trashed communication:', error);
+      throw new Error(`Failed to delete communication: ${error.message}`);
+    }
+
+    if (!data) {
+      throw new NotFoundError('Trashed communication');
+    }
+
+    await this.hardDelete([id]);
+
+    return true;
+  }
+
+  /**
+   * Permanently delete communications trashed more than `retentionDays` ago
+   *
+   * Run on a schedule (see /api/cron/purge-communications).
+   *
+   * @param retentionDays - Days a communication stays in the trash
+   * @returns Number of communications deleted
+   */
+  async purgeTrash(retentionDays: number = COMMUNICATION_TRASH_RETENTION_DAYS): Promise<number> {
+    const cutoff = new Date(Date.now() - retentionDays * 24 * 60 * 60 * 1000).toISOString();
+    let purged = 0;
+
+    for (;;) {
+      const { data, error } = await this.supabase
+        .from('communications')
+        .select('id')
+        .lt('deleted_at', cutoff)
+        .order('deleted_at', { ascending: true })
+        .limit(PURGE_BATCH_SIZE);
+
+      if (error) {
+        console.error('Failed to load communications to purge:', error);
+        throw new Error(`Failed to purge communications: ${error.message}`);
+      }
+
+      const ids = (data ?? []).map((row) => row.id);
+      if (ids.length === 0) {
+        return purged;
+      }
+
+      await this.hardDelete(ids);
+      purged += ids.length;
+    }
+  }
+
   // ---------------------------------------------------------------------------
   // Helpers
   // ---------------------------------------------------------------------------
 
   /**
-   * Encode pagination cursor
+   * Delete communications and their attachments for good
    */
-  private encodeCursor(data: { occurredAt: string; id: string }): string {
-    return Buffer.from(JSON.stringify(data)).toString('base64');
+  private async hardDelete(ids: string[]): Promise<void> {
+    const { data: attachments, error: attachmentsError } = await this.supabase
+      .from('customer_attachments')
+      .select('id')
+      .in('communication_id', ids);
+
+    if (attachmentsError) {
+      console.error('Failed to load communication attachments:', attachmentsError);
+      throw new Error(`Failed to delete communication: ${attachmentsError.message}`);
+    }
+
+    await new AttachmentService(this.supabase).remove(
+      (attachments ?? []).map((attachment) => attachment.id)
+    );
+
+    const { error } = await this.supabase
+      .from('communications')
+      .delete()
+      .in('id', ids);
+
+    if (error) {
+      console.error('Failed to delete communication:', error);
+      throw new Error(`Failed to delete communication: ${error.message}`);
+    }
   }
 
   /**
-   * Decode pagination cursor
+   * Create the follow_up calendar event for a newly logged communication
    */
-  private decodeCursor(cursor: string): { occurredAt: string; id: string } | null {
-    try {
-      const decoded = Buffer.from(cursor, 'base64').toString('utf-8');
-      return JSON.parse(decoded);
-    } catch {
-      return null;
+  private async scheduleFollowUp(
+    communication: CommunicationWithLogger,
+    followUp: FollowUpInput,
+    createdBy: string
+  ): Promise<void> {
+    let title = followUp.title;
+
+    if (!title) {
+      const { data: customer } = await this.supabase
+        .from('customers')
+        .select('name')
+        .eq('id', communication.customer_id)
+        .single();
+
+      title = customer ? `Follow up with ${customer.name}` : 'Follow up';
+    }
+
+    const endDatetime =
+      followUp.endDatetime ??
+      new Date(
+        new Date(followUp.startDatetime).getTime() + DEFAULT_FOLLOW_UP_MINUTES * 60 * 1000
+      ).toISOString();
+
+    await new CalendarService(this.supabase).create(
+      {
+        customerId: communication.customer_id,
+        title,
+        description: followUp.description || communication.summary.slice(0, 2000),
+        eventType: 'follow_up',
+        startDatetime: followUp.startDatetime,
+        endDatetime,
+        allDay: followUp.allDay,
+        communicationId: communication.id,
+      },
+      createdBy
+    );
+  }
+
+  /**
+   * Load the communications for a page of threads and nest them,
+   * oldest first within each thread
+   */
+  private async attachCommunications(
+    threads: CommunicationThreadRow[],
+    includeDeleted = false
+  ): Promise<CommunicationThread[]> {
+    if (threads.length === 0) {
+      return [];
+    }
+
+    let query = this.supabase
+      .from('communications')
+      .select(COMMUNICATION_SELECT)
+      .in(
+        'thread_id',
+        threads.map((thread) => thread.id)
+      )
+      .order('occurred_at', { ascending: true });
+
+    if (!includeDeleted) {
+      query = query.is('deleted_at', null);
+    }
+
+    const { data, error } = await query;
+
+    if (error) {
+      console.error('Failed to load thread communications:', error);
+      throw new Error(`Failed to load thread communications: ${error.message}`);
+    }
+
+    const byThread = new Map<string, CommunicationWithLogger[]>();
+    for (const communication of data as CommunicationWithLogger[]) {
+      const list = byThread.get(communication.thread_id) ?? [];
+      list.push(communication);
+      byThread.set(communication.thread_id, list);
+    }
+
+    return threads.map((thread) => ({
+      ...thread,
+      communications: byThread.get(thread.id) ?? [],
+    }));
+  }
+
+  /**
+   * Whether a customer has any of the given tags
+   */
+  private async customerHasAnyTag(customerId: string, tagIds: string[]): Promise<boolean> {
+    const { count, error } = await this.supabase
+      .from('customer_tag_links')
+      .select('tag_id', { count: 'exact', head: true })
+      .eq('customer_id', customerId)
+      .in('tag_id', tagIds);
+
+    if (error) {
+      console.error('Failed to check customer tags:', error);
+      throw new Error(`Failed to list communications: ${error.message}`);
     }
+
+    return (count ?? 0) > 0;
   }
 
   /**
    * Get communication statistics for a customer
    *
-   * @param customerId - Customer ID
-   * @returns Communication counts by type
+   * Counts are aggregated in the database (get_communication_stats and
+   * get_communication_monthly_counts), optionally within a date range.
+   *
+   * @param input - Customer and optional date range
+   * @returns Counts by type and direction, call metrics, a monthly series
+   *   and the last contact dates
    */
-  async getStats(customerId: string): Promise<{
-    total: number;
-    byType: Record<Communication['type'], number>;
-    byDirection: Record<Communication['direction'], number>;
-  }> {
-    const { data, error } = await this.supabase
-      .from('communications')
-      .select('type, direction')
-      .eq('customer_id', customerId);
+  async getStats(input: CommunicationStatsInput): Promise<CommunicationStats> {
+    const args = {
+      p_customer_id: input.customerId,
+      p_from: input.from ?? null,
+      p_to: input.to ?? null,
+      p_include_deleted: input.includeDeleted ?? false,
+    };
+
+    const [groups, months] = await Promise.all([
+      this.supabase.rpc('get_communication_stats', args),
+      this.supabase.rpc('get_communication_monthly_counts', {
+        ...args,
+        p_timezone: DEFAULT_TIMEZONE,
+      }),
+    ]);
 
+    const error = groups.error ?? months.error;
     if (error) {
       console.error('Failed to get communication stats:', error);
       throw new Error(`Failed to get communication stats: ${error.message}`);
     }
 
-    const byType: Record<Communication['type'], number> = {
-      call: 0,
-      text: 0,
-      email: 0,
-    };
-    const byDirection: Record<Communication['direction'], number> = {
-      inbound: 0,
-      outbound: 0,
-    };
+    // Start every bucket at zero so new channels always appear in the result
+    const byType = Object.fromEntries(
+      COMMUNICATION_TYPES.map((type) => [type, 0])
+    ) as Record<Communication['type'], number>;
+    const byDirection = Object.fromEntries(
+      COMMUNICATION_DIRECTIONS.map((direction) => [direction, 0])
+    ) as Record<Communication['direction'], number>;
+
+    const byOutcome = Object.fromEntries(
+      CALL_OUTCOMES.map((outcome) => [outcome, 0])
+    ) as Record<NonNullable<Communication['call_outcome']>, number>;
+    let total = 0;
+    let callTotal = 0;
+    let connectedDurationSum = 0;
+    let connectedDurationCount = 0;
+    let needsCallback = 0;
+    let lastContactAt: string | null = null;
+    let lastInboundAt: string | null = null;
 
-    for (const comm of data) {
-      byType[comm.type]++;
-      byDirection[comm.direction]++;
+    for (const group of groups.data ?? []) {
+      total += group.total;
+      byType[group.type] += group.total;
+      byDirection[group.direction] += group.total;
+
+      if (!lastContactAt || group.last_occurred_at > lastContactAt) {
+        lastContactAt = group.last_occurred_at;
+      }
+      if (
+        group.direction === 'inbound' &&
+        (!lastInboundAt || group.last_occurred_at > lastInboundAt)
+      ) {
+        lastInboundAt = group.last_occurred_at;
+      }
+
+      if (group.type === 'call') {
+        callTotal += group.total;
+        if (group.call_outcome) {
+          byOutcome[group.call_outcome] += group.total;
+        }
+        connectedDurationSum += Number(group.connected_duration_sum);
+        connectedDurationCount += group.connected_duration_count;
+        needsCallback += group.needs_callback;
+      }
     }
 
+    const withOutcome = Object.values(byOutcome).reduce((sum, n) => sum + n, 0);
+
     return {
-      total: data.length,
+      total,
       byType,
       byDirection,
+      calls: {
+        total: callTotal,
+        withOutcome,
+        connected: byOutcome.connected,
+        answerRate: withOutcome > 0 ? byOutcome.connected / withOutcome : null,
+        averageDurationSeconds:
+          connectedDurationCount > 0
+            ? Math.round(connectedDurationSum / connectedDurationCount)
+            : null,
+        byOutcome,
+        needsCallback,
+      },
+      monthly: this.fillMonths(months.data ?? []),
+      lastContactAt,
+      lastInboundAt,
     };
   }
+
+  /**
+   * Turn per-month, per-direction rows into a continuous monthly series
+   */
+  private fillMonths(
+    rows: { month: string; direction: Communication['direction']; total: number }[]
+  ): MonthlyCommunicationCount[] {
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const counts = new Map<string, MonthlyCommunicationCount>();
+    for (const row of rows) {
+      const month = row.month.slice(0, 7);
+      const entry = counts.get(month) ?? { month, inbound: 0, outbound: 0, total: 0 };
+      entry[row.direction] += row.total;
+      entry.total += row.total;
+      counts.set(month, entry);
+    }
+
+    const keys = [...counts.keys()].sort();
+    let [year, month] = keys[0].split('-').map(Number);
+    const [lastYear, lastMonth] = keys[keys.length - 1].split('-').map(Number);
+
+    const series: MonthlyCommunicationCount[] = [];
+    while (year < lastYear || (year === lastYear && month <= lastMonth)) {
+      const key = `${year}-${String(month).padStart(2, '0')}`;
+      series.push(counts.get(key) ?? { month: key, inbound: 0, outbound: 0, total: 0 });
+
+      month++;
+      if (month > 12) {
+        month = 1;
+        year++;
+      }
+    }
+
+    return series;
+  }
+}
+
+// =============================================================================
+// Filter Helpers
+// =============================================================================
+
+/**
+ * Combine a single-value filter with its multi-select list; null when empty
+ */
+export function combineFilter<T extends string>(single: T | undefined, list: T[] | undefined): T[] | null {
+  const values = [...new Set([...(list ?? []), ...(single ? [single] : [])])];
+  return values.length > 0 ? values : null;
+}
+
+/**
+ * Inner-joined communications embed used when filtering threads. With an
+ * attachments filter the attachments are embedded too: inner-joined to keep
+ * communications that have them, left-joined (and filtered to null) for
+ * those without.
+ */
+function filteredEmbed(hasAttachments: boolean | undefined): string {
+  if (hasAttachments === undefined) {
+    return 'communications!inner(id)';
+  }
+
+  const join = hasAttachments ? '!inner' : '';
+  return `communications!inner(id, attachments:customer_attachments!customer_attachments_communication_id_fkey${join}(id))`;
 }
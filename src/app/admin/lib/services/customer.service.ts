@@ -248,6 +248,7 @@ export class CustomerService {
           `
           )
           .eq('customer_id', id)
+          .is('deleted_at', null)
           .order('occurred_at', { ascending: false })
           .limit(50),
 
@@ -17,6 +17,8 @@ import type {
   LineItem,
   LineItemInput,
 } from '@/lib/validations/estimate';
+import type { KeysetPageInfo } from '@/lib/types/api';
+import { buildKeysetPage, decodeCursor, isReversed, keysetFilter } from '@/lib/utils/cursor';
 import {
   isValidStatusTransition,
   calculateEstimateTotals,
@@ -50,21 +52,26 @@ export interface EstimateWithCustomer extends Estimate {
 /**
  * Estimate list result with pagination info
  */
-export interface EstimateListResult {
+export interface EstimateListResult extends KeysetPageInfo {
   estimates: EstimateWithCustomer[];
-  hasMore: boolean;
-  nextCursor: string | null;
+  total: number;
 }
 
 /**
  * Options for listing estimates
+ *
+ * A cursor pages newest first on (created_at, id) and takes precedence
+ * over offset; other sort orders page by offset.
  */
 export interface EstimateListOptions {
   limit?: number;
+  offset?: number;
   cursor?: string;
   customerId?: string;
-  status?: EstimateStatus;
+  status?: EstimateStatus | EstimateStatus[];
   search?: string;
+  sortBy?: 'created_at' | 'estimate_number' | 'total_cents' | 'valid_until';
+  sortOrder?: 'asc' | 'desc';
 }
 
 export class EstimateService {
@@ -260,12 +267,19 @@ export class EstimateService {
     const {
       limit = 25,
       offset = 0,
+      cursor,
       customerId,
       status,
       sortBy = 'created_at',
       sortOrder = 'desc',
     } = options;
 
+    // Keyset paging applies to the default newest-first order
+    const keyset = sortBy === 'created_at' && sortOrder === 'desc' && (!!cursor || offset === 0);
+    const scope = `estimates:${customerId ?? 'all'}`;
+    const position = cursor && keyset ? decodeCursor(cursor, scope) : null;
+    const reversed = isReversed(position);
+
     // Build query
     let query = this.supabase
       .from('estimates')
@@ -300,11 +314,25 @@ export class EstimateService {
       }
     }
 
-    // Apply sorting
-    query = query.order(sortBy, { ascending: sortOrder === 'asc' });
+    if (keyset) {
+      // Apply cursor-based pagination on (created_at, id)
+      if (position) {
+        query = query.or(keysetFilter('created_at', position));
+      }
 
-    // Apply pagination
-    query = query.range(offset, offset + limit - 1);
+      query = query
+        .order('created_at', { ascending: reversed })
+        .order('id', { ascending: reversed })
+        .limit(limit + 1);
+    } else {
+      // Apply sorting
+      query = query
+        .order(sortBy, { ascending: sortOrder === 'asc' })
+        .order('id', { ascending: sortOrder === 'asc' });
+
+      // Apply pagination
+      query = query.range(offset, offset + limit - 1);
+    }
 
     const { data, error, count } = await query;
 
@@ -315,10 +343,25 @@ export class EstimateService {
 
     const total = count ?? 0;
 
+    if (keyset) {
+      const { items, ...page } = buildKeysetPage(
+        (data ?? []) as unknown as EstimateSummary[],
+        limit,
+        position,
+        scope,
+        (estimate) => ({ value: estimate.created_at, id: estimate.id })
+      );
+
+      return { estimates: items, total, ...page };
+    }
+
     return {
       estimates: (data ?? []) as unknown as EstimateSummary[],
       total,
       hasMore: offset + limit < total,
+      hasPrevious: offset > 0,
+      nextCursor: null,
+      prevCursor: null,
     };
   }
 
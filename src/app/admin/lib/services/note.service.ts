@@ -7,6 +7,8 @@
 
 import type { SupabaseClient } from '@supabase/supabase-js';
 import type { Database, CustomerNote, CustomerAttachment, Admin } from '@/lib/types/database';
+import type { KeysetPageInfo } from '@/lib/types/api';
+import { buildKeysetPage, decodeCursor, isReversed, keysetFilter } from '@/lib/utils/cursor';
 
 type DbClient = SupabaseClient<Database>;
 
@@ -31,10 +33,8 @@ export interface NoteWithDetails extends NoteWithAuthor {
 /**
  * Note list result with pagination info
  */
-export interface NoteListResult {
+export interface NoteListResult extends KeysetPageInfo {
   notes: NoteWithAuthor[];
-  hasMore: boolean;
-  nextCursor: string | null;
 }
 
 export class NoteService {
@@ -144,6 +144,9 @@ export class NoteService {
     } = {}
   ): Promise<NoteListResult> {
     const { limit = 25, cursor } = options;
+    const scope = `customer_notes:${customerId}`;
+    const position = cursor ? decodeCursor(cursor, scope) : null;
+    const reversed = isReversed(position);
 
     let query = this.supabase
       .from('customer_notes')
@@ -156,17 +159,13 @@ export class NoteService {
         )
       `)
       .eq('customer_id', customerId)
-      .order('created_at', { ascending: false })
+      .order('created_at', { ascending: reversed })
+      .order('id', { ascending: reversed })
       .limit(limit + 1);
 
-    // Apply cursor-based pagination
-    if (cursor) {
-      try {
-        const decoded = JSON.parse(Buffer.from(cursor, 'base64').toString());
-        query = query.lt('created_at', decoded.createdAt);
-      } catch (e) {
-        // Invalid cursor, ignore
-      }
+    // Apply cursor-based pagination on (created_at, id)
+    if (position) {
+      query = query.or(keysetFilter('created_at', position));
     }
 
     const { data, error } = await query;
@@ -176,8 +175,13 @@ export class NoteService {
       throw new Error(`Failed to list notes: ${error.message}`);
     }
 
-    const hasMore = data.length > limit;
-    const notes = hasMore ? data.slice(0, -1) : data;
+    const { items: notes, ...page } = buildKeysetPage(
+      data as any[],
+      limit,
+      position,
+      scope,
+      (note) => ({ value: note.created_at, id: note.id })
+    );
 
     // Transform the nested admin data
     const transformedNotes: NoteWithAuthor[] = notes.map((note: any) => {
@@ -188,19 +192,9 @@ export class NoteService {
       };
     });
 
-    // Generate next cursor
-    let nextCursor: string | null = null;
-    if (hasMore && notes.length > 0) {
-      const lastNote = notes[notes.length - 1];
-      nextCursor = Buffer.from(
-        JSON.stringify({ createdAt: lastNote.created_at })
-      ).toString('base64');
-    }
-
     return {
       notes: transformedNotes,
-      hasMore,
-      nextCursor,
+      ...page,
     };
   }
 
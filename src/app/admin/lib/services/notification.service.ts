@@ -0,0 +1,243 @@
+/**
+ * Notification Service
+ *
+ * @file src/lib/services/notification.service.ts
+ *
+ * Sends email and text messages to customers through the configured
+ * NotificationProvider and logs every attempt as an outbound
+ * communication. The communication is created before sending (status
+ * 'pending') so a message is never sent without a record, then updated
+ * with the provider's delivery result.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { Database } from '@/lib/types/database';
+import type { CommunicationWithLogger } from '@/lib/types/communication';
+import {
+  getEmailProvider,
+  getSmsProvider,
+  type EmailProvider,
+  type SmsProvider,
+  type DeliveryResult,
+} from '@/lib/notifications';
+import { CommunicationService } from './communication.service';
+import { TemplateService } from './template.service';
+import { ValidationError } from '@/lib/utils/errors';
+import {
+  MAX_EMAIL_BODY_LENGTH,
+  MAX_SMS_BODY_LENGTH,
+  SINGLE_LINE_PATTERN,
+  type SendEmailInput,
+  type SendSmsInput,
+  type SendMessageInput,
+} from '@/lib/validations/notification';
+import type { SendTemplateInput } from '@/lib/validations/template';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+export interface NotificationProviders {
+  email?: EmailProvider;
+  sms?: SmsProvider;
+}
+
+interface SendOptions {
+  /** Template the message was rendered from */
+  templateId?: string;
+}
+
+// =============================================================================
+// Notification Service
+// =============================================================================
+
+export class NotificationService {
+  private supabase: SupabaseClientType;
+  private communications: CommunicationService;
+  private providers: NotificationProviders;
+
+  /**
+   * @param supabase - Supabase client
+   * @param providers - Override providers (tests); defaults come from the environment
+   */
+  constructor(supabase: SupabaseClientType, providers: NotificationProviders = {}) {
+    this.supabase = supabase;
+    this.communications = new CommunicationService(supabase);
+    this.providers = providers;
+  }
+
+  /**
+   * Send a message on the channel given in the input
+   *
+   * @param input - Validated message
+   * @param sentBy - Admin ID sending the message
+   * @returns The logged communication with its delivery status
+   */
+  async send(input: SendMessageInput, sentBy: string): Promise<CommunicationWithLogger> {
+    return input.channel === 'email'
+      ? this.sendEmail(input, sentBy)
+      : this.sendSms(input, sentBy);
+  }
+
+  /**
+   * Render a template for a customer and send it on the template's channel
+   *
+   * @param input - Template, customer and records to fill merge fields from
+   * @param sentBy - Admin ID sending the message
+   * @returns The logged communication, linked to the template
+   * @throws ValidationError if the template has unknown or missing variables
+   */
+  async sendTemplate(input: SendTemplateInput, sentBy: string): Promise<CommunicationWithLogger> {
+    const { templateId, parentId, ...context } = input;
+    const rendered = await new TemplateService(this.supabase).render(templateId, context);
+    const options = { templateId: rendered.templateId };
+
+    // Merge fields can push a message past the channel limit
+    const max = rendered.channel === 'email' ? MAX_EMAIL_BODY_LENGTH : MAX_SMS_BODY_LENGTH;
+    if (rendered.body.length > max) {
+      throw new ValidationError(`The filled-in message is over ${max} characters`);
+    }
+    // A merge field value with a line break must not reach the Subject header
+    if (rendered.subject && !SINGLE_LINE_PATTERN.test(rendered.subject)) {
+      throw new ValidationError('The filled-in subject must be a single line');
+    }
+
+    return rendered.channel === 'email'
+      ? this.sendEmail(
+          {
+            channel: 'email',
+            customerId: input.customerId,
+            parentId,
+            subject: rendered.subject ?? '',
+            body: rendered.body,
+          },
+          sentBy,
+          options
+        )
+      : this.sendSms(
+          { channel: 'sms', customerId: input.customerId, parentId, body: rendered.body },
+          sentBy,
+          options
+        );
+  }
+
+  /**
+   * Email a customer at their address on file
+   *
+   * @param input - Validated email
+   * @param sentBy - Admin ID sending the email
+   * @param options - Template the message came from, if any
+   * @returns The logged communication with its delivery status
+   */
+  async sendEmail(
+    input: SendEmailInput,
+    sentBy: string,
+    options: SendOptions = {}
+  ): Promise<CommunicationWithLogger> {
+    const customer = await this.getRecipient(input.customerId);
+    if (!customer.email) {
+      throw new Error('This customer has no email address');
+    }
+
+    const provider = this.providers.email ?? getEmailProvider();
+
+    const communication = await this.communications.create(
+      {
+        customerId: input.customerId,
+        type: 'email',
+        direction: 'outbound',
+        summary: `Subject: ${input.subject}\n\n${input.body}`,
+        occurredAt: new Date().toISOString(),
+        parentId: input.parentId,
+      },
+      sentBy,
+      { deliveryProvider: provider.name, templateId: options.templateId }
+    );
+
+    const result = await deliver(() =>
+      provider.send({ to: customer.email!, subject: input.subject, text: input.body })
+    );
+
+    return this.communications.updateDeliveryStatus(communication.id, result);
+  }
+
+  /**
+   * Text a customer at their phone number on file
+   *
+   * @param input - Validated text message
+   * @param sentBy - Admin ID sending the message
+   * @param options - Template the message came from, if any
+   * @returns The logged communication with its delivery status
+   */
+  async sendSms(
+    input: SendSmsInput,
+    sentBy: string,
+    options: SendOptions = {}
+  ): Promise<CommunicationWithLogger> {
+    const customer = await this.getRecipient(input.customerId);
+
+    const provider = this.providers.sms ?? getSmsProvider();
+
+    const communication = await this.communications.create(
+      {
+        customerId: input.customerId,
+        type: 'text',
+        direction: 'outbound',
+        summary: input.body,
+        occurredAt: new Date().toISOString(),
+        parentId: input.parentId,
+      },
+      sentBy,
+      { deliveryProvider: provider.name, templateId: options.templateId }
+    );
+
+    const result = await deliver(() =>
+      provider.send({ to: customer.phone_normalized, body: input.body })
+    );
+
+    return this.communications.updateDeliveryStatus(communication.id, result);
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  private async getRecipient(customerId: string) {
+    const { data, error } = await this.supabase
+      .from('customers')
+      .select('id, name, email, phone_normalized')
+      .eq('id', customerId)
+      .is('deleted_at', null)
+      .single();
+
+    if (error || !data) {
+      throw new Error('Customer not found');
+    }
+
+    return data;
+  }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/**
+ * Run a provider send, turning unexpected exceptions into a failed result
+ * so the communication still records what happened
+ */
+async function deliver(send: () => Promise<DeliveryResult>): Promise<DeliveryResult> {
+  try {
+    return await send();
+  } catch (error) {
+    return {
+      status: 'failed',
+      providerMessageId: null,
+      error: error instanceof Error ? error.message : 'Send failed',
+    };
+  }
+}
@@ -0,0 +1,223 @@
+/**
+ * Response Queue Service
+ *
+ * @file src/lib/services/response-queue.service.ts
+ *
+ * The "needs response" queue: customers whose inbound communications have
+ * no later outbound communication. Each customer appears once, keyed by
+ * their oldest unanswered message, and is overdue once the response SLA
+ * (in business hours) has passed.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { Database, NeedsResponseQueueRow } from '@/lib/types/database';
+import type {
+  CommunicationWithCustomer,
+  NeedsResponseEntry,
+  NeedsResponseQueue,
+} from '@/lib/types/communication';
+import { addBusinessHours } from '@/lib/utils/business-hours';
+import { NotFoundError } from '@/lib/utils/errors';
+import {
+  DEFAULT_RESPONSE_SLA_HOURS,
+  type NeedsResponseQueueInput,
+} from '@/lib/validations/communication';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+/**
+ * Configured response SLA in business hours
+ */
+export const RESPONSE_SLA_HOURS =
+  Number(process.env.RESPONSE_SLA_BUSINESS_HOURS) > 0
+    ? Number(process.env.RESPONSE_SLA_BUSINESS_HOURS)
+    : DEFAULT_RESPONSE_SLA_HOURS;
+
+const QUEUE_COMMUNICATION_SELECT = `
+  *,
+  customer:customers!communications_customer_id_fkey(
+    id,
+    name,
+    phone
+  ),
+  logged_by_admin:admins!communications_logged_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+// =============================================================================
+// Response Queue Service
+// =============================================================================
+
+export class ResponseQueueService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * Load the queue, oldest waiting customer first
+   *
+   * @param input - Queue options
+   * @param now - Reference time for overdue checks
+   * @returns Queue entries with SLA deadlines and counts
+   */
+  async getQueue(
+    input: NeedsResponseQueueInput,
+    now: Date = new Date()
+  ): Promise<NeedsResponseQueue> {
+    const slaHours = input.slaHours ?? RESPONSE_SLA_HOURS;
+    const rows = await this.loadRows();
+
+    const deadlines = rows.map((row) => {
+      const dueAt = addBusinessHours(new Date(row.waiting_since), slaHours);
+      return { row, dueAt, isOverdue: dueAt <= now };
+    });
+
+    const overdue = deadlines.filter((deadline) => deadline.isOverdue).length;
+    const visible = input.overdueOnly
+      ? deadlines.filter((deadline) => deadline.isOverdue)
+      : deadlines;
+
+    const communications = await this.loadCommunications(
+      visible.map(({ row }) => row.communication_id)
+    );
+
+    const entries: NeedsResponseEntry[] = [];
+    for (const { row, dueAt, isOverdue } of visible) {
+      const communication = communications.get(row.communication_id);
+      if (!communication) continue;
+
+      entries.push({
+        communication,
+        unansweredCount: row.unanswered_count,
+        waitingSince: row.waiting_since,
+        dueAt: dueAt.toISOString(),
+        isOverdue,
+      });
+    }
+
+    return { entries, total: rows.length, overdue, slaHours };
+  }
+
+  /**
+   * Count customers waiting and overdue, for the dashboard
+   */
+  async getCounts(
+    now: Date = new Date()
+  ): Promise<{ total: number; overdue: number; slaHours: number }> {
+    const rows = await this.loadRows();
+    const overdue = rows.filter(
+      (row) => addBusinessHours(new Date(row.waiting_since), RESPONSE_SLA_HOURS) <= now
+    ).length;
+
+    return { total: rows.length, overdue, slaHours: RESPONSE_SLA_HOURS };
+  }
+
+  /**
+   * Hide a customer's waiting communications until a later time
+   *
+   * Only the communications waiting now are snoozed; a new inbound
+   * message puts the customer back in the queue straight away.
+   *
+   * @param customerId - Customer in the queue
+   * @param until - When they come back
+   */
+  async snooze(customerId: string, until: string): Promise<void> {
+    const ids = await this.getWaitingIds(customerId);
+
+    const { error } = await this.supabase
+      .from('communications')
+      .update({ response_snoozed_until: until })
+      .in('id', ids);
+
+    if (error) {
+      console.error('Failed to snooze communications:', error);
+      throw new Error(`Failed to snooze: ${error.message}`);
+    }
+  }
+
+  /**
+   * Remove a customer from the queue without replying
+   *
+   * @param customerId - Customer in the queue
+   * @param dismissedBy - Admin ID dismissing
+   */
+  async dismiss(customerId: string, dismissedBy: string): Promise<void> {
+    const ids = await this.getWaitingIds(customerId);
+
+    const { error } = await this.supabase
+      .from('communications')
+      .update({
+        response_dismissed_at: new Date().toISOString(),
+        response_dismissed_by: dismissedBy,
+      })
+      .in('id', ids);
+
+    if (error) {
+      console.error('Failed to dismiss communications:', error);
+      throw new Error(`Failed to dismiss: ${error.message}`);
+    }
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  private async loadRows(customerId?: string): Promise<NeedsResponseQueueRow[]> {
+    const { data, error } = await this.supabase.rpc('get_needs_response_queue', {
+      p_customer_id: customerId ?? null,
+    });
+
+    if (error) {
+      console.error('Failed to load needs-response queue:', error);
+      throw new Error(`Failed to load needs-response queue: ${error.message}`);
+    }
+
+    return (data ?? []) as NeedsResponseQueueRow[];
+  }
+
+  private async getWaitingIds(customerId: string): Promise<string[]> {
+    const [row] = await this.loadRows(customerId);
+
+    if (!row) {
+      throw new NotFoundError('Waiting communication');
+    }
+
+    return row.communication_ids;
+  }
+
+  private async loadCommunications(
+    ids: string[]
+  ): Promise<Map<string, CommunicationWithCustomer>> {
+    if (ids.length === 0) {
+      return new Map();
+    }
+
+    const { data, error } = await this.supabase
+      .from('communications')
+      .select(QUEUE_COMMUNICATION_SELECT)
+      .in('id', ids);
+
+    if (error) {
+      console.error('Failed to load queued communications:', error);
+      throw new Error(`Failed to load queued communications: ${error.message}`);
+    }
+
+    return new Map(
+      ((data ?? []) as CommunicationWithCustomer[]).map((communication) => [
+        communication.id,
+        communication,
+      ])
+    );
+  }
+}
@@ -0,0 +1,182 @@
+/**
+ * Search Service
+ *
+ * @file src/lib/services/search.service.ts
+ *
+ * Global search across customers, communications, notes, estimates and
+ * properties. Matching and scoring happen in the database (global_search);
+ * this service works out what the query looks like (phone digits, an
+ * estimate number, free text) and shapes the rows into grouped results
+ * that link to the right customer tab.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { CommunicationType, Database } from '@/lib/types/database';
+import { communicationTypeLabels } from '@/lib/types/communication';
+import {
+  searchEntityLabels,
+  type GlobalSearchGroup,
+  type GlobalSearchResponse,
+  type GlobalSearchResult,
+  type SearchQueryInterpretation,
+} from '@/lib/types/search';
+import { parseHighlights } from '@/lib/utils/highlight';
+import { extractDigits, formatPhone } from '@/lib/utils/phone';
+import { formatDateTime } from '@/lib/utils/timezone';
+import {
+  SEARCH_ENTITY_TYPES,
+  type GlobalSearchInput,
+  type SearchEntityType,
+} from '@/lib/validations/search';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+type GlobalSearchRow = Database['public']['Functions']['global_search']['Returns'][number];
+
+/** EST-0042, est 42, #42 or plain digits */
+const ESTIMATE_NUMBER_PATTERN = /^(?:est)?[\s#-]*0*(\d{1,9})$/i;
+
+/** Only characters that appear in typed phone numbers */
+const PHONE_PATTERN = /^[\d\s().+-]+$/;
+
+/** Fewest digits treated as a phone fragment */
+const MIN_PHONE_DIGITS = 4;
+
+/** Customer tab each entity type opens */
+const CUSTOMER_TAB: Record<SearchEntityType, string | null> = {
+  customer: null,
+  communication: 'communications',
+  note: 'notes',
+  estimate: 'estimates',
+  property: 'properties',
+};
+
+// =============================================================================
+// Query Interpretation
+// =============================================================================
+
+/**
+ * Work out which structured matches a query allows
+ *
+ * @example
+ * interpretQuery('EST-0042')       // { phoneDigits: null, estimateNumber: 42 }
+ * interpretQuery('(555) 123-4567') // { phoneDigits: '5551234567', estimateNumber: null }
+ */
+export function interpretQuery(query: string): SearchQueryInterpretation {
+  const trimmed = query.trim();
+  const digits = extractDigits(trimmed);
+
+  const estimateMatch = trimmed.match(ESTIMATE_NUMBER_PATTERN);
+  const estimateNumber = estimateMatch ? Number(estimateMatch[1]) : null;
+
+  return {
+    phoneDigits:
+      PHONE_PATTERN.test(trimmed) && digits.length >= MIN_PHONE_DIGITS ? digits : null,
+    estimateNumber: estimateNumber && estimateNumber > 0 ? estimateNumber : null,
+  };
+}
+
+// =============================================================================
+// Search Service
+// =============================================================================
+
+export class SearchService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * Search every entity type at once
+   *
+   * @param input - Validated query, per-type limit and optional type filter
+   * @returns Non-empty groups in display order, best match first in each
+   */
+  async globalSearch(input: GlobalSearchInput): Promise<GlobalSearchResponse> {
+    const interpretation = interpretQuery(input.query);
+
+    const { data, error } = await this.supabase.rpc('global_search', {
+      p_query: input.query,
+      p_digits: interpretation.phoneDigits,
+      p_estimate_number: interpretation.estimateNumber,
+      p_limit: input.limit,
+    });
+
+    if (error) {
+      console.error('Failed to run global search:', error);
+      throw new Error(`Failed to search: ${error.message}`);
+    }
+
+    const rows = (data ?? []) as GlobalSearchRow[];
+    const types = input.types?.length ? input.types : SEARCH_ENTITY_TYPES;
+
+    const groups: GlobalSearchGroup[] = [];
+    for (const type of SEARCH_ENTITY_TYPES) {
+      if (!types.includes(type)) continue;
+
+      const results = rows
+        .filter((row) => row.entity_type === type)
+        .sort((a, b) => b.score - a.score)
+        .map(toResult);
+
+      if (results.length > 0) {
+        groups.push({ type, label: searchEntityLabels[type], results });
+      }
+    }
+
+    return {
+      query: input.query,
+      interpretation,
+      groups,
+      total: groups.reduce((sum, group) => sum + group.results.length, 0),
+    };
+  }
+}
+
+// =============================================================================
+// Shaping Helpers
+// =============================================================================
+
+function toResult(row: GlobalSearchRow): GlobalSearchResult {
+  const tab = CUSTOMER_TAB[row.entity_type];
+
+  return {
+    type: row.entity_type,
+    id: row.entity_id,
+    customerId: row.customer_id,
+    customerName: row.customer_name,
+    ...describe(row),
+    snippet: row.headline ? parseHighlights(row.headline) : null,
+    score: row.score,
+    href: tab
+      ? `/admin/customers/${row.customer_id}?tab=${tab}`
+      : `/admin/customers/${row.customer_id}`,
+  };
+}
+
+function describe(row: GlobalSearchRow): { title: string; subtitle: string | null } {
+  switch (row.entity_type) {
+    case 'customer':
+      return { title: row.customer_name, subtitle: row.label ? formatPhone(row.label) : null };
+    case 'communication':
+      return {
+        title: `${communicationTypeLabels[row.label as CommunicationType] ?? 'Communication'} · ${row.customer_name}`,
+        subtitle: formatDateTime(row.occurred_at),
+      };
+    case 'note':
+      return {
+        title: `Note · ${row.customer_name}`,
+        subtitle: formatDateTime(row.occurred_at, 'medium'),
+      };
+    case 'estimate':
+    case 'property':
+      return { title: row.label ?? '', subtitle: row.customer_name };
+  }
+}
@@ -0,0 +1,349 @@
+/**
+ * Message Template Service
+ *
+ * @file src/lib/services/template.service.ts
+ *
+ * CRUD for message templates and rendering them for a customer. Merge field
+ * values are loaded only for the variables a template actually uses.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { Database } from '@/lib/types/database';
+import type { MessageTemplateWithAuthor, RenderedTemplate } from '@/lib/types/template';
+import {
+  extractVariables,
+  renderTemplate,
+  type TemplateValues,
+} from '@/lib/notifications/templates';
+import { formatPhone } from '@/lib/utils/phone';
+import { formatCents } from '@/lib/utils/currency';
+import { formatDateTime } from '@/lib/utils/timezone';
+import { ConflictError, NotFoundError, ValidationError } from '@/lib/utils/errors';
+import type {
+  CreateTemplateInput,
+  UpdateTemplateInput,
+  TemplateContextInput,
+} from '@/lib/validations/template';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+const TEMPLATE_SELECT = `
+  *,
+  created_by_admin:admins!message_templates_created_by_fkey(
+    id,
+    full_name
+  )
+`;
+
+// =============================================================================
+// Template Service
+// =============================================================================
+
+export class TemplateService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * List templates, optionally for one channel
+   *
+   * @param channel - Only return templates for this channel
+   * @returns Templates ordered by name
+   */
+  async list(channel?: 'email' | 'sms'): Promise<MessageTemplateWithAuthor[]> {
+    let query = this.supabase.from('message_templates').select(TEMPLATE_SELECT).order('name');
+
+    if (channel) {
+      query = query.eq('channel', channel);
+    }
+
+    const { data, error } = await query;
+
+    if (error) {
+      throw new Error(`Failed to list templates: ${error.message}`);
+    }
+
+    return (data ?? []) as MessageTemplateWithAuthor[];
+  }
+
+  /**
+   * Get a template by ID
+   *
+   * @throws NotFoundError if the template does not exist
+   */
+  async getById(id: string): Promise<MessageTemplateWithAuthor> {
+    const { data, error } = await this.supabase
+      .from('message_templates')
+      .select(TEMPLATE_SELECT)
+      .eq('id', id)
+      .maybeSingle();
+
+    if (error) {
+      throw new Error(`Failed to get template: ${error.message}`);
+    }
+
+    if (!data) {
+      throw new NotFoundError('Template');
+    }
+
+    return data as MessageTemplateWithAuthor;
+  }
+
+  /**
+   * Create a template
+   *
+   * @param input - Validated template fields
+   * @param createdBy - Admin ID creating the template
+   */
+  async create(input: CreateTemplateInput, createdBy: string): Promise<MessageTemplateWithAuthor> {
+    const { data, error } = await this.supabase
+      .from('message_templates')
+      .insert({
+        name: input.name,
+        channel: input.channel,
+        subject: input.subject,
+        body: input.body,
+        created_by: createdBy,
+      })
+      .select(TEMPLATE_SELECT)
+      .single();
+
+    if (error) {
+      throw toTemplateError('create', error);
+    }
+
+    return data as MessageTemplateWithAuthor;
+  }
+
+  /**
+   * Update a template's fields
+   *
+   * @param input - Validated template fields with ID
+   */
+  async update(input: UpdateTemplateInput): Promise<MessageTemplateWithAuthor> {
+    const { data, error } = await this.supabase
+      .from('message_templates')
+      .update({
+        name: input.name,
+        channel: input.channel,
+        subject: input.subject,
+        body: input.body,
+      })
+      .eq('id', input.id)
+      .select(TEMPLATE_SELECT)
+      .maybeSingle();
+
+    if (error) {
+      throw toTemplateError('update', error);
+    }
+
+    if (!data) {
+      throw new NotFoundError('Template');
+    }
+
+    return data as MessageTemplateWithAuthor;
+  }
+
+  /**
+   * Delete a template. Messages already sent keep their text; their
+   * template_id is cleared.
+   */
+  async delete(id: string): Promise<void> {
+    const { error } = await this.supabase.from('message_templates').delete().eq('id', id);
+
+    if (error) {
+      throw new Error(`Failed to delete template: ${error.message}`);
+    }
+  }
+
+  // ---------------------------------------------------------------------------
+  // Rendering
+  // ---------------------------------------------------------------------------
+
+  /**
+   * Render a template for a customer
+   *
+   * @param templateId - Template to render
+   * @param context - Customer and optional records to fill merge fields from
+   * @throws ValidationError listing unknown variables and variables with no value
+   */
+  async render(templateId: string, context: TemplateContextInput): Promise<RenderedTemplate> {
+    const template = await this.getById(templateId);
+
+    const parts = { subject: template.subject ?? '', body: template.body };
+    const values = await this.getValues(
+      [...extractVariables(parts.subject), ...extractVariables(parts.body)],
+      context
+    );
+    const rendered = renderTemplate(parts, values);
+
+    return {
+      templateId: template.id,
+      channel: template.channel,
+      subject: template.channel === 'email' ? rendered.subject : null,
+      body: rendered.body,
+    };
+  }
+
+  /**
+   * Load merge field values for the variables in use
+   */
+  private async getValues(
+    variables: string[],
+    context: TemplateContextInput
+  ): Promise<TemplateValues> {
+    const groups = new Set(variables.map((name) => name.split('.')[0]));
+    const values: TemplateValues = {};
+
+    const { data: customer, error } = await this.supabase
+      .from('customers')
+      .select('id, name, email, phone')
+      .eq('id', context.customerId)
+      .is('deleted_at', null)
+      .maybeSingle();
+
+    if (error) {
+      throw new Error(`Failed to load customer: ${error.message}`);
+    }
+
+    if (!customer) {
+      throw new NotFoundError('Customer');
+    }
+
+    values['customer.name'] = customer.name;
+    values['customer.first_name'] = customer.name.split(/\s+/)[0] || null;
+    values['customer.phone'] = formatPhone(customer.phone);
+    values['customer.email'] = customer.email;
+
+    if (groups.has('property')) {
+      Object.assign(values, await this.getPropertyValues(context));
+    }
+
+    if (groups.has('appointment')) {
+      Object.assign(values, await this.getAppointmentValues(context));
+    }
+
+    if (groups.has('estimate')) {
+      Object.assign(values, await this.getEstimateValues(context));
+    }
+
+    return values;
+  }
+
+  private async getPropertyValues(context: TemplateContextInput): Promise<TemplateValues> {
+    let query = this.supabase
+      .from('properties')
+      .select('address_line1, address_line2, city, state, zip_code')
+      .eq('customer_id', context.customerId);
+
+    query = context.propertyId
+      ? query.eq('id', context.propertyId)
+      : query.order('created_at', { ascending: true });
+
+    const { data, error } = await query.limit(1).maybeSingle();
+
+    if (error) {
+      throw new Error(`Failed to load property: ${error.message}`);
+    }
+
+    if (!data) {
+      if (context.propertyId) throw new NotFoundError('Property');
+      return {};
+    }
+
+    const street = [data.address_line1, data.address_line2].filter(Boolean).join(' ');
+
+    return {
+      'property.address': `${street}, ${data.city}, ${data.state} ${data.zip_code}`,
+      'property.city': data.city,
+    };
+  }
+
+  private async getAppointmentValues(context: TemplateContextInput): Promise<TemplateValues> {
+    let query = this.supabase
+      .from('calendar_events')
+      .select('title, start_datetime, all_day')
+      .eq('customer_id', context.customerId);
+
+    query = context.eventId
+      ? query.eq('id', context.eventId)
+      : query
+          .eq('status', 'scheduled')
+          .gte('start_datetime', new Date().toISOString())
+          .order('start_datetime', { ascending: true });
+
+    const { data, error } = await query.limit(1).maybeSingle();
+
+    if (error) {
+      throw new Error(`Failed to load appointment: ${error.message}`);
+    }
+
+    if (!data) {
+      if (context.eventId) throw new NotFoundError('Appointment');
+      return {};
+    }
+
+    return {
+      'appointment.title': data.title,
+      'appointment.date': formatDateTime(data.start_datetime, 'full'),
+      'appointment.time': data.all_day
+        ? formatDateTime(data.start_datetime, 'full')
+        : formatDateTime(data.start_datetime, 'fullWithTime'),
+    };
+  }
+
+  private async getEstimateValues(context: TemplateContextInput): Promise<TemplateValues> {
+    let query = this.supabase
+      .from('estimates')
+      .select('estimate_number, total_cents, valid_until')
+      .eq('customer_id', context.customerId);
+
+    query = context.estimateId
+      ? query.eq('id', context.estimateId)
+      : query.order('created_at', { ascending: false });
+
+    const { data, error } = await query.limit(1).maybeSingle();
+
+    if (error) {
+      throw new Error(`Failed to load estimate: ${error.message}`);
+    }
+
+    if (!data) {
+      if (context.estimateId) throw new NotFoundError('Estimate');
+      return {};
+    }
+
+    return {
+      'estimate.number': data.estimate_number,
+      'estimate.total': formatCents(data.total_cents),
+      // valid_until is a calendar date; format it without a timezone shift
+      'estimate.valid_until': data.valid_until
+        ? formatDateTime(`${data.valid_until}T12:00:00Z`, 'medium')
+        : null,
+    };
+  }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function toTemplateError(action: 'create' | 'update', error: { code?: string; message: string }) {
+  if (error.code === '23505') {
+    return new ConflictError('A template with this name already exists');
+  }
+
+  if (error.code === '23514') {
+    return new ValidationError(`Invalid template: ${error.message}`);
+  }
+
+  return new Error(`Failed to ${action} template: ${error.message}`);
+}
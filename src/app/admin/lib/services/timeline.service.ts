@@ -0,0 +1,354 @@
+/**
+ * Timeline Service
+ *
+ * @file src/lib/services/timeline.service.ts
+ *
+ * A customer's communications, notes, estimates, calendar events and
+ * attachments as one list, newest first. Filtering and keyset pagination
+ * run on the customer_timeline view, so one cursor covers every source;
+ * the rows of each page are then loaded from their own tables.
+ *
+ * All methods receive a Supabase client instance for proper auth context.
+ */
+
+import type { SupabaseClient } from '@supabase/supabase-js';
+import type { CustomerTimelineRow, Database } from '@/lib/types/database';
+import type { CommunicationWithLogger } from '@/lib/types/communication';
+import type {
+  CustomerTimelineResult,
+  TimelineEstimate,
+  TimelineEvent,
+  TimelineItem,
+  TimelineItemKind,
+  TimelineNote,
+  TimelineStandaloneAttachment,
+} from '@/lib/types/timeline';
+import { buildKeysetPage, decodeCursor, isReversed, keysetFilter } from '@/lib/utils/cursor';
+import type { CustomerTimelineInput } from '@/lib/validations/timeline';
+import { COMMUNICATION_SELECT, combineFilter } from './communication.service';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+type SupabaseClientType = SupabaseClient<Database>;
+
+type TimelineRow = Pick<CustomerTimelineRow, 'kind' | 'id' | 'occurred_at'>;
+
+type TimelineTable =
+  | 'communications'
+  | 'customer_notes'
+  | 'estimates'
+  | 'calendar_events'
+  | 'customer_attachments';
+
+const NOTE_SELECT = `
+  *,
+  author:admins!customer_notes_created_by_fkey(
+    id,
+    full_name,
+    email
+  ),
+  attachments:customer_attachments!customer_attachments_note_id_fkey(
+    id,
+    filename,
+    content_type,
+    size_bytes,
+    storage_path,
+    created_at
+  )
+`;
+
+const ESTIMATE_SELECT = `
+  id,
+  estimate_number,
+  status,
+  total_cents,
+  valid_until,
+  created_at,
+  created_by_admin:admins!estimates_created_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+const EVENT_SELECT = `
+  *,
+  created_by_admin:admins!calendar_events_created_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+const ATTACHMENT_SELECT = `
+  *,
+  uploaded_by_admin:admins!customer_attachments_uploaded_by_fkey(
+    id,
+    email,
+    full_name
+  )
+`;
+
+// =============================================================================
+// Timeline Service
+// =============================================================================
+
+export class TimelineService {
+  private supabase: SupabaseClientType;
+
+  constructor(supabase: SupabaseClientType) {
+    this.supabase = supabase;
+  }
+
+  /**
+   * A page of a customer's timeline
+   *
+   * Takes the communication filters plus `kinds`. Type, direction and call
+   * filters only match communications; the rest apply to every kind.
+   *
+   * @param input - Customer, filters and keyset cursor
+   * @returns Items newest first with pagination info
+   */
+  async listByCustomer(input: CustomerTimelineInput): Promise<CustomerTimelineResult> {
+    const {
+      customerId,
+      kinds,
+      limit = 25,
+      cursor,
+      search,
+      dateFrom,
+      dateTo,
+      callOutcome,
+      minCallDurationSeconds,
+      maxCallDurationSeconds,
+      loggedBy,
+      tagIds,
+      hasAttachments,
+      estimateId,
+      calendarEventId,
+      propertyId,
+      poolId,
+    } = input;
+    const types = combineFilter(input.type, input.types);
+    const directions = combineFilter(input.direction, input.directions);
+
+    const scope = `customer_timeline:${customerId}`;
+    const position = cursor ? decodeCursor(cursor, scope) : null;
+    const reversed = isReversed(position);
+
+    // Tags belong to the customer, so the whole timeline matches or none of it does
+    if (tagIds?.length && !(await this.customerHasAnyTag(customerId, tagIds))) {
+      return {
+        items: [],
+        hasMore: false,
+        hasPrevious: false,
+        nextCursor: null,
+        prevCursor: null,
+        total: 0,
+      };
+    }
+
+    let query = this.supabase
+      .from('customer_timeline')
+      .select('kind, id, occurred_at', { count: 'exact' })
+      .eq('customer_id', customerId)
+      .order('occurred_at', { ascending: reversed })
+      .order('id', { ascending: reversed })
+      .limit(limit + 1); // Fetch one extra to check if there are more
+
+    if (kinds?.length) {
+      query = query.in('kind', kinds);
+    }
+
+    // Communication-only columns are null for other kinds, so these
+    // filters leave communications only
+    if (types) {
+      query = query.in('communication_type', types);
+    }
+    if (directions) {
+      query = query.in('direction', directions);
+    }
+    if (callOutcome) {
+      query = query.eq('call_outcome', callOutcome);
+    }
+    if (minCallDurationSeconds !== undefined) {
+      query = query.gte('call_duration_seconds', minCallDurationSeconds);
+    }
+    if (maxCallDurationSeconds !== undefined) {
+      query = query.lte('call_duration_seconds', maxCallDurationSeconds);
+    }
+
+    // Linked records: communications about them, and the estimate or
+    // event itself (events and estimates also carry their property / pool)
+    if (estimateId) {
+      query = query.eq('estimate_id', estimateId);
+    }
+    if (calendarEventId) {
+      query = query.eq('calendar_event_id', calendarEventId);
+    }
+    if (propertyId) {
+      query = query.eq('property_id', propertyId);
+    }
+    if (poolId) {
+      query = query.eq('pool_id', poolId);
+    }
+
+    // Apply logged-by filter (author, creator or uploader for other kinds)
+    if (loggedBy?.length) {
+      query = query.in('actor_id', loggedBy);
+    }
+
+    if (hasAttachments !== undefined) {
+      query = query.eq('has_attachments', hasAttachments);
+    }
+
+    // Apply date range filters
+    if (dateFrom) {
+      query = query.gte('occurred_at', dateFrom);
+    }
+    if (dateTo) {
+      query = query.lt('occurred_at', dateTo);
+    }
+
+    // Apply full-text search
+    if (search && search.trim()) {
+      query = query.textSearch('search_vector', search.trim(), {
+        type: 'websearch',
+        config: 'english',
+      });
+    }
+
+    // Apply cursor-based pagination
+    if (position) {
+      query = query.or(keysetFilter('occurred_at', position));
+    }
+
+    const { data, error, count } = await query;
+
+    if (error) {
+      console.error('Failed to list customer timeline:', error);
+      throw new Error(`Failed to list timeline: ${error.message}`);
+    }
+
+    const rows = (data ?? []) as TimelineRow[];
+    const { items: pageRows, ...page } = buildKeysetPage(
+      rows,
+      limit,
+      position,
+      scope,
+      (row) => ({ value: row.occurred_at, id: row.id })
+    );
+
+    const items = await this.loadItems(pageRows);
+
+    return {
+      items,
+      ...page,
+      total: count ?? undefined,
+    };
+  }
+
+  /**
+   * Load the entities behind a page of timeline rows, keeping the page order.
+   * Rows deleted between the two queries are dropped.
+   */
+  private async loadItems(rows: TimelineRow[]): Promise<TimelineItem[]> {
+    const idsOf = (kind: TimelineItemKind) =>
+      rows.filter((row) => row.kind === kind).map((row) => row.id);
+
+    const [communications, notes, estimates, events, attachments] = await Promise.all([
+      this.loadRows<CommunicationWithLogger>(
+        'communications',
+        COMMUNICATION_SELECT,
+        idsOf('communication')
+      ),
+      this.loadRows<TimelineNote>('customer_notes', NOTE_SELECT, idsOf('note')),
+      this.loadRows<TimelineEstimate>('estimates', ESTIMATE_SELECT, idsOf('estimate')),
+      this.loadRows<TimelineEvent>('calendar_events', EVENT_SELECT, idsOf('event')),
+      this.loadRows<TimelineStandaloneAttachment>(
+        'customer_attachments',
+        ATTACHMENT_SELECT,
+        idsOf('attachment')
+      ),
+    ]);
+
+    const items: TimelineItem[] = [];
+
+    for (const row of rows) {
+      const base = { id: row.id, occurredAt: row.occurred_at };
+
+      switch (row.kind) {
+        case 'communication': {
+          const communication = communications.get(row.id);
+          if (communication) items.push({ ...base, kind: 'communication', communication });
+          break;
+        }
+        case 'note': {
+          const note = notes.get(row.id);
+          if (note) items.push({ ...base, kind: 'note', note });
+          break;
+        }
+        case 'estimate': {
+          const estimate = estimates.get(row.id);
+          if (estimate) items.push({ ...base, kind: 'estimate', estimate });
+          break;
+        }
+        case 'event': {
+          const event = events.get(row.id);
+          if (event) items.push({ ...base, kind: 'event', event });
+          break;
+        }
+        case 'attachment': {
+          const attachment = attachments.get(row.id);
+          if (attachment) items.push({ ...base, kind: 'attachment', attachment });
+          break;
+        }
+      }
+    }
+
+    return items;
+  }
+
+  /**
+   * Rows of one table by ID
+   */
+  private async loadRows<T extends { id: string }>(
+    table: TimelineTable,
+    select: string,
+    ids: string[]
+  ): Promise<Map<string, T>> {
+    if (ids.length === 0) {
+      return new Map();
+    }
+
+    const { data, error } = await this.supabase.from(table).select(select).in('id', ids);
+
+    if (error) {
+      console.error(`Failed to load timeline ${table}:`, error);
+      throw new Error(`Failed to load timeline: ${error.message}`);
+    }
+
+    const loaded = (data ?? []) as unknown as T[];
+    return new Map(loaded.map((row) => [row.id, row]));
+  }
+
+  /**
+   * Whether a customer has any of the given tags
+   */
+  private async customerHasAnyTag(customerId: string, tagIds: string[]): Promise<boolean> {
+    const { count, error } = await this.supabase
+      .from('customer_tag_links')
+      .select('tag_id', { count: 'exact', head: true })
+      .eq('customer_id', customerId)
+      .in('tag_id', tagIds);
+
+    if (error) {
+      console.error('Failed to check customer tags:', error);
+      throw new Error(`Failed to list timeline: ${error.message}`);
+    }
+
+    return (count ?? 0) > 0;
+  }
+}
@@ -0,0 +1,203 @@
+-- ============================================================================
+-- Migration: 00015_communication_threads.sql
+-- Description: Conversation threads for communications (replies, open/answered)
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: communication_thread_status
+-- Description: Enum for conversation state
+-- ============================================================================
+CREATE TYPE communication_thread_status AS ENUM ('open', 'answered');
+
+-- ============================================================================
+-- Table: communication_threads
+-- Description: Groups related communications into a conversation. Every
+--              communication belongs to exactly one thread. Aggregate columns
+--              are maintained by triggers on communications.
+-- ============================================================================
+CREATE TABLE communication_threads (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Related customer (required)
+    customer_id UUID NOT NULL REFERENCES customers(id) ON DELETE CASCADE,
+
+    -- Conversation state: open = waiting on us, answered = we replied last
+    status communication_thread_status NOT NULL DEFAULT 'open',
+
+    -- Most recent occurred_at across the thread's communications
+    last_activity_at TIMESTAMPTZ NOT NULL,
+
+    -- Number of communications in the thread
+    message_count INTEGER NOT NULL DEFAULT 0,
+
+    -- Timestamps
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT communication_threads_message_count_positive CHECK (message_count >= 0)
+);
+
+-- ============================================================================
+-- Columns: communications.thread_id, communications.parent_id
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN thread_id UUID REFERENCES communication_threads(id) ON DELETE CASCADE,
+    ADD COLUMN parent_id UUID REFERENCES communications(id) ON DELETE SET NULL;
+
+-- Backfill: every existing communication becomes the root of its own thread
+INSERT INTO communication_threads (id, customer_id, status, last_activity_at, message_count, created_at)
+SELECT
+    id,
+    customer_id,
+    CASE WHEN direction = 'outbound' THEN 'answered' ELSE 'open' END::communication_thread_status,
+    occurred_at,
+    1,
+    created_at
+FROM communications;
+
+UPDATE communications SET thread_id = id;
+
+ALTER TABLE communications ALTER COLUMN thread_id SET NOT NULL;
+
+ALTER TABLE communications
+    ADD CONSTRAINT communications_parent_not_self CHECK (parent_id IS NULL OR parent_id <> id);
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For listing threads by customer (most recently active first)
+CREATE INDEX idx_communication_threads_customer_activity
+    ON communication_threads (customer_id, last_activity_at DESC);
+
+-- For cross-customer queues of open conversations
+CREATE INDEX idx_communication_threads_status
+    ON communication_threads (status, last_activity_at DESC);
+
+-- For loading the communications of a thread in order
+CREATE INDEX idx_communications_thread_id ON communications (thread_id, occurred_at);
+
+-- For finding replies to a communication
+CREATE INDEX idx_communications_parent_id ON communications (parent_id)
+    WHERE parent_id IS NOT NULL;
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Recalculates the aggregate columns of a thread from its communications.
+-- Deletes the thread when its last communication is removed.
+CREATE OR REPLACE FUNCTION refresh_communication_thread(p_thread_id UUID)
+RETURNS VOID AS $$
+DECLARE
+    latest RECORD;
+    total INTEGER;
+BEGIN
+    SELECT COUNT(*) INTO total FROM communications WHERE thread_id = p_thread_id;
+
+    IF total = 0 THEN
+        DELETE FROM communication_threads WHERE id = p_thread_id;
+        RETURN;
+    END IF;
+
+    SELECT occurred_at, direction INTO latest
+    FROM communications
+    WHERE thread_id = p_thread_id
+    ORDER BY occurred_at DESC, created_at DESC
+    LIMIT 1;
+
+    UPDATE communication_threads
+    SET
+        last_activity_at = latest.occurred_at,
+        message_count = total,
+        status = CASE
+            WHEN latest.direction = 'outbound' THEN 'answered'
+            ELSE 'open'
+        END::communication_thread_status,
+        updated_at = NOW()
+    WHERE id = p_thread_id;
+END;
+$$ LANGUAGE plpgsql;
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+-- Resolve thread_id before insert: replies join their parent's thread,
+-- everything else starts a new thread
+CREATE OR REPLACE FUNCTION assign_communication_thread()
+RETURNS TRIGGER AS $$
+DECLARE
+    parent RECORD;
+BEGIN
+    IF NEW.parent_id IS NOT NULL THEN
+        SELECT customer_id, thread_id INTO parent
+        FROM communications
+        WHERE id = NEW.parent_id;
+
+        IF NOT FOUND THEN
+            RAISE EXCEPTION 'Parent communication % does not exist', NEW.parent_id;
+        END IF;
+
+        IF parent.customer_id <> NEW.customer_id THEN
+            RAISE EXCEPTION 'Parent communication belongs to a different customer';
+        END IF;
+
+        NEW.thread_id := parent.thread_id;
+    ELSIF NEW.thread_id IS NULL THEN
+        INSERT INTO communication_threads (customer_id, last_activity_at, message_count)
+        VALUES (NEW.customer_id, NEW.occurred_at, 0)
+        RETURNING id INTO NEW.thread_id;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_communications_assign_thread
+    BEFORE INSERT ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION assign_communication_thread();
+
+-- Keep thread aggregates in sync after any change to its communications
+CREATE OR REPLACE FUNCTION sync_communication_thread()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        PERFORM refresh_communication_thread(OLD.thread_id);
+    END IF;
+
+    IF TG_OP IN ('INSERT', 'UPDATE') AND (TG_OP = 'INSERT' OR NEW.thread_id IS DISTINCT FROM OLD.thread_id) THEN
+        PERFORM refresh_communication_thread(NEW.thread_id);
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_communications_sync_thread
+    AFTER INSERT OR UPDATE OF thread_id, direction, occurred_at OR DELETE ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION sync_communication_thread();
+
+-- Auto-update updated_at timestamp
+CREATE TRIGGER trg_communication_threads_updated_at
+    BEFORE UPDATE ON communication_threads
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON TABLE communication_threads IS 'Conversation grouping for communications. Aggregates maintained by triggers.';
+COMMENT ON COLUMN communication_threads.id IS 'Primary key (UUID)';
+COMMENT ON COLUMN communication_threads.customer_id IS 'Customer this conversation is with';
+COMMENT ON COLUMN communication_threads.status IS 'open (customer spoke last) or answered (we spoke last)';
+COMMENT ON COLUMN communication_threads.last_activity_at IS 'Latest occurred_at across the thread';
+COMMENT ON COLUMN communication_threads.message_count IS 'Number of communications in the thread';
+COMMENT ON COLUMN communications.thread_id IS 'Conversation this communication belongs to';
+COMMENT ON COLUMN communications.parent_id IS 'Communication this one replies to (optional)';
+COMMENT ON FUNCTION refresh_communication_thread(UUID) IS 'Recomputes last activity, count and status for a thread';
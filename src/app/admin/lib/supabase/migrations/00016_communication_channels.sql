@@ -0,0 +1,25 @@
+-- ============================================================================
+-- Migration: 00016_communication_channels.sql
+-- Description: Additional communication channels (voicemail, in-person,
+--              website form, social media)
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: communication_type
+-- Description: New enum values. IF NOT EXISTS makes re-running the
+--              migration a no-op. Inside a transaction a new value cannot
+--              be used until it commits, so nothing here references them
+--              (PostgreSQL before 12 rejects ADD VALUE in a transaction
+--              block outright).
+-- ============================================================================
+ALTER TYPE communication_type ADD VALUE IF NOT EXISTS 'voicemail';
+ALTER TYPE communication_type ADD VALUE IF NOT EXISTS 'in_person';
+ALTER TYPE communication_type ADD VALUE IF NOT EXISTS 'web_form';
+ALTER TYPE communication_type ADD VALUE IF NOT EXISTS 'social';
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON TABLE communications IS 'Manual log of customer interactions (calls, texts, emails, voicemails, site visits, web forms, social)';
+COMMENT ON COLUMN communications.type IS 'Communication channel: call, text, email, voicemail, in_person, web_form, social';
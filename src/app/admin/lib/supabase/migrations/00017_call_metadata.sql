@@ -0,0 +1,59 @@
+-- ============================================================================
+-- Migration: 00017_call_metadata.sql
+-- Description: Structured call fields (duration, outcome, phone number)
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: call_outcome
+-- Description: Enum for how a phone call ended
+-- ============================================================================
+CREATE TYPE call_outcome AS ENUM (
+    'connected',
+    'no_answer',
+    'busy',
+    'left_voicemail',
+    'wrong_number'
+);
+
+-- ============================================================================
+-- Columns: call metadata on communications
+-- Description: Only populated for type = 'call'. All optional so staff can
+--              still log a call with just a summary.
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN call_duration_seconds INTEGER,
+    ADD COLUMN call_outcome call_outcome,
+    ADD COLUMN call_number TEXT;
+
+ALTER TABLE communications
+    ADD CONSTRAINT communications_call_fields_only_for_calls CHECK (
+        type = 'call'
+        OR (call_duration_seconds IS NULL AND call_outcome IS NULL AND call_number IS NULL)
+    ),
+    ADD CONSTRAINT communications_call_duration_range CHECK (
+        call_duration_seconds IS NULL
+        OR (call_duration_seconds >= 0 AND call_duration_seconds <= 86400)
+    ),
+    ADD CONSTRAINT communications_call_number_format CHECK (
+        call_number IS NULL OR call_number ~ '^\+?[0-9]+$'
+    );
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For answer-rate and outcome filtering per customer
+CREATE INDEX idx_communications_call_outcome ON communications (customer_id, call_outcome)
+    WHERE type = 'call';
+
+-- For matching calls to a dialed/received number
+CREATE INDEX idx_communications_call_number ON communications (call_number)
+    WHERE call_number IS NOT NULL;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.call_duration_seconds IS 'Call length in seconds (calls only)';
+COMMENT ON COLUMN communications.call_outcome IS 'connected, no_answer, busy, left_voicemail, wrong_number (calls only)';
+COMMENT ON COLUMN communications.call_number IS 'E.164 number dialed (outbound) or received from (inbound) (calls only)';
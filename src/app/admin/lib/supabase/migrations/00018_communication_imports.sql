@@ -0,0 +1,128 @@
+-- ============================================================================
+-- Migration: 00018_communication_imports.sql
+-- Description: Import support for communications (external IDs for
+--              deduplication, review queue for unmatched messages)
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: communication_import_source
+-- Description: Enum for where an imported communication came from
+-- ============================================================================
+CREATE TYPE communication_import_source AS ENUM ('email');
+
+-- ============================================================================
+-- Type: import_queue_status
+-- Description: Enum for review queue item state
+-- ============================================================================
+CREATE TYPE import_queue_status AS ENUM ('pending', 'resolved', 'dismissed');
+
+-- ============================================================================
+-- Columns: communications.import_source, communications.external_message_id
+-- Description: Identify imported rows so re-importing the same file is a
+--              no-op. For email this is the Message-ID header.
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN import_source communication_import_source,
+    ADD COLUMN external_message_id TEXT;
+
+ALTER TABLE communications
+    ADD CONSTRAINT communications_external_message_unique
+        UNIQUE (customer_id, import_source, external_message_id),
+    ADD CONSTRAINT communications_external_message_requires_source CHECK (
+        external_message_id IS NULL OR import_source IS NOT NULL
+    ),
+    ADD CONSTRAINT communications_external_message_length CHECK (
+        external_message_id IS NULL OR char_length(external_message_id) <= 998
+    );
+
+-- ============================================================================
+-- Table: communication_import_queue
+-- Description: Imported messages that could not be matched to exactly one
+--              customer. Staff assign a customer (creating the communication)
+--              or dismiss the item.
+-- ============================================================================
+CREATE TABLE communication_import_queue (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Where the message came from and its external identifier
+    source communication_import_source NOT NULL,
+    external_message_id TEXT NOT NULL,
+
+    -- Communication details prepared at import time
+    type communication_type NOT NULL,
+    direction communication_direction,
+    summary TEXT NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+
+    -- Address or number we tried to match, and why matching failed
+    counterparty TEXT,
+    reason TEXT NOT NULL,
+
+    -- Source-specific details (headers, raw row, etc.)
+    payload JSONB NOT NULL DEFAULT '{}'::jsonb,
+
+    -- Review state
+    status import_queue_status NOT NULL DEFAULT 'pending',
+    communication_id UUID REFERENCES communications(id) ON DELETE SET NULL,
+    resolved_by UUID REFERENCES admins(id) ON DELETE SET NULL,
+    resolved_at TIMESTAMPTZ,
+
+    -- Admin who ran the import
+    imported_by UUID REFERENCES admins(id) ON DELETE SET NULL,
+
+    -- Record creation timestamp
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT communication_import_queue_external_unique UNIQUE (source, external_message_id),
+    CONSTRAINT communication_import_queue_summary_max_length CHECK (char_length(summary) <= 5000),
+    CONSTRAINT communication_import_queue_reason_valid CHECK (
+        reason IN ('no_customer_match', 'multiple_customer_matches')
+    )
+);
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- Case-insensitive customer email lookup for matching
+CREATE INDEX idx_customers_email_lower ON customers (lower(email))
+    WHERE email IS NOT NULL AND email <> '' AND deleted_at IS NULL;
+
+-- For finding a reply's parent by Message-ID
+CREATE INDEX idx_communications_external_message_id ON communications (external_message_id)
+    WHERE external_message_id IS NOT NULL;
+
+-- For the review queue (oldest pending first)
+CREATE INDEX idx_communication_import_queue_pending ON communication_import_queue (occurred_at)
+    WHERE status = 'pending';
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Returns active customers whose email matches any of the given addresses
+-- (case-insensitive)
+CREATE OR REPLACE FUNCTION find_customers_by_emails(emails TEXT[])
+RETURNS TABLE (id UUID, email TEXT) AS $$
+    SELECT c.id, lower(c.email)
+    FROM customers c
+    WHERE c.deleted_at IS NULL
+      AND c.email IS NOT NULL
+      AND c.email <> ''
+      AND lower(c.email) = ANY (SELECT lower(e) FROM unnest(emails) AS e);
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.import_source IS 'Importer that created this row (NULL = logged manually)';
+COMMENT ON COLUMN communications.external_message_id IS 'Source identifier (e.g. email Message-ID) used to skip duplicates on re-import';
+COMMENT ON TABLE communication_import_queue IS 'Imported messages awaiting manual customer assignment';
+COMMENT ON COLUMN communication_import_queue.counterparty IS 'Address or number that could not be matched to a customer';
+COMMENT ON COLUMN communication_import_queue.reason IS 'no_customer_match or multiple_customer_matches';
+COMMENT ON COLUMN communication_import_queue.payload IS 'Source-specific details shown to the reviewer';
+COMMENT ON COLUMN communication_import_queue.communication_id IS 'Communication created when the item was resolved';
+COMMENT ON FUNCTION find_customers_by_emails(TEXT[]) IS 'Case-insensitive customer lookup by email for importers';
@@ -0,0 +1,47 @@
+-- ============================================================================
+-- Migration: 00019_sms_imports.sql
+-- Description: Text message history import (CSV/JSON exports from the
+--              business phone line)
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: communication_import_source
+-- Description: New enum value for SMS exports
+-- ============================================================================
+ALTER TYPE communication_import_source ADD VALUE IF NOT EXISTS 'sms';
+
+-- ============================================================================
+-- Constraint: communication_import_queue.reason
+-- Description: ambiguous_direction queues an export row with a single
+--              number that matches one customer but says nothing about
+--              who sent it; the reviewer picks the direction.
+-- ============================================================================
+ALTER TABLE communication_import_queue
+    DROP CONSTRAINT communication_import_queue_reason_valid;
+
+ALTER TABLE communication_import_queue
+    ADD CONSTRAINT communication_import_queue_reason_valid CHECK (
+        reason IN ('no_customer_match', 'multiple_customer_matches', 'ambiguous_direction')
+    );
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Returns active customers whose normalized phone matches any of the given
+-- numbers. Inputs go through normalize_phone() so matching uses exactly the
+-- same rules that populate customers.phone_normalized.
+CREATE OR REPLACE FUNCTION find_customers_by_phones(phones TEXT[])
+RETURNS TABLE (phone TEXT, id UUID) AS $$
+    SELECT p.phone, c.id
+    FROM unnest(phones) AS p(phone)
+    JOIN customers c ON c.phone_normalized = normalize_phone(p.phone)
+    WHERE c.deleted_at IS NULL;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON FUNCTION find_customers_by_phones(TEXT[]) IS 'Customer lookup by phone (normalized with normalize_phone) for importers; returns each input number with its matching customer';
+COMMENT ON COLUMN communication_import_queue.reason IS 'no_customer_match, multiple_customer_matches or ambiguous_direction (one customer, direction unknown)';
@@ -0,0 +1,73 @@
+-- ============================================================================
+-- Migration: 00020_call_detail_records.sql
+-- Description: Call-detail-record (CDR) ingestion from the phone system and
+--              callback tracking for missed inbound calls
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: communication_import_source
+-- Description: New enum value for phone system call-detail exports
+-- ============================================================================
+ALTER TYPE communication_import_source ADD VALUE IF NOT EXISTS 'cdr';
+
+-- ============================================================================
+-- Column: communications.needs_callback
+-- Description: Set on missed inbound calls so staff know to call back.
+--              Cleared automatically when an outbound call reaches the
+--              customer, or manually.
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN needs_callback BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE communications
+    ADD CONSTRAINT communications_needs_callback_inbound_call CHECK (
+        needs_callback = false OR (type = 'call' AND direction = 'inbound')
+    );
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For listing outstanding callbacks per customer
+CREATE INDEX idx_communications_needs_callback ON communications (customer_id, occurred_at)
+    WHERE needs_callback;
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Clear outstanding callbacks once we call the customer back. A call that
+-- connected or left a voicemail counts; no answer/busy/wrong number do not.
+-- Calls logged without an outcome are assumed to have reached them.
+CREATE OR REPLACE FUNCTION clear_communication_callbacks()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.type = 'call'
+       AND NEW.direction = 'outbound'
+       AND (NEW.call_outcome IS NULL OR NEW.call_outcome IN ('connected', 'left_voicemail')) THEN
+        UPDATE communications
+        SET needs_callback = false
+        WHERE customer_id = NEW.customer_id
+          AND needs_callback
+          AND occurred_at <= NEW.occurred_at;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+CREATE TRIGGER trg_communications_clear_callbacks
+    AFTER INSERT ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION clear_communication_callbacks();
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.needs_callback IS 'Missed inbound call awaiting a callback (cleared by a later outbound call)';
+COMMENT ON FUNCTION clear_communication_callbacks() IS 'Clears needs_callback on earlier inbound calls when an outbound call reaches the customer';
@@ -0,0 +1,56 @@
+-- ============================================================================
+-- Migration: 00021_notification_delivery.sql
+-- Description: Delivery tracking for email and SMS sent from the CRM
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: delivery_status
+-- Description: Enum for the state of a message sent through a provider
+-- ============================================================================
+CREATE TYPE delivery_status AS ENUM ('pending', 'sent', 'delivered', 'failed');
+
+-- ============================================================================
+-- Columns: delivery tracking on communications
+-- Description: Only populated for messages sent from the CRM. NULL status
+--              means the communication was logged manually or imported.
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN delivery_status delivery_status,
+    ADD COLUMN delivery_provider TEXT,
+    ADD COLUMN delivery_provider_message_id TEXT,
+    ADD COLUMN delivery_error TEXT,
+    ADD COLUMN delivery_updated_at TIMESTAMPTZ;
+
+ALTER TABLE communications
+    ADD CONSTRAINT communications_delivery_only_outbound CHECK (
+        delivery_status IS NULL OR direction = 'outbound'
+    ),
+    ADD CONSTRAINT communications_delivery_requires_provider CHECK (
+        delivery_status IS NULL OR delivery_provider IS NOT NULL
+    ),
+    ADD CONSTRAINT communications_delivery_error_length CHECK (
+        delivery_error IS NULL OR char_length(delivery_error) <= 1000
+    );
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For applying provider status callbacks
+CREATE INDEX idx_communications_delivery_provider_message
+    ON communications (delivery_provider, delivery_provider_message_id)
+    WHERE delivery_provider_message_id IS NOT NULL;
+
+-- For finding failed sends
+CREATE INDEX idx_communications_delivery_failed ON communications (customer_id, occurred_at DESC)
+    WHERE delivery_status = 'failed';
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.delivery_status IS 'pending, sent, delivered, failed (NULL = not sent from the CRM)';
+COMMENT ON COLUMN communications.delivery_provider IS 'Notification provider that sent the message (smtp, http-sms, outbox)';
+COMMENT ON COLUMN communications.delivery_provider_message_id IS 'Provider message ID used to match delivery status callbacks';
+COMMENT ON COLUMN communications.delivery_error IS 'Provider error when delivery_status = failed';
+COMMENT ON COLUMN communications.delivery_updated_at IS 'When delivery_status last changed';
@@ -0,0 +1,93 @@
+-- ============================================================================
+-- Migration: 00022_message_templates.sql
+-- Description: Reusable email/SMS message templates with merge fields
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Type: message_template_channel
+-- Description: Channel a template is written for
+-- ============================================================================
+CREATE TYPE message_template_channel AS ENUM ('email', 'sms');
+
+-- ============================================================================
+-- Table: message_templates
+-- Description: Named message bodies with {{merge.fields}} that are filled in
+--              from customer, property, appointment and estimate data when
+--              sending. Variables are validated by the application.
+-- ============================================================================
+CREATE TABLE message_templates (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Display name (unique, case-insensitive)
+    name TEXT NOT NULL,
+
+    -- Channel this template is sent on
+    channel message_template_channel NOT NULL,
+
+    -- Email subject (required for email, unused for SMS)
+    subject TEXT,
+
+    -- Message body with merge fields
+    body TEXT NOT NULL,
+
+    -- Admin who created the template
+    created_by UUID REFERENCES admins(id) ON DELETE SET NULL,
+
+    -- Timestamps
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT message_templates_name_not_empty CHECK (char_length(trim(name)) >= 1),
+    CONSTRAINT message_templates_name_max_length CHECK (char_length(name) <= 100),
+    CONSTRAINT message_templates_body_not_empty CHECK (char_length(trim(body)) >= 1),
+    CONSTRAINT message_templates_body_max_length CHECK (char_length(body) <= 4800),
+    CONSTRAINT message_templates_subject_max_length CHECK (subject IS NULL OR char_length(subject) <= 200),
+    CONSTRAINT message_templates_email_subject CHECK (
+        channel <> 'email' OR char_length(trim(coalesce(subject, ''))) >= 1
+    )
+);
+
+-- ============================================================================
+-- Columns: communications.template_id
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN template_id UUID REFERENCES message_templates(id) ON DELETE SET NULL;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- Template names are unique regardless of case
+CREATE UNIQUE INDEX idx_message_templates_name ON message_templates (lower(name));
+
+-- For listing templates by channel
+CREATE INDEX idx_message_templates_channel ON message_templates (channel, name);
+
+-- For finding messages sent from a template
+CREATE INDEX idx_communications_template_id
+    ON communications (template_id)
+    WHERE template_id IS NOT NULL;
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+-- Auto-update updated_at timestamp
+CREATE TRIGGER trg_message_templates_updated_at
+    BEFORE UPDATE ON message_templates
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON TABLE message_templates IS 'Reusable email and SMS bodies with merge fields';
+COMMENT ON COLUMN message_templates.name IS 'Display name (unique, case-insensitive)';
+COMMENT ON COLUMN message_templates.channel IS 'Channel the template is sent on: email or sms';
+COMMENT ON COLUMN message_templates.subject IS 'Email subject with merge fields (email only)';
+COMMENT ON COLUMN message_templates.body IS 'Message body with {{merge.fields}} (max 4,800 chars)';
+COMMENT ON COLUMN message_templates.created_by IS 'Admin who created the template';
+COMMENT ON COLUMN communications.template_id IS 'Template the message was sent from, if any';
@@ -0,0 +1,32 @@
+-- ============================================================================
+-- Migration: 00023_communication_attachments.sql
+-- Description: Link customer attachments to communications
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns: customer_attachments.communication_id
+-- Description: Photos received by text, PDFs received by email, etc. An
+--              attachment belongs to the customer and to at most one note
+--              or communication.
+-- ============================================================================
+ALTER TABLE customer_attachments
+    ADD COLUMN communication_id UUID REFERENCES communications(id) ON DELETE SET NULL;
+
+ALTER TABLE customer_attachments
+    ADD CONSTRAINT customer_attachments_single_parent CHECK (
+        note_id IS NULL OR communication_id IS NULL
+    );
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For listing attachments by communication
+CREATE INDEX idx_customer_attachments_communication_id ON customer_attachments (communication_id)
+    WHERE communication_id IS NOT NULL;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN customer_attachments.communication_id IS 'Optional link to a communication';
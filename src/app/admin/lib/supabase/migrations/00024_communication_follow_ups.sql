@@ -0,0 +1,28 @@
+-- ============================================================================
+-- Migration: 00024_communication_follow_ups.sql
+-- Description: Link follow-up calendar events to the communication that
+--              prompted them
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns: calendar_events.communication_id
+-- Description: Set when a follow-up is scheduled while logging a
+--              communication ("call back Thursday"). Cleared if the
+--              communication is deleted; the event itself is kept.
+-- ============================================================================
+ALTER TABLE calendar_events
+    ADD COLUMN communication_id UUID REFERENCES communications(id) ON DELETE SET NULL;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For loading the follow-ups of a communication
+CREATE INDEX idx_calendar_events_communication_id ON calendar_events (communication_id)
+    WHERE communication_id IS NOT NULL;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN calendar_events.communication_id IS 'Communication this follow-up was scheduled from';
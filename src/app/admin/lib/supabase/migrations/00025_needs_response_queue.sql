@@ -0,0 +1,75 @@
+-- ============================================================================
+-- Migration: 00025_needs_response_queue.sql
+-- Description: "Needs response" queue of inbound communications with no
+--              later outbound reply, with snooze/dismiss state
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns: communications response queue state
+-- Description: Set on inbound communications when staff snooze or dismiss
+--              them in the queue. A new inbound message from the customer
+--              is unaffected and puts them back in the queue.
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN response_snoozed_until TIMESTAMPTZ,
+    ADD COLUMN response_dismissed_at TIMESTAMPTZ,
+    ADD COLUMN response_dismissed_by UUID REFERENCES admins(id) ON DELETE SET NULL;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For finding each customer's latest outbound communication
+CREATE INDEX idx_communications_outbound ON communications (customer_id, occurred_at DESC)
+    WHERE direction = 'outbound';
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- One row per customer with inbound communications newer than their latest
+-- outbound communication, excluding snoozed and dismissed ones. Oldest
+-- waiting customer first; the SLA deadline is applied by the application.
+CREATE OR REPLACE FUNCTION get_needs_response_queue(p_customer_id UUID DEFAULT NULL)
+RETURNS TABLE (
+    customer_id UUID,
+    communication_id UUID,
+    waiting_since TIMESTAMPTZ,
+    unanswered_count INTEGER,
+    communication_ids UUID[]
+) AS $$
+    WITH pending AS (
+        SELECT c.id, c.customer_id, c.occurred_at
+        FROM communications c
+        JOIN customers cu ON cu.id = c.customer_id AND cu.deleted_at IS NULL
+        WHERE c.direction = 'inbound'
+          AND (p_customer_id IS NULL OR c.customer_id = p_customer_id)
+          AND c.response_dismissed_at IS NULL
+          AND (c.response_snoozed_until IS NULL OR c.response_snoozed_until <= now())
+          AND NOT EXISTS (
+              SELECT 1
+              FROM communications o
+              WHERE o.customer_id = c.customer_id
+                AND o.direction = 'outbound'
+                AND o.occurred_at >= c.occurred_at
+          )
+    )
+    SELECT
+        p.customer_id,
+        (array_agg(p.id ORDER BY p.occurred_at, p.id))[1],
+        min(p.occurred_at),
+        count(*)::INTEGER,
+        array_agg(p.id ORDER BY p.occurred_at, p.id)
+    FROM pending p
+    GROUP BY p.customer_id
+    ORDER BY min(p.occurred_at);
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.response_snoozed_until IS 'Hidden from the needs-response queue until this time';
+COMMENT ON COLUMN communications.response_dismissed_at IS 'Removed from the needs-response queue without a reply';
+COMMENT ON COLUMN communications.response_dismissed_by IS 'Admin who dismissed this from the needs-response queue';
+COMMENT ON FUNCTION get_needs_response_queue(UUID) IS 'Customers waiting on a reply: unanswered inbound communications grouped per customer, oldest first';
@@ -0,0 +1,112 @@
+-- ============================================================================
+-- Migration: 00026_communication_analytics.sql
+-- Description: Response-time and workload reporting per admin, aggregated
+--              in the database over a date range
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Time from an inbound communication to our first outbound reply. Only the
+-- first inbound of a run counts (the customer's previous communication was
+-- not inbound), so three texts in a row are one wait. Waits starting in
+-- [p_from, p_to) are measured; the reply is credited to the admin who
+-- logged it. The row with admin_id NULL is the team-wide figure.
+CREATE OR REPLACE FUNCTION get_communication_response_times(
+    p_from TIMESTAMPTZ,
+    p_to TIMESTAMPTZ
+)
+RETURNS TABLE (
+    admin_id UUID,
+    responses INTEGER,
+    median_seconds DOUBLE PRECISION,
+    average_seconds DOUBLE PRECISION
+) AS $$
+    WITH waits AS (
+        SELECT c.id, c.customer_id, c.occurred_at
+        FROM communications c
+        WHERE c.direction = 'inbound'
+          AND c.occurred_at >= p_from
+          AND c.occurred_at < p_to
+          AND COALESCE((
+              SELECT p.direction
+              FROM communications p
+              WHERE p.customer_id = c.customer_id
+                AND (p.occurred_at, p.id) < (c.occurred_at, c.id)
+              ORDER BY p.occurred_at DESC, p.id DESC
+              LIMIT 1
+          ), 'outbound') = 'outbound'
+    ),
+    replies AS (
+        SELECT
+            r.logged_by,
+            EXTRACT(EPOCH FROM (r.occurred_at - w.occurred_at)) AS seconds
+        FROM waits w
+        CROSS JOIN LATERAL (
+            SELECT o.occurred_at, o.logged_by
+            FROM communications o
+            WHERE o.customer_id = w.customer_id
+              AND o.direction = 'outbound'
+              AND o.occurred_at >= w.occurred_at
+            ORDER BY o.occurred_at, o.id
+            LIMIT 1
+        ) r
+    )
+    SELECT
+        logged_by,
+        count(*)::INTEGER,
+        percentile_cont(0.5) WITHIN GROUP (ORDER BY seconds),
+        avg(seconds)
+    FROM replies
+    GROUP BY GROUPING SETS ((logged_by), ());
+$$ LANGUAGE sql STABLE;
+
+-- Communications logged per admin by type and direction
+CREATE OR REPLACE FUNCTION get_communication_volume(
+    p_from TIMESTAMPTZ,
+    p_to TIMESTAMPTZ
+)
+RETURNS TABLE (
+    admin_id UUID,
+    type communication_type,
+    direction communication_direction,
+    total INTEGER
+) AS $$
+    SELECT logged_by, type, direction, count(*)::INTEGER
+    FROM communications
+    WHERE occurred_at >= p_from
+      AND occurred_at < p_to
+    GROUP BY logged_by, type, direction;
+$$ LANGUAGE sql STABLE;
+
+-- Communications logged per admin by local day of week (0 = Sunday) and hour
+CREATE OR REPLACE FUNCTION get_communication_hours(
+    p_from TIMESTAMPTZ,
+    p_to TIMESTAMPTZ,
+    p_timezone TEXT DEFAULT 'America/New_York'
+)
+RETURNS TABLE (
+    admin_id UUID,
+    day_of_week INTEGER,
+    hour INTEGER,
+    total INTEGER
+) AS $$
+    SELECT
+        logged_by,
+        EXTRACT(DOW FROM occurred_at AT TIME ZONE p_timezone)::INTEGER,
+        EXTRACT(HOUR FROM occurred_at AT TIME ZONE p_timezone)::INTEGER,
+        count(*)::INTEGER
+    FROM communications
+    WHERE occurred_at >= p_from
+      AND occurred_at < p_to
+    GROUP BY 1, 2, 3;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON FUNCTION get_communication_response_times(TIMESTAMPTZ, TIMESTAMPTZ) IS 'Median and mean inbound-to-first-reply time per replying admin, plus a team row (admin_id NULL)';
+COMMENT ON FUNCTION get_communication_volume(TIMESTAMPTZ, TIMESTAMPTZ) IS 'Communication counts per logging admin, type and direction';
+COMMENT ON FUNCTION get_communication_hours(TIMESTAMPTZ, TIMESTAMPTZ, TEXT) IS 'Communication counts per logging admin by local day of week and hour';
@@ -0,0 +1,74 @@
+-- ============================================================================
+-- Migration: 00027_communication_stats.sql
+-- Description: Per-customer communication stats aggregated in the database,
+--              with optional date range and a monthly series
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Counts per type, direction and call outcome for one customer, with the
+-- connected-call duration totals and the latest occurrence in each group.
+-- p_from / p_to bound occurred_at ([p_from, p_to)); NULL means unbounded.
+CREATE OR REPLACE FUNCTION get_communication_stats(
+    p_customer_id UUID,
+    p_from TIMESTAMPTZ DEFAULT NULL,
+    p_to TIMESTAMPTZ DEFAULT NULL
+)
+RETURNS TABLE (
+    type communication_type,
+    direction communication_direction,
+    call_outcome call_outcome,
+    total INTEGER,
+    needs_callback INTEGER,
+    connected_duration_sum BIGINT,
+    connected_duration_count INTEGER,
+    last_occurred_at TIMESTAMPTZ
+) AS $$
+    SELECT
+        c.type,
+        c.direction,
+        c.call_outcome,
+        count(*)::INTEGER,
+        count(*) FILTER (WHERE c.needs_callback)::INTEGER,
+        COALESCE(sum(c.call_duration_seconds) FILTER (WHERE c.call_outcome = 'connected'), 0)::BIGINT,
+        count(c.call_duration_seconds) FILTER (WHERE c.call_outcome = 'connected')::INTEGER,
+        max(c.occurred_at)
+    FROM communications c
+    WHERE c.customer_id = p_customer_id
+      AND (p_from IS NULL OR c.occurred_at >= p_from)
+      AND (p_to IS NULL OR c.occurred_at < p_to)
+    GROUP BY c.type, c.direction, c.call_outcome;
+$$ LANGUAGE sql STABLE;
+
+-- Communications per local calendar month and direction for one customer
+CREATE OR REPLACE FUNCTION get_communication_monthly_counts(
+    p_customer_id UUID,
+    p_from TIMESTAMPTZ DEFAULT NULL,
+    p_to TIMESTAMPTZ DEFAULT NULL,
+    p_timezone TEXT DEFAULT 'America/New_York'
+)
+RETURNS TABLE (
+    month DATE,
+    direction communication_direction,
+    total INTEGER
+) AS $$
+    SELECT
+        date_trunc('month', c.occurred_at AT TIME ZONE p_timezone)::DATE,
+        c.direction,
+        count(*)::INTEGER
+    FROM communications c
+    WHERE c.customer_id = p_customer_id
+      AND (p_from IS NULL OR c.occurred_at >= p_from)
+      AND (p_to IS NULL OR c.occurred_at < p_to)
+    GROUP BY 1, 2
+    ORDER BY 1;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON FUNCTION get_communication_stats(UUID, TIMESTAMPTZ, TIMESTAMPTZ) IS 'Customer communication counts grouped by type, direction and call outcome, with call duration totals and latest occurrence';
+COMMENT ON FUNCTION get_communication_monthly_counts(UUID, TIMESTAMPTZ, TIMESTAMPTZ, TEXT) IS 'Customer communication counts per local month and direction';
@@ -0,0 +1,88 @@
+-- ============================================================================
+-- Migration: 00028_communication_search.sql
+-- Description: Relevance-ranked communication search with highlighted
+--              snippets and keyset paging
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Full-text search over communications.search_vector (websearch syntax).
+-- p_sort 'relevance' orders by ts_rank, then newest first; 'date' orders
+-- newest first. Pages by p_offset, or after the keyset position
+-- (p_after_rank, p_after_occurred_at, p_after_id) when p_after_id is set.
+-- headline marks matches with U+E000 / U+E001, which cannot appear in
+-- typed text, so callers can highlight without trusting HTML.
+-- total_count is the number of matches before paging.
+CREATE OR REPLACE FUNCTION search_communications(
+    p_query TEXT,
+    p_customer_id UUID DEFAULT NULL,
+    p_sort TEXT DEFAULT 'relevance',
+    p_limit INTEGER DEFAULT 10,
+    p_offset INTEGER DEFAULT 0,
+    p_after_rank DOUBLE PRECISION DEFAULT NULL,
+    p_after_occurred_at TIMESTAMPTZ DEFAULT NULL,
+    p_after_id UUID DEFAULT NULL
+)
+RETURNS TABLE (
+    id UUID,
+    rank DOUBLE PRECISION,
+    headline TEXT,
+    total_count INTEGER
+) AS $$
+    WITH q AS (
+        SELECT websearch_to_tsquery('english', p_query) AS query
+    ),
+    matches AS (
+        SELECT
+            c.id,
+            c.summary,
+            c.occurred_at,
+            ts_rank(c.search_vector, q.query)::DOUBLE PRECISION AS rank,
+            count(*) OVER ()::INTEGER AS total_count
+        FROM communications c, q
+        WHERE c.search_vector @@ q.query
+          AND (p_customer_id IS NULL OR c.customer_id = p_customer_id)
+    ),
+    page AS (
+        SELECT m.*
+        FROM matches m
+        WHERE p_after_id IS NULL
+           OR (p_sort = 'date'
+               AND (m.occurred_at, m.id) < (p_after_occurred_at, p_after_id))
+           OR (p_sort <> 'date'
+               AND (m.rank, m.occurred_at, m.id) < (p_after_rank, p_after_occurred_at, p_after_id))
+        ORDER BY
+            CASE WHEN p_sort = 'date' THEN NULL ELSE m.rank END DESC NULLS LAST,
+            m.occurred_at DESC,
+            m.id DESC
+        LIMIT p_limit
+        OFFSET CASE WHEN p_after_id IS NULL THEN p_offset ELSE 0 END
+    )
+    SELECT
+        p.id,
+        p.rank,
+        ts_headline(
+            'english',
+            p.summary,
+            q.query,
+            format(
+                'StartSel=%s, StopSel=%s, MaxFragments=2, MaxWords=30, MinWords=12, FragmentDelimiter=" … "',
+                chr(57344),
+                chr(57345)
+            )
+        ),
+        p.total_count
+    FROM page p, q
+    ORDER BY
+        CASE WHEN p_sort = 'date' THEN NULL ELSE p.rank END DESC NULLS LAST,
+        p.occurred_at DESC,
+        p.id DESC;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON FUNCTION search_communications(TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID) IS 'Ranked full-text communication search with highlighted snippets, paged by offset or keyset';
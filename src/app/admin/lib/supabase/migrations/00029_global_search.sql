@@ -0,0 +1,221 @@
+-- ============================================================================
+-- Migration: 00029_global_search.sql
+-- Description: One search across customers, communications, notes,
+--              estimates (number and line items) and property addresses
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns
+-- ============================================================================
+
+ALTER TABLE customer_notes
+    ADD COLUMN search_vector TSVECTOR
+        GENERATED ALWAYS AS (to_tsvector('english', content)) STORED;
+
+ALTER TABLE estimates
+    ADD COLUMN search_vector TSVECTOR
+        GENERATED ALWAYS AS (
+            to_tsvector(
+                'english',
+                COALESCE(jsonb_path_query_array(line_items, '$[*].description')::TEXT, '')
+                    || ' ' || COALESCE(notes, '')
+            )
+        ) STORED;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+CREATE INDEX idx_customer_notes_fts ON customer_notes USING gin(search_vector);
+
+CREATE INDEX idx_estimates_fts ON estimates USING gin(search_vector);
+
+-- Customer email lookups from the header search
+CREATE INDEX idx_customers_email_trgm ON customers
+    USING gin (email gin_trgm_ops);
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Up to p_limit matches per entity type, best first. p_query is matched as
+-- free text (websearch full-text, trigram and substring); p_digits matches
+-- customer phones and call numbers; p_estimate_number matches estimates by
+-- number (42 for EST-0042). Soft-deleted customers and their records are
+-- left out.
+--
+-- label carries the entity's identifying text (customer phone,
+-- communication type, estimate number, property address); headline marks
+-- matched words with U+E000 / U+E001 like search_communications.
+CREATE OR REPLACE FUNCTION global_search(
+    p_query TEXT,
+    p_digits TEXT DEFAULT NULL,
+    p_estimate_number INTEGER DEFAULT NULL,
+    p_limit INTEGER DEFAULT 5
+)
+RETURNS TABLE (
+    entity_type TEXT,
+    entity_id UUID,
+    customer_id UUID,
+    customer_name TEXT,
+    label TEXT,
+    headline TEXT,
+    score DOUBLE PRECISION,
+    occurred_at TIMESTAMPTZ
+) AS $$
+    WITH q AS (
+        SELECT
+            websearch_to_tsquery('english', p_query) AS query,
+            '%' || replace(replace(replace(p_query, '\', '\\'), '%', '\%'), '_', '\_') || '%' AS pattern,
+            '%' || p_digits || '%' AS digits_pattern
+    ),
+    customer_hits AS (
+        SELECT
+            'customer'::TEXT AS entity_type,
+            c.id AS entity_id,
+            c.id AS customer_id,
+            c.name AS customer_name,
+            c.phone AS label,
+            NULL::TEXT AS body,
+            (CASE
+                WHEN c.phone_normalized LIKE q.digits_pattern THEN 1
+                ELSE GREATEST(
+                    similarity(c.name, p_query),
+                    CASE WHEN c.name ILIKE q.pattern OR c.email ILIKE q.pattern THEN 0.5 ELSE 0 END
+                )
+            END)::DOUBLE PRECISION AS score,
+            c.created_at AS occurred_at
+        FROM customers c, q
+        WHERE c.deleted_at IS NULL
+          AND (
+              c.phone_normalized LIKE q.digits_pattern
+              OR c.name % p_query
+              OR c.name ILIKE q.pattern
+              OR c.email ILIKE q.pattern
+          )
+        ORDER BY score DESC, c.name
+        LIMIT p_limit
+    ),
+    communication_hits AS (
+        SELECT
+            'communication'::TEXT,
+            m.id,
+            m.customer_id,
+            cu.name,
+            m.type::TEXT,
+            m.summary,
+            (CASE
+                WHEN m.call_number LIKE q.digits_pattern THEN 1
+                ELSE ts_rank(m.search_vector, q.query)
+            END)::DOUBLE PRECISION AS score,
+            m.occurred_at
+        FROM communications m
+        JOIN customers cu ON cu.id = m.customer_id AND cu.deleted_at IS NULL, q
+        WHERE m.search_vector @@ q.query
+           OR m.call_number LIKE q.digits_pattern
+        ORDER BY score DESC, m.occurred_at DESC
+        LIMIT p_limit
+    ),
+    note_hits AS (
+        SELECT
+            'note'::TEXT,
+            n.id,
+            n.customer_id,
+            cu.name,
+            NULL::TEXT,
+            n.content,
+            ts_rank(n.search_vector, q.query)::DOUBLE PRECISION AS score,
+            n.created_at
+        FROM customer_notes n
+        JOIN customers cu ON cu.id = n.customer_id AND cu.deleted_at IS NULL, q
+        WHERE n.search_vector @@ q.query
+        ORDER BY score DESC, n.created_at DESC
+        LIMIT p_limit
+    ),
+    estimate_hits AS (
+        SELECT
+            'estimate'::TEXT,
+            e.id,
+            e.customer_id,
+            cu.name,
+            e.estimate_number,
+            COALESCE(
+                (SELECT string_agg(item->>'description', ' · ')
+                 FROM jsonb_array_elements(e.line_items) AS item),
+                ''
+            ) || COALESCE(' · ' || e.notes, ''),
+            (CASE
+                WHEN NULLIF(regexp_replace(e.estimate_number, '^EST-0*', ''), '')::INTEGER
+                     = p_estimate_number THEN 1
+                ELSE ts_rank(e.search_vector, q.query)
+            END)::DOUBLE PRECISION AS score,
+            e.created_at
+        FROM estimates e
+        JOIN customers cu ON cu.id = e.customer_id AND cu.deleted_at IS NULL, q
+        WHERE NULLIF(regexp_replace(e.estimate_number, '^EST-0*', ''), '')::INTEGER = p_estimate_number
+           OR e.search_vector @@ q.query
+        ORDER BY score DESC, e.created_at DESC
+        LIMIT p_limit
+    ),
+    property_hits AS (
+        SELECT
+            'property'::TEXT,
+            p.id,
+            p.customer_id,
+            cu.name,
+            p.address_line1 || ', ' || p.city || ', ' || p.state || ' ' || p.zip_code,
+            NULL::TEXT,
+            GREATEST(
+                word_similarity(p_query, p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code),
+                CASE
+                    WHEN (p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code) ILIKE q.pattern
+                    THEN 0.5 ELSE 0
+                END
+            )::DOUBLE PRECISION AS score,
+            p.created_at
+        FROM properties p
+        JOIN customers cu ON cu.id = p.customer_id AND cu.deleted_at IS NULL, q
+        WHERE p_query <% (p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code)
+           OR (p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code) ILIKE q.pattern
+        ORDER BY score DESC, p.address_line1
+        LIMIT p_limit
+    ),
+    hits AS (
+        SELECT * FROM customer_hits
+        UNION ALL SELECT * FROM communication_hits
+        UNION ALL SELECT * FROM note_hits
+        UNION ALL SELECT * FROM estimate_hits
+        UNION ALL SELECT * FROM property_hits
+    )
+    SELECT
+        h.entity_type,
+        h.entity_id,
+        h.customer_id,
+        h.customer_name,
+        h.label,
+        CASE
+            WHEN h.body IS NULL OR h.body = '' THEN NULL
+            ELSE ts_headline(
+                'english',
+                h.body,
+                q.query,
+                format(
+                    'StartSel=%s, StopSel=%s, MaxFragments=1, MaxWords=20, MinWords=8',
+                    chr(57344),
+                    chr(57345)
+                )
+            )
+        END,
+        h.score,
+        h.occurred_at
+    FROM hits h, q
+    ORDER BY h.entity_type, h.score DESC;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN customer_notes.search_vector IS 'Auto-generated tsvector for full-text search';
+COMMENT ON COLUMN estimates.search_vector IS 'Auto-generated tsvector over line item descriptions and notes';
+COMMENT ON FUNCTION global_search(TEXT, TEXT, INTEGER, INTEGER) IS 'Header search across customers, communications, notes, estimates and properties, best matches per entity type';
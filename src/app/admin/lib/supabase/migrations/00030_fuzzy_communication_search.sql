@@ -0,0 +1,310 @@
+-- ============================================================================
+-- Migration: 00030_fuzzy_communication_search.sql
+-- Description: Typo-tolerant trigram matching and digit search on
+--              communication summaries, blended with full-text ranking
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns
+-- ============================================================================
+
+-- Digit runs in the summary with phone/number punctuation removed, so
+-- "(555) 123-4567" is stored as 5551234567 and "EST-0042" as 0042.
+-- Numbers separated only by spaces or other text stay separate runs.
+ALTER TABLE communications
+    ADD COLUMN summary_digits TEXT
+        GENERATED ALWAYS AS (
+            btrim(regexp_replace(
+                regexp_replace(summary, '(\d)\s*[().-]\s*(?=\d)', '\1', 'g'),
+                '\D+', ' ', 'g'
+            ))
+        ) STORED;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- Fuzzy matching on summary (misspellings, partial words)
+CREATE INDEX idx_communications_summary_trgm ON communications
+    USING gin (summary gin_trgm_ops);
+
+-- Substring matching on numbers mentioned in the summary
+CREATE INDEX idx_communications_summary_digits_trgm ON communications
+    USING gin (summary_digits gin_trgm_ops);
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Replaced to add p_digits and fuzzy matching; the signature changes
+DROP FUNCTION IF EXISTS search_communications(
+    TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID
+);
+
+-- Full-text search over communications, tolerant of typos and partial
+-- words. A row matches on search_vector (websearch syntax), on trigram
+-- word similarity between p_query and summary, or when p_digits appears
+-- in summary_digits or call_number.
+--
+-- rank blends the three: a full-text or digit match adds 1, so exact
+-- matches always outrank typo-only ones, and ts_rank (normalised to 0-1)
+-- plus half the trigram word similarity order results within each tier.
+--
+-- Sorting, paging and headline markers are as before (00028).
+CREATE FUNCTION search_communications(
+    p_query TEXT,
+    p_customer_id UUID DEFAULT NULL,
+    p_sort TEXT DEFAULT 'relevance',
+    p_limit INTEGER DEFAULT 10,
+    p_offset INTEGER DEFAULT 0,
+    p_after_rank DOUBLE PRECISION DEFAULT NULL,
+    p_after_occurred_at TIMESTAMPTZ DEFAULT NULL,
+    p_after_id UUID DEFAULT NULL,
+    p_digits TEXT DEFAULT NULL
+)
+RETURNS TABLE (
+    id UUID,
+    rank DOUBLE PRECISION,
+    headline TEXT,
+    total_count INTEGER
+) AS $$
+    WITH q AS (
+        SELECT
+            websearch_to_tsquery('english', p_query) AS query,
+            '%' || p_digits || '%' AS digits_pattern
+    ),
+    matches AS (
+        SELECT
+            c.id,
+            c.summary,
+            c.occurred_at,
+            (
+                CASE
+                    WHEN c.search_vector @@ q.query
+                      OR c.summary_digits LIKE q.digits_pattern
+                      OR c.call_number LIKE q.digits_pattern
+                    THEN 1 ELSE 0
+                END
+                + ts_rank(c.search_vector, q.query, 32)
+                + 0.5 * word_similarity(p_query, c.summary)
+            )::DOUBLE PRECISION AS rank,
+            count(*) OVER ()::INTEGER AS total_count
+        FROM communications c, q
+        WHERE (
+                c.search_vector @@ q.query
+                OR p_query <% c.summary
+                OR c.summary_digits LIKE q.digits_pattern
+                OR c.call_number LIKE q.digits_pattern
+              )
+          AND (p_customer_id IS NULL OR c.customer_id = p_customer_id)
+    ),
+    page AS (
+        SELECT m.*
+        FROM matches m
+        WHERE p_after_id IS NULL
+           OR (p_sort = 'date'
+               AND (m.occurred_at, m.id) < (p_after_occurred_at, p_after_id))
+           OR (p_sort <> 'date'
+               AND (m.rank, m.occurred_at, m.id) < (p_after_rank, p_after_occurred_at, p_after_id))
+        ORDER BY
+            CASE WHEN p_sort = 'date' THEN NULL ELSE m.rank END DESC NULLS LAST,
+            m.occurred_at DESC,
+            m.id DESC
+        LIMIT p_limit
+        OFFSET CASE WHEN p_after_id IS NULL THEN p_offset ELSE 0 END
+    )
+    SELECT
+        p.id,
+        p.rank,
+        ts_headline(
+            'english',
+            p.summary,
+            q.query,
+            format(
+                'StartSel=%s, StopSel=%s, MaxFragments=2, MaxWords=30, MinWords=12, FragmentDelimiter=" … "',
+                chr(57344),
+                chr(57345)
+            )
+        ),
+        p.total_count
+    FROM page p, q
+    ORDER BY
+        CASE WHEN p_sort = 'date' THEN NULL ELSE p.rank END DESC NULLS LAST,
+        p.occurred_at DESC,
+        p.id DESC;
+$$ LANGUAGE sql STABLE
+SET pg_trgm.word_similarity_threshold = 0.4;
+
+-- Communications now come from search_communications, so the header
+-- search gets the same fuzzy and digit matching
+CREATE OR REPLACE FUNCTION global_search(
+    p_query TEXT,
+    p_digits TEXT DEFAULT NULL,
+    p_estimate_number INTEGER DEFAULT NULL,
+    p_limit INTEGER DEFAULT 5
+)
+RETURNS TABLE (
+    entity_type TEXT,
+    entity_id UUID,
+    customer_id UUID,
+    customer_name TEXT,
+    label TEXT,
+    headline TEXT,
+    score DOUBLE PRECISION,
+    occurred_at TIMESTAMPTZ
+) AS $$
+    WITH q AS (
+        SELECT
+            websearch_to_tsquery('english', p_query) AS query,
+            '%' || replace(replace(replace(p_query, '\', '\\'), '%', '\%'), '_', '\_') || '%' AS pattern,
+            '%' || p_digits || '%' AS digits_pattern
+    ),
+    customer_hits AS (
+        SELECT
+            'customer'::TEXT AS entity_type,
+            c.id AS entity_id,
+            c.id AS customer_id,
+            c.name AS customer_name,
+            c.phone AS label,
+            NULL::TEXT AS body,
+            (CASE
+                WHEN c.phone_normalized LIKE q.digits_pattern THEN 1
+                ELSE GREATEST(
+                    similarity(c.name, p_query),
+                    CASE WHEN c.name ILIKE q.pattern OR c.email ILIKE q.pattern THEN 0.5 ELSE 0 END
+                )
+            END)::DOUBLE PRECISION AS score,
+            c.created_at AS occurred_at
+        FROM customers c, q
+        WHERE c.deleted_at IS NULL
+          AND (
+              c.phone_normalized LIKE q.digits_pattern
+              OR c.name % p_query
+              OR c.name ILIKE q.pattern
+              OR c.email ILIKE q.pattern
+          )
+        ORDER BY score DESC, c.name
+        LIMIT p_limit
+    ),
+    communication_hits AS (
+        -- Ranked, typo-tolerant matching shared with search_communications
+        SELECT
+            'communication'::TEXT,
+            m.id,
+            m.customer_id,
+            cu.name,
+            m.type::TEXT,
+            m.summary,
+            s.rank AS score,
+            m.occurred_at
+        FROM search_communications(
+            p_query,
+            p_limit => p_limit,
+            p_digits => p_digits
+        ) s
+        JOIN communications m ON m.id = s.id
+        JOIN customers cu ON cu.id = m.customer_id AND cu.deleted_at IS NULL
+        ORDER BY score DESC, m.occurred_at DESC
+    ),
+    note_hits AS (
+        SELECT
+            'note'::TEXT,
+            n.id,
+            n.customer_id,
+            cu.name,
+            NULL::TEXT,
+            n.content,
+            ts_rank(n.search_vector, q.query)::DOUBLE PRECISION AS score,
+            n.created_at
+        FROM customer_notes n
+        JOIN customers cu ON cu.id = n.customer_id AND cu.deleted_at IS NULL, q
+        WHERE n.search_vector @@ q.query
+        ORDER BY score DESC, n.created_at DESC
+        LIMIT p_limit
+    ),
+    estimate_hits AS (
+        SELECT
+            'estimate'::TEXT,
+            e.id,
+            e.customer_id,
+            cu.name,
+            e.estimate_number,
+            COALESCE(
+                (SELECT string_agg(item->>'description', ' · ')
+                 FROM jsonb_array_elements(e.line_items) AS item),
+                ''
+            ) || COALESCE(' · ' || e.notes, ''),
+            (CASE
+                WHEN NULLIF(regexp_replace(e.estimate_number, '^EST-0*', ''), '')::INTEGER
+                     = p_estimate_number THEN 1
+                ELSE ts_rank(e.search_vector, q.query)
+            END)::DOUBLE PRECISION AS score,
+            e.created_at
+        FROM estimates e
+        JOIN customers cu ON cu.id = e.customer_id AND cu.deleted_at IS NULL, q
+        WHERE NULLIF(regexp_replace(e.estimate_number, '^EST-0*', ''), '')::INTEGER = p_estimate_number
+           OR e.search_vector @@ q.query
+        ORDER BY score DESC, e.created_at DESC
+        LIMIT p_limit
+    ),
+    property_hits AS (
+        SELECT
+            'property'::TEXT,
+            p.id,
+            p.customer_id,
+            cu.name,
+            p.address_line1 || ', ' || p.city || ', ' || p.state || ' ' || p.zip_code,
+            NULL::TEXT,
+            GREATEST(
+                word_similarity(p_query, p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code),
+                CASE
+                    WHEN (p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code) ILIKE q.pattern
+                    THEN 0.5 ELSE 0
+                END
+            )::DOUBLE PRECISION AS score,
+            p.created_at
+        FROM properties p
+        JOIN customers cu ON cu.id = p.customer_id AND cu.deleted_at IS NULL, q
+        WHERE p_query <% (p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code)
+           OR (p.address_line1 || ' ' || p.city || ' ' || p.state || ' ' || p.zip_code) ILIKE q.pattern
+        ORDER BY score DESC, p.address_line1
+        LIMIT p_limit
+    ),
+    hits AS (
+        SELECT * FROM customer_hits
+        UNION ALL SELECT * FROM communication_hits
+        UNION ALL SELECT * FROM note_hits
+        UNION ALL SELECT * FROM estimate_hits
+        UNION ALL SELECT * FROM property_hits
+    )
+    SELECT
+        h.entity_type,
+        h.entity_id,
+        h.customer_id,
+        h.customer_name,
+        h.label,
+        CASE
+            WHEN h.body IS NULL OR h.body = '' THEN NULL
+            ELSE ts_headline(
+                'english',
+                h.body,
+                q.query,
+                format(
+                    'StartSel=%s, StopSel=%s, MaxFragments=1, MaxWords=20, MinWords=8',
+                    chr(57344),
+                    chr(57345)
+                )
+            )
+        END,
+        h.score,
+        h.occurred_at
+    FROM hits h, q
+    ORDER BY h.entity_type, h.score DESC;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.summary_digits IS 'Auto-generated digit runs from summary (phone numbers, gate codes, estimate numbers) for substring search';
+COMMENT ON FUNCTION search_communications(TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID, TEXT) IS 'Ranked full-text, fuzzy and digit communication search with highlighted snippets, paged by offset or keyset';
@@ -0,0 +1,194 @@
+-- ============================================================================
+-- Migration: 00031_communication_saved_views.sql
+-- Description: Named, shareable communication filter sets, and the richer
+--              filters (logged by, several types/directions, customer tags,
+--              attachments) in communication search
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Table: communication_saved_views
+-- Description: Filter sets saved by name. Shared views are visible to every
+--              admin and can be opened from a link; private views only to
+--              the admin who saved them. filters holds the application's
+--              CommunicationFilters object and is validated there.
+-- ============================================================================
+CREATE TABLE communication_saved_views (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Display name (unique per admin, case-insensitive)
+    name TEXT NOT NULL,
+
+    -- CommunicationFilters as JSON
+    filters JSONB NOT NULL DEFAULT '{}'::jsonb,
+
+    -- Visible to every admin
+    is_shared BOOLEAN NOT NULL DEFAULT true,
+
+    -- Admin who saved the view
+    created_by UUID NOT NULL REFERENCES admins(id) ON DELETE CASCADE,
+
+    -- Timestamps
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT communication_saved_views_name_not_empty CHECK (char_length(trim(name)) >= 1),
+    CONSTRAINT communication_saved_views_name_max_length CHECK (char_length(name) <= 100),
+    CONSTRAINT communication_saved_views_filters_object CHECK (jsonb_typeof(filters) = 'object')
+);
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- View names are unique per admin regardless of case
+CREATE UNIQUE INDEX idx_communication_saved_views_name
+    ON communication_saved_views (created_by, lower(name));
+
+-- For listing shared views
+CREATE INDEX idx_communication_saved_views_shared
+    ON communication_saved_views (name)
+    WHERE is_shared;
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+-- Auto-update updated_at timestamp
+CREATE TRIGGER trg_communication_saved_views_updated_at
+    BEFORE UPDATE ON communication_saved_views
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Replaced to accept the CommunicationFilters vocabulary; the signature
+-- changes. Array filters match any listed value and are ignored when NULL;
+-- p_tag_ids matches customers with any of the tags; p_has_attachments
+-- keeps communications with (true) or without (false) attachments.
+DROP FUNCTION IF EXISTS search_communications(
+    TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID, TEXT
+);
+
+CREATE FUNCTION search_communications(
+    p_query TEXT,
+    p_customer_id UUID DEFAULT NULL,
+    p_sort TEXT DEFAULT 'relevance',
+    p_limit INTEGER DEFAULT 10,
+    p_offset INTEGER DEFAULT 0,
+    p_after_rank DOUBLE PRECISION DEFAULT NULL,
+    p_after_occurred_at TIMESTAMPTZ DEFAULT NULL,
+    p_after_id UUID DEFAULT NULL,
+    p_digits TEXT DEFAULT NULL,
+    p_types communication_type[] DEFAULT NULL,
+    p_directions communication_direction[] DEFAULT NULL,
+    p_logged_by UUID[] DEFAULT NULL,
+    p_tag_ids UUID[] DEFAULT NULL,
+    p_has_attachments BOOLEAN DEFAULT NULL,
+    p_from TIMESTAMPTZ DEFAULT NULL,
+    p_to TIMESTAMPTZ DEFAULT NULL,
+    p_call_outcome call_outcome DEFAULT NULL,
+    p_min_call_duration INTEGER DEFAULT NULL,
+    p_max_call_duration INTEGER DEFAULT NULL
+)
+RETURNS TABLE (
+    id UUID,
+    rank DOUBLE PRECISION,
+    headline TEXT,
+    total_count INTEGER
+) AS $$
+    WITH q AS (
+        SELECT
+            websearch_to_tsquery('english', p_query) AS query,
+            '%' || p_digits || '%' AS digits_pattern
+    ),
+    matches AS (
+        SELECT
+            c.id,
+            c.summary,
+            c.occurred_at,
+            (
+                CASE
+                    WHEN c.search_vector @@ q.query
+                      OR c.summary_digits LIKE q.digits_pattern
+                      OR c.call_number LIKE q.digits_pattern
+                    THEN 1 ELSE 0
+                END
+                + ts_rank(c.search_vector, q.query, 32)
+                + 0.5 * word_similarity(p_query, c.summary)
+            )::DOUBLE PRECISION AS rank,
+            count(*) OVER ()::INTEGER AS total_count
+        FROM communications c, q
+        WHERE (
+                c.search_vector @@ q.query
+                OR p_query <% c.summary
+                OR c.summary_digits LIKE q.digits_pattern
+                OR c.call_number LIKE q.digits_pattern
+              )
+          AND (p_customer_id IS NULL OR c.customer_id = p_customer_id)
+          AND (p_types IS NULL OR c.type = ANY (p_types))
+          AND (p_directions IS NULL OR c.direction = ANY (p_directions))
+          AND (p_logged_by IS NULL OR c.logged_by = ANY (p_logged_by))
+          AND (p_tag_ids IS NULL OR EXISTS (
+              SELECT 1 FROM customer_tag_links l
+              WHERE l.customer_id = c.customer_id AND l.tag_id = ANY (p_tag_ids)
+          ))
+          AND (p_has_attachments IS NULL OR p_has_attachments = EXISTS (
+              SELECT 1 FROM customer_attachments a WHERE a.communication_id = c.id
+          ))
+          AND (p_from IS NULL OR c.occurred_at >= p_from)
+          AND (p_to IS NULL OR c.occurred_at <= p_to)
+          AND (p_call_outcome IS NULL OR c.call_outcome = p_call_outcome)
+          AND (p_min_call_duration IS NULL OR c.call_duration_seconds >= p_min_call_duration)
+          AND (p_max_call_duration IS NULL OR c.call_duration_seconds <= p_max_call_duration)
+    ),
+    page AS (
+        SELECT m.*
+        FROM matches m
+        WHERE p_after_id IS NULL
+           OR (p_sort = 'date'
+               AND (m.occurred_at, m.id) < (p_after_occurred_at, p_after_id))
+           OR (p_sort <> 'date'
+               AND (m.rank, m.occurred_at, m.id) < (p_after_rank, p_after_occurred_at, p_after_id))
+        ORDER BY
+            CASE WHEN p_sort = 'date' THEN NULL ELSE m.rank END DESC NULLS LAST,
+            m.occurred_at DESC,
+            m.id DESC
+        LIMIT p_limit
+        OFFSET CASE WHEN p_after_id IS NULL THEN p_offset ELSE 0 END
+    )
+    SELECT
+        p.id,
+        p.rank,
+        ts_headline(
+            'english',
+            p.summary,
+            q.query,
+            format(
+                'StartSel=%s, StopSel=%s, MaxFragments=2, MaxWords=30, MinWords=12, FragmentDelimiter=" … "',
+                chr(57344),
+                chr(57345)
+            )
+        ),
+        p.total_count
+    FROM page p, q
+    ORDER BY
+        CASE WHEN p_sort = 'date' THEN NULL ELSE p.rank END DESC NULLS LAST,
+        p.occurred_at DESC,
+        p.id DESC;
+$$ LANGUAGE sql STABLE
+SET pg_trgm.word_similarity_threshold = 0.4;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON TABLE communication_saved_views IS 'Named communication filter sets, private or shared between admins';
+COMMENT ON COLUMN communication_saved_views.name IS 'Display name (unique per admin, case-insensitive)';
+COMMENT ON COLUMN communication_saved_views.filters IS 'CommunicationFilters object applied when the view is opened';
+COMMENT ON COLUMN communication_saved_views.is_shared IS 'Whether every admin can see and open the view';
+COMMENT ON COLUMN communication_saved_views.created_by IS 'Admin who saved the view';
+COMMENT ON FUNCTION search_communications(TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID, TEXT, communication_type[], communication_direction[], UUID[], UUID[], BOOLEAN, TIMESTAMPTZ, TIMESTAMPTZ, call_outcome, INTEGER, INTEGER) IS 'Ranked full-text, fuzzy and digit communication search with filters and highlighted snippets, paged by offset or keyset';
@@ -0,0 +1,196 @@
+-- ============================================================================
+-- Migration: 00032_communication_revisions.sql
+-- Description: Edit history for communications: a revision with a field-level
+--              diff per edit, an edited marker on communications, and restore
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Table: communication_revisions
+-- Description: One row per edit to a communication's content fields (type,
+--              direction, summary, occurred_at and call details). Written
+--              by a trigger, so edits made outside the app are kept too.
+--              changes maps each changed field to {"from": ..., "to": ...};
+--              previous holds every tracked field as it was before the edit.
+-- ============================================================================
+CREATE TABLE communication_revisions (
+    -- Primary key
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Edited communication
+    communication_id UUID NOT NULL REFERENCES communications(id) ON DELETE CASCADE,
+
+    -- 1 for the first edit, counting up
+    revision_number INTEGER NOT NULL,
+
+    -- Admin who edited (NULL for system changes or a removed admin)
+    edited_by UUID REFERENCES admins(id) ON DELETE SET NULL,
+
+    -- Field-level diff and the state before the edit
+    changes JSONB NOT NULL,
+    previous JSONB NOT NULL,
+
+    -- Set when the edit restored an earlier revision
+    restored_from UUID REFERENCES communication_revisions(id) ON DELETE SET NULL,
+
+    -- When the edit was made
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- Constraints
+    CONSTRAINT communication_revisions_number_positive CHECK (revision_number > 0),
+    CONSTRAINT communication_revisions_changes_object CHECK (jsonb_typeof(changes) = 'object'),
+    CONSTRAINT communication_revisions_unique_number UNIQUE (communication_id, revision_number)
+);
+
+-- ============================================================================
+-- Columns: communications.edited_at, edited_by, revision_count
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN edited_at TIMESTAMPTZ,
+    ADD COLUMN edited_by UUID REFERENCES admins(id) ON DELETE SET NULL,
+    ADD COLUMN revision_count INTEGER NOT NULL DEFAULT 0;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- Revision history is read newest first via the unique (communication_id,
+-- revision_number) index
+
+-- For "edited by" lookups per admin
+CREATE INDEX idx_communication_revisions_edited_by
+    ON communication_revisions (edited_by, created_at DESC)
+    WHERE edited_by IS NOT NULL;
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+-- Record a revision when tracked fields change and stamp the edited marker.
+-- The editor is the signed-in admin (auth.uid()), or NULL for service-role
+-- and SQL changes.
+CREATE OR REPLACE FUNCTION record_communication_revision()
+RETURNS TRIGGER AS $$
+DECLARE
+    old_fields JSONB;
+    new_fields JSONB;
+    diff JSONB := '{}'::jsonb;
+    field TEXT;
+    editor UUID;
+BEGIN
+    old_fields := jsonb_build_object(
+        'type', OLD.type,
+        'direction', OLD.direction,
+        'summary', OLD.summary,
+        'occurred_at', OLD.occurred_at,
+        'call_duration_seconds', OLD.call_duration_seconds,
+        'call_outcome', OLD.call_outcome,
+        'call_number', OLD.call_number
+    );
+    new_fields := jsonb_build_object(
+        'type', NEW.type,
+        'direction', NEW.direction,
+        'summary', NEW.summary,
+        'occurred_at', NEW.occurred_at,
+        'call_duration_seconds', NEW.call_duration_seconds,
+        'call_outcome', NEW.call_outcome,
+        'call_number', NEW.call_number
+    );
+
+    FOR field IN SELECT jsonb_object_keys(old_fields) LOOP
+        IF old_fields -> field IS DISTINCT FROM new_fields -> field THEN
+            diff := diff || jsonb_build_object(
+                field,
+                jsonb_build_object('from', old_fields -> field, 'to', new_fields -> field)
+            );
+        END IF;
+    END LOOP;
+
+    IF diff = '{}'::jsonb THEN
+        RETURN NEW;
+    END IF;
+
+    SELECT id INTO editor FROM admins WHERE id = auth.uid();
+
+    NEW.revision_count := OLD.revision_count + 1;
+    NEW.edited_at := NOW();
+    NEW.edited_by := editor;
+
+    INSERT INTO communication_revisions (
+        communication_id, revision_number, edited_by, changes, previous
+    ) VALUES (
+        NEW.id, NEW.revision_count, editor, diff, old_fields
+    );
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_communications_record_revision
+    BEFORE UPDATE ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION record_communication_revision();
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Put back the values a revision replaced. The restore is itself an edit:
+-- it records a new revision pointing at the restored one. Returns the
+-- communication ID.
+CREATE OR REPLACE FUNCTION restore_communication_revision(p_revision_id UUID)
+RETURNS UUID AS $$
+DECLARE
+    revision RECORD;
+    count_before INTEGER;
+BEGIN
+    SELECT communication_id, previous INTO revision
+    FROM communication_revisions
+    WHERE id = p_revision_id;
+
+    IF NOT FOUND THEN
+        RAISE EXCEPTION 'Revision % does not exist', p_revision_id
+            USING ERRCODE = 'no_data_found';
+    END IF;
+
+    SELECT revision_count INTO count_before
+    FROM communications
+    WHERE id = revision.communication_id
+    FOR UPDATE;
+
+    UPDATE communications
+    SET
+        type = (revision.previous ->> 'type')::communication_type,
+        direction = (revision.previous ->> 'direction')::communication_direction,
+        summary = revision.previous ->> 'summary',
+        occurred_at = (revision.previous ->> 'occurred_at')::timestamptz,
+        call_duration_seconds = (revision.previous ->> 'call_duration_seconds')::integer,
+        call_outcome = (revision.previous ->> 'call_outcome')::call_outcome,
+        call_number = revision.previous ->> 'call_number'
+    WHERE id = revision.communication_id;
+
+    -- Nothing to restore when the values already match
+    UPDATE communication_revisions
+    SET restored_from = p_revision_id
+    WHERE communication_id = revision.communication_id
+      AND revision_number > count_before;
+
+    RETURN revision.communication_id;
+END;
+$$ LANGUAGE plpgsql;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON TABLE communication_revisions IS 'Edit history of communications with field-level diffs';
+COMMENT ON COLUMN communication_revisions.communication_id IS 'Edited communication';
+COMMENT ON COLUMN communication_revisions.revision_number IS 'Edit sequence number per communication, from 1';
+COMMENT ON COLUMN communication_revisions.edited_by IS 'Admin who made the edit (NULL for system changes)';
+COMMENT ON COLUMN communication_revisions.changes IS 'Changed fields as {"field": {"from": old, "to": new}}';
+COMMENT ON COLUMN communication_revisions.previous IS 'All tracked fields before the edit';
+COMMENT ON COLUMN communication_revisions.restored_from IS 'Revision whose previous values this edit restored';
+COMMENT ON COLUMN communication_revisions.created_at IS 'When the edit was made';
+COMMENT ON COLUMN communications.edited_at IS 'When the content was last edited (NULL if never)';
+COMMENT ON COLUMN communications.edited_by IS 'Admin who last edited the content';
+COMMENT ON COLUMN communications.revision_count IS 'Number of recorded edits';
+COMMENT ON FUNCTION restore_communication_revision(UUID) IS 'Restores the values a revision replaced, recording the restore as a new revision';
@@ -0,0 +1,427 @@
+-- ============================================================================
+-- Migration: 00033_communication_trash.sql
+-- Description: Soft delete for communications. Deleting moves a
+--              communication to the trash; lists, search, stats, analytics
+--              and the needs-response queue ignore trashed communications.
+--              Trashed communications are purged by the application after
+--              the configured retention period.
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns: communications.deleted_at, deleted_by
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by UUID REFERENCES admins(id) ON DELETE SET NULL;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- For the trash view (per customer, most recently deleted first)
+CREATE INDEX idx_communications_trash
+    ON communications (customer_id, deleted_at DESC)
+    WHERE deleted_at IS NOT NULL;
+
+-- For purging communications past the retention period
+CREATE INDEX idx_communications_deleted_at
+    ON communications (deleted_at)
+    WHERE deleted_at IS NOT NULL;
+
+-- ============================================================================
+-- Functions: thread aggregates
+-- ============================================================================
+
+-- Replaced so trashed communications do not count towards a thread. A
+-- thread whose communications are all in the trash is kept with
+-- message_count 0 (restoring brings it back); it is deleted only once its
+-- last communication is permanently deleted.
+CREATE OR REPLACE FUNCTION refresh_communication_thread(p_thread_id UUID)
+RETURNS VOID AS $$
+DECLARE
+    latest RECORD;
+    total INTEGER;
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM communications WHERE thread_id = p_thread_id) THEN
+        DELETE FROM communication_threads WHERE id = p_thread_id;
+        RETURN;
+    END IF;
+
+    SELECT COUNT(*) INTO total
+    FROM communications
+    WHERE thread_id = p_thread_id AND deleted_at IS NULL;
+
+    IF total = 0 THEN
+        UPDATE communication_threads
+        SET message_count = 0, updated_at = NOW()
+        WHERE id = p_thread_id;
+        RETURN;
+    END IF;
+
+    SELECT occurred_at, direction INTO latest
+    FROM communications
+    WHERE thread_id = p_thread_id AND deleted_at IS NULL
+    ORDER BY occurred_at DESC, created_at DESC
+    LIMIT 1;
+
+    UPDATE communication_threads
+    SET
+        last_activity_at = latest.occurred_at,
+        message_count = total,
+        status = CASE
+            WHEN latest.direction = 'outbound' THEN 'answered'
+            ELSE 'open'
+        END::communication_thread_status,
+        updated_at = NOW()
+    WHERE id = p_thread_id;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Also refresh the thread when a communication is trashed or restored
+DROP TRIGGER trg_communications_sync_thread ON communications;
+
+CREATE TRIGGER trg_communications_sync_thread
+    AFTER INSERT OR UPDATE OF thread_id, direction, occurred_at, deleted_at OR DELETE ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION sync_communication_thread();
+
+-- ============================================================================
+-- Functions: stats (replaced, signature changes)
+-- ============================================================================
+
+-- p_include_deleted counts trashed communications too
+DROP FUNCTION IF EXISTS get_communication_stats(UUID, TIMESTAMPTZ, TIMESTAMPTZ);
+
+CREATE FUNCTION get_communication_stats(
+    p_customer_id UUID,
+    p_from TIMESTAMPTZ DEFAULT NULL,
+    p_to TIMESTAMPTZ DEFAULT NULL,
+    p_include_deleted BOOLEAN DEFAULT FALSE
+)
+RETURNS TABLE (
+    type communication_type,
+    direction communication_direction,
+    call_outcome call_outcome,
+    total INTEGER,
+    needs_callback INTEGER,
+    connected_duration_sum BIGINT,
+    connected_duration_count INTEGER,
+    last_occurred_at TIMESTAMPTZ
+) AS $$
+    SELECT
+        c.type,
+        c.direction,
+        c.call_outcome,
+        count(*)::INTEGER,
+        count(*) FILTER (WHERE c.needs_callback)::INTEGER,
+        COALESCE(sum(c.call_duration_seconds) FILTER (WHERE c.call_outcome = 'connected'), 0)::BIGINT,
+        count(c.call_duration_seconds) FILTER (WHERE c.call_outcome = 'connected')::INTEGER,
+        max(c.occurred_at)
+    FROM communications c
+    WHERE c.customer_id = p_customer_id
+      AND (p_include_deleted OR c.deleted_at IS NULL)
+      AND (p_from IS NULL OR c.occurred_at >= p_from)
+      AND (p_to IS NULL OR c.occurred_at < p_to)
+    GROUP BY c.type, c.direction, c.call_outcome;
+$$ LANGUAGE sql STABLE;
+
+DROP FUNCTION IF EXISTS get_communication_monthly_counts(UUID, TIMESTAMPTZ, TIMESTAMPTZ, TEXT);
+
+CREATE FUNCTION get_communication_monthly_counts(
+    p_customer_id UUID,
+    p_from TIMESTAMPTZ DEFAULT NULL,
+    p_to TIMESTAMPTZ DEFAULT NULL,
+    p_timezone TEXT DEFAULT 'America/New_York',
+    p_include_deleted BOOLEAN DEFAULT FALSE
+)
+RETURNS TABLE (
+    month DATE,
+    direction communication_direction,
+    total INTEGER
+) AS $$
+    SELECT
+        date_trunc('month', c.occurred_at AT TIME ZONE p_timezone)::DATE,
+        c.direction,
+        count(*)::INTEGER
+    FROM communications c
+    WHERE c.customer_id = p_customer_id
+      AND (p_include_deleted OR c.deleted_at IS NULL)
+      AND (p_from IS NULL OR c.occurred_at >= p_from)
+      AND (p_to IS NULL OR c.occurred_at < p_to)
+    GROUP BY 1, 2
+    ORDER BY 1;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Functions: analytics (replaced, trashed communications excluded)
+-- ============================================================================
+
+CREATE OR REPLACE FUNCTION get_communication_response_times(
+    p_from TIMESTAMPTZ,
+    p_to TIMESTAMPTZ
+)
+RETURNS TABLE (
+    admin_id UUID,
+    responses INTEGER,
+    median_seconds DOUBLE PRECISION,
+    average_seconds DOUBLE PRECISION
+) AS $$
+    WITH waits AS (
+        SELECT c.id, c.customer_id, c.occurred_at
+        FROM communications c
+        WHERE c.direction = 'inbound'
+          AND c.deleted_at IS NULL
+          AND c.occurred_at >= p_from
+          AND c.occurred_at < p_to
+          AND COALESCE((
+              SELECT p.direction
+              FROM communications p
+              WHERE p.customer_id = c.customer_id
+                AND p.deleted_at IS NULL
+                AND (p.occurred_at, p.id) < (c.occurred_at, c.id)
+              ORDER BY p.occurred_at DESC, p.id DESC
+              LIMIT 1
+          ), 'outbound') = 'outbound'
+    ),
+    replies AS (
+        SELECT
+            r.logged_by,
+            EXTRACT(EPOCH FROM (r.occurred_at - w.occurred_at)) AS seconds
+        FROM waits w
+        CROSS JOIN LATERAL (
+            SELECT o.occurred_at, o.logged_by
+            FROM communications o
+            WHERE o.customer_id = w.customer_id
+              AND o.direction = 'outbound'
+              AND o.deleted_at IS NULL
+              AND o.occurred_at >= w.occurred_at
+            ORDER BY o.occurred_at, o.id
+            LIMIT 1
+        ) r
+    )
+    SELECT
+        logged_by,
+        count(*)::INTEGER,
+        percentile_cont(0.5) WITHIN GROUP (ORDER BY seconds),
+        avg(seconds)
+    FROM replies
+    GROUP BY GROUPING SETS ((logged_by), ());
+$$ LANGUAGE sql STABLE;
+
+CREATE OR REPLACE FUNCTION get_communication_volume(
+    p_from TIMESTAMPTZ,
+    p_to TIMESTAMPTZ
+)
+RETURNS TABLE (
+    admin_id UUID,
+    type communication_type,
+    direction communication_direction,
+    total INTEGER
+) AS $$
+    SELECT logged_by, type, direction, count(*)::INTEGER
+    FROM communications
+    WHERE deleted_at IS NULL
+      AND occurred_at >= p_from
+      AND occurred_at < p_to
+    GROUP BY logged_by, type, direction;
+$$ LANGUAGE sql STABLE;
+
+CREATE OR REPLACE FUNCTION get_communication_hours(
+    p_from TIMESTAMPTZ,
+    p_to TIMESTAMPTZ,
+    p_timezone TEXT DEFAULT 'America/New_York'
+)
+RETURNS TABLE (
+    admin_id UUID,
+    day_of_week INTEGER,
+    hour INTEGER,
+    total INTEGER
+) AS $$
+    SELECT
+        logged_by,
+        EXTRACT(DOW FROM occurred_at AT TIME ZONE p_timezone)::INTEGER,
+        EXTRACT(HOUR FROM occurred_at AT TIME ZONE p_timezone)::INTEGER,
+        count(*)::INTEGER
+    FROM communications
+    WHERE deleted_at IS NULL
+      AND occurred_at >= p_from
+      AND occurred_at < p_to
+    GROUP BY 1, 2, 3;
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Functions: needs-response queue (replaced, trashed communications excluded)
+-- ============================================================================
+
+-- A trashed reply no longer answers the customer
+CREATE OR REPLACE FUNCTION get_needs_response_queue(p_customer_id UUID DEFAULT NULL)
+RETURNS TABLE (
+    customer_id UUID,
+    communication_id UUID,
+    waiting_since TIMESTAMPTZ,
+    unanswered_count INTEGER,
+    communication_ids UUID[]
+) AS $$
+    WITH pending AS (
+        SELECT c.id, c.customer_id, c.occurred_at
+        FROM communications c
+        JOIN customers cu ON cu.id = c.customer_id AND cu.deleted_at IS NULL
+        WHERE c.direction = 'inbound'
+          AND c.deleted_at IS NULL
+          AND (p_customer_id IS NULL OR c.customer_id = p_customer_id)
+          AND c.response_dismissed_at IS NULL
+          AND (c.response_snoozed_until IS NULL OR c.response_snoozed_until <= now())
+          AND NOT EXISTS (
+              SELECT 1
+              FROM communications o
+              WHERE o.customer_id = c.customer_id
+                AND o.direction = 'outbound'
+                AND o.deleted_at IS NULL
+                AND o.occurred_at >= c.occurred_at
+          )
+    )
+    SELECT
+        p.customer_id,
+        (array_agg(p.id ORDER BY p.occurred_at, p.id))[1],
+        min(p.occurred_at),
+        count(*)::INTEGER,
+        array_agg(p.id ORDER BY p.occurred_at, p.id)
+    FROM pending p
+    GROUP BY p.customer_id
+    ORDER BY min(p.occurred_at);
+$$ LANGUAGE sql STABLE;
+
+-- ============================================================================
+-- Functions: search (replaced, signature changes)
+-- ============================================================================
+
+-- p_include_deleted searches trashed communications too. global_search
+-- calls this with the default, so the header search skips the trash.
+-- p_from / p_to bound occurred_at half-open ([p_from, p_to)), like the
+-- stats functions.
+DROP FUNCTION IF EXISTS search_communications(
+    TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID, TEXT,
+    communication_type[], communication_direction[], UUID[], UUID[], BOOLEAN,
+    TIMESTAMPTZ, TIMESTAMPTZ, call_outcome, INTEGER, INTEGER
+);
+
+CREATE FUNCTION search_communications(
+    p_query TEXT,
+    p_customer_id UUID DEFAULT NULL,
+    p_sort TEXT DEFAULT 'relevance',
+    p_limit INTEGER DEFAULT 10,
+    p_offset INTEGER DEFAULT 0,
+    p_after_rank DOUBLE PRECISION DEFAULT NULL,
+    p_after_occurred_at TIMESTAMPTZ DEFAULT NULL,
+    p_after_id UUID DEFAULT NULL,
+    p_digits TEXT DEFAULT NULL,
+    p_types communication_type[] DEFAULT NULL,
+    p_directions communication_direction[] DEFAULT NULL,
+    p_logged_by UUID[] DEFAULT NULL,
+    p_tag_ids UUID[] DEFAULT NULL,
+    p_has_attachments BOOLEAN DEFAULT NULL,
+    p_from TIMESTAMPTZ DEFAULT NULL,
+    p_to TIMESTAMPTZ DEFAULT NULL,
+    p_call_outcome call_outcome DEFAULT NULL,
+    p_min_call_duration INTEGER DEFAULT NULL,
+    p_max_call_duration INTEGER DEFAULT NULL,
+    p_include_deleted BOOLEAN DEFAULT FALSE
+)
+RETURNS TABLE (
+    id UUID,
+    rank DOUBLE PRECISION,
+    headline TEXT,
+    total_count INTEGER
+) AS $$
+    WITH q AS (
+        SELECT
+            websearch_to_tsquery('english', p_query) AS query,
+            '%' || p_digits || '%' AS digits_pattern
+    ),
+    matches AS (
+        SELECT
+            c.id,
+            c.summary,
+            c.occurred_at,
+            (
+                CASE
+                    WHEN c.search_vector @@ q.query
+                      OR c.summary_digits LIKE q.digits_pattern
+                      OR c.call_number LIKE q.digits_pattern
+                    THEN 1 ELSE 0
+                END
+                + ts_rank(c.search_vector, q.query, 32)
+                + 0.5 * word_similarity(p_query, c.summary)
+            )::DOUBLE PRECISION AS rank,
+            count(*) OVER ()::INTEGER AS total_count
+        FROM communications c, q
+        WHERE (
+                c.search_vector @@ q.query
+                OR p_query <% c.summary
+                OR c.summary_digits LIKE q.digits_pattern
+                OR c.call_number LIKE q.digits_pattern
+              )
+          AND (p_include_deleted OR c.deleted_at IS NULL)
+          AND (p_customer_id IS NULL OR c.customer_id = p_customer_id)
+          AND (p_types IS NULL OR c.type = ANY (p_types))
+          AND (p_directions IS NULL OR c.direction = ANY (p_directions))
+          AND (p_logged_by IS NULL OR c.logged_by = ANY (p_logged_by))
+          AND (p_tag_ids IS NULL OR EXISTS (
+              SELECT 1 FROM customer_tag_links l
+              WHERE l.customer_id = c.customer_id AND l.tag_id = ANY (p_tag_ids)
+          ))
+          AND (p_has_attachments IS NULL OR p_has_attachments = EXISTS (
+              SELECT 1 FROM customer_attachments a WHERE a.communication_id = c.id
+          ))
+          AND (p_from IS NULL OR c.occurred_at >= p_from)
+          AND (p_to IS NULL OR c.occurred_at < p_to)
+          AND (p_call_outcome IS NULL OR c.call_outcome = p_call_outcome)
+          AND (p_min_call_duration IS NULL OR c.call_duration_seconds >= p_min_call_duration)
+          AND (p_max_call_duration IS NULL OR c.call_duration_seconds <= p_max_call_duration)
+    ),
+    page AS (
+        SELECT m.*
+        FROM matches m
+        WHERE p_after_id IS NULL
+           OR (p_sort = 'date'
+               AND (m.occurred_at, m.id) < (p_after_occurred_at, p_after_id))
+           OR (p_sort <> 'date'
+               AND (m.rank, m.occurred_at, m.id) < (p_after_rank, p_after_occurred_at, p_after_id))
+        ORDER BY
+            CASE WHEN p_sort = 'date' THEN NULL ELSE m.rank END DESC NULLS LAST,
+            m.occurred_at DESC,
+            m.id DESC
+        LIMIT p_limit
+        OFFSET CASE WHEN p_after_id IS NULL THEN p_offset ELSE 0 END
+    )
+    SELECT
+        p.id,
+        p.rank,
+        ts_headline(
+            'english',
+            p.summary,
+            q.query,
+            format(
+                'StartSel=%s, StopSel=%s, MaxFragments=2, MaxWords=30, MinWords=12, FragmentDelimiter=" … "',
+                chr(57344),
+                chr(57345)
+            )
+        ),
+        p.total_count
+    FROM page p, q
+    ORDER BY
+        CASE WHEN p_sort = 'date' THEN NULL ELSE p.rank END DESC NULLS LAST,
+        p.occurred_at DESC,
+        p.id DESC;
+$$ LANGUAGE sql STABLE
+SET pg_trgm.word_similarity_threshold = 0.4;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.deleted_at IS 'Soft delete timestamp. NULL = active; set = in the trash until purged.';
+COMMENT ON COLUMN communications.deleted_by IS 'Admin who moved the communication to the trash';
+COMMENT ON FUNCTION get_communication_stats(UUID, TIMESTAMPTZ, TIMESTAMPTZ, BOOLEAN) IS 'Customer communication counts grouped by type, direction and call outcome, with call duration totals and latest occurrence';
+COMMENT ON FUNCTION get_communication_monthly_counts(UUID, TIMESTAMPTZ, TIMESTAMPTZ, TEXT, BOOLEAN) IS 'Customer communication counts per local month and direction';
+COMMENT ON FUNCTION search_communications(TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID, TEXT, communication_type[], communication_direction[], UUID[], UUID[], BOOLEAN, TIMESTAMPTZ, TIMESTAMPTZ, call_outcome, INTEGER, INTEGER, BOOLEAN) IS 'Ranked full-text, fuzzy and digit communication search with filters and highlighted snippets, paged by offset or keyset';
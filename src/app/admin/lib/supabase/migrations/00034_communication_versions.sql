@@ -0,0 +1,48 @@
+-- ============================================================================
+-- Migration: 00034_communication_versions.sql
+-- Description: Optimistic locking for communication edits, matching
+--              calendar_events.version
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns: communications.version
+-- ============================================================================
+ALTER TABLE communications
+    ADD COLUMN version INTEGER NOT NULL DEFAULT 1,
+    ADD CONSTRAINT communications_version_positive CHECK (version >= 1);
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+-- Increment version when editable content changes. Delivery updates,
+-- callbacks, queue state and trash do not count, so they never make an
+-- open edit form stale.
+CREATE OR REPLACE FUNCTION update_communication_version()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF (
+        OLD.type IS DISTINCT FROM NEW.type OR
+        OLD.direction IS DISTINCT FROM NEW.direction OR
+        OLD.summary IS DISTINCT FROM NEW.summary OR
+        OLD.occurred_at IS DISTINCT FROM NEW.occurred_at OR
+        OLD.call_duration_seconds IS DISTINCT FROM NEW.call_duration_seconds OR
+        OLD.call_outcome IS DISTINCT FROM NEW.call_outcome OR
+        OLD.call_number IS DISTINCT FROM NEW.call_number
+    ) THEN
+        NEW.version = OLD.version + 1;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_communications_version
+    BEFORE UPDATE ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION update_communication_version();
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.version IS 'Version for optimistic locking (increments on content edits)';
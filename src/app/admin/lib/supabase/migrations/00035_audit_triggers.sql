@@ -0,0 +1,113 @@
+-- ============================================================================
+-- Migration: 00035_audit_triggers.sql
+-- Description: Audit triggers for every table allowed in audit_log
+--              (see 00013_audit_log.sql)
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Write one audit_log row per inserted, updated or deleted row. old_values /
+-- new_values hold the whole row as JSONB, minus generated search columns.
+-- changed_by is the signed-in admin (auth.uid()), or NULL for service-role
+-- and SQL changes. Updates that only touch updated_at are skipped.
+--
+-- customer_tag_links has no id of its own, so its record_id is the
+-- customer_id; the tag is in the values.
+--
+-- SECURITY DEFINER so the insert succeeds whatever the caller may write.
+CREATE OR REPLACE FUNCTION record_audit_log()
+RETURNS TRIGGER AS $$
+DECLARE
+    old_row JSONB;
+    new_row JSONB;
+    row_id UUID;
+    editor UUID;
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        old_row := to_jsonb(OLD) - 'search_vector' - 'summary_digits';
+    END IF;
+    IF TG_OP IN ('INSERT', 'UPDATE') THEN
+        new_row := to_jsonb(NEW) - 'search_vector' - 'summary_digits';
+    END IF;
+
+    IF TG_OP = 'UPDATE' AND (old_row - 'updated_at') = (new_row - 'updated_at') THEN
+        RETURN NULL;
+    END IF;
+
+    IF TG_TABLE_NAME = 'customer_tag_links' THEN
+        row_id := (COALESCE(new_row, old_row) ->> 'customer_id')::UUID;
+    ELSE
+        row_id := (COALESCE(new_row, old_row) ->> 'id')::UUID;
+    END IF;
+
+    SELECT id INTO editor FROM public.admins WHERE id = auth.uid();
+
+    INSERT INTO public.audit_log (table_name, record_id, action, old_values, new_values, changed_by)
+    VALUES (TG_TABLE_NAME, row_id, TG_OP::audit_action, old_row, new_row, editor);
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql SECURITY DEFINER
+SET search_path = public;
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+CREATE TRIGGER trg_customers_audit
+    AFTER INSERT OR UPDATE OR DELETE ON customers
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_properties_audit
+    AFTER INSERT OR UPDATE OR DELETE ON properties
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_pools_audit
+    AFTER INSERT OR UPDATE OR DELETE ON pools
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_calendar_events_audit
+    AFTER INSERT OR UPDATE OR DELETE ON calendar_events
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_estimates_audit
+    AFTER INSERT OR UPDATE OR DELETE ON estimates
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_communications_audit
+    AFTER INSERT OR UPDATE OR DELETE ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_customer_notes_audit
+    AFTER INSERT OR UPDATE OR DELETE ON customer_notes
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_customer_attachments_audit
+    AFTER INSERT OR UPDATE OR DELETE ON customer_attachments
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_customer_tags_audit
+    AFTER INSERT OR UPDATE OR DELETE ON customer_tags
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+CREATE TRIGGER trg_customer_tag_links_audit
+    AFTER INSERT OR UPDATE OR DELETE ON customer_tag_links
+    FOR EACH ROW
+    EXECUTE FUNCTION record_audit_log();
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON FUNCTION record_audit_log() IS 'Audit trigger: records the old and new row and the acting admin in audit_log';
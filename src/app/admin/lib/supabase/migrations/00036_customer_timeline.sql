@@ -0,0 +1,111 @@
+-- ============================================================================
+-- Migration: 00036_customer_timeline.sql
+-- Description: Customer activity timeline across communications, notes,
+--              estimates, calendar events and attachments
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- View: customer_timeline
+-- Description: One row per timeline entry, so the timeline can be filtered
+--              and keyset-paginated on (occurred_at, id) as a single list.
+--              Columns that only apply to communications are NULL for
+--              other kinds. Trashed communications are left out, as are
+--              attachments on a communication or note (they are shown with
+--              their parent). Each branch is served by its table's
+--              (customer_id, date) index.
+-- ============================================================================
+CREATE VIEW customer_timeline
+WITH (security_invoker = true) AS
+    SELECT
+        'communication'::TEXT AS kind,
+        c.id,
+        c.customer_id,
+        c.occurred_at,
+        c.logged_by AS actor_id,
+        c.type AS communication_type,
+        c.direction,
+        c.call_outcome,
+        c.call_duration_seconds,
+        EXISTS (
+            SELECT 1 FROM customer_attachments a WHERE a.communication_id = c.id
+        ) AS has_attachments,
+        c.search_vector
+    FROM communications c
+    WHERE c.deleted_at IS NULL
+
+    UNION ALL
+
+    SELECT
+        'note'::TEXT,
+        n.id,
+        n.customer_id,
+        n.created_at,
+        n.created_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        EXISTS (
+            SELECT 1 FROM customer_attachments a WHERE a.note_id = n.id
+        ),
+        n.search_vector
+    FROM customer_notes n
+
+    UNION ALL
+
+    SELECT
+        'estimate'::TEXT,
+        e.id,
+        e.customer_id,
+        e.created_at,
+        e.created_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        FALSE,
+        e.search_vector
+    FROM estimates e
+
+    UNION ALL
+
+    SELECT
+        'event'::TEXT,
+        ev.id,
+        ev.customer_id,
+        ev.start_datetime,
+        ev.created_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        FALSE,
+        to_tsvector('english', ev.title || ' ' || COALESCE(ev.description, ''))
+    FROM calendar_events ev
+
+    UNION ALL
+
+    SELECT
+        'attachment'::TEXT,
+        a.id,
+        a.customer_id,
+        a.created_at,
+        a.uploaded_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        TRUE,
+        to_tsvector('english', a.filename)
+    FROM customer_attachments a
+    WHERE a.communication_id IS NULL AND a.note_id IS NULL;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON VIEW customer_timeline IS 'Customer activity across communications, notes, estimates, events and attachments';
+COMMENT ON COLUMN customer_timeline.kind IS 'communication, note, estimate, event or attachment';
+COMMENT ON COLUMN customer_timeline.occurred_at IS 'When it happened: occurred_at, start_datetime or created_at';
+COMMENT ON COLUMN customer_timeline.actor_id IS 'Admin who logged, wrote, created or uploaded it';
+COMMENT ON COLUMN customer_timeline.search_vector IS 'English full-text vector for every kind, so one websearch_to_tsquery(''english'', ...) matches them all';
@@ -0,0 +1,489 @@
+-- ============================================================================
+-- Migration: 00037_communication_links.sql
+-- Description: Optional links from a communication to the estimate,
+--              calendar event, property or pool it is about
+-- Pool Service CRM - Database Schema
+-- ============================================================================
+
+-- ============================================================================
+-- Columns: communications links
+-- ============================================================================
+
+-- ON DELETE SET NULL: deleting an estimate or event keeps the conversation.
+-- calendar_event_id is what the communication is about; the existing
+-- calendar_events.communication_id is the follow-up scheduled from it.
+ALTER TABLE communications
+    ADD COLUMN estimate_id UUID REFERENCES estimates(id) ON DELETE SET NULL,
+    ADD COLUMN calendar_event_id UUID REFERENCES calendar_events(id) ON DELETE SET NULL,
+    ADD COLUMN property_id UUID REFERENCES properties(id) ON DELETE SET NULL,
+    ADD COLUMN pool_id UUID REFERENCES pools(id) ON DELETE SET NULL;
+
+-- ============================================================================
+-- Indexes
+-- ============================================================================
+
+-- Related communications for an estimate, event, property or pool
+CREATE INDEX idx_communications_estimate_id ON communications (estimate_id, occurred_at DESC)
+    WHERE estimate_id IS NOT NULL;
+
+CREATE INDEX idx_communications_calendar_event_id ON communications (calendar_event_id, occurred_at DESC)
+    WHERE calendar_event_id IS NOT NULL;
+
+CREATE INDEX idx_communications_property_id ON communications (property_id, occurred_at DESC)
+    WHERE property_id IS NOT NULL;
+
+CREATE INDEX idx_communications_pool_id ON communications (pool_id, occurred_at DESC)
+    WHERE pool_id IS NOT NULL;
+
+-- ============================================================================
+-- Triggers
+-- ============================================================================
+
+-- Linked records must belong to the communication's customer, and a linked
+-- pool must be at the linked property. A pool without a property fills in
+-- the property.
+CREATE OR REPLACE FUNCTION check_communication_links()
+RETURNS TRIGGER AS $$
+DECLARE
+    pool_property UUID;
+BEGIN
+    IF NEW.estimate_id IS NOT NULL AND NOT EXISTS (
+        SELECT 1 FROM estimates WHERE id = NEW.estimate_id AND customer_id = NEW.customer_id
+    ) THEN
+        RAISE EXCEPTION 'Linked estimate belongs to a different customer';
+    END IF;
+
+    IF NEW.calendar_event_id IS NOT NULL AND NOT EXISTS (
+        SELECT 1 FROM calendar_events
+        WHERE id = NEW.calendar_event_id AND customer_id = NEW.customer_id
+    ) THEN
+        RAISE EXCEPTION 'Linked calendar event belongs to a different customer';
+    END IF;
+
+    IF NEW.pool_id IS NOT NULL THEN
+        SELECT property_id INTO pool_property FROM pools WHERE id = NEW.pool_id;
+
+        IF NEW.property_id IS NULL THEN
+            NEW.property_id := pool_property;
+        ELSIF pool_property IS DISTINCT FROM NEW.property_id THEN
+            RAISE EXCEPTION 'Linked pool is not at the linked property';
+        END IF;
+    END IF;
+
+    IF NEW.property_id IS NOT NULL AND NOT EXISTS (
+        SELECT 1 FROM properties WHERE id = NEW.property_id AND customer_id = NEW.customer_id
+    ) THEN
+        RAISE EXCEPTION 'Linked property belongs to a different customer';
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_communications_check_links
+    BEFORE INSERT OR UPDATE OF customer_id, estimate_id, calendar_event_id, property_id, pool_id
+    ON communications
+    FOR EACH ROW
+    EXECUTE FUNCTION check_communication_links();
+
+-- Re-linking is an edit: it bumps the version (00034) so concurrent edits
+-- conflict, and records a revision (00032) so it shows in the history and
+-- can be restored
+CREATE OR REPLACE FUNCTION update_communication_version()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF (
+        OLD.type IS DISTINCT FROM NEW.type OR
+        OLD.direction IS DISTINCT FROM NEW.direction OR
+        OLD.summary IS DISTINCT FROM NEW.summary OR
+        OLD.occurred_at IS DISTINCT FROM NEW.occurred_at OR
+        OLD.call_duration_seconds IS DISTINCT FROM NEW.call_duration_seconds OR
+        OLD.call_outcome IS DISTINCT FROM NEW.call_outcome OR
+        OLD.call_number IS DISTINCT FROM NEW.call_number OR
+        OLD.estimate_id IS DISTINCT FROM NEW.estimate_id OR
+        OLD.calendar_event_id IS DISTINCT FROM NEW.calendar_event_id OR
+        OLD.property_id IS DISTINCT FROM NEW.property_id OR
+        OLD.pool_id IS DISTINCT FROM NEW.pool_id
+    ) THEN
+        NEW.version = OLD.version + 1;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION record_communication_revision()
+RETURNS TRIGGER AS $$
+DECLARE
+    old_fields JSONB;
+    new_fields JSONB;
+    diff JSONB := '{}'::jsonb;
+    field TEXT;
+    editor UUID;
+BEGIN
+    old_fields := jsonb_build_object(
+        'type', OLD.type,
+        'direction', OLD.direction,
+        'summary', OLD.summary,
+        'occurred_at', OLD.occurred_at,
+        'call_duration_seconds', OLD.call_duration_seconds,
+        'call_outcome', OLD.call_outcome,
+        'call_number', OLD.call_number,
+        'estimate_id', OLD.estimate_id,
+        'calendar_event_id', OLD.calendar_event_id,
+        'property_id', OLD.property_id,
+        'pool_id', OLD.pool_id
+    );
+    new_fields := jsonb_build_object(
+        'type', NEW.type,
+        'direction', NEW.direction,
+        'summary', NEW.summary,
+        'occurred_at', NEW.occurred_at,
+        'call_duration_seconds', NEW.call_duration_seconds,
+        'call_outcome', NEW.call_outcome,
+        'call_number', NEW.call_number,
+        'estimate_id', NEW.estimate_id,
+        'calendar_event_id', NEW.calendar_event_id,
+        'property_id', NEW.property_id,
+        'pool_id', NEW.pool_id
+    );
+
+    FOR field IN SELECT jsonb_object_keys(old_fields) LOOP
+        IF old_fields -> field IS DISTINCT FROM new_fields -> field THEN
+            diff := diff || jsonb_build_object(
+                field,
+                jsonb_build_object('from', old_fields -> field, 'to', new_fields -> field)
+            );
+        END IF;
+    END LOOP;
+
+    IF diff = '{}'::jsonb THEN
+        RETURN NEW;
+    END IF;
+
+    SELECT id INTO editor FROM admins WHERE id = auth.uid();
+
+    NEW.revision_count := OLD.revision_count + 1;
+    NEW.edited_at := NOW();
+    NEW.edited_by := editor;
+
+    INSERT INTO communication_revisions (
+        communication_id, revision_number, edited_by, changes, previous
+    ) VALUES (
+        NEW.id, NEW.revision_count, editor, diff, old_fields
+    );
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- ============================================================================
+-- Functions
+-- ============================================================================
+
+-- Restore puts the links back too. A linked record deleted since then
+-- stays unlinked; revisions from before this migration have no link
+-- fields in previous and leave the links alone.
+CREATE OR REPLACE FUNCTION restore_communication_revision(p_revision_id UUID)
+RETURNS UUID AS $$
+DECLARE
+    revision RECORD;
+    count_before INTEGER;
+BEGIN
+    SELECT communication_id, previous INTO revision
+    FROM communication_revisions
+    WHERE id = p_revision_id;
+
+    IF NOT FOUND THEN
+        RAISE EXCEPTION 'Revision % does not exist', p_revision_id
+            USING ERRCODE = 'no_data_found';
+    END IF;
+
+    SELECT revision_count INTO count_before
+    FROM communications
+    WHERE id = revision.communication_id
+    FOR UPDATE;
+
+    UPDATE communications c
+    SET
+        type = (revision.previous ->> 'type')::communication_type,
+        direction = (revision.previous ->> 'direction')::communication_direction,
+        summary = revision.previous ->> 'summary',
+        occurred_at = (revision.previous ->> 'occurred_at')::timestamptz,
+        call_duration_seconds = (revision.previous ->> 'call_duration_seconds')::integer,
+        call_outcome = (revision.previous ->> 'call_outcome')::call_outcome,
+        call_number = revision.previous ->> 'call_number',
+        estimate_id = CASE WHEN revision.previous ? 'estimate_id' THEN (
+            SELECT e.id FROM estimates e
+            WHERE e.id = (revision.previous ->> 'estimate_id')::uuid
+        ) ELSE c.estimate_id END,
+        calendar_event_id = CASE WHEN revision.previous ? 'calendar_event_id' THEN (
+            SELECT ev.id FROM calendar_events ev
+            WHERE ev.id = (revision.previous ->> 'calendar_event_id')::uuid
+        ) ELSE c.calendar_event_id END,
+        property_id = CASE WHEN revision.previous ? 'property_id' THEN (
+            SELECT p.id FROM properties p
+            WHERE p.id = (revision.previous ->> 'property_id')::uuid
+        ) ELSE c.property_id END,
+        pool_id = CASE WHEN revision.previous ? 'pool_id' THEN (
+            SELECT pl.id FROM pools pl
+            WHERE pl.id = (revision.previous ->> 'pool_id')::uuid
+        ) ELSE c.pool_id END
+    WHERE c.id = revision.communication_id;
+
+    -- Nothing to restore when the values already match
+    UPDATE communication_revisions
+    SET restored_from = p_revision_id
+    WHERE communication_id = revision.communication_id
+      AND revision_number > count_before;
+
+    RETURN revision.communication_id;
+END;
+$$ LANGUAGE plpgsql;
+
+-- search_communications gains the link filters
+DROP FUNCTION IF EXISTS search_communications(
+    TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID, TEXT,
+    communication_type[], communication_direction[], UUID[], UUID[], BOOLEAN,
+    TIMESTAMPTZ, TIMESTAMPTZ, call_outcome, INTEGER, INTEGER, BOOLEAN
+);
+
+CREATE FUNCTION search_communications(
+    p_query TEXT,
+    p_customer_id UUID DEFAULT NULL,
+    p_sort TEXT DEFAULT 'relevance',
+    p_limit INTEGER DEFAULT 10,
+    p_offset INTEGER DEFAULT 0,
+    p_after_rank DOUBLE PRECISION DEFAULT NULL,
+    p_after_occurred_at TIMESTAMPTZ DEFAULT NULL,
+    p_after_id UUID DEFAULT NULL,
+    p_digits TEXT DEFAULT NULL,
+    p_types communication_type[] DEFAULT NULL,
+    p_directions communication_direction[] DEFAULT NULL,
+    p_logged_by UUID[] DEFAULT NULL,
+    p_tag_ids UUID[] DEFAULT NULL,
+    p_has_attachments BOOLEAN DEFAULT NULL,
+    p_from TIMESTAMPTZ DEFAULT NULL,
+    p_to TIMESTAMPTZ DEFAULT NULL,
+    p_call_outcome call_outcome DEFAULT NULL,
+    p_min_call_duration INTEGER DEFAULT NULL,
+    p_max_call_duration INTEGER DEFAULT NULL,
+    p_include_deleted BOOLEAN DEFAULT FALSE,
+    p_estimate_id UUID DEFAULT NULL,
+    p_calendar_event_id UUID DEFAULT NULL,
+    p_property_id UUID DEFAULT NULL,
+    p_pool_id UUID DEFAULT NULL
+)
+RETURNS TABLE (
+    id UUID,
+    rank DOUBLE PRECISION,
+    headline TEXT,
+    total_count INTEGER
+) AS $$
+    WITH q AS (
+        SELECT
+            websearch_to_tsquery('english', p_query) AS query,
+            '%' || p_digits || '%' AS digits_pattern
+    ),
+    matches AS (
+        SELECT
+            c.id,
+            c.summary,
+            c.occurred_at,
+            (
+                CASE
+                    WHEN c.search_vector @@ q.query
+                      OR c.summary_digits LIKE q.digits_pattern
+                      OR c.call_number LIKE q.digits_pattern
+                    THEN 1 ELSE 0
+                END
+                + ts_rank(c.search_vector, q.query, 32)
+                + 0.5 * word_similarity(p_query, c.summary)
+            )::DOUBLE PRECISION AS rank,
+            count(*) OVER ()::INTEGER AS total_count
+        FROM communications c, q
+        WHERE (
+                c.search_vector @@ q.query
+                OR p_query <% c.summary
+                OR c.summary_digits LIKE q.digits_pattern
+                OR c.call_number LIKE q.digits_pattern
+              )
+          AND (p_include_deleted OR c.deleted_at IS NULL)
+          AND (p_customer_id IS NULL OR c.customer_id = p_customer_id)
+          AND (p_types IS NULL OR c.type = ANY (p_types))
+          AND (p_directions IS NULL OR c.direction = ANY (p_directions))
+          AND (p_logged_by IS NULL OR c.logged_by = ANY (p_logged_by))
+          AND (p_tag_ids IS NULL OR EXISTS (
+              SELECT 1 FROM customer_tag_links l
+              WHERE l.customer_id = c.customer_id AND l.tag_id = ANY (p_tag_ids)
+          ))
+          AND (p_has_attachments IS NULL OR p_has_attachments = EXISTS (
+              SELECT 1 FROM customer_attachments a WHERE a.communication_id = c.id
+          ))
+          AND (p_from IS NULL OR c.occurred_at >= p_from)
+          AND (p_to IS NULL OR c.occurred_at < p_to)
+          AND (p_call_outcome IS NULL OR c.call_outcome = p_call_outcome)
+          AND (p_min_call_duration IS NULL OR c.call_duration_seconds >= p_min_call_duration)
+          AND (p_max_call_duration IS NULL OR c.call_duration_seconds <= p_max_call_duration)
+          AND (p_estimate_id IS NULL OR c.estimate_id = p_estimate_id)
+          AND (p_calendar_event_id IS NULL OR c.calendar_event_id = p_calendar_event_id)
+          AND (p_property_id IS NULL OR c.property_id = p_property_id)
+          AND (p_pool_id IS NULL OR c.pool_id = p_pool_id)
+    ),
+    page AS (
+        SELECT m.*
+        FROM matches m
+        WHERE p_after_id IS NULL
+           OR (p_sort = 'date'
+               AND (m.occurred_at, m.id) < (p_after_occurred_at, p_after_id))
+           OR (p_sort <> 'date'
+               AND (m.rank, m.occurred_at, m.id) < (p_after_rank, p_after_occurred_at, p_after_id))
+        ORDER BY
+            CASE WHEN p_sort = 'date' THEN NULL ELSE m.rank END DESC NULLS LAST,
+            m.occurred_at DESC,
+            m.id DESC
+        LIMIT p_limit
+        OFFSET CASE WHEN p_after_id IS NULL THEN p_offset ELSE 0 END
+    )
+    SELECT
+        p.id,
+        p.rank,
+        ts_headline(
+            'english',
+            p.summary,
+            q.query,
+            format(
+                'StartSel=%s, StopSel=%s, MaxFragments=2, MaxWords=30, MinWords=12, FragmentDelimiter=" … "',
+                chr(57344),
+                chr(57345)
+            )
+        ),
+        p.total_count
+    FROM page p, q
+    ORDER BY
+        CASE WHEN p_sort = 'date' THEN NULL ELSE p.rank END DESC NULLS LAST,
+        p.occurred_at DESC,
+        p.id DESC;
+$$ LANGUAGE sql STABLE
+SET pg_trgm.word_similarity_threshold = 0.4;
+
+-- ============================================================================
+-- View: customer_timeline
+-- Description: Adds the link columns. Communications carry their links;
+--              an estimate or event matches its own ID, and events and
+--              estimates match their property / pool.
+-- ============================================================================
+CREATE OR REPLACE VIEW customer_timeline
+WITH (security_invoker = true) AS
+    SELECT
+        'communication'::TEXT AS kind,
+        c.id,
+        c.customer_id,
+        c.occurred_at,
+        c.logged_by AS actor_id,
+        c.type AS communication_type,
+        c.direction,
+        c.call_outcome,
+        c.call_duration_seconds,
+        EXISTS (
+            SELECT 1 FROM customer_attachments a WHERE a.communication_id = c.id
+        ) AS has_attachments,
+        c.search_vector,
+        c.estimate_id,
+        c.calendar_event_id,
+        c.property_id,
+        c.pool_id
+    FROM communications c
+    WHERE c.deleted_at IS NULL
+
+    UNION ALL
+
+    SELECT
+        'note'::TEXT,
+        n.id,
+        n.customer_id,
+        n.created_at,
+        n.created_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        EXISTS (
+            SELECT 1 FROM customer_attachments a WHERE a.note_id = n.id
+        ),
+        n.search_vector,
+        NULL::UUID,
+        NULL::UUID,
+        NULL::UUID,
+        NULL::UUID
+    FROM customer_notes n
+
+    UNION ALL
+
+    SELECT
+        'estimate'::TEXT,
+        e.id,
+        e.customer_id,
+        e.created_at,
+        e.created_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        FALSE,
+        e.search_vector,
+        e.id,
+        NULL::UUID,
+        NULL::UUID,
+        e.pool_id
+    FROM estimates e
+
+    UNION ALL
+
+    SELECT
+        'event'::TEXT,
+        ev.id,
+        ev.customer_id,
+        ev.start_datetime,
+        ev.created_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        FALSE,
+        to_tsvector('english', ev.title || ' ' || COALESCE(ev.description, '')),
+        NULL::UUID,
+        ev.id,
+        ev.property_id,
+        ev.pool_id
+    FROM calendar_events ev
+
+    UNION ALL
+
+    SELECT
+        'attachment'::TEXT,
+        a.id,
+        a.customer_id,
+        a.created_at,
+        a.uploaded_by,
+        NULL::communication_type,
+        NULL::communication_direction,
+        NULL::call_outcome,
+        NULL::INTEGER,
+        TRUE,
+        to_tsvector('english', a.filename),
+        NULL::UUID,
+        NULL::UUID,
+        NULL::UUID,
+        NULL::UUID
+    FROM customer_attachments a
+    WHERE a.communication_id IS NULL AND a.note_id IS NULL;
+
+-- ============================================================================
+-- Comments
+-- ============================================================================
+COMMENT ON COLUMN communications.estimate_id IS 'Estimate the communication is about';
+COMMENT ON COLUMN communications.calendar_event_id IS 'Calendar event (appointment) the communication is about';
+COMMENT ON COLUMN communications.property_id IS 'Property the communication is about';
+COMMENT ON COLUMN communications.pool_id IS 'Pool the communication is about';
+COMMENT ON COLUMN communications.version IS 'Version for optimistic locking (increments on content and link edits)';
+COMMENT ON FUNCTION search_communications(TEXT, UUID, TEXT, INTEGER, INTEGER, DOUBLE PRECISION, TIMESTAMPTZ, UUID, TEXT, communication_type[], communication_direction[], UUID[], UUID[], BOOLEAN, TIMESTAMPTZ, TIMESTAMPTZ, call_outcome, INTEGER, INTEGER, BOOLEAN, UUID, UUID, UUID, UUID) IS 'Ranked full-text, fuzzy and digit communication search with filters and highlighted snippets, paged by offset or keyset';
@@ -0,0 +1,73 @@
+/**
+ * Analytics Types
+ *
+ * @file src/lib/types/analytics.ts
+ *
+ * Response-time and workload metrics for communications, team-wide and
+ * per admin, over a date range.
+ */
+
+import type { Admin, CommunicationDirection, CommunicationType } from './database';
+
+// =============================================================================
+// Metric Types
+// =============================================================================
+
+/**
+ * Time from an inbound communication to our first outbound reply
+ */
+export interface ResponseTimeMetrics {
+  /** Inbound waits that received a reply */
+  responses: number;
+  medianSeconds: number | null;
+  averageSeconds: number | null;
+}
+
+/**
+ * Communications logged, by type and direction
+ */
+export interface CommunicationVolume {
+  total: number;
+  byType: Record<CommunicationType, number>;
+  byDirection: Record<CommunicationDirection, number>;
+}
+
+/**
+ * When communications happen, in the business timezone
+ */
+export interface CommunicationActivity {
+  /** Counts per hour of day, index 0-23 */
+  byHour: number[];
+  /** Counts per day of week (0 = Sunday) and hour: byDayHour[day][hour] */
+  byDayHour: number[][];
+  /** Up to three hours with the most activity, busiest first */
+  busiestHours: { hour: number; total: number }[];
+}
+
+/**
+ * Metrics for one admin
+ */
+export interface AdminCommunicationMetrics {
+  admin: Pick<Admin, 'id' | 'email' | 'full_name'>;
+  /** Replies this admin sent */
+  responseTime: ResponseTimeMetrics;
+  /** Communications this admin logged */
+  volume: CommunicationVolume;
+  activity: CommunicationActivity;
+}
+
+/**
+ * Communication analytics for a date range
+ */
+export interface CommunicationAnalytics {
+  from: string;
+  to: string;
+  timezone: string;
+  team: {
+    responseTime: ResponseTimeMetrics;
+    volume: CommunicationVolume;
+    activity: CommunicationActivity;
+  };
+  /** Admins with any activity in the range, busiest first */
+  admins: AdminCommunicationMetrics[];
+}
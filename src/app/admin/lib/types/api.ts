@@ -76,6 +76,36 @@ export interface PaginatedResult<T> {
   totalCount?: number;
 }
 
+/**
+ * Keyset pagination cursor, shared by every timeline list
+ *
+ * Identifies the row a page starts after: its sort column value with the
+ * row ID as tie-breaker, so rows with identical timestamps are never
+ * skipped. Encoded and signed by lib/utils/cursor.
+ */
+export interface KeysetCursor {
+  /** Sort column value of the boundary row (e.g. occurred_at) */
+  value: string;
+  /** ID of the boundary row */
+  id: string;
+  /** Leading score for lists ranked before they are dated (e.g. search relevance) */
+  score?: number;
+  /** 'next' pages toward older rows, 'prev' back toward newer ones */
+  direction: 'next' | 'prev';
+}
+
+/**
+ * Pagination fields returned with a keyset-paginated page
+ */
+export interface KeysetPageInfo {
+  /** More rows after this page */
+  hasMore: boolean;
+  /** Rows before this page */
+  hasPrevious: boolean;
+  nextCursor: string | null;
+  prevCursor: string | null;
+}
+
 /**
  * Standard API response metadata.
  */
@@ -0,0 +1,172 @@
+/**
+ * Audit Types
+ *
+ * @file src/lib/types/audit.ts
+ *
+ * Audit log entries written by the record_audit_log() trigger: who
+ * inserted, updated or deleted a row, and what it looked like before and
+ * after.
+ */
+
+import type { KeysetPageInfo } from './api';
+import type { Admin, AuditAction, AuditLog } from './database';
+
+// =============================================================================
+// Audited Tables
+// =============================================================================
+
+/**
+ * Tables with an audit trigger (matches the audit_log table_name CHECK)
+ */
+export type AuditTable =
+  | 'customers'
+  | 'properties'
+  | 'pools'
+  | 'calendar_events'
+  | 'estimates'
+  | 'communications'
+  | 'customer_notes'
+  | 'customer_attachments'
+  | 'customer_tags'
+  | 'customer_tag_links';
+
+/**
+ * Display labels for audited tables (one record)
+ */
+export const auditTableLabels: Record<AuditTable, string> = {
+  customers: 'Customer',
+  properties: 'Property',
+  pools: 'Pool',
+  calendar_events: 'Calendar Event',
+  estimates: 'Estimate',
+  communications: 'Communication',
+  customer_notes: 'Note',
+  customer_attachments: 'Attachment',
+  customer_tags: 'Tag',
+  customer_tag_links: 'Customer Tag',
+};
+
+/**
+ * Display labels for audit actions
+ */
+export const auditActionLabels: Record<AuditAction, string> = {
+  INSERT: 'Created',
+  UPDATE: 'Updated',
+  DELETE: 'Deleted',
+};
+
+// =============================================================================
+// Entry Types
+// =============================================================================
+
+/**
+ * One field that differs between old_values and new_values
+ */
+export interface AuditFieldChange {
+  field: string;
+  from: unknown;
+  to: unknown;
+}
+
+/**
+ * Audit log entry with the admin who made the change
+ */
+export interface AuditLogEntry extends AuditLog {
+  /** Null for changes made outside the app (service role, SQL) */
+  changed_by_admin: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  /** Fields that changed; every set field for inserts and deletes */
+  changes: AuditFieldChange[];
+}
+
+/**
+ * Paginated audit log, newest first
+ */
+export interface AuditLogListResult extends KeysetPageInfo {
+  items: AuditLogEntry[];
+  total?: number;
+}
+
+/**
+ * Filters for the audit log; dates are ISO datetimes
+ */
+export interface AuditLogFilters {
+  tableName?: AuditTable;
+  recordId?: string;
+  action?: AuditAction;
+  changedBy?: string;
+  from?: string;
+  to?: string;
+}
+
+/**
+ * Options for the audit log filter bar
+ */
+export interface AuditFilterOptions {
+  admins: Pick<Admin, 'id' | 'email' | 'full_name'>[];
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/**
+ * Fields that change on every write or are bookkeeping only
+ */
+const IGNORED_AUDIT_FIELDS = new Set(['updated_at', 'version']);
+
+/**
+ * Fields tried, in order, when naming the record an entry is about
+ */
+const RECORD_NAME_FIELDS = [
+  'name',
+  'estimate_number',
+  'title',
+  'address_line1',
+  'filename',
+  'summary',
+  'content',
+];
+
+/**
+ * Fields that differ between two row snapshots. With only one snapshot
+ * (insert or delete), every non-null field is listed.
+ */
+export function getAuditChanges(
+  oldValues: Record<string, unknown> | null,
+  newValues: Record<string, unknown> | null
+): AuditFieldChange[] {
+  const fields = new Set([...Object.keys(oldValues ?? {}), ...Object.keys(newValues ?? {})]);
+  const changes: AuditFieldChange[] = [];
+
+  for (const field of fields) {
+    if (IGNORED_AUDIT_FIELDS.has(field)) continue;
+
+    const from = oldValues?.[field] ?? null;
+    const to = newValues?.[field] ?? null;
+
+    if (JSON.stringify(from) !== JSON.stringify(to)) {
+      changes.push({ field, from, to });
+    }
+  }
+
+  return changes;
+}
+
+/**
+ * Short name for the record an entry is about, from its latest snapshot
+ *
+ * @example describeAuditRecord(entry) // 'EST-2024-0012'
+ */
+export function describeAuditRecord(entry: AuditLog): string | null {
+  const values = entry.new_values ?? entry.old_values;
+  if (!values) return null;
+
+  for (const field of RECORD_NAME_FIELDS) {
+    const value = values[field];
+    if (typeof value === 'string' && value.trim()) {
+      return value.length > 80 ? `${value.slice(0, 80)}…` : value;
+    }
+  }
+
+  return null;
+}
@@ -279,6 +279,7 @@ export function toFullCalendarEvent(event: CalendarEventWithCustomer): FullCalen
       poolId: event.pool_id,
       poolType: event.pool?.type,
       locationUrl: event.location_url,
+      communicationId: event.communication_id,
       version: event.version,
       statusOpacity: statusStyles.opacity,
       statusDotColor: statusStyles.dotColor,
@@ -0,0 +1,837 @@
+/**
+ * Communication Types
+ *
+ * @file src/lib/types/communication.ts
+ *
+ * Extended TypeScript types for communication-related operations.
+ * These types extend the base database types with related data.
+ */
+
+import type {
+  Communication,
+  CommunicationThreadRow,
+  CallOutcome,
+  DeliveryStatus,
+  CustomerAttachment,
+  CalendarEvent,
+  CalendarEventStatus,
+  Estimate,
+  Pool,
+  Property,
+  Admin,
+  CommunicationSavedView,
+  CommunicationRevision,
+  CustomerTag,
+} from './database';
+import type { ActionResult, KeysetPageInfo } from './api';
+import type { HighlightSegment } from '@/lib/utils/highlight';
+import type { CommunicationSearchSort } from '@/lib/validations/communication';
+import { formatPhone } from '@/lib/utils/phone';
+import { formatDateTime, formatSecondsDuration } from '@/lib/utils/timezone';
+import { getEventStatusLabel } from '@/lib/validations/calendar';
+
+// =============================================================================
+// Communication with Relations
+// =============================================================================
+
+/**
+ * File attached to a communication
+ */
+export type CommunicationAttachment = Pick<
+  CustomerAttachment,
+  'id' | 'filename' | 'content_type' | 'size_bytes' | 'storage_path' | 'created_at'
+>;
+
+/**
+ * Follow-up calendar event scheduled from a communication
+ */
+export type CommunicationFollowUp = Pick<
+  CalendarEvent,
+  'id' | 'title' | 'start_datetime' | 'end_datetime' | 'all_day' | 'status'
+>;
+
+/**
+ * Records a communication can be about (see estimate_id, calendar_event_id,
+ * property_id and pool_id)
+ */
+export type CommunicationLinkedEstimate = Pick<
+  Estimate,
+  'id' | 'estimate_number' | 'status' | 'total_cents'
+>;
+
+export type CommunicationLinkedEvent = Pick<
+  CalendarEvent,
+  'id' | 'title' | 'start_datetime' | 'all_day' | 'status'
+>;
+
+export type CommunicationLinkedProperty = Pick<
+  Property,
+  'id' | 'address_line1' | 'city' | 'state'
+>;
+
+export type CommunicationLinkedPool = Pick<Pool, 'id' | 'type' | 'property_id'>;
+
+/**
+ * Communication with the admin who logged it, who last edited it, its
+ * attachments, follow-ups and the records it is about
+ */
+export interface CommunicationWithLogger extends Communication {
+  logged_by_admin: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  /** Set once the content has been edited (see edited_at) */
+  edited_by_admin: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  attachments: CommunicationAttachment[];
+  follow_ups: CommunicationFollowUp[];
+  estimate: CommunicationLinkedEstimate | null;
+  calendar_event: CommunicationLinkedEvent | null;
+  property: CommunicationLinkedProperty | null;
+  pool: CommunicationLinkedPool | null;
+}
+
+/**
+ * Communication with customer info (for global search results)
+ */
+export interface CommunicationWithCustomer extends Communication {
+  customer: {
+    id: string;
+    name: string;
+    phone: string;
+  };
+  logged_by_admin: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+}
+
+/**
+ * Result of saving an edit. A stale edit (someone else saved first) fails
+ * with code CONFLICT and the current server copy to merge against.
+ */
+export type UpdateCommunicationResult =
+  | ActionResult<CommunicationWithLogger>
+  | { success: false; error: string; code: 'CONFLICT'; current: CommunicationWithLogger };
+
+// =============================================================================
+// Thread Types
+// =============================================================================
+
+/**
+ * A conversation: the thread record plus its communications,
+ * oldest first so replies read top to bottom
+ */
+export interface CommunicationThread extends CommunicationThreadRow {
+  communications: CommunicationWithLogger[];
+}
+
+// =============================================================================
+// List Result Types
+// =============================================================================
+
+/**
+ * Paginated list of conversation threads, most recently active first
+ */
+export interface CommunicationThreadListResult extends KeysetPageInfo {
+  items: CommunicationThread[];
+  total?: number;
+}
+
+/**
+ * A communication matching a search, with its relevance and the parts of
+ * the summary around the matched words
+ */
+export interface CommunicationSearchHit extends CommunicationWithCustomer {
+  /** ts_rank of the match; higher is more relevant */
+  rank: number;
+  /** Summary excerpt with matched words highlighted */
+  snippet: HighlightSegment[];
+}
+
+/**
+ * Search result for communications
+ */
+export interface CommunicationSearchResult {
+  items: CommunicationSearchHit[];
+  total: number;
+  sort: CommunicationSearchSort;
+  hasMore: boolean;
+  nextCursor: string | null;
+}
+
+// =============================================================================
+// Filter Types
+// =============================================================================
+
+/**
+ * Filter options for communication list
+ */
+export interface CommunicationFilters {
+  type?: Communication['type'];
+  /** Any of these types (combined with type) */
+  types?: Communication['type'][];
+  direction?: Communication['direction'];
+  /** Any of these directions (combined with direction) */
+  directions?: Communication['direction'][];
+  /** Logged by any of these admins */
+  loggedBy?: string[];
+  /** Customer has any of these tags */
+  tagIds?: string[];
+  /** true: with attachments; false: without */
+  hasAttachments?: boolean;
+  dateFrom?: string;
+  dateTo?: string;
+  search?: string;
+  callOutcome?: CallOutcome;
+  minCallDurationSeconds?: number;
+  maxCallDurationSeconds?: number;
+  /** Linked to this estimate, calendar event, property or pool */
+  estimateId?: string;
+  calendarEventId?: string;
+  propertyId?: string;
+  poolId?: string;
+}
+
+/**
+ * A named filter set, loadable by the communications list and search
+ */
+export interface SavedCommunicationView extends Omit<CommunicationSavedView, 'filters'> {
+  filters: CommunicationFilters;
+  creator: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  /** Whether the current admin saved it and may change it */
+  canEdit: boolean;
+}
+
+/**
+ * Values offered by the admin and tag filters
+ */
+export interface CommunicationFilterOptions {
+  admins: Pick<Admin, 'id' | 'email' | 'full_name'>[];
+  tags: Pick<CustomerTag, 'id' | 'name' | 'color'>[];
+}
+
+/**
+ * A customer's records a communication can be linked to
+ */
+export interface CommunicationLinkOptions {
+  estimates: CommunicationLinkedEstimate[];
+  events: CommunicationLinkedEvent[];
+  properties: (CommunicationLinkedProperty & { pools: CommunicationLinkedPool[] })[];
+}
+
+// =============================================================================
+// Revision Types
+// =============================================================================
+
+/**
+ * Fields whose edits are kept in the revision history
+ */
+export type CommunicationRevisionField =
+  | 'type'
+  | 'direction'
+  | 'summary'
+  | 'occurred_at'
+  | 'call_duration_seconds'
+  | 'call_outcome'
+  | 'call_number'
+  | 'estimate_id'
+  | 'calendar_event_id'
+  | 'property_id'
+  | 'pool_id';
+
+/**
+ * Revision fields holding the ID of a linked record
+ */
+export const COMMUNICATION_LINK_FIELDS = [
+  'estimate_id',
+  'calendar_event_id',
+  'property_id',
+  'pool_id',
+] as const;
+
+export type CommunicationLinkField = (typeof COMMUNICATION_LINK_FIELDS)[number];
+
+/**
+ * Display names of linked records by ID, for revision values
+ */
+export type CommunicationLinkLabels = Record<string, string>;
+
+/**
+ * One changed field in a revision, with display values
+ */
+export interface CommunicationRevisionFieldChange {
+  field: CommunicationRevisionField;
+  label: string;
+  from: unknown;
+  to: unknown;
+  fromFormatted: string;
+  toFormatted: string;
+}
+
+/**
+ * A revision with its editor and changes ready to display
+ */
+export interface CommunicationRevisionEntry extends Omit<CommunicationRevision, 'changes'> {
+  editor: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  changes: CommunicationRevisionFieldChange[];
+}
+
+// =============================================================================
+// Trash Types
+// =============================================================================
+
+/**
+ * A communication in the trash, with who deleted it and when it will be
+ * permanently deleted
+ */
+export interface TrashedCommunication extends CommunicationWithLogger {
+  deleted_at: string;
+  deleted_by_admin: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  purge_at: string;
+}
+
+/**
+ * Paginated trash, most recently deleted first
+ */
+export interface CommunicationTrashListResult extends KeysetPageInfo {
+  items: TrashedCommunication[];
+  total?: number;
+  /** Days a communication stays in the trash before it is purged */
+  retentionDays: number;
+}
+
+// =============================================================================
+// Export Types
+// =============================================================================
+
+/**
+ * One communication as written to an export, with timestamps already
+ * converted to the export's timezone
+ */
+export interface CommunicationExportRecord {
+  id: string;
+  customerId: string;
+  customerName: string;
+  customerPhone: string;
+  threadId: string;
+  parentId: string | null;
+  type: Communication['type'];
+  direction: Communication['direction'];
+  summary: string;
+  /** ISO 8601 with the timezone's offset, e.g. 2025-01-15T14:30:00-05:00 */
+  occurredAt: string;
+  createdAt: string;
+  timezone: string;
+  loggedBy: { id: string; name: string; email: string } | null;
+  callOutcome: CallOutcome | null;
+  callDurationSeconds: number | null;
+  callNumber: string | null;
+  deliveryStatus: Communication['delivery_status'];
+  /** References only; files are opened through the CRM */
+  attachments: {
+    id: string;
+    filename: string;
+    contentType: string;
+    sizeBytes: number;
+    storagePath: string;
+  }[];
+}
+
+/**
+ * A rendered export file
+ */
+export interface CommunicationExportFile {
+  filename: string;
+  contentType: string;
+  body: string | Uint8Array;
+  count: number;
+}
+
+// =============================================================================
+// Needs Response Types
+// =============================================================================
+
+/**
+ * A customer waiting on a reply, keyed by their oldest unanswered inbound
+ * communication
+ */
+export interface NeedsResponseEntry {
+  communication: CommunicationWithCustomer;
+  /** Inbound communications since our last outbound one */
+  unansweredCount: number;
+  waitingSince: string;
+  /** When the response SLA runs out */
+  dueAt: string;
+  isOverdue: boolean;
+}
+
+/**
+ * The needs-response queue, oldest first
+ */
+export interface NeedsResponseQueue {
+  entries: NeedsResponseEntry[];
+  /** Customers waiting, before overdueOnly filtering */
+  total: number;
+  overdue: number;
+  slaHours: number;
+}
+
+// =============================================================================
+// Stats Types
+// =============================================================================
+
+/**
+ * Call metrics for a customer
+ */
+export interface CallStats {
+  total: number;
+  /** Calls with an outcome recorded */
+  withOutcome: number;
+  connected: number;
+  /** connected / withOutcome, 0-1 (null when no outcomes recorded) */
+  answerRate: number | null;
+  /** Mean duration of connected calls with a duration, in seconds */
+  averageDurationSeconds: number | null;
+  byOutcome: Record<CallOutcome, number>;
+  /** Missed inbound calls still waiting for a callback */
+  needsCallback: number;
+}
+
+/**
+ * Communications in one calendar month (business timezone)
+ */
+export interface MonthlyCommunicationCount {
+  /** YYYY-MM */
+  month: string;
+  inbound: number;
+  outbound: number;
+  total: number;
+}
+
+/**
+ * Communication statistics for a customer
+ */
+export interface CommunicationStats {
+  total: number;
+  byType: Record<Communication['type'], number>;
+  byDirection: Record<Communication['direction'], number>;
+  calls: CallStats;
+  /** One entry per month from the first to the last communication, gaps included */
+  monthly: MonthlyCommunicationCount[];
+  /** Most recent communication in either direction */
+  lastContactAt: string | null;
+  /** Most recent communication from the customer */
+  lastInboundAt: string | null;
+}
+
+// =============================================================================
+// UI Helper Types
+// =============================================================================
+
+/**
+ * Communication display data for UI components
+ */
+export interface CommunicationDisplay {
+  id: string;
+  type: Communication['type'];
+  typeLabel: string;
+  direction: Communication['direction'];
+  directionLabel: string;
+  summary: string;
+  occurredAt: string;
+  occurredAtFormatted: string;
+  loggedBy: string;
+  createdAt: string;
+  call: {
+    durationSeconds: number | null;
+    durationFormatted: string | null;
+    outcome: CallOutcome | null;
+    outcomeLabel: string | null;
+    number: string | null;
+    numberFormatted: string | null;
+    /** Missed inbound call that has not been returned yet */
+    needsCallback: boolean;
+  } | null;
+  attachmentCount: number;
+  /** Image attachments (photos, screenshots); the rest are PDFs */
+  imageAttachmentCount: number;
+  /** Set for messages sent from the CRM */
+  delivery: {
+    status: DeliveryStatus;
+    statusLabel: string;
+    provider: string | null;
+    error: string | null;
+  } | null;
+  /** Most recent follow-up scheduled from this communication */
+  followUp: {
+    id: string;
+    title: string;
+    startFormatted: string;
+    status: CalendarEventStatus;
+    statusLabel: string;
+    isCompleted: boolean;
+  } | null;
+  /** Set once the content has been edited after logging */
+  edited: {
+    at: string;
+    atFormatted: string;
+    by: string | null;
+    revisionCount: number;
+  } | null;
+}
+
+/**
+ * Display label map for communication types
+ */
+export const communicationTypeLabels: Record<Communication['type'], string> = {
+  call: 'Call',
+  text: 'Text',
+  email: 'Email',
+  voicemail: 'Voicemail',
+  in_person: 'In Person',
+  web_form: 'Web Form',
+  social: 'Social',
+};
+
+/**
+ * Icon name map for communication types
+ */
+export const communicationTypeIcons: Record<Communication['type'], string> = {
+  call: 'Phone',
+  text: 'MessageSquare',
+  email: 'Mail',
+  voicemail: 'Voicemail',
+  in_person: 'Users',
+  web_form: 'Globe',
+  social: 'AtSign',
+};
+
+/**
+ * Color map for communication types (Tailwind classes)
+ */
+export const communicationTypeColors: Record<Communication['type'], {
+  bg: string;
+  text: string;
+  border: string;
+}> = {
+  call: {
+    bg: 'bg-blue-50',
+    text: 'text-blue-700',
+    border: 'border-blue-200',
+  },
+  text: {
+    bg: 'bg-emerald-50',
+    text: 'text-emerald-700',
+    border: 'border-emerald-200',
+  },
+  email: {
+    bg: 'bg-amber-50',
+    text: 'text-amber-700',
+    border: 'border-amber-200',
+  },
+  voicemail: {
+    bg: 'bg-indigo-50',
+    text: 'text-indigo-700',
+    border: 'border-indigo-200',
+  },
+  in_person: {
+    bg: 'bg-rose-50',
+    text: 'text-rose-700',
+    border: 'border-rose-200',
+  },
+  web_form: {
+    bg: 'bg-cyan-50',
+    text: 'text-cyan-700',
+    border: 'border-cyan-200',
+  },
+  social: {
+    bg: 'bg-violet-50',
+    text: 'text-violet-700',
+    border: 'border-violet-200',
+  },
+};
+
+/**
+ * Display label map for call outcomes
+ */
+export const callOutcomeLabels: Record<CallOutcome, string> = {
+  connected: 'Connected',
+  no_answer: 'No Answer',
+  busy: 'Busy',
+  left_voicemail: 'Left Voicemail',
+  wrong_number: 'Wrong Number',
+};
+
+/**
+ * Color map for call outcomes (Tailwind classes)
+ */
+export const callOutcomeColors: Record<CallOutcome, {
+  bg: string;
+  text: string;
+}> = {
+  connected: {
+    bg: 'bg-emerald-50',
+    text: 'text-emerald-700',
+  },
+  no_answer: {
+    bg: 'bg-zinc-100',
+    text: 'text-zinc-600',
+  },
+  busy: {
+    bg: 'bg-amber-50',
+    text: 'text-amber-700',
+  },
+  left_voicemail: {
+    bg: 'bg-indigo-50',
+    text: 'text-indigo-700',
+  },
+  wrong_number: {
+    bg: 'bg-red-50',
+    text: 'text-red-700',
+  },
+};
+
+/**
+ * Display label map for delivery statuses
+ */
+export const deliveryStatusLabels: Record<DeliveryStatus, string> = {
+  pending: 'Sending',
+  sent: 'Sent',
+  delivered: 'Delivered',
+  failed: 'Failed',
+};
+
+/**
+ * Color map for delivery statuses (Tailwind classes)
+ */
+export const deliveryStatusColors: Record<DeliveryStatus, {
+  bg: string;
+  text: string;
+}> = {
+  pending: {
+    bg: 'bg-zinc-100',
+    text: 'text-zinc-600',
+  },
+  sent: {
+    bg: 'bg-blue-50',
+    text: 'text-blue-700',
+  },
+  delivered: {
+    bg: 'bg-emerald-50',
+    text: 'text-emerald-700',
+  },
+  failed: {
+    bg: 'bg-red-50',
+    text: 'text-red-700',
+  },
+};
+
+/**
+ * Color map for thread status (Tailwind classes)
+ */
+export const communicationThreadStatusColors: Record<CommunicationThreadRow['status'], {
+  bg: string;
+  text: string;
+}> = {
+  open: {
+    bg: 'bg-amber-50',
+    text: 'text-amber-700',
+  },
+  answered: {
+    bg: 'bg-zinc-100',
+    text: 'text-zinc-600',
+  },
+};
+
+/**
+ * Color map for communication directions (Tailwind classes)
+ */
+export const communicationDirectionColors: Record<Communication['direction'], {
+  bg: string;
+  text: string;
+}> = {
+  inbound: {
+    bg: 'bg-zinc-100',
+    text: 'text-zinc-700',
+  },
+  outbound: {
+    bg: 'bg-zinc-100',
+    text: 'text-zinc-600',
+  },
+};
+
+/**
+ * Display label map for fields in the revision history
+ */
+export const communicationRevisionFieldLabels: Record<CommunicationRevisionField, string> = {
+  type: 'Type',
+  direction: 'Direction',
+  summary: 'Summary',
+  occurred_at: 'Date & time',
+  call_duration_seconds: 'Call duration',
+  call_outcome: 'Call outcome',
+  call_number: 'Call number',
+  estimate_id: 'Estimate',
+  calendar_event_id: 'Appointment',
+  property_id: 'Property',
+  pool_id: 'Pool',
+};
+
+/**
+ * Display label map for pool types on linked pools
+ */
+export const linkedPoolTypeLabels: Record<CommunicationLinkedPool['type'], string> = {
+  inground: 'Inground',
+  above_ground: 'Above Ground',
+  spa: 'Spa',
+  other: 'Other',
+};
+
+// =============================================================================
+// Display Helpers
+// =============================================================================
+
+/**
+ * Name each linked record by ID, for formatRevisionValue
+ */
+export function buildLinkLabels(records: {
+  estimates?: CommunicationLinkedEstimate[];
+  events?: CommunicationLinkedEvent[];
+  properties?: CommunicationLinkedProperty[];
+  pools?: CommunicationLinkedPool[];
+}): CommunicationLinkLabels {
+  const labels: CommunicationLinkLabels = {};
+
+  records.estimates?.forEach((estimate) => {
+    labels[estimate.id] = `Estimate ${estimate.estimate_number}`;
+  });
+  records.events?.forEach((event) => {
+    labels[event.id] = `${event.title} · ${formatDateTime(event.start_datetime, 'medium')}`;
+  });
+  records.properties?.forEach((property) => {
+    labels[property.id] = `${property.address_line1}, ${property.city}`;
+  });
+  records.pools?.forEach((pool) => {
+    labels[pool.id] = `${linkedPoolTypeLabels[pool.type] ?? 'Other'} pool`;
+  });
+
+  return labels;
+}
+
+/**
+ * Format a tracked field's value from a revision for display. Link fields
+ * hold IDs; pass linkLabels to name them.
+ */
+export function formatRevisionValue(
+  field: CommunicationRevisionField,
+  value: unknown,
+  linkLabels: CommunicationLinkLabels = {}
+): string {
+  if (value === null || value === undefined || value === '') {
+    return '—';
+  }
+
+  switch (field) {
+    case 'estimate_id':
+    case 'calendar_event_id':
+    case 'property_id':
+    case 'pool_id':
+      return linkLabels[String(value)] ?? 'Deleted record';
+    case 'type':
+      return communicationTypeLabels[value as Communication['type']] ?? String(value);
+    case 'direction':
+      return value === 'inbound' ? 'Inbound' : 'Outbound';
+    case 'occurred_at':
+      return formatDateTime(String(value), 'mediumWithTime');
+    case 'call_duration_seconds':
+      return formatSecondsDuration(Number(value));
+    case 'call_outcome':
+      return callOutcomeLabels[value as CallOutcome] ?? String(value);
+    case 'call_number':
+      return formatPhone(String(value));
+    default:
+      return String(value);
+  }
+}
+
+/**
+ * Build the UI display shape for a communication
+ */
+export function toCommunicationDisplay(
+  communication: CommunicationWithLogger
+): CommunicationDisplay {
+  const isCall = communication.type === 'call';
+
+  return {
+    id: communication.id,
+    type: communication.type,
+    typeLabel: communicationTypeLabels[communication.type],
+    direction: communication.direction,
+    directionLabel: communication.direction === 'inbound' ? 'Inbound' : 'Outbound',
+    summary: communication.summary,
+    occurredAt: communication.occurred_at,
+    occurredAtFormatted: formatDateTime(communication.occurred_at, 'mediumWithTime'),
+    loggedBy: communication.logged_by_admin?.full_name ?? 'Unknown',
+    createdAt: communication.created_at,
+    call: isCall
+      ? {
+          durationSeconds: communication.call_duration_seconds,
+          durationFormatted:
+            communication.call_duration_seconds != null
+              ? formatSecondsDuration(communication.call_duration_seconds)
+              : null,
+          outcome: communication.call_outcome,
+          outcomeLabel: communication.call_outcome
+            ? callOutcomeLabels[communication.call_outcome]
+            : null,
+          number: communication.call_number,
+          numberFormatted: communication.call_number
+            ? formatPhone(communication.call_number)
+            : null,
+          needsCallback: communication.needs_callback,
+        }
+      : null,
+    attachmentCount: communication.attachments?.length ?? 0,
+    imageAttachmentCount: (communication.attachments ?? []).filter((attachment) =>
+      attachment.content_type.startsWith('image/')
+    ).length,
+    delivery: communication.delivery_status
+      ? {
+          status: communication.delivery_status,
+          statusLabel: deliveryStatusLabels[communication.delivery_status],
+          provider: communication.delivery_provider,
+          error: communication.delivery_error,
+        }
+      : null,
+    followUp: toFollowUpDisplay(communication.follow_ups ?? []),
+    edited: communication.edited_at
+      ? {
+          at: communication.edited_at,
+          atFormatted: formatDateTime(communication.edited_at, 'mediumWithTime'),
+          by: communication.edited_by_admin?.full_name ?? null,
+          revisionCount: communication.revision_count,
+        }
+      : null,
+  };
+}
+
+/**
+ * Summarize the latest follow-up scheduled from a communication
+ */
+function toFollowUpDisplay(
+  followUps: CommunicationFollowUp[]
+): CommunicationDisplay['followUp'] {
+  const latest = [...followUps].sort((a, b) =>
+    b.start_datetime.localeCompare(a.start_datetime)
+  )[0];
+
+  if (!latest) {
+    return null;
+  }
+
+  return {
+    id: latest.id,
+    title: latest.title,
+    startFormatted: formatDateTime(
+      latest.start_datetime,
+      latest.all_day ? 'medium' : 'mediumWithTime'
+    ),
+    status: latest.status,
+    statusLabel: getEventStatusLabel(latest.status),
+    isCompleted: latest.status === 'completed',
+  };
+}
@@ -5,6 +5,7 @@
  */
 
 import type { EstimateStatus, LineItem } from '@/lib/validations/estimate';
+import type { KeysetPageInfo } from './api';
 
 // ============================================================================
 // Base Types
@@ -116,6 +117,7 @@ export interface EstimateSummary {
 export interface EstimateListOptions {
   limit?: number;
   offset?: number;
+  cursor?: string;
   customerId?: string;
   status?: EstimateStatus | EstimateStatus[];
   sortBy?: 'created_at' | 'estimate_number' | 'total_cents' | 'valid_until';
@@ -125,10 +127,9 @@ export interface EstimateListOptions {
 /**
  * Result of listing estimates
  */
-export interface EstimateListResult {
+export interface EstimateListResult extends KeysetPageInfo {
   estimates: EstimateSummary[];
   total: number;
-  hasMore: boolean;
 }
 
 // ============================================================================
@@ -0,0 +1,94 @@
+/**
+ * Import Types
+ *
+ * @file src/lib/types/import.ts
+ *
+ * Types for importing communications from external sources and the
+ * review queue for messages that could not be matched to a customer.
+ */
+
+import type {
+  Admin,
+  CommunicationImportQueueItem,
+  Customer,
+  CommunicationImportSource,
+  ImportQueueReason,
+} from './database';
+
+// =============================================================================
+// Import Results
+// =============================================================================
+
+/**
+ * A message that could not be imported
+ */
+export interface ImportFailure {
+  /** File the message came from */
+  file: string;
+  /** Message-ID or row reference, when known */
+  reference: string | null;
+  error: string;
+}
+
+/**
+ * Outcome of an import run
+ */
+export interface ImportResult {
+  source: CommunicationImportSource;
+  /** True when nothing was written and the counts are a preview */
+  dryRun: boolean;
+  /** Communications created (dry run: messages matched to one customer) */
+  imported: number;
+  /** Messages skipped because they were already imported or queued */
+  duplicates: number;
+  /** Messages sent to the review queue (dry run: unmatched messages) */
+  queued: number;
+  /** Messages that could not be read or saved */
+  failed: number;
+  errors: ImportFailure[];
+}
+
+// =============================================================================
+// Review Queue
+// =============================================================================
+
+/**
+ * Queue item with the admins who imported and resolved it, and the
+ * customers the importer matched it to
+ */
+export interface ImportQueueItemWithAdmins extends CommunicationImportQueueItem {
+  imported_by_admin: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  resolved_by_admin: Pick<Admin, 'id' | 'email' | 'full_name'> | null;
+  candidate_customers: Pick<Customer, 'id' | 'name' | 'phone' | 'email'>[];
+}
+
+/**
+ * Paginated review queue
+ */
+export interface ImportQueueListResult {
+  items: ImportQueueItemWithAdmins[];
+  total: number;
+  hasMore: boolean;
+}
+
+// =============================================================================
+// Display Maps
+// =============================================================================
+
+/**
+ * Human-readable labels for why a message was queued
+ */
+export const importQueueReasonLabels: Record<ImportQueueReason, string> = {
+  no_customer_match: 'No matching customer',
+  multiple_customer_matches: 'Matches several customers',
+  ambiguous_direction: 'Direction unknown',
+};
+
+/**
+ * Human-readable labels for import sources
+ */
+export const importSourceLabels: Record<CommunicationImportSource, string> = {
+  email: 'Email',
+  sms: 'Text Messages',
+  cdr: 'Phone System',
+};
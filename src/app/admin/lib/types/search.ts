@@ -0,0 +1,78 @@
+/**
+ * Search Types
+ *
+ * @file src/lib/types/search.ts
+ *
+ * Results of the global search, grouped by entity type. Every result
+ * belongs to a customer and links to the matching tab of their page.
+ */
+
+import type { HighlightSegment } from '@/lib/utils/highlight';
+import type { SearchEntityType } from '@/lib/validations/search';
+
+// =============================================================================
+// Result Types
+// =============================================================================
+
+/**
+ * How the query was interpreted, besides free text
+ */
+export interface SearchQueryInterpretation {
+  /** Digits matched against phone and call numbers (4 or more typed) */
+  phoneDigits: string | null;
+  /** Estimate number matched exactly (42 for EST-0042) */
+  estimateNumber: number | null;
+}
+
+/**
+ * A single match
+ */
+export interface GlobalSearchResult {
+  type: SearchEntityType;
+  id: string;
+  customerId: string;
+  customerName: string;
+  title: string;
+  subtitle: string | null;
+  /** Matched text with highlighted words, when the match was on body text */
+  snippet: HighlightSegment[] | null;
+  /** Relevance within its entity type; 1 for exact phone or number matches */
+  score: number;
+  /** Customer page, opened on the tab holding this result */
+  href: string;
+}
+
+/**
+ * Matches of one entity type, best first
+ */
+export interface GlobalSearchGroup {
+  type: SearchEntityType;
+  label: string;
+  results: GlobalSearchResult[];
+}
+
+/**
+ * Global search response
+ */
+export interface GlobalSearchResponse {
+  query: string;
+  interpretation: SearchQueryInterpretation;
+  /** Non-empty groups in display order */
+  groups: GlobalSearchGroup[];
+  total: number;
+}
+
+// =============================================================================
+// Display Helpers
+// =============================================================================
+
+/**
+ * Group headings for each entity type
+ */
+export const searchEntityLabels: Record<SearchEntityType, string> = {
+  customer: 'Customers',
+  communication: 'Communications',
+  note: 'Notes',
+  estimate: 'Estimates',
+  property: 'Properties',
+};
@@ -0,0 +1,35 @@
+/**
+ * Message Template Types
+ *
+ * @file src/lib/types/template.ts
+ *
+ * Types for message templates and their rendered output.
+ */
+
+import type { MessageTemplate, MessageTemplateChannel } from './database';
+
+/**
+ * Template with the admin who created it
+ */
+export interface MessageTemplateWithAuthor extends MessageTemplate {
+  created_by_admin: { id: string; full_name: string } | null;
+}
+
+/**
+ * A template filled in for one customer
+ */
+export interface RenderedTemplate {
+  templateId: string;
+  channel: MessageTemplateChannel;
+  /** Rendered subject (email only) */
+  subject: string | null;
+  body: string;
+}
+
+/**
+ * Display label map for template channels
+ */
+export const templateChannelLabels: Record<MessageTemplateChannel, string> = {
+  email: 'Email',
+  sms: 'Text Message',
+};
@@ -0,0 +1,117 @@
+/**
+ * Timeline Types
+ *
+ * @file src/lib/types/timeline.ts
+ *
+ * A customer's activity as one list: communications, notes, estimates,
+ * calendar events and attachments, newest first, paginated with a single
+ * cursor (see the customer_timeline view).
+ */
+
+import type { KeysetPageInfo } from './api';
+import type { CommunicationFilters, CommunicationWithLogger } from './communication';
+import type { NoteWithAuthor } from './customer';
+import type {
+  Admin,
+  CalendarEvent,
+  CustomerAttachment,
+  CustomerTimelineRow,
+  Estimate,
+} from './database';
+
+// =============================================================================
+// Item Types
+// =============================================================================
+
+export type TimelineItemKind = CustomerTimelineRow['kind'];
+
+/**
+ * Display labels for timeline item kinds
+ */
+export const timelineKindLabels: Record<TimelineItemKind, string> = {
+  communication: 'Communication',
+  note: 'Note',
+  estimate: 'Estimate',
+  event: 'Event',
+  attachment: 'Attachment',
+};
+
+type TimelineAdmin = Pick<Admin, 'id' | 'email' | 'full_name'>;
+
+type TimelineAttachment = Pick<
+  CustomerAttachment,
+  'id' | 'filename' | 'content_type' | 'size_bytes' | 'storage_path' | 'created_at'
+>;
+
+/**
+ * Note on the timeline, with its attachments
+ */
+export interface TimelineNote extends NoteWithAuthor {
+  attachments: TimelineAttachment[];
+}
+
+/**
+ * Estimate on the timeline (line items are left out)
+ */
+export type TimelineEstimate = Pick<
+  Estimate,
+  'id' | 'estimate_number' | 'status' | 'total_cents' | 'valid_until' | 'created_at'
+> & {
+  created_by_admin: TimelineAdmin | null;
+};
+
+/**
+ * Calendar event on the timeline
+ */
+export interface TimelineEvent extends CalendarEvent {
+  created_by_admin: TimelineAdmin | null;
+}
+
+/**
+ * Attachment uploaded on its own (not to a communication or note)
+ */
+export interface TimelineStandaloneAttachment extends CustomerAttachment {
+  uploaded_by_admin: TimelineAdmin | null;
+}
+
+interface TimelineItemBase {
+  /** ID of the communication, note, estimate, event or attachment */
+  id: string;
+  /** When it happened: occurred_at, start_datetime or created_at */
+  occurredAt: string;
+}
+
+/**
+ * One timeline entry; switch on `kind` for the entity
+ */
+export type TimelineItem =
+  | (TimelineItemBase & { kind: 'communication'; communication: CommunicationWithLogger })
+  | (TimelineItemBase & { kind: 'note'; note: TimelineNote })
+  | (TimelineItemBase & { kind: 'estimate'; estimate: TimelineEstimate })
+  | (TimelineItemBase & { kind: 'event'; event: TimelineEvent })
+  | (TimelineItemBase & { kind: 'attachment'; attachment: TimelineStandaloneAttachment });
+
+// =============================================================================
+// Filter and Result Types
+// =============================================================================
+
+/**
+ * Timeline filters: the communication filter vocabulary plus item kinds.
+ *
+ * loggedBy matches whoever logged, wrote, created or uploaded an item;
+ * dates, search and attachments apply to every kind. Type, direction and
+ * call filters only describe communications, so setting one limits the
+ * timeline to communications. Linked record filters keep communications
+ * about that record plus the estimate or event itself.
+ */
+export interface TimelineFilters extends CommunicationFilters {
+  kinds?: TimelineItemKind[];
+}
+
+/**
+ * A page of a customer's timeline, newest first
+ */
+export interface CustomerTimelineResult extends KeysetPageInfo {
+  items: TimelineItem[];
+  total?: number;
+}
@@ -0,0 +1,92 @@
+/**
+ * Business Hours Utilities
+ *
+ * @file src/lib/utils/business-hours.ts
+ *
+ * Deadline math in business hours, used for response SLAs. Business hours
+ * are Monday-Friday, BUSINESS_HOURS_START to BUSINESS_HOURS_END (24h clock,
+ * defaults 8 and 17) in DEFAULT_TIMEZONE.
+ */
+
+import { toZonedTime, fromZonedTime } from 'date-fns-tz';
+import { addDays, addMinutes, differenceInMinutes, setHours, startOfDay } from 'date-fns';
+import { DEFAULT_TIMEZONE } from './timezone';
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+export interface BusinessHours {
+  /** First business hour of the day (0-23) */
+  startHour: number;
+  /** Hour the business day ends (1-24) */
+  endHour: number;
+  /** Business days, 0 = Sunday */
+  days: number[];
+  timezone: string;
+}
+
+function envHour(value: string | undefined, fallback: number): number {
+  const hour = Number(value);
+  return Number.isInteger(hour) && hour >= 0 && hour <= 24 ? hour : fallback;
+}
+
+export const DEFAULT_BUSINESS_HOURS: BusinessHours = {
+  startHour: envHour(process.env.BUSINESS_HOURS_START, 8),
+  endHour: envHour(process.env.BUSINESS_HOURS_END, 17),
+  days: [1, 2, 3, 4, 5],
+  timezone: DEFAULT_TIMEZONE,
+};
+
+// =============================================================================
+// Deadline Functions
+// =============================================================================
+
+/**
+ * Add business hours to a moment, skipping nights and weekends
+ *
+ * A message received Friday at 4pm with a 4 business hour SLA is due
+ * Monday at 11am (with 8-17 hours).
+ *
+ * @param start - When the clock starts
+ * @param hours - Business hours to add
+ * @param config - Business hours (defaults to DEFAULT_BUSINESS_HOURS)
+ * @returns The deadline
+ */
+export function addBusinessHours(
+  start: Date,
+  hours: number,
+  config: BusinessHours = DEFAULT_BUSINESS_HOURS
+): Date {
+  if (config.days.length === 0 || config.endHour <= config.startHour) {
+    throw new Error('Business hours must include at least one day and end after they start');
+  }
+
+  let local = toZonedTime(start, config.timezone);
+  let remaining = Math.round(hours * 60);
+
+  // Bounded so a misconfiguration cannot loop forever
+  for (let i = 0; i < 3660; i++) {
+    const dayStart = setHours(startOfDay(local), config.startHour);
+    const dayEnd = setHours(startOfDay(local), config.endHour);
+
+    if (!config.days.includes(local.getDay()) || local >= dayEnd) {
+      local = setHours(startOfDay(addDays(local, 1)), config.startHour);
+      continue;
+    }
+
+    if (local < dayStart) {
+      local = dayStart;
+    }
+
+    const available = differenceInMinutes(dayEnd, local);
+    if (remaining <= available) {
+      return fromZonedTime(addMinutes(local, remaining), config.timezone);
+    }
+
+    remaining -= available;
+    local = setHours(startOfDay(addDays(local, 1)), config.startHour);
+  }
+
+  throw new Error('Could not compute business hours deadline');
+}
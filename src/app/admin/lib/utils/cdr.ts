@@ -0,0 +1,147 @@
+/**
+ * Call-Detail Record Parsing
+ *
+ * @file src/lib/utils/cdr.ts
+ *
+ * Reads call-detail record (CDR) CSV exports from the phone system.
+ * Expected columns are caller, callee, start, duration and disposition;
+ * common alternative names (Asterisk's src/dst/calldate/billsec, etc.)
+ * are accepted too. Start times without an offset are read in the
+ * business timezone.
+ */
+
+import type { CallOutcome } from '@/lib/types/database';
+import { parseCsv, pickCsvField, parseCsvTimestamp } from './csv';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+export interface CdrRow {
+  /** 1-based row number in the file, for error reporting */
+  row: number;
+  /** Unique call ID from the export, if it has one */
+  id: string | null;
+  caller: string;
+  callee: string;
+  startedAt: Date;
+  durationSeconds: number | null;
+  /** Disposition as exported, e.g. "ANSWERED" */
+  disposition: string | null;
+  outcome: CallOutcome | null;
+}
+
+export interface CdrParseResult {
+  rows: CdrRow[];
+  errors: { row: number; error: string }[];
+}
+
+// =============================================================================
+// Column Aliases
+// =============================================================================
+
+const ID_COLUMNS = ['uniqueid', 'unique_id', 'call_id', 'id', 'linkedid'];
+const CALLER_COLUMNS = ['caller', 'src', 'source', 'from', 'caller_id', 'calling_number', 'clid'];
+const CALLEE_COLUMNS = ['callee', 'dst', 'destination', 'to', 'called_number', 'dialed_number'];
+const START_COLUMNS = ['start', 'start_time', 'call_start', 'calldate', 'date', 'timestamp'];
+const DURATION_COLUMNS = ['duration', 'billsec', 'duration_seconds', 'talk_time'];
+const DISPOSITION_COLUMNS = ['disposition', 'status', 'result', 'outcome'];
+
+/**
+ * Disposition values mapped to call outcomes (lowercased, spaces/dashes
+ * as underscores)
+ */
+const DISPOSITION_OUTCOMES: Record<string, CallOutcome> = {
+  answered: 'connected',
+  answer: 'connected',
+  connected: 'connected',
+  completed: 'connected',
+  no_answer: 'no_answer',
+  noanswer: 'no_answer',
+  missed: 'no_answer',
+  unanswered: 'no_answer',
+  failed: 'no_answer',
+  congestion: 'no_answer',
+  canceled: 'no_answer',
+  cancelled: 'no_answer',
+  busy: 'busy',
+  voicemail: 'left_voicemail',
+  left_voicemail: 'left_voicemail',
+};
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/**
+ * Parses a CDR CSV export
+ *
+ * @param content - File contents
+ * @returns Parsed rows and per-row errors
+ */
+export function parseCdrExport(content: string): CdrParseResult {
+  const result: CdrParseResult = { rows: [], errors: [] };
+
+  parseCsv(content).forEach((record, index) => {
+    const row = index + 1;
+    const caller = pickCsvField(record, CALLER_COLUMNS);
+    const callee = pickCsvField(record, CALLEE_COLUMNS);
+    const startedAt = parseCsvTimestamp(pickCsvField(record, START_COLUMNS));
+
+    if (!caller || !callee) {
+      result.errors.push({ row, error: 'Missing caller or callee' });
+      return;
+    }
+
+    if (!startedAt) {
+      result.errors.push({ row, error: 'Missing or invalid start time' });
+      return;
+    }
+
+    const disposition = pickCsvField(record, DISPOSITION_COLUMNS);
+
+    result.rows.push({
+      row,
+      id: pickCsvField(record, ID_COLUMNS),
+      caller,
+      callee,
+      startedAt,
+      durationSeconds: parseCdrDuration(pickCsvField(record, DURATION_COLUMNS)),
+      disposition,
+      outcome: dispositionToOutcome(disposition),
+    });
+  });
+
+  return result;
+}
+
+/**
+ * Maps a phone system disposition to a call outcome
+ *
+ * @example
+ * dispositionToOutcome('NO ANSWER') // 'no_answer'
+ * dispositionToOutcome('ANSWERED') // 'connected'
+ */
+export function dispositionToOutcome(disposition: string | null): CallOutcome | null {
+  if (!disposition) return null;
+  const key = disposition.trim().toLowerCase().replace(/[\s-]+/g, '_');
+  return DISPOSITION_OUTCOMES[key] ?? null;
+}
+
+/**
+ * Parses a CDR duration: plain seconds ("272") or clock time ("0:04:32")
+ *
+ * @example
+ * parseCdrDuration('272') // 272
+ * parseCdrDuration('4:32') // 272
+ */
+export function parseCdrDuration(value: string | null): number | null {
+  if (!value) return null;
+
+  const parts = value.trim().split(':').map((part) => Number(part));
+  if (parts.length > 3 || parts.some((part) => !Number.isFinite(part) || part < 0)) {
+    return null;
+  }
+
+  return Math.round(parts.reduce((total, part) => total * 60 + part, 0));
+}
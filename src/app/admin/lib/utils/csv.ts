@@ -0,0 +1,185 @@
+/**
+ * CSV Utilities
+ *
+ * @file src/lib/utils/csv.ts
+ *
+ * RFC 4180 CSV parsing for import files (quoted fields, escaped quotes,
+ * embedded newlines, CRLF line endings) and writing for exports, plus the
+ * field helpers shared by the phone-system importers.
+ */
+
+import { DEFAULT_TIMEZONE, toUTC } from './timezone';
+
+/** Trailing UTC offset or zone name, e.g. "Z", "-04:00", "+0100", "GMT", "EST" */
+const EXPLICIT_ZONE_PATTERN = /(?:Z|[+-]\d{2}:?\d{2}|\b(?:UTC|GMT|[ECMP][SD]T))$/i;
+
+/**
+ * Parses CSV text into rows keyed by header.
+ *
+ * Header names are trimmed, lowercased and have spaces/dashes replaced with
+ * underscores so "Call Start" and "call-start" both become "call_start".
+ * Blank lines are skipped.
+ *
+ * @param text - CSV file contents
+ * @returns Array of row objects
+ *
+ * @example
+ * parseCsv('Phone,Message\n5551234567,"Hi, there"')
+ * // [{ phone: '5551234567', message: 'Hi, there' }]
+ */
+export function parseCsv(text: string): Record<string, string>[] {
+  const [header, ...rows] = parseCsvRows(text.replace(/^\uFEFF/, ''));
+  if (!header) return [];
+
+  const keys = header.map(normalizeCsvHeader);
+
+  return rows
+    .filter((row) => row.some((cell) => cell.trim() !== ''))
+    .map((row) =>
+      Object.fromEntries(keys.map((key, index) => [key, (row[index] ?? '').trim()]))
+    );
+}
+
+/**
+ * Normalizes a header name for lookups
+ *
+ * @example
+ * normalizeCsvHeader(' Call Start ') // 'call_start'
+ */
+export function normalizeCsvHeader(header: string): string {
+  return header.trim().toLowerCase().replace(/[\s-]+/g, '_');
+}
+
+/**
+ * Returns the first non-empty value among the given column aliases
+ *
+ * @example
+ * pickCsvField({ from: '', src: '5551234567' }, ['from', 'src']) // '5551234567'
+ */
+export function pickCsvField(record: Record<string, string>, columns: string[]): string | null {
+  for (const column of columns) {
+    const value = record[column];
+    if (value) return value;
+  }
+  return null;
+}
+
+/**
+ * Parses an exported timestamp: ISO/locale date strings and Unix
+ * timestamps (seconds or milliseconds).
+ *
+ * Phone systems export local wall-clock times without an offset, so a
+ * string with no offset or zone name is read in the business timezone,
+ * not the server's.
+ *
+ * @param value - Timestamp as exported
+ * @param timezone - Timezone of offset-less values (defaults to DEFAULT_TIMEZONE)
+ * @returns The instant, or null if empty/invalid
+ *
+ * @example
+ * parseCsvTimestamp('2024-03-14 14:05:00', 'America/New_York') // 2024-03-14T18:05:00.000Z
+ */
+export function parseCsvTimestamp(
+  value: string | null,
+  timezone: string = DEFAULT_TIMEZONE
+): Date | null {
+  const trimmed = value?.trim();
+  if (!trimmed) return null;
+
+  if (/^\d+$/.test(trimmed)) {
+    const numeric = Number(trimmed);
+    const date = new Date(trimmed.length <= 10 ? numeric * 1000 : numeric);
+    return isNaN(date.getTime()) ? null : date;
+  }
+
+  if (EXPLICIT_ZONE_PATTERN.test(trimmed)) {
+    const date = new Date(trimmed);
+    return isNaN(date.getTime()) ? null : date;
+  }
+
+  // Parse as server-local wall-clock time (a bare ISO date would be read
+  // as UTC), then reinterpret those fields in the business timezone
+  const local = new Date(/^\d{4}-\d{2}-\d{2}$/.test(trimmed) ? `${trimmed}T00:00:00` : trimmed);
+  return isNaN(local.getTime()) ? null : toUTC(local, timezone);
+}
+
+/**
+ * Parses CSV text into raw rows of cells
+ */
+function parseCsvRows(text: string): string[][] {
+  const rows: string[][] = [];
+  let row: string[] = [];
+  let cell = '';
+  let inQuotes = false;
+
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+
+    if (inQuotes) {
+      if (char === '"' && text[i + 1] === '"') {
+        cell += '"';
+        i++;
+      } else if (char === '"') {
+        inQuotes = false;
+      } else {
+        cell += char;
+      }
+      continue;
+    }
+
+    if (char === '"') {
+      inQuotes = true;
+    } else if (char === ',') {
+      row.push(cell);
+      cell = '';
+    } else if (char === '\n' || char === '\r') {
+      if (char === '\r' && text[i + 1] === '\n') i++;
+      row.push(cell);
+      rows.push(row);
+      row = [];
+      cell = '';
+    } else {
+      cell += char;
+    }
+  }
+
+  if (cell !== '' || row.length > 0) {
+    row.push(cell);
+    rows.push(row);
+  }
+
+  return rows;
+}
+
+/**
+ * Writes rows as CSV with a header line and CRLF line endings.
+ *
+ * Fields with commas, quotes or newlines are quoted. Text starting with
+ * =, +, - or @ is prefixed with an apostrophe so spreadsheets do not run
+ * it as a formula.
+ *
+ * @param headers - Column names
+ * @param rows - One array of values per row, in header order
+ * @returns CSV text
+ *
+ * @example
+ * toCsv(['Name', 'Note'], [['Ann', 'Hi, there']])
+ * // 'Name,Note\r\nAnn,"Hi, there"\r\n'
+ */
+export function toCsv(
+  headers: string[],
+  rows: (string | number | boolean | null | undefined)[][]
+): string {
+  return [headers, ...rows].map((row) => row.map(formatCsvCell).join(',') + '\r\n').join('');
+}
+
+function formatCsvCell(value: string | number | boolean | null | undefined): string {
+  if (value === null || value === undefined) return '';
+
+  let text = String(value);
+  if (typeof value === 'string' && /^[=+\-@]/.test(text)) {
+    text = `'${text}`;
+  }
+
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
@@ -0,0 +1,151 @@
+/**
+ * Keyset Pagination Cursors
+ *
+ * @file src/lib/utils/cursor.ts
+ *
+ * Encodes KeysetCursor positions as opaque, signed tokens and turns them
+ * into PostgREST filters. Lists sort by (column DESC, id DESC); a cursor
+ * holds the boundary row's column value and ID, so rows sharing a
+ * timestamp page correctly.
+ *
+ * Tokens are `<base64url payload>.<base64url HMAC-SHA256>` signed with
+ * CURSOR_SECRET (falling back to SUPABASE_SERVICE_ROLE_KEY) and bound to a
+ * scope such as `communications:<customerId>`, so a cursor cannot be
+ * edited or replayed against another list.
+ */
+
+import { createHmac, timingSafeEqual } from 'crypto';
+import type { KeysetCursor, KeysetPageInfo } from '@/lib/types/api';
+import { ValidationError } from './errors';
+
+// =============================================================================
+// Encoding
+// =============================================================================
+
+function getSecret(): string {
+  const secret = process.env.CURSOR_SECRET ?? process.env.SUPABASE_SERVICE_ROLE_KEY;
+  if (!secret) {
+    throw new Error('CURSOR_SECRET is not configured');
+  }
+  return secret;
+}
+
+function sign(payload: string, scope: string): string {
+  return createHmac('sha256', getSecret()).update(`${scope}\n${payload}`).digest('base64url');
+}
+
+/**
+ * Encode a cursor as a signed token
+ *
+ * @param cursor - Boundary row position
+ * @param scope - List the cursor belongs to
+ */
+export function encodeCursor(cursor: KeysetCursor, scope: string): string {
+  const payload = Buffer.from(
+    JSON.stringify({ v: cursor.value, i: cursor.id, d: cursor.direction, s: cursor.score })
+  ).toString('base64url');
+
+  return `${payload}.${sign(payload, scope)}`;
+}
+
+/**
+ * Decode and verify a cursor token
+ *
+ * @param token - Token from a previous page
+ * @param scope - List the cursor must belong to
+ * @throws ValidationError when the token is malformed, edited or from another list
+ */
+export function decodeCursor(token: string, scope: string): KeysetCursor {
+  const [payload, signature, ...rest] = token.split('.');
+
+  if (!payload || !signature || rest.length > 0) {
+    throw new ValidationError('Invalid pagination cursor');
+  }
+
+  const expected = Buffer.from(sign(payload, scope));
+  const provided = Buffer.from(signature);
+  if (expected.length !== provided.length || !timingSafeEqual(expected, provided)) {
+    throw new ValidationError('Invalid pagination cursor');
+  }
+
+  try {
+    const data = JSON.parse(Buffer.from(payload, 'base64url').toString('utf-8'));
+    if (
+      typeof data.v !== 'string' ||
+      typeof data.i !== 'string' ||
+      (data.d !== 'next' && data.d !== 'prev') ||
+      (data.s !== undefined && typeof data.s !== 'number')
+    ) {
+      throw new Error('Unexpected cursor shape');
+    }
+    return { value: data.v, id: data.i, direction: data.d, score: data.s };
+  } catch {
+    throw new ValidationError('Invalid pagination cursor');
+  }
+}
+
+// =============================================================================
+// Query Helpers
+// =============================================================================
+
+/**
+ * PostgREST `or` filter selecting rows after the cursor in a
+ * (column DESC, id DESC) list, or before it when paging back
+ *
+ * @example query.or(keysetFilter('occurred_at', cursor))
+ */
+export function keysetFilter(column: string, cursor: KeysetCursor): string {
+  const op = cursor.direction === 'next' ? 'lt' : 'gt';
+  // Quoted so timestamps (with ':' and '.') survive PostgREST parsing
+  const value = `"${cursor.value.replace(/"/g, '\\"')}"`;
+  const id = `"${cursor.id}"`;
+
+  return `${column}.${op}.${value},and(${column}.eq.${value},id.${op}.${id})`;
+}
+
+/**
+ * Whether the query should sort ascending: paging back fetches the rows
+ * just before the cursor, nearest first, and they are reversed afterwards
+ */
+export function isReversed(cursor: KeysetCursor | null): boolean {
+  return cursor?.direction === 'prev';
+}
+
+/**
+ * Trim a page fetched with limit + 1 and build its cursors
+ *
+ * @param rows - Rows as returned by the query (limit + 1 at most)
+ * @param limit - Page size
+ * @param cursor - Cursor the page was fetched with, if any
+ * @param scope - List the cursors belong to
+ * @param position - Sort value and ID of a row
+ * @returns Rows in display order (newest first) with pagination info
+ */
+export function buildKeysetPage<T>(
+  rows: T[],
+  limit: number,
+  cursor: KeysetCursor | null,
+  scope: string,
+  position: (row: T) => { value: string; id: string }
+): { items: T[] } & KeysetPageInfo {
+  const overflow = rows.length > limit;
+  const page = overflow ? rows.slice(0, limit) : rows;
+  const items = isReversed(cursor) ? [...page].reverse() : page;
+
+  // Paging back: the overflow row lies before the page; we came from after it
+  const hasMore = isReversed(cursor) ? true : overflow;
+  const hasPrevious = isReversed(cursor) ? overflow : cursor !== null;
+
+  const first = items[0];
+  const last = items[items.length - 1];
+
+  return {
+    items,
+    hasMore,
+    hasPrevious,
+    nextCursor:
+      hasMore && last ? encodeCursor({ ...position(last), direction: 'next' }, scope) : null,
+    prevCursor:
+      hasPrevious && first ? encodeCursor({ ...position(first), direction: 'prev' }, scope) : null,
+  };
+}
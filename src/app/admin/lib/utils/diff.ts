@@ -0,0 +1,97 @@
+/**
+ * Text Diff Utilities
+ *
+ * @file src/lib/utils/diff.ts
+ *
+ * Word-level diff for showing how a communication summary changed between
+ * revisions. Whitespace is kept as its own token so the output reads back
+ * exactly as either version.
+ */
+
+/**
+ * A run of text that is unchanged, only in the new text, or only in the old
+ */
+export interface DiffSegment {
+  text: string;
+  type: 'equal' | 'added' | 'removed';
+}
+
+/**
+ * Largest token grid diffed word by word; bigger inputs show as a full
+ * replacement rather than spending time on the table
+ */
+const MAX_DIFF_CELLS = 250_000;
+
+/**
+ * Diff two texts by word
+ *
+ * @example
+ * diffWords('pump is loud', 'pump is quiet')
+ * // [{ text: 'pump is ', type: 'equal' }, { text: 'loud', type: 'removed' },
+ * //  { text: 'quiet', type: 'added' }]
+ */
+export function diffWords(before: string, after: string): DiffSegment[] {
+  const a = tokenize(before);
+  const b = tokenize(after);
+
+  if (a.length * b.length > MAX_DIFF_CELLS) {
+    return merge([
+      { text: before, type: 'removed' },
+      { text: after, type: 'added' },
+    ]);
+  }
+
+  // lengths[i][j]: longest common subsequence of a[i..] and b[j..]
+  const lengths = Array.from({ length: a.length + 1 }, () =>
+    new Array<number>(b.length + 1).fill(0)
+  );
+  for (let i = a.length - 1; i >= 0; i--) {
+    for (let j = b.length - 1; j >= 0; j--) {
+      lengths[i][j] =
+        a[i] === b[j] ? lengths[i + 1][j + 1] + 1 : Math.max(lengths[i + 1][j], lengths[i][j + 1]);
+    }
+  }
+
+  const segments: DiffSegment[] = [];
+  let i = 0;
+  let j = 0;
+  while (i < a.length && j < b.length) {
+    if (a[i] === b[j]) {
+      segments.push({ text: a[i], type: 'equal' });
+      i++;
+      j++;
+    } else if (lengths[i + 1][j] >= lengths[i][j + 1]) {
+      segments.push({ text: a[i++], type: 'removed' });
+    } else {
+      segments.push({ text: b[j++], type: 'added' });
+    }
+  }
+  while (i < a.length) segments.push({ text: a[i++], type: 'removed' });
+  while (j < b.length) segments.push({ text: b[j++], type: 'added' });
+
+  return merge(segments);
+}
+
+function tokenize(text: string): string[] {
+  return text.split(/(\s+)/).filter((token) => token !== '');
+}
+
+/**
+ * Join neighbouring segments of the same type
+ */
+function merge(segments: DiffSegment[]): DiffSegment[] {
+  const merged: DiffSegment[] = [];
+
+  for (const segment of segments) {
+    if (!segment.text) continue;
+
+    const last = merged[merged.length - 1];
+    if (last && last.type === segment.type) {
+      last.text += segment.text;
+    } else {
+      merged.push({ ...segment });
+    }
+  }
+
+  return merged;
+}
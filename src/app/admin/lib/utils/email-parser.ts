@@ -0,0 +1,468 @@
+/**
+ * Email Parsing Utilities
+ *
+ * @file src/lib/utils/email-parser.ts
+ *
+ * Minimal RFC 5322 / MIME parsing for importing customer email into the
+ * communication log. Handles folded headers, RFC 2047 encoded words,
+ * multipart bodies, quoted-printable and base64 transfer encodings, and
+ * mbox archives (mboxrd "From " quoting).
+ *
+ * Only what the importer needs is extracted: addresses, subject, date,
+ * Message-ID/In-Reply-To and a plain-text body. Attachments are skipped.
+ */
+
+// =============================================================================
+// Types
+// =============================================================================
+
+export interface EmailAddress {
+  name: string | null;
+  /** Lowercased address */
+  address: string;
+}
+
+export interface ParsedEmail {
+  /** Message-ID without angle brackets, or null if missing */
+  messageId: string | null;
+  /** In-Reply-To Message-ID without angle brackets */
+  inReplyTo: string | null;
+  from: EmailAddress | null;
+  to: EmailAddress[];
+  cc: EmailAddress[];
+  subject: string;
+  /** Parsed Date header, or null if missing/invalid */
+  date: Date | null;
+  /** Plain-text body (HTML converted when no text part exists) */
+  text: string;
+}
+
+type Headers = Map<string, string[]>;
+
+interface MimePart {
+  headers: Headers;
+  body: string;
+}
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/**
+ * Parses a single RFC 5322 message (.eml contents).
+ *
+ * @param raw - Full message source
+ * @returns Parsed message fields
+ */
+export function parseEmail(raw: string): ParsedEmail {
+  const part = splitPart(raw.replace(/\r\n?/g, '\n'));
+  const headers = part.headers;
+
+  const from = parseAddressList(getHeader(headers, 'from') ?? '')[0] ?? null;
+  const dateHeader = getHeader(headers, 'date');
+  const date = dateHeader ? parseEmailDate(dateHeader) : null;
+
+  return {
+    messageId: extractMessageId(getHeader(headers, 'message-id')),
+    inReplyTo: extractMessageId(getHeader(headers, 'in-reply-to')),
+    from,
+    to: parseAddressList(getHeader(headers, 'to') ?? ''),
+    cc: parseAddressList(getHeader(headers, 'cc') ?? ''),
+    subject: decodeEncodedWords(getHeader(headers, 'subject') ?? '').trim(),
+    date,
+    text: extractText(part).trim(),
+  };
+}
+
+/**
+ * Splits an mbox archive into individual message sources.
+ *
+ * Messages start with a "From " envelope line at the beginning of the file
+ * or after a blank line. Quoted ">From " lines inside bodies are unescaped.
+ *
+ * @param raw - mbox file contents
+ * @returns Array of raw messages (envelope line removed)
+ */
+export function splitMbox(raw: string): string[] {
+  const lines = raw.replace(/\r\n?/g, '\n').split('\n');
+  const messages: string[] = [];
+  let current: string[] | null = null;
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+    const isEnvelope = line.startsWith('From ') && (i === 0 || lines[i - 1] === '');
+
+    if (isEnvelope) {
+      if (current) messages.push(current.join('\n'));
+      current = [];
+      continue;
+    }
+
+    if (current) {
+      current.push(line.replace(/^>(>*From )/, '$1'));
+    }
+  }
+
+  if (current) messages.push(current.join('\n'));
+
+  return messages.map((message) => message.replace(/\n+$/, '\n')).filter((m) => m.trim());
+}
+
+/**
+ * Checks whether file contents look like an mbox archive rather than a
+ * single message.
+ *
+ * @param raw - File contents
+ * @returns true if the file starts with an mbox envelope line
+ */
+export function isMbox(raw: string): boolean {
+  return /^From \S+/.test(raw.trimStart());
+}
+
+/**
+ * Parses an address header ("To", "Cc", ...) into addresses.
+ *
+ * @example
+ * parseAddressList('"Doe, Jane" <Jane@Example.com>, bob@example.com')
+ * // [{ name: 'Doe, Jane', address: 'jane@example.com' },
+ * //  { name: null, address: 'bob@example.com' }]
+ */
+export function parseAddressList(value: string): EmailAddress[] {
+  const decoded = decodeEncodedWords(value);
+  const addresses: EmailAddress[] = [];
+
+  for (const entry of splitOutsideQuotes(decoded, ',')) {
+    // Drop group syntax ("undisclosed-recipients:;") and comments
+    const cleaned = entry.replace(/\([^)]*\)/g, '').replace(/^[^<"]*:\s*/, '').replace(/;$/, '').trim();
+    if (!cleaned) continue;
+
+    const angle = cleaned.match(/^(.*)<([^>]+)>\s*$/);
+    if (angle) {
+      const name = angle[1].trim().replace(/^"(.*)"$/, '$1').trim();
+      const address = angle[2].trim().toLowerCase();
+      if (address.includes('@')) {
+        addresses.push({ name: name || null, address });
+      }
+      continue;
+    }
+
+    if (cleaned.includes('@')) {
+      addresses.push({ name: null, address: cleaned.toLowerCase() });
+    }
+  }
+
+  return addresses;
+}
+
+/**
+ * Decodes RFC 2047 encoded words in a header value.
+ *
+ * @example
+ * decodeEncodedWords('=?UTF-8?B?UG9vbCBxdW90ZQ==?=') // 'Pool quote'
+ */
+export function decodeEncodedWords(value: string): string {
+  // Whitespace between adjacent encoded words is not significant
+  const joined = value.replace(/(\?=)\s+(=\?)/g, '$1$2');
+
+  return joined.replace(
+    /=\?([^?]+)\?([BbQq])\?([^?]*)\?=/g,
+    (_match, charset: string, encoding: string, text: string) => {
+      try {
+        const bytes =
+          encoding.toUpperCase() === 'B'
+            ? Buffer.from(text, 'base64')
+            : decodeQuotedPrintableBytes(text.replace(/_/g, ' '));
+        return decodeBytes(bytes, charset);
+      } catch {
+        return text;
+      }
+    }
+  );
+}
+
+// =============================================================================
+// MIME Parsing
+// =============================================================================
+
+/**
+ * Splits a MIME entity into headers and body
+ */
+function splitPart(raw: string): MimePart {
+  const separator = raw.indexOf('\n\n');
+  const headerBlock = separator === -1 ? raw : raw.slice(0, separator);
+  const body = separator === -1 ? '' : raw.slice(separator + 2);
+
+  return { headers: parseHeaders(headerBlock), body };
+}
+
+/**
+ * Parses a header block, unfolding continuation lines
+ */
+function parseHeaders(block: string): Headers {
+  const headers: Headers = new Map();
+  const unfolded = block.replace(/\n[ \t]+/g, ' ');
+
+  for (const line of unfolded.split('\n')) {
+    const colon = line.indexOf(':');
+    if (colon <= 0) continue;
+
+    const name = line.slice(0, colon).trim().toLowerCase();
+    const value = line.slice(colon + 1).trim();
+    const existing = headers.get(name);
+    if (existing) {
+      existing.push(value);
+    } else {
+      headers.set(name, [value]);
+    }
+  }
+
+  return headers;
+}
+
+function getHeader(headers: Headers, name: string): string | null {
+  return headers.get(name)?.[0] ?? null;
+}
+
+/**
+ * Parses a structured header like Content-Type into a value and parameters
+ */
+function parseStructuredHeader(value: string | null): {
+  value: string;
+  params: Record<string, string>;
+} {
+  if (!value) return { value: '', params: {} };
+
+  const [first, ...rest] = splitOutsideQuotes(value, ';');
+  const params: Record<string, string> = {};
+
+  for (const param of rest) {
+    const eq = param.indexOf('=');
+    if (eq === -1) continue;
+    const key = param.slice(0, eq).trim().toLowerCase();
+    const paramValue = param.slice(eq + 1).trim().replace(/^"(.*)"$/, '$1');
+    params[key] = paramValue;
+  }
+
+  return { value: first.trim().toLowerCase(), params };
+}
+
+/**
+ * Extracts the best plain-text representation of a MIME entity.
+ * Prefers text/plain, falls back to text/html converted to text.
+ */
+function extractText(part: MimePart): string {
+  const contentType = parseStructuredHeader(getHeader(part.headers, 'content-type'));
+  const mediaType = contentType.value || 'text/plain';
+
+  if (mediaType.startsWith('multipart/')) {
+    const boundary = contentType.params.boundary;
+    if (!boundary) return '';
+
+    const children = splitMultipart(part.body, boundary).map(splitPart);
+    const candidates = children.filter((child) => !isAttachment(child));
+
+    const plain = candidates.find((child) => getMediaType(child) === 'text/plain');
+    if (plain) return extractText(plain);
+
+    for (const child of candidates) {
+      const text = extractText(child);
+      if (text.trim()) return text;
+    }
+
+    return '';
+  }
+
+  if (mediaType === 'message/rfc822') {
+    return extractText(splitPart(part.body));
+  }
+
+  if (mediaType !== 'text/plain' && mediaType !== 'text/html') {
+    return '';
+  }
+
+  const text = decodeBody(
+    part.body,
+    getHeader(part.headers, 'content-transfer-encoding'),
+    contentType.params.charset
+  );
+
+  return mediaType === 'text/html' ? htmlToText(text) : text;
+}
+
+function getMediaType(part: MimePart): string {
+  return parseStructuredHeader(getHeader(part.headers, 'content-type')).value || 'text/plain';
+}
+
+function isAttachment(part: MimePart): boolean {
+  const disposition = parseStructuredHeader(getHeader(part.headers, 'content-disposition'));
+  return disposition.value === 'attachment';
+}
+
+/**
+ * Splits a multipart body on its boundary, ignoring preamble and epilogue
+ */
+function splitMultipart(body: string, boundary: string): string[] {
+  const delimiter = `--${boundary}`;
+  const parts: string[] = [];
+  let current: string[] | null = null;
+
+  for (const line of body.split('\n')) {
+    const trimmed = line.trimEnd();
+    if (trimmed === `${delimiter}--`) {
+      if (current) parts.push(current.join('\n'));
+      current = null;
+      break;
+    }
+    if (trimmed === delimiter) {
+      if (current) parts.push(current.join('\n'));
+      current = [];
+      continue;
+    }
+    current?.push(line);
+  }
+
+  if (current) parts.push(current.join('\n'));
+
+  return parts;
+}
+
+/**
+ * Decodes a body according to its transfer encoding and charset
+ */
+function decodeBody(body: string, transferEncoding: string | null, charset = 'utf-8'): string {
+  const encoding = (transferEncoding ?? '7bit').trim().toLowerCase();
+
+  if (encoding === 'base64') {
+    return decodeBytes(Buffer.from(body.replace(/\s+/g, ''), 'base64'), charset);
+  }
+
+  if (encoding === 'quoted-printable') {
+    const withoutSoftBreaks = body.replace(/=\n/g, '');
+    return decodeBytes(decodeQuotedPrintableBytes(withoutSoftBreaks), charset);
+  }
+
+  return body;
+}
+
+function decodeQuotedPrintableBytes(text: string): Buffer {
+  const bytes: number[] = [];
+
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+    const hex = text.slice(i + 1, i + 3);
+    if (char === '=' && /^[0-9A-Fa-f]{2}$/.test(hex)) {
+      bytes.push(parseInt(hex, 16));
+      i += 2;
+    } else {
+      bytes.push(...Buffer.from(char, 'utf-8'));
+    }
+  }
+
+  return Buffer.from(bytes);
+}
+
+function decodeBytes(bytes: Buffer, charset: string): string {
+  try {
+    return new TextDecoder(charset.trim().toLowerCase()).decode(bytes);
+  } catch {
+    return bytes.toString('utf-8');
+  }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/**
+ * Splits on a delimiter, ignoring delimiters inside quotes or angle brackets
+ */
+function splitOutsideQuotes(value: string, delimiter: string): string[] {
+  const result: string[] = [];
+  let current = '';
+  let inQuotes = false;
+  let inAngle = false;
+
+  for (let i = 0; i < value.length; i++) {
+    const char = value[i];
+
+    if (char === '\\' && inQuotes && i + 1 < value.length) {
+      current += char + value[i + 1];
+      i++;
+      continue;
+    }
+    if (char === '"') inQuotes = !inQuotes;
+    if (!inQuotes && char === '<') inAngle = true;
+    if (!inQuotes && char === '>') inAngle = false;
+
+    if (char === delimiter && !inQuotes && !inAngle) {
+      result.push(current);
+      current = '';
+    } else {
+      current += char;
+    }
+  }
+
+  result.push(current);
+  return result;
+}
+
+function extractMessageId(value: string | null): string | null {
+  if (!value) return null;
+  const match = value.match(/<([^>]+)>/);
+  const id = (match ? match[1] : value).trim();
+  return id || null;
+}
+
+/**
+ * Parses an RFC 5322 date. Strips trailing comments such as "(UTC)" that
+ * some clients append.
+ */
+function parseEmailDate(value: string): Date | null {
+  const cleaned = value.replace(/\([^)]*\)\s*$/, '').trim();
+  const date = new Date(cleaned);
+  return isNaN(date.getTime()) ? null : date;
+}
+
+/**
+ * Converts an HTML body to readable plain text
+ */
+function htmlToText(html: string): string {
+  return html
+    .replace(/<(script|style|head)[^>]*>[\s\S]*?<\/\1>/gi, '')
+    .replace(/<br\s*\/?>/gi, '\n')
+    .replace(/<\/(p|div|li|tr|h[1-6])>/gi, '\n')
+    .replace(/<[^>]+>/g, '')
+    .replace(/&nbsp;/gi, ' ')
+    .replace(/&lt;/gi, '<')
+    .replace(/&gt;/gi, '>')
+    .replace(/&quot;/gi, '"')
+    .replace(/&#39;|&apos;/gi, "'")
+    .replace(/&#(\d+);/g, (_m, code: string) => String.fromCharCode(Number(code)))
+    .replace(/&amp;/gi, '&')
+    .replace(/[ \t]+\n/g, '\n')
+    .replace(/\n{3,}/g, '\n\n');
+}
+
+/**
+ * Removes quoted reply history ("> ..." lines and the "On ... wrote:"
+ * attribution) so the summary only holds the new content.
+ *
+ * @param text - Plain-text body
+ * @returns Body without quoted history
+ */
+export function stripQuotedReply(text: string): string {
+  const lines = text.split('\n');
+  const kept: string[] = [];
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+
+    if (/^On .+wrote:\s*$/.test(line.trim())) break;
+    if (/^-{2,}\s*Original Message\s*-{2,}$/i.test(line.trim())) break;
+    if (line.startsWith('>')) continue;
+
+    kept.push(line);
+  }
+
+  return kept.join('\n').trim();
+}
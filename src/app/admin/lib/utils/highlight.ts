@@ -0,0 +1,53 @@
+/**
+ * Search Highlight Utilities
+ *
+ * @file src/lib/utils/highlight.ts
+ *
+ * Search functions mark matched words with the private-use characters
+ * U+E000 (start) and U+E001 (stop) instead of HTML, so snippets built from
+ * user-entered text can be rendered safely as plain segments.
+ */
+
+/** Start-of-match marker emitted by search functions */
+export const HIGHLIGHT_START = '\uE000';
+
+/** End-of-match marker emitted by search functions */
+export const HIGHLIGHT_STOP = '\uE001';
+
+/**
+ * A run of snippet text, highlighted when it matched the search
+ */
+export interface HighlightSegment {
+  text: string;
+  highlighted: boolean;
+}
+
+/**
+ * Split a marked snippet into plain and highlighted segments
+ *
+ * @example
+ * parseHighlights('the \uE000pump\uE001 is loud')
+ * // [{ text: 'the ', highlighted: false }, { text: 'pump', highlighted: true }, ...]
+ */
+export function parseHighlights(marked: string): HighlightSegment[] {
+  const segments: HighlightSegment[] = [];
+  let highlighted = false;
+  let text = '';
+
+  const flush = () => {
+    if (text) segments.push({ text, highlighted });
+    text = '';
+  };
+
+  for (const char of marked) {
+    if (char === HIGHLIGHT_START || char === HIGHLIGHT_STOP) {
+      flush();
+      highlighted = char === HIGHLIGHT_START;
+    } else {
+      text += char;
+    }
+  }
+  flush();
+
+  return segments;
+}
@@ -0,0 +1,281 @@
+/**
+ * PDF Utilities
+ *
+ * @file src/lib/utils/pdf.ts
+ *
+ * A small text-only PDF writer for printable exports such as communication
+ * transcripts. Lays out wrapped paragraphs on US Letter pages with the
+ * standard Helvetica fonts, starts new pages as needed and numbers them.
+ *
+ * Text is encoded as WinAnsi (Latin-1 plus typographic quotes and dashes);
+ * characters outside it print as "?".
+ */
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+const PAGE_WIDTH = 612;
+const PAGE_HEIGHT = 792;
+const MARGIN = 54;
+const FOOTER_SIZE = 8;
+const LINE_HEIGHT = 1.35;
+
+/**
+ * Helvetica advance widths (1/1000 em) for ASCII 32-126
+ */
+const HELVETICA_WIDTHS = [
+  278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+  556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+  611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+  667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+  222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/**
+ * Unicode characters with a WinAnsi code outside Latin-1
+ */
+const WIN_ANSI_EXTRAS: Record<string, number> = {
+  '€': 0x80,
+  '…': 0x85,
+  '‘': 0x91,
+  '’': 0x92,
+  '“': 0x93,
+  '”': 0x94,
+  '•': 0x95,
+  '–': 0x96,
+  '—': 0x97,
+  '™': 0x99,
+};
+
+// =============================================================================
+// Types
+// =============================================================================
+
+export interface PdfTextStyle {
+  /** Font size in points (default 10) */
+  size?: number;
+  bold?: boolean;
+  /** Grey secondary text */
+  muted?: boolean;
+  /** Left indent in points */
+  indent?: number;
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function toWinAnsi(char: string): number {
+  const code = char.charCodeAt(0);
+  if ((code >= 0x20 && code <= 0x7e) || (code >= 0xa0 && code <= 0xff)) return code;
+  return WIN_ANSI_EXTRAS[char] ?? 0x3f;
+}
+
+function charWidth(code: number, size: number, bold: boolean): number {
+  const width = code >= 32 && code <= 126 ? HELVETICA_WIDTHS[code - 32] : 556;
+  // Helvetica-Bold runs about 5% wider; close enough for wrapping
+  return ((bold ? width * 1.05 : width) * size) / 1000;
+}
+
+function textWidth(text: string, size: number, bold: boolean): number {
+  let width = 0;
+  for (const char of text) {
+    width += charWidth(toWinAnsi(char), size, bold);
+  }
+  return width;
+}
+
+/**
+ * Encodes text as a PDF literal string, escaping non-ASCII bytes in octal
+ */
+function pdfString(text: string): string {
+  let out = '(';
+  for (const char of text) {
+    const code = toWinAnsi(char);
+    if (code === 0x28 || code === 0x29 || code === 0x5c) {
+      out += `\\${char}`;
+    } else if (code > 0x7e) {
+      out += `\\${code.toString(8).padStart(3, '0')}`;
+    } else {
+      out += String.fromCharCode(code);
+    }
+  }
+  return `${out})`;
+}
+
+/**
+ * Splits a paragraph into lines that fit the given width, breaking words
+ * that are too long on their own
+ */
+function wrapText(text: string, maxWidth: number, size: number, bold: boolean): string[] {
+  const lines: string[] = [];
+
+  for (const paragraph of text.replace(/\r\n?/g, '\n').replace(/\t/g, '    ').split('\n')) {
+    let line = '';
+
+    for (const word of paragraph.split(' ')) {
+      const candidate = line ? `${line} ${word}` : word;
+      if (textWidth(candidate, size, bold) <= maxWidth) {
+        line = candidate;
+        continue;
+      }
+
+      if (line) lines.push(line);
+      line = '';
+
+      for (const char of word) {
+        if (line && textWidth(line + char, size, bold) > maxWidth) {
+          lines.push(line);
+          line = '';
+        }
+        line += char;
+      }
+    }
+
+    lines.push(line);
+  }
+
+  return lines;
+}
+
+// =============================================================================
+// PDF Document
+// =============================================================================
+
+/**
+ * Builds a paginated text document
+ *
+ * @example
+ * const pdf = new PdfDocument('Communication history');
+ * pdf.text('Jane Doe', { size: 16, bold: true }).space(8).text(summary);
+ * const bytes = pdf.toBytes();
+ */
+export class PdfDocument {
+  private title: string;
+  private pages: string[][] = [[]];
+  private y = PAGE_HEIGHT - MARGIN;
+
+  constructor(title: string) {
+    this.title = title;
+  }
+
+  /**
+   * Add a wrapped paragraph; newlines start new lines
+   */
+  text(text: string, style: PdfTextStyle = {}): this {
+    const size = style.size ?? 10;
+    const bold = style.bold ?? false;
+    const indent = style.indent ?? 0;
+    const lineHeight = size * LINE_HEIGHT;
+    const font = bold ? 'F2' : 'F1';
+    const color = style.muted ? '0.45 g' : '0.1 g';
+
+    for (const line of wrapText(text, PAGE_WIDTH - MARGIN * 2 - indent, size, bold)) {
+      this.ensureSpace(lineHeight);
+      this.y -= lineHeight;
+      if (line) {
+        const position = `${MARGIN + indent} ${this.y.toFixed(2)} Td`;
+        this.current.push(`BT /${font} ${size} Tf ${color} ${position} ${pdfString(line)} Tj ET`);
+      }
+    }
+
+    return this;
+  }
+
+  /**
+   * Add vertical space in points
+   */
+  space(points: number): this {
+    this.y -= points;
+    return this;
+  }
+
+  /**
+   * Draw a thin horizontal line across the text area
+   */
+  rule(): this {
+    this.ensureSpace(8);
+    this.y -= 4;
+    this.current.push(
+      `0.85 G 0.5 w ${MARGIN} ${this.y.toFixed(2)} m ${PAGE_WIDTH - MARGIN} ${this.y.toFixed(2)} l S`
+    );
+    this.y -= 4;
+    return this;
+  }
+
+  /**
+   * Start a new page unless at least this much space remains
+   */
+  keepTogether(points: number): this {
+    this.ensureSpace(points);
+    return this;
+  }
+
+  /**
+   * Serialize the document, adding "title · Page n of N" footers
+   */
+  toBytes(): Uint8Array {
+    const objects: string[] = [];
+    const pageCount = this.pages.length;
+    const firstPageObject = 5;
+
+    objects[1] = '<< /Type /Catalog /Pages 2 0 R >>';
+    objects[2] = `<< /Type /Pages /Count ${pageCount} /Kids [${this.pages
+      .map((_, index) => `${firstPageObject + index * 2} 0 R`)
+      .join(' ')}] >>`;
+    objects[3] = '<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>';
+    objects[4] =
+      '<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>';
+
+    this.pages.forEach((operations, index) => {
+      const footer = `${this.title} · Page ${index + 1} of ${pageCount}`;
+      const content = [
+        ...operations,
+        `BT /F1 ${FOOTER_SIZE} Tf 0.45 g ${MARGIN} ${MARGIN / 2} Td ${pdfString(footer)} Tj ET`,
+      ].join('\n');
+
+      const pageObject = firstPageObject + index * 2;
+      objects[pageObject] =
+        `<< /Type /Page /Parent 2 0 R /MediaBox [0 0 ${PAGE_WIDTH} ${PAGE_HEIGHT}] ` +
+        `/Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents ${pageObject + 1} 0 R >>`;
+      objects[pageObject + 1] = `<< /Length ${content.length} >>\nstream\n${content}\nendstream`;
+    });
+
+    const infoObject = objects.length;
+    objects[infoObject] = `<< /Title ${pdfString(this.title)} /Producer (Pure Life Pools CRM) >>`;
+
+    // Every character is ASCII, so string offsets are byte offsets
+    let pdf = '%PDF-1.4\n';
+    const offsets: number[] = [];
+    for (let i = 1; i < objects.length; i++) {
+      offsets[i] = pdf.length;
+      pdf += `${i} 0 obj\n${objects[i]}\nendobj\n`;
+    }
+
+    const xref = pdf.length;
+    pdf += `xref\n0 ${objects.length}\n0000000000 65535 f \n`;
+    for (let i = 1; i < objects.length; i++) {
+      pdf += `${String(offsets[i]).padStart(10, '0')} 00000 n \n`;
+    }
+    pdf += `trailer\n<< /Size ${objects.length} /Root 1 0 R /Info ${infoObject} 0 R >>\n`;
+    pdf += `startxref\n${xref}\n%%EOF\n`;
+
+    return new TextEncoder().encode(pdf);
+  }
+
+  // ---------------------------------------------------------------------------
+  // Helpers
+  // ---------------------------------------------------------------------------
+
+  private get current(): string[] {
+    return this.pages[this.pages.length - 1];
+  }
+
+  private ensureSpace(points: number): void {
+    if (this.y - points < MARGIN && this.current.length > 0) {
+      this.pages.push([]);
+      this.y = PAGE_HEIGHT - MARGIN;
+    }
+  }
+}
@@ -0,0 +1,147 @@
+/**
+ * SMS Export Parsing
+ *
+ * @file src/lib/utils/sms-export.ts
+ *
+ * Reads text message history exported from the business phone line as CSV
+ * or JSON. Column names vary between exports, so each field is looked up by
+ * a list of common aliases.
+ *
+ * A row identifies the customer either with an explicit direction plus a
+ * single number column, or with from/to columns (the customer side is then
+ * decided at import time by which number matches a customer). Timestamps
+ * without an offset are read in the business timezone.
+ */
+
+import { parseCsv, normalizeCsvHeader, pickCsvField, parseCsvTimestamp } from './csv';
+
+// =============================================================================
+// Types
+// =============================================================================
+
+export interface SmsExportRow {
+  /** 1-based row number in the file, for error reporting */
+  row: number;
+  /** Message ID from the export, if it has one */
+  id: string | null;
+  direction: 'inbound' | 'outbound' | null;
+  from: string | null;
+  to: string | null;
+  /** Counterparty number when the export has a single number column */
+  number: string | null;
+  sentAt: Date;
+  body: string;
+}
+
+export interface SmsExportParseResult {
+  rows: SmsExportRow[];
+  errors: { row: number; error: string }[];
+}
+
+// =============================================================================
+// Column Aliases
+// =============================================================================
+
+const ID_COLUMNS = ['id', 'message_id', 'sms_id', 'uid'];
+const DIRECTION_COLUMNS = ['direction', 'type', 'message_type', 'folder'];
+const FROM_COLUMNS = ['from', 'from_number', 'sender'];
+const TO_COLUMNS = ['to', 'to_number', 'recipient'];
+const NUMBER_COLUMNS = ['number', 'phone', 'phone_number', 'contact', 'contact_number', 'address'];
+const DATE_COLUMNS = ['timestamp', 'date', 'datetime', 'sent_at', 'time', 'created_at', 'date_sent'];
+const BODY_COLUMNS = ['body', 'message', 'text', 'content', 'message_body'];
+
+const INBOUND_VALUES = ['inbound', 'incoming', 'received', 'in', 'inbox', '1'];
+const OUTBOUND_VALUES = ['outbound', 'outgoing', 'sent', 'out', 'outbox', '2'];
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/**
+ * Parses an SMS export file
+ *
+ * @param fileName - Used to pick CSV or JSON parsing
+ * @param content - File contents
+ * @returns Parsed rows and per-row errors
+ */
+export function parseSmsExport(fileName: string, content: string): SmsExportParseResult {
+  const records = fileName.toLowerCase().endsWith('.json')
+    ? parseJsonRecords(content)
+    : parseCsv(content);
+
+  const result: SmsExportParseResult = { rows: [], errors: [] };
+
+  records.forEach((record, index) => {
+    const row = index + 1;
+    const sentAt = parseCsvTimestamp(pickCsvField(record, DATE_COLUMNS));
+    const body = pickCsvField(record, BODY_COLUMNS);
+
+    if (!sentAt) {
+      result.errors.push({ row, error: 'Missing or invalid timestamp' });
+      return;
+    }
+
+    if (!body) {
+      result.errors.push({ row, error: 'Missing message text' });
+      return;
+    }
+
+    const parsed: SmsExportRow = {
+      row,
+      id: pickCsvField(record, ID_COLUMNS),
+      direction: parseDirection(pickCsvField(record, DIRECTION_COLUMNS)),
+      from: pickCsvField(record, FROM_COLUMNS),
+      to: pickCsvField(record, TO_COLUMNS),
+      number: pickCsvField(record, NUMBER_COLUMNS),
+      sentAt,
+      body,
+    };
+
+    if (!parsed.number && !parsed.from && !parsed.to) {
+      result.errors.push({ row, error: 'Missing phone number' });
+      return;
+    }
+
+    result.rows.push(parsed);
+  });
+
+  return result;
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/**
+ * Reads a JSON export: either an array of messages or an object with a
+ * `messages` array. Keys are normalized like CSV headers.
+ */
+function parseJsonRecords(content: string): Record<string, string>[] {
+  const data = JSON.parse(content) as unknown;
+  const list = Array.isArray(data)
+    ? data
+    : Array.isArray((data as { messages?: unknown })?.messages)
+      ? (data as { messages: unknown[] }).messages
+      : null;
+
+  if (!list) {
+    throw new Error('Expected an array of messages');
+  }
+
+  return list.map((item) =>
+    Object.fromEntries(
+      Object.entries((item ?? {}) as Record<string, unknown>).map(([key, value]) => [
+        normalizeCsvHeader(key),
+        value === null || value === undefined ? '' : String(value).trim(),
+      ])
+    )
+  );
+}
+
+function parseDirection(value: string | null): SmsExportRow['direction'] {
+  if (!value) return null;
+  const normalized = value.trim().toLowerCase();
+  if (INBOUND_VALUES.includes(normalized)) return 'inbound';
+  if (OUTBOUND_VALUES.includes(normalized)) return 'outbound';
+  return null;
+}
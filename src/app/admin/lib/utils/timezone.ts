@@ -395,6 +395,28 @@ export function formatDuration(minutes: number): string {
   return `${hours}h ${remainingMinutes}m`;
 }
 
+/**
+ * Format a duration given in seconds (e.g. call length)
+ *
+ * @param seconds - Duration in seconds
+ * @returns Formatted string (e.g., "1h 2m", "4m 12s" or "45s")
+ */
+export function formatSecondsDuration(seconds: number): string {
+  const total = Math.max(0, Math.round(seconds));
+
+  if (total < 60) {
+    return `${total}s`;
+  }
+
+  if (total < 3600) {
+    const minutes = Math.floor(total / 60);
+    const remainingSeconds = total % 60;
+    return remainingSeconds === 0 ? `${minutes}m` : `${minutes}m ${remainingSeconds}s`;
+  }
+
+  return formatDuration(Math.round(total / 60));
+}
+
 // =============================================================================
 // Timezone Detection
 // =============================================================================
@@ -417,6 +439,22 @@ export function getBrowserTimezone(): string | undefined {
   }
 }
 
+/**
+ * Whether a string is an IANA timezone this runtime knows
+ *
+ * @example
+ * isValidTimezone('America/Chicago') // true
+ * isValidTimezone('Eastern') // false
+ */
+export function isValidTimezone(timezone: string): boolean {
+  try {
+    new Intl.DateTimeFormat('en-US', { timeZone: timezone });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
 /**
  * Get timezone abbreviation for display
  *
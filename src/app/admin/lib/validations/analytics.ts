@@ -0,0 +1,71 @@
+/**
+ * Analytics Validation Schemas
+ *
+ * @file src/lib/validations/analytics.ts
+ *
+ * Zod schemas for communication analytics queries.
+ *
+ * Validation rules:
+ * - from / to: Optional ISO datetimes; default to the last 30 days
+ * - The range must end after it starts and span at most 366 days
+ * - adminId: Optional, limits the per-admin breakdown to one admin
+ */
+
+import { z } from 'zod';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/**
+ * Days covered when no range is given
+ */
+export const DEFAULT_ANALYTICS_DAYS = 30;
+
+/**
+ * Longest range accepted, in days
+ */
+export const MAX_ANALYTICS_DAYS = 366;
+
+/**
+ * Range presets for UI selects
+ */
+export const analyticsRangeOptions = [
+  { value: '7', label: 'Last 7 days' },
+  { value: '30', label: 'Last 30 days' },
+  { value: '90', label: 'Last 90 days' },
+  { value: '365', label: 'Last 12 months' },
+] as const;
+
+// =============================================================================
+// Analytics Range Schema
+// =============================================================================
+
+/**
+ * Schema for a communication analytics query
+ */
+export const communicationAnalyticsSchema = z
+  .object({
+    from: z.string().datetime({ message: 'Invalid start date' }).optional(),
+    to: z.string().datetime({ message: 'Invalid end date' }).optional(),
+    adminId: z.string().uuid('Invalid admin ID').optional(),
+  })
+  .transform((data) => {
+    const to = data.to ?? new Date().toISOString();
+    const from =
+      data.from ??
+      new Date(new Date(to).getTime() - DEFAULT_ANALYTICS_DAYS * 24 * 60 * 60 * 1000).toISOString();
+    return { ...data, from, to };
+  })
+  .refine((data) => new Date(data.to) > new Date(data.from), {
+    message: 'End date must be after start date',
+    path: ['to'],
+  })
+  .refine(
+    (data) =>
+      new Date(data.to).getTime() - new Date(data.from).getTime() <=
+      MAX_ANALYTICS_DAYS * 24 * 60 * 60 * 1000,
+    { message: `Date range cannot exceed ${MAX_ANALYTICS_DAYS} days`, path: ['from'] }
+  );
+
+export type CommunicationAnalyticsInput = z.infer<typeof communicationAnalyticsSchema>;
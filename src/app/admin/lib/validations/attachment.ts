@@ -0,0 +1,100 @@
+/**
+ * Attachment Validation
+ *
+ * @file src/lib/validations/attachment.ts
+ *
+ * Limits for customer attachments, matching the customer_attachments
+ * table constraints and the customer-attachments storage bucket.
+ *
+ * Validation rules:
+ * - JPEG, PNG, WebP and PDF only
+ * - Up to 5 files per upload, 3 MB together (the server action body
+ *   limit, see ./upload)
+ */
+
+import { z } from 'zod';
+import { MAX_UPLOAD_TOTAL_SIZE, formatMegabytes } from './upload';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/**
+ * Storage bucket holding attachment files
+ */
+export const ATTACHMENT_BUCKET = 'customer-attachments';
+
+/**
+ * MIME types accepted for attachments
+ */
+export const ATTACHMENT_CONTENT_TYPES = [
+  'image/jpeg',
+  'image/png',
+  'image/webp',
+  'application/pdf',
+] as const;
+export type AttachmentContentType = (typeof ATTACHMENT_CONTENT_TYPES)[number];
+
+/**
+ * Value for a file input's accept attribute
+ */
+export const ATTACHMENT_ACCEPT = ATTACHMENT_CONTENT_TYPES.join(',');
+
+/**
+ * Maximum size of a single attachment in bytes; one file can use the
+ * whole upload
+ */
+export const MAX_ATTACHMENT_SIZE = MAX_UPLOAD_TOTAL_SIZE;
+
+/**
+ * Maximum attachments accepted in a single upload
+ */
+export const MAX_ATTACHMENTS_PER_UPLOAD = 5;
+
+// =============================================================================
+// Schemas
+// =============================================================================
+
+/**
+ * Schema for attachment ID parameter
+ */
+export const attachmentIdSchema = z.string().uuid('Invalid attachment ID');
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+export function isAttachmentContentType(type: string): type is AttachmentContentType {
+  return (ATTACHMENT_CONTENT_TYPES as readonly string[]).includes(type);
+}
+
+/**
+ * Check uploaded attachment files against the limits
+ *
+ * @returns An error message, or null when all files are acceptable
+ */
+export function validateAttachmentFiles(files: File[]): string | null {
+  if (files.length === 0) {
+    return 'Please choose at least one file';
+  }
+
+  if (files.length > MAX_ATTACHMENTS_PER_UPLOAD) {
+    return `You can attach up to ${MAX_ATTACHMENTS_PER_UPLOAD} files at a time`;
+  }
+
+  for (const file of files) {
+    if (!isAttachmentContentType(file.type)) {
+      return `${file.name} is not a supported file type (JPEG, PNG, WebP or PDF)`;
+    }
+    if (file.size > MAX_ATTACHMENT_SIZE) {
+      return `${file.name} is larger than ${formatMegabytes(MAX_ATTACHMENT_SIZE)}`;
+    }
+  }
+
+  const totalSize = files.reduce((sum, file) => sum + file.size, 0);
+  if (totalSize > MAX_UPLOAD_TOTAL_SIZE) {
+    return `Attachments can be up to ${formatMegabytes(MAX_UPLOAD_TOTAL_SIZE)} together`;
+  }
+
+  return null;
+}
@@ -0,0 +1,88 @@
+/**
+ * Audit Validation Schemas
+ *
+ * @file src/lib/validations/audit.ts
+ *
+ * Zod schemas for audit log queries.
+ *
+ * Validation rules:
+ * - tableName: One of the audited tables
+ * - recordId / changedBy: Optional UUIDs
+ * - from / to: Optional ISO datetimes; the range must end after it starts
+ * - limit: 1-100, defaults to 50
+ */
+
+import { z } from 'zod';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/**
+ * Tables with an audit trigger
+ */
+export const AUDIT_TABLES = [
+  'customers',
+  'properties',
+  'pools',
+  'calendar_events',
+  'estimates',
+  'communications',
+  'customer_notes',
+  'customer_attachments',
+  'customer_tags',
+  'customer_tag_links',
+] as const;
+
+/**
+ * Audit actions
+ */
+export const AUDIT_ACTIONS = ['INSERT', 'UPDATE', 'DELETE'] as const;
+
+// =============================================================================
+// Audit Log Schemas
+// =============================================================================
+
+/**
+ * Schema for the filterable audit log
+ */
+export const listAuditLogSchema = z
+  .object({
+    tableName: z.enum(AUDIT_TABLES).optional(),
+    recordId: z.string().uuid('Invalid record ID').optional(),
+    action: z.enum(AUDIT_ACTIONS).optional(),
+    changedBy: z.string().uuid('Invalid admin ID').optional(),
+    from: z.string().datetime({ message: 'Invalid start date' }).optional(),
+    to: z.string().datetime({ message: 'Invalid end date' }).optional(),
+    limit: z.number().int().min(1).max(100).default(50),
+    cursor: z.string().optional(),
+  })
+  .refine((data) => !data.from || !data.to || new Date(data.to) > new Date(data.from), {
+    message: 'End date must be after start date',
+    path: ['to'],
+  });
+
+export type ListAuditLogInput = z.infer<typeof listAuditLogSchema>;
+
+/**
+ * Schema for one record's history
+ */
+export const recordAuditHistorySchema = z.object({
+  tableName: z.enum(AUDIT_TABLES),
+  recordId: z.string().uuid('Invalid record ID'),
+  limit: z.number().int().min(1).max(100).default(50),
+  cursor: z.string().optional(),
+});
+
+export type RecordAuditHistoryInput = z.infer<typeof recordAuditHistorySchema>;
+
+/**
+ * Schema for one admin's activity
+ */
+export const adminAuditActivitySchema = z.object({
+  adminId: z.string().uuid('Invalid admin ID'),
+  limit: z.number().int().min(1).max(100).default(50),
+  cursor: z.string().optional(),
+});
+
+export type AdminAuditActivityInput = z.infer<typeof adminAuditActivitySchema>;
@@ -110,6 +110,10 @@ export const createCalendarEventSchema = z
       .optional()
       .nullable()
       .or(z.literal('')),
+    communicationId: uuidSchema
+      .optional()
+      .nullable()
+      .describe('Communication this follow-up was scheduled from'),
   })
   .refine(
     (data) => {
@@ -7,13 +7,18 @@
  * These are the source of truth for both runtime validation and TypeScript types.
  *
  * Validation rules from specification:
- * - type: Required, one of 'call', 'text', 'email'
+ * - type: Required, one of 'call', 'text', 'email', 'voicemail', 'in_person',
+ *   'web_form', 'social'
  * - direction: Required, one of 'inbound', 'outbound'
  * - summary: Required, 1-5000 characters
  * - occurredAt: Required, valid ISO datetime
+ * - parentId: Optional, communication being replied to (same customer)
+ * - callDurationSeconds / callOutcome / callNumber: Optional, calls only
+ * - followUp: Optional, schedules a follow_up calendar event
  */
 
 import { z } from 'zod';
+import { normalizePhone } from '@/lib/utils/phone';
 
 // =============================================================================
 // Constants
@@ -26,6 +31,10 @@ export const communicationTypeOptions = [
   { value: 'call', label: 'Phone Call' },
   { value: 'text', label: 'Text Message' },
   { value: 'email', label: 'Email' },
+  { value: 'voicemail', label: 'Voicemail' },
+  { value: 'in_person', label: 'In Person' },
+  { value: 'web_form', label: 'Website Form' },
+  { value: 'social', label: 'Social Media' },
 ] as const;
 
 /**
@@ -36,10 +45,29 @@ export const communicationDirectionOptions = [
   { value: 'outbound', label: 'Outbound' },
 ] as const;
 
+/**
+ * Call outcome options for UI dropdowns
+ */
+export const callOutcomeOptions = [
+  { value: 'connected', label: 'Connected' },
+  { value: 'no_answer', label: 'No Answer' },
+  { value: 'busy', label: 'Busy' },
+  { value: 'left_voicemail', label: 'Left Voicemail' },
+  { value: 'wrong_number', label: 'Wrong Number' },
+] as const;
+
 /**
  * Valid communication types
  */
-export const COMMUNICATION_TYPES = ['call', 'text', 'email'] as const;
+export const COMMUNICATION_TYPES = [
+  'call',
+  'text',
+  'email',
+  'voicemail',
+  'in_person',
+  'web_form',
+  'social',
+] as const;
 export type CommunicationType = (typeof COMMUNICATION_TYPES)[number];
 
 /**
@@ -48,6 +76,29 @@ export type CommunicationType = (typeof COMMUNICATION_TYPES)[number];
 export const COMMUNICATION_DIRECTIONS = ['inbound', 'outbound'] as const;
 export type CommunicationDirection = (typeof COMMUNICATION_DIRECTIONS)[number];
 
+/**
+ * Valid call outcomes
+ */
+export const CALL_OUTCOMES = [
+  'connected',
+  'no_answer',
+  'busy',
+  'left_voicemail',
+  'wrong_number',
+] as const;
+export type CallOutcome = (typeof CALL_OUTCOMES)[number];
+
+/**
+ * Longest call we accept, in seconds (24 hours)
+ */
+export const MAX_CALL_DURATION_SECONDS = 86400;
+
+/**
+ * Valid conversation thread states
+ */
+export const COMMUNICATION_THREAD_STATUSES = ['open', 'answered'] as const;
+export type CommunicationThreadStatus = (typeof COMMUNICATION_THREAD_STATUSES)[number];
+
 // =============================================================================
 // Base Schemas
 // =============================================================================
@@ -91,6 +142,106 @@ const occurredAtSchema = z
     { message: 'Please enter a valid date and time' }
   );
 
+/**
+ * Call outcome enum schema
+ */
+const callOutcomeSchema = z.enum(CALL_OUTCOMES, {
+  errorMap: () => ({ message: 'Please select a call outcome' }),
+});
+
+/**
+ * Call duration schema (whole seconds)
+ */
+const callDurationSchema = z
+  .number()
+  .int('Duration must be a whole number of seconds')
+  .min(0, 'Duration cannot be negative')
+  .max(MAX_CALL_DURATION_SECONDS, 'Duration cannot exceed 24 hours');
+
+/**
+ * Number dialed or received; normalized to E.164 by the service, so it
+ * must be a complete number (empty clears it)
+ */
+const callNumberSchema = z
+  .string()
+  .max(30, 'Phone number is too long')
+  .transform((val) => val.trim())
+  .refine((val) => val === '' || /^\+[1-9]\d{7,14}$/.test(normalizePhone(val)), {
+    message: 'Please enter a full phone number, including area code',
+  });
+
+/**
+ * Call metadata only makes sense on calls
+ */
+function refineCallFields(
+  data: {
+    type?: CommunicationType;
+    callDurationSeconds?: number | null;
+    callOutcome?: CallOutcome | null;
+    callNumber?: string | null;
+  },
+  ctx: z.RefinementCtx
+) {
+  if (data.type === undefined || data.type === 'call') {
+    return;
+  }
+
+  const hasCallFields =
+    data.callDurationSeconds != null || data.callOutcome != null || !!data.callNumber;
+
+  if (hasCallFields) {
+    ctx.addIssue({
+      code: z.ZodIssueCode.custom,
+      message: 'Call details can only be recorded on calls',
+      path: ['type'],
+    });
+  }
+}
+
+/**
+ * Length of a follow-up event when no end time is given, in minutes
+ */
+export const DEFAULT_FOLLOW_UP_MINUTES = 30;
+
+/**
+ * Follow-up to schedule on the calendar alongside a new communication
+ * Title defaults to "Follow up with {customer}", end to start + 30 minutes
+ */
+const followUpSchema = z
+  .object({
+    title: z
+      .string()
+      .max(200, 'Title must be 200 characters or less')
+      .transform((val) => val.trim())
+      .optional(),
+    startDatetime: z.string().datetime({ message: 'Please enter a valid follow-up time' }),
+    endDatetime: z.string().datetime({ message: 'Please enter a valid end time' }).optional(),
+    allDay: z.boolean().default(false),
+    description: z
+      .string()
+      .max(2000, 'Description must be 2000 characters or less')
+      .optional(),
+  })
+  .refine(
+    (data) => !data.endDatetime || new Date(data.endDatetime) > new Date(data.startDatetime),
+    { message: 'End time must be after start time', path: ['endDatetime'] }
+  );
+
+export type FollowUpInput = z.infer<typeof followUpSchema>;
+
+/**
+ * Records a communication is about; each must belong to the same customer
+ * (checked by the database)
+ */
+const communicationLinksSchema = z.object({
+  estimateId: z.string().uuid('Invalid estimate ID'),
+  calendarEventId: z.string().uuid('Invalid calendar event ID'),
+  propertyId: z.string().uuid('Invalid property ID'),
+  poolId: z.string().uuid('Invalid pool ID'),
+});
+
+export type CommunicationLinksInput = Partial<z.infer<typeof communicationLinksSchema>>;
+
 // =============================================================================
 // Create Communication Schema
 // =============================================================================
@@ -104,7 +255,13 @@ export const createCommunicationSchema = z.object({
   direction: communicationDirectionSchema,
   summary: summarySchema,
   occurredAt: occurredAtSchema,
-});
+  parentId: z.string().uuid('Invalid parent communication ID').optional(),
+  callDurationSeconds: callDurationSchema.optional(),
+  callOutcome: callOutcomeSchema.optional(),
+  callNumber: callNumberSchema.optional(),
+  followUp: followUpSchema.optional(),
+  ...communicationLinksSchema.partial().shape,
+}).superRefine(refineCallFields);
 
 export type CreateCommunicationInput = z.infer<typeof createCommunicationSchema>;
 
@@ -114,15 +271,24 @@ export type CreateCommunicationInput = z.infer<typeof createCommunicationSchema>
 
 /**
  * Schema for updating an existing communication
- * All fields except id are optional - only provided fields are updated
+ * All fields except id and version are optional - only provided fields are
+ * updated. version is the one the edit started from (optimistic locking).
  */
 export const updateCommunicationSchema = z.object({
   id: z.string().uuid('Invalid communication ID'),
+  version: z.number().int().positive('Version must be a positive integer'),
   type: communicationTypeSchema.optional(),
   direction: communicationDirectionSchema.optional(),
   summary: summarySchema.optional(),
   occurredAt: occurredAtSchema.optional(),
-});
+  callDurationSeconds: callDurationSchema.nullable().optional(),
+  callOutcome: callOutcomeSchema.nullable().optional(),
+  callNumber: callNumberSchema.nullable().optional(),
+  estimateId: communicationLinksSchema.shape.estimateId.nullable().optional(),
+  calendarEventId: communicationLinksSchema.shape.calendarEventId.nullable().optional(),
+  propertyId: communicationLinksSchema.shape.propertyId.nullable().optional(),
+  poolId: communicationLinksSchema.shape.poolId.nullable().optional(),
+}).superRefine(refineCallFields);
 
 export type UpdateCommunicationInput = z.infer<typeof updateCommunicationSchema>;
 
@@ -140,42 +306,316 @@ export const deleteCommunicationSchema = z.object({
 export type DeleteCommunicationInput = z.infer<typeof deleteCommunicationSchema>;
 
 // =============================================================================
-// List Communications Schema
+// Trash Schemas
 // =============================================================================
 
 /**
- * Schema for listing communications with filters and pagination
+ * Days a deleted communication stays in the trash before it is permanently
+ * deleted; overridden by COMMUNICATION_TRASH_RETENTION_DAYS
  */
-export const listCommunicationsSchema = z.object({
-  customerId: z.string().uuid('Invalid customer ID'),
+export const DEFAULT_COMMUNICATION_TRASH_RETENTION_DAYS = 30;
+
+/**
+ * Schema for listing trashed communications
+ */
+export const listCommunicationTrashSchema = z.object({
+  customerId: z.string().uuid('Invalid customer ID').optional(),
   limit: z.number().min(1).max(100).optional().default(25),
   cursor: z.string().nullable().optional(),
+});
+
+export type ListCommunicationTrashInput = z.infer<typeof listCommunicationTrashSchema>;
+
+// =============================================================================
+// Revision Schemas
+// =============================================================================
+
+/**
+ * Schema for restoring the values a revision replaced
+ */
+export const restoreCommunicationRevisionSchema = z.object({
+  revisionId: z.string().uuid('Invalid revision ID'),
+});
+
+export type RestoreCommunicationRevisionInput = z.infer<
+  typeof restoreCommunicationRevisionSchema
+>;
+
+// =============================================================================
+// Communication Filters Schema
+// =============================================================================
+
+/**
+ * Most values accepted in one multi-select filter
+ */
+export const MAX_FILTER_VALUES = 50;
+
+/**
+ * Filters shared by the communications list, search and saved views
+ *
+ * Single type/direction are kept for existing callers and combine with
+ * the multi-select lists. tagIds matches customers with any of the tags;
+ * hasAttachments true keeps communications with attachments, false those
+ * without. dateFrom is inclusive and dateTo exclusive.
+ */
+export const communicationFiltersSchema = z.object({
   type: communicationTypeSchema.optional(),
+  types: z.array(communicationTypeSchema).max(COMMUNICATION_TYPES.length).optional(),
   direction: communicationDirectionSchema.optional(),
+  directions: z
+    .array(communicationDirectionSchema)
+    .max(COMMUNICATION_DIRECTIONS.length)
+    .optional(),
+  loggedBy: z.array(z.string().uuid('Invalid admin ID')).max(MAX_FILTER_VALUES).optional(),
+  tagIds: z.array(z.string().uuid('Invalid tag ID')).max(MAX_FILTER_VALUES).optional(),
+  hasAttachments: z.boolean().optional(),
   search: z.string().max(200).optional(),
   dateFrom: z.string().datetime().optional(),
   dateTo: z.string().datetime().optional(),
+  callOutcome: callOutcomeSchema.optional(),
+  minCallDurationSeconds: callDurationSchema.optional(),
+  maxCallDurationSeconds: callDurationSchema.optional(),
+  ...communicationLinksSchema.partial().shape,
+});
+
+export type CommunicationFiltersInput = z.infer<typeof communicationFiltersSchema>;
+
+// =============================================================================
+// List Communications Schema
+// =============================================================================
+
+/**
+ * Schema for listing communications with filters and pagination
+ */
+export const listCommunicationsSchema = communicationFiltersSchema.extend({
+  customerId: z.string().uuid('Invalid customer ID'),
+  limit: z.number().min(1).max(100).optional().default(25),
+  cursor: z.string().nullable().optional(),
+  /** Include communications in the trash */
+  includeDeleted: z.boolean().optional().default(false),
 });
 
 export type ListCommunicationsInput = z.infer<typeof listCommunicationsSchema>;
 
+/**
+ * Schema for the communications linked to an estimate, calendar event,
+ * property or pool
+ */
+export const listLinkedCommunicationsSchema = communicationLinksSchema
+  .partial()
+  .extend({
+    limit: z.number().min(1).max(100).optional().default(50),
+  })
+  .refine(
+    (data) => !!(data.estimateId || data.calendarEventId || data.propertyId || data.poolId),
+    { message: 'Choose an estimate, calendar event, property or pool' }
+  );
+
+export type ListLinkedCommunicationsInput = z.infer<typeof listLinkedCommunicationsSchema>;
+
+/**
+ * Schema for the records a customer's communications can be linked to
+ */
+export const communicationLinkOptionsSchema = z.object({
+  customerId: z.string().uuid('Invalid customer ID'),
+});
+
 // =============================================================================
 // Search Communications Schema
 // =============================================================================
 
+export const COMMUNICATION_SEARCH_SORTS = ['relevance', 'date'] as const;
+
+export type CommunicationSearchSort = (typeof COMMUNICATION_SEARCH_SORTS)[number];
+
 /**
  * Schema for full-text search on communications
+ *
+ * Accepts the same filters as the list (the query replaces search). Pages
+ * by offset, or by the cursor from the previous page when given.
  */
-export const searchCommunicationsSchema = z.object({
-  customerId: z.string().uuid('Invalid customer ID').optional(),
-  query: z
+export const searchCommunicationsSchema = communicationFiltersSchema
+  .omit({ search: true })
+  .extend({
+    customerId: z.string().uuid('Invalid customer ID').optional(),
+    query: z
+      .string()
+      .min(1, 'Search query is required')
+      .max(200, 'Search query is too long'),
+    limit: z.number().min(1).max(50).optional().default(10),
+    offset: z.number().int().min(0).optional().default(0),
+    cursor: z.string().optional(),
+    sort: z.enum(COMMUNICATION_SEARCH_SORTS).optional().default('relevance'),
+    /** Include communications in the trash */
+    includeDeleted: z.boolean().optional().default(false),
+  });
+
+export type SearchCommunicationsInput = z.infer<typeof searchCommunicationsSchema>;
+
+// =============================================================================
+// Saved View Schemas
+// =============================================================================
+
+/**
+ * Schema for saving a named filter set
+ *
+ * Shared views can be listed and opened from a link by every admin;
+ * private views only by the admin who saved them.
+ */
+export const saveCommunicationViewSchema = z.object({
+  name: z
     .string()
-    .min(1, 'Search query is required')
-    .max(200, 'Search query is too long'),
-  limit: z.number().min(1).max(50).optional().default(10),
+    .trim()
+    .min(1, 'View name is required')
+    .max(100, 'View name must be 100 characters or less'),
+  filters: communicationFiltersSchema,
+  isShared: z.boolean().optional().default(true),
 });
 
-export type SearchCommunicationsInput = z.infer<typeof searchCommunicationsSchema>;
+export type SaveCommunicationViewInput = z.infer<typeof saveCommunicationViewSchema>;
+
+/**
+ * Schema for renaming, re-sharing or replacing a saved view's filters
+ */
+export const updateCommunicationViewSchema = saveCommunicationViewSchema
+  .partial()
+  .extend({ id: z.string().uuid('Invalid view ID') });
+
+export type UpdateCommunicationViewInput = z.infer<typeof updateCommunicationViewSchema>;
+
+// =============================================================================
+// Communication Stats Schema
+// =============================================================================
+
+/**
+ * Schema for a customer's communication stats, optionally limited to a
+ * date range
+ */
+export const communicationStatsSchema = z
+  .object({
+    customerId: z.string().uuid('Invalid customer ID'),
+    from: z.string().datetime().optional(),
+    to: z.string().datetime().optional(),
+    /** Count communications in the trash */
+    includeDeleted: z.boolean().optional().default(false),
+  })
+  .refine((data) => !data.from || !data.to || new Date(data.to) > new Date(data.from), {
+    message: 'End date must be after start date',
+    path: ['to'],
+  });
+
+export type CommunicationStatsInput = z.infer<typeof communicationStatsSchema>;
+
+// =============================================================================
+// Thread Status Schema
+// =============================================================================
+
+/**
+ * Schema for manually marking a thread open or answered
+ * (e.g. a "thanks!" text that needs no reply)
+ */
+export const updateThreadStatusSchema = z.object({
+  threadId: z.string().uuid('Invalid thread ID'),
+  status: z.enum(COMMUNICATION_THREAD_STATUSES, {
+    errorMap: () => ({ message: 'Please select a thread status' }),
+  }),
+});
+
+export type UpdateThreadStatusInput = z.infer<typeof updateThreadStatusSchema>;
+
+// =============================================================================
+// Needs Response Queue Schemas
+// =============================================================================
+
+/**
+ * Business hours an inbound communication may wait for a reply before it is
+ * overdue; overridden by RESPONSE_SLA_BUSINESS_HOURS
+ */
+export const DEFAULT_RESPONSE_SLA_HOURS = 4;
+
+/**
+ * Schema for loading the needs-response queue
+ */
+export const needsResponseQueueSchema = z.object({
+  /** Only customers past the SLA */
+  overdueOnly: z.boolean().optional().default(false),
+  /** Override the configured SLA, in business hours */
+  slaHours: z.number().positive().max(200).optional(),
+});
+
+export type NeedsResponseQueueInput = z.infer<typeof needsResponseQueueSchema>;
+
+/**
+ * Schema for hiding a customer's waiting communications until later
+ */
+export const snoozeNeedsResponseSchema = z.object({
+  customerId: z.string().uuid('Invalid customer ID'),
+  until: z
+    .string()
+    .datetime({ message: 'Please choose when to bring this back' })
+    .refine((val) => new Date(val).getTime() > Date.now(), {
+      message: 'Snooze time must be in the future',
+    }),
+});
+
+export type SnoozeNeedsResponseInput = z.infer<typeof snoozeNeedsResponseSchema>;
+
+/**
+ * Schema for removing a customer from the queue without replying
+ * (e.g. a "thanks!" text)
+ */
+export const dismissNeedsResponseSchema = z.object({
+  customerId: z.string().uuid('Invalid customer ID'),
+});
+
+export type DismissNeedsResponseInput = z.infer<typeof dismissNeedsResponseSchema>;
+
+// =============================================================================
+// Call Duration Helpers
+// =============================================================================
+
+/**
+ * Parse a call duration typed by staff into seconds
+ *
+ * Accepts "m:ss", "h:mm:ss" or a plain number of minutes. Seconds, and
+ * minutes after an hour, must be two digits from 00 to 59.
+ *
+ * @param value - Raw input
+ * @returns Duration in seconds, or null if empty/invalid
+ */
+export function parseCallDuration(value: string | undefined | null): number | null {
+  const trimmed = value?.trim();
+  if (!trimmed) return null;
+
+  if (trimmed.includes(':')) {
+    const match = /^(?:(\d+):([0-5]\d)|(\d+)):([0-5]\d)$/.exec(trimmed);
+    if (!match) return null;
+    const [, hours, hourMinutes, minutes, secs] = match;
+    const seconds =
+      hours !== undefined
+        ? Number(hours) * 3600 + Number(hourMinutes) * 60 + Number(secs)
+        : Number(minutes) * 60 + Number(secs);
+    return seconds <= MAX_CALL_DURATION_SECONDS ? seconds : null;
+  }
+
+  const minutes = Number(trimmed);
+  if (!Number.isFinite(minutes) || minutes < 0) return null;
+  const seconds = Math.round(minutes * 60);
+  return seconds <= MAX_CALL_DURATION_SECONDS ? seconds : null;
+}
+
+/**
+ * Format seconds as "m:ss" for a duration input
+ *
+ * @param seconds - Duration in seconds
+ * @returns Input value like "4:05", or empty string when null
+ */
+export function formatCallDurationInput(seconds: number | null | undefined): string {
+  if (seconds == null) return '';
+  const minutes = Math.floor(seconds / 60);
+  const remainder = seconds % 60;
+  return `${minutes}:${String(remainder).padStart(2, '0')}`;
+}
 
 // =============================================================================
 // Communication ID Schema
@@ -0,0 +1,58 @@
+/**
+ * Export Validation Schemas
+ *
+ * @file src/lib/validations/export.ts
+ *
+ * Zod schemas for communication history exports.
+ *
+ * Validation rules:
+ * - format: csv, jsonl or pdf
+ * - timezone: IANA timezone for timestamps; defaults to DEFAULT_TIMEZONE
+ * - customerId: Optional, limits the export to one customer
+ * - Any CommunicationFilters narrow the export further
+ */
+
+import { z } from 'zod';
+import { DEFAULT_TIMEZONE, isValidTimezone } from '@/lib/utils/timezone';
+import { communicationFiltersSchema } from './communication';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+export const COMMUNICATION_EXPORT_FORMATS = ['csv', 'jsonl', 'pdf'] as const;
+
+export type CommunicationExportFormat = (typeof COMMUNICATION_EXPORT_FORMATS)[number];
+
+/**
+ * Most communications in one export; larger exports must be narrowed
+ */
+export const MAX_EXPORT_COMMUNICATIONS = 10000;
+
+/**
+ * Format options for UI selects
+ */
+export const communicationExportFormatOptions = [
+  { value: 'pdf', label: 'PDF transcript' },
+  { value: 'csv', label: 'CSV spreadsheet' },
+  { value: 'jsonl', label: 'JSON Lines' },
+] as const;
+
+// =============================================================================
+// Export Schema
+// =============================================================================
+
+/**
+ * Schema for a communication export
+ */
+export const communicationExportSchema = communicationFiltersSchema.extend({
+  customerId: z.string().uuid('Invalid customer ID').optional(),
+  format: z.enum(COMMUNICATION_EXPORT_FORMATS).optional().default('csv'),
+  timezone: z
+    .string()
+    .refine(isValidTimezone, 'Unknown timezone')
+    .optional()
+    .default(DEFAULT_TIMEZONE),
+});
+
+export type CommunicationExportInput = z.infer<typeof communicationExportSchema>;
@@ -0,0 +1,142 @@
+/**
+ * Import Validation Schemas
+ *
+ * @file src/lib/validations/import.ts
+ *
+ * Zod schemas and limits for importing communications from external
+ * sources and for working the import review queue.
+ *
+ * Validation rules:
+ * - Up to 20 files per import, 3 MB together (the server action body
+ *   limit, see ./upload)
+ * - Email imports accept .eml and .mbox files
+ * - SMS imports accept .csv and .json exports
+ * - Call-detail record imports accept .csv exports
+ * - Resolving a queue item requires a customer to assign it to
+ */
+
+import { z } from 'zod';
+import { COMMUNICATION_DIRECTIONS } from './communication';
+import { MAX_UPLOAD_TOTAL_SIZE, formatMegabytes } from './upload';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/**
+ * Maximum files accepted in a single import
+ */
+export const MAX_IMPORT_FILES = 20;
+
+/**
+ * Maximum combined size of the files in one import in bytes; the whole
+ * upload has to fit in a single server action request
+ */
+export const MAX_IMPORT_TOTAL_SIZE = MAX_UPLOAD_TOTAL_SIZE;
+
+/**
+ * Maximum size of a single import file in bytes; a file can use the
+ * whole upload
+ */
+export const MAX_IMPORT_FILE_SIZE = MAX_IMPORT_TOTAL_SIZE;
+
+/**
+ * File extensions accepted by the email importer
+ */
+export const EMAIL_IMPORT_EXTENSIONS = ['.eml', '.mbox'] as const;
+
+/**
+ * File extensions accepted by the SMS importer
+ */
+export const SMS_IMPORT_EXTENSIONS = ['.csv', '.json'] as const;
+
+/**
+ * File extensions accepted by the call-detail record importer
+ */
+export const CDR_IMPORT_EXTENSIONS = ['.csv'] as const;
+
+/**
+ * Valid import queue statuses
+ */
+export const IMPORT_QUEUE_STATUSES = ['pending', 'resolved', 'dismissed'] as const;
+export type ImportQueueStatus = (typeof IMPORT_QUEUE_STATUSES)[number];
+
+// =============================================================================
+// Queue Schemas
+// =============================================================================
+
+/**
+ * Schema for listing the import review queue
+ */
+export const listImportQueueSchema = z.object({
+  status: z.enum(IMPORT_QUEUE_STATUSES).optional().default('pending'),
+  limit: z.number().min(1).max(100).optional().default(25),
+  offset: z.number().min(0).optional().default(0),
+});
+
+export type ListImportQueueInput = z.infer<typeof listImportQueueSchema>;
+
+/**
+ * Schema for assigning a queued message to a customer
+ */
+export const resolveImportQueueItemSchema = z.object({
+  id: z.string().uuid('Invalid queue item ID'),
+  customerId: z.string().uuid('Please select a customer'),
+  /** Required when the importer could not tell the direction */
+  direction: z.enum(COMMUNICATION_DIRECTIONS).optional(),
+});
+
+export type ResolveImportQueueItemInput = z.infer<typeof resolveImportQueueItemSchema>;
+
+/**
+ * Schema for operations requiring just a queue item ID
+ */
+export const importQueueItemIdSchema = z.object({
+  id: z.string().uuid('Invalid queue item ID'),
+});
+
+export type ImportQueueItemIdInput = z.infer<typeof importQueueItemIdSchema>;
+
+// =============================================================================
+// File Helpers
+// =============================================================================
+
+/**
+ * Validates uploaded files against count, size and extension limits
+ *
+ * @param files - Uploaded files
+ * @param extensions - Accepted lowercase extensions (with leading dot)
+ * @returns Error message, or null if all files are acceptable
+ */
+export function validateImportFiles(
+  files: File[],
+  extensions: readonly string[]
+): string | null {
+  if (files.length === 0) {
+    return 'Please choose at least one file';
+  }
+
+  if (files.length > MAX_IMPORT_FILES) {
+    return `You can import up to ${MAX_IMPORT_FILES} files at a time`;
+  }
+
+  for (const file of files) {
+    const name = file.name.toLowerCase();
+    if (!extensions.some((ext) => name.endsWith(ext))) {
+      return `${file.name} is not a supported file type (${extensions.join(', ')})`;
+    }
+    if (file.size > MAX_IMPORT_FILE_SIZE) {
+      return `${file.name} is larger than ${formatMegabytes(MAX_IMPORT_FILE_SIZE)}`;
+    }
+  }
+
+  const totalSize = files.reduce((sum, file) => sum + file.size, 0);
+  if (totalSize > MAX_IMPORT_TOTAL_SIZE) {
+    return (
+      `Files can be up to ${formatMegabytes(MAX_IMPORT_TOTAL_SIZE)} together; ` +
+      'import them in smaller batches'
+    );
+  }
+
+  return null;
+}
@@ -0,0 +1,86 @@
+/**
+ * Notification Validation Schemas
+ *
+ * @file src/lib/validations/notification.ts
+ *
+ * Zod schemas for sending email and text messages to customers.
+ *
+ * Validation rules:
+ * - channel: Required, 'email' or 'sms'
+ * - subject: Required for email, 1-200 characters, a single line (it is
+ *   written as a mail header)
+ * - body: Required, 1-4800 characters for email (the logged summary adds the
+ *   subject line and must fit in 5000), 1-1600 for SMS (10 segments)
+ * - parentId: Optional, communication being replied to
+ */
+
+import { z } from 'zod';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/**
+ * Valid notification channels
+ */
+export const NOTIFICATION_CHANNELS = ['email', 'sms'] as const;
+export type NotificationChannel = (typeof NOTIFICATION_CHANNELS)[number];
+
+export const MAX_EMAIL_BODY_LENGTH = 4800;
+export const MAX_SMS_BODY_LENGTH = 1600;
+
+/**
+ * Values written into mail headers must not contain CR or LF
+ */
+export const SINGLE_LINE_PATTERN = /^[^\r\n]*$/;
+
+// =============================================================================
+// Send Message Schema
+// =============================================================================
+
+const baseSendSchema = z.object({
+  customerId: z.string().uuid('Invalid customer ID'),
+  parentId: z.string().uuid('Invalid parent communication ID').optional(),
+});
+
+/**
+ * Schema for sending an email to a customer
+ */
+export const sendEmailSchema = baseSendSchema.extend({
+  channel: z.literal('email'),
+  subject: z
+    .string()
+    .min(1, 'Subject is required')
+    .max(200, 'Subject must be 200 characters or less')
+    .regex(SINGLE_LINE_PATTERN, 'Subject must be a single line')
+    .transform((val) => val.trim()),
+  body: z
+    .string()
+    .min(1, 'Message is required')
+    .max(MAX_EMAIL_BODY_LENGTH, `Message must be ${MAX_EMAIL_BODY_LENGTH} characters or less`)
+    .transform((val) => val.trim()),
+});
+
+/**
+ * Schema for sending a text message to a customer
+ */
+export const sendSmsSchema = baseSendSchema.extend({
+  channel: z.literal('sms'),
+  body: z
+    .string()
+    .min(1, 'Message is required')
+    .max(MAX_SMS_BODY_LENGTH, `Message must be ${MAX_SMS_BODY_LENGTH} characters or less`)
+    .transform((val) => val.trim()),
+});
+
+/**
+ * Schema for sending a message on either channel
+ */
+export const sendMessageSchema = z.discriminatedUnion('channel', [
+  sendEmailSchema,
+  sendSmsSchema,
+]);
+
+export type SendEmailInput = z.infer<typeof sendEmailSchema>;
+export type SendSmsInput = z.infer<typeof sendSmsSchema>;
+export type SendMessageInput = z.infer<typeof sendMessageSchema>;
@@ -0,0 +1,53 @@
+/**
+ * Search Validation Schemas
+ *
+ * @file src/lib/validations/search.ts
+ *
+ * Zod schemas for the global (header) search.
+ *
+ * Validation rules:
+ * - query: 2-200 characters; phone digits, estimate numbers (EST-0042),
+ *   addresses and free text are all accepted
+ * - limit: Results per entity type, 1-20 (default 5)
+ * - types: Optional subset of entity types to return
+ */
+
+import { z } from 'zod';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/**
+ * Entity types returned by global search, in display order
+ */
+export const SEARCH_ENTITY_TYPES = [
+  'customer',
+  'communication',
+  'note',
+  'estimate',
+  'property',
+] as const;
+
+export type SearchEntityType = (typeof SEARCH_ENTITY_TYPES)[number];
+
+/**
+ * Default results per entity type
+ */
+export const DEFAULT_SEARCH_LIMIT = 5;
+
+// =============================================================================
+// Global Search Schema
+// =============================================================================
+
+export const globalSearchSchema = z.object({
+  query: z
+    .string()
+    .trim()
+    .min(2, 'Search query must be at least 2 characters')
+    .max(200, 'Search query is too long'),
+  limit: z.number().int().min(1).max(20).optional().default(DEFAULT_SEARCH_LIMIT),
+  types: z.array(z.enum(SEARCH_ENTITY_TYPES)).optional(),
+});
+
+export type GlobalSearchInput = z.infer<typeof globalSearchSchema>;
@@ -0,0 +1,141 @@
+/**
+ * Message Template Validation Schemas
+ *
+ * @file src/lib/validations/template.ts
+ *
+ * Zod schemas for message template CRUD, previews and sending.
+ *
+ * Validation rules:
+ * - name: Required, 1-100 characters
+ * - channel: Required, 'email' or 'sms'
+ * - subject: Required for email, 1-200 characters on a single line; ignored
+ *   for SMS
+ * - body: Required, up to 4800 characters for email, 1600 for SMS
+ * - subject and body may only use known merge fields
+ */
+
+import { z } from 'zod';
+import { findUnknownVariables } from '@/lib/notifications/templates';
+import {
+  NOTIFICATION_CHANNELS,
+  MAX_EMAIL_BODY_LENGTH,
+  MAX_SMS_BODY_LENGTH,
+  SINGLE_LINE_PATTERN,
+} from './notification';
+
+// =============================================================================
+// Template Schemas
+// =============================================================================
+
+const templateFieldsSchema = z.object({
+  name: z
+    .string()
+    .min(1, 'Name is required')
+    .max(100, 'Name must be 100 characters or less')
+    .transform((val) => val.trim()),
+  channel: z.enum(NOTIFICATION_CHANNELS),
+  subject: z
+    .string()
+    .max(200, 'Subject must be 200 characters or less')
+    .regex(SINGLE_LINE_PATTERN, 'Subject must be a single line')
+    .transform((val) => val.trim())
+    .optional()
+    .nullable(),
+  body: z
+    .string()
+    .min(1, 'Message is required')
+    .transform((val) => val.trim()),
+});
+
+/**
+ * Channel-specific rules shared by create and update
+ */
+function refineTemplate(
+  data: z.infer<typeof templateFieldsSchema>,
+  ctx: z.RefinementCtx
+) {
+  if (data.channel === 'email' && !data.subject) {
+    ctx.addIssue({ code: 'custom', path: ['subject'], message: 'Subject is required' });
+  }
+
+  const max = data.channel === 'email' ? MAX_EMAIL_BODY_LENGTH : MAX_SMS_BODY_LENGTH;
+  if (data.body.length > max) {
+    ctx.addIssue({
+      code: 'custom',
+      path: ['body'],
+      message: `Message must be ${max} characters or less`,
+    });
+  }
+
+  for (const field of ['subject', 'body'] as const) {
+    const unknown = findUnknownVariables(data[field] ?? '');
+    if (unknown.length > 0) {
+      ctx.addIssue({
+        code: 'custom',
+        path: [field],
+        message: `Unknown variables: ${unknown.map((name) => `{{${name}}}`).join(', ')}`,
+      });
+    }
+  }
+}
+
+/**
+ * Schema for creating a template
+ */
+export const createTemplateSchema = templateFieldsSchema
+  .superRefine(refineTemplate)
+  .transform((data) => ({
+    ...data,
+    subject: data.channel === 'email' ? data.subject ?? null : null,
+  }));
+
+/**
+ * Schema for updating a template (full replacement of editable fields)
+ */
+export const updateTemplateSchema = templateFieldsSchema
+  .extend({ id: z.string().uuid('Invalid template ID') })
+  .superRefine(refineTemplate)
+  .transform((data) => ({
+    ...data,
+    subject: data.channel === 'email' ? data.subject ?? null : null,
+  }));
+
+/**
+ * Schema for template ID parameter
+ */
+export const templateIdSchema = z.string().uuid('Invalid template ID');
+
+// =============================================================================
+// Render Schemas
+// =============================================================================
+
+/**
+ * Records to fill merge fields from. When omitted, the customer's first
+ * property, next scheduled appointment and most recent estimate are used.
+ */
+const templateContextSchema = z.object({
+  customerId: z.string().uuid('Invalid customer ID'),
+  propertyId: z.string().uuid('Invalid property ID').optional(),
+  eventId: z.string().uuid('Invalid event ID').optional(),
+  estimateId: z.string().uuid('Invalid estimate ID').optional(),
+});
+
+/**
+ * Schema for previewing a template against a customer
+ */
+export const previewTemplateSchema = templateContextSchema.extend({
+  templateId: templateIdSchema,
+});
+
+/**
+ * Schema for sending a message from a template
+ */
+export const sendTemplateSchema = previewTemplateSchema.extend({
+  parentId: z.string().uuid('Invalid parent communication ID').optional(),
+});
+
+export type CreateTemplateInput = z.infer<typeof createTemplateSchema>;
+export type UpdateTemplateInput = z.infer<typeof updateTemplateSchema>;
+export type TemplateContextInput = z.infer<typeof templateContextSchema>;
+export type PreviewTemplateInput = z.infer<typeof previewTemplateSchema>;
+export type SendTemplateInput = z.infer<typeof sendTemplateSchema>;
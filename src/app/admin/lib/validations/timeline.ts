@@ -0,0 +1,57 @@
+/**
+ * Timeline Validation Schemas
+ *
+ * @file src/lib/validations/timeline.ts
+ *
+ * Zod schemas for the customer activity timeline.
+ *
+ * Validation rules:
+ * - Filters: the communication filters (see communicationFiltersSchema)
+ * - kinds: Optional subset of the timeline item kinds
+ * - limit: 1-100, defaults to 25
+ */
+
+import { z } from 'zod';
+import { communicationFiltersSchema } from './communication';
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/**
+ * Entities shown on the timeline
+ */
+export const TIMELINE_ITEM_KINDS = [
+  'communication',
+  'note',
+  'estimate',
+  'event',
+  'attachment',
+] as const;
+
+/**
+ * Item kind options for UI selects
+ */
+export const timelineKindOptions = [
+  { value: 'communication', label: 'Communications' },
+  { value: 'note', label: 'Notes' },
+  { value: 'estimate', label: 'Estimates' },
+  { value: 'event', label: 'Events' },
+  { value: 'attachment', label: 'Attachments' },
+] as const;
+
+// =============================================================================
+// Customer Timeline Schema
+// =============================================================================
+
+/**
+ * Schema for a page of a customer's timeline
+ */
+export const customerTimelineSchema = communicationFiltersSchema.extend({
+  customerId: z.string().uuid('Invalid customer ID'),
+  kinds: z.array(z.enum(TIMELINE_ITEM_KINDS)).max(TIMELINE_ITEM_KINDS.length).optional(),
+  limit: z.number().min(1).max(100).optional().default(25),
+  cursor: z.string().nullable().optional(),
+});
+
+export type CustomerTimelineInput = z.infer<typeof customerTimelineSchema>;
@@ -0,0 +1,36 @@
+/**
+ * Upload Limits
+ *
+ * @file src/lib/validations/upload.ts
+ *
+ * Request size limit for server actions, shared by next.config.ts and the
+ * file validators. Uploads are sent as one multipart request, so the files
+ * in a single upload must fit under it together. Vercel rejects function
+ * request bodies over 4.5 MB before the action runs, so the limit stays
+ * below that.
+ *
+ * Kept free of imports so next.config.ts can load it.
+ */
+
+/**
+ * Server action body size limit in megabytes
+ */
+export const SERVER_ACTION_BODY_LIMIT_MB = 4;
+
+/**
+ * Value for experimental.serverActions.bodySizeLimit
+ */
+export const SERVER_ACTION_BODY_SIZE_LIMIT = `${SERVER_ACTION_BODY_LIMIT_MB}mb` as const;
+
+/**
+ * Maximum combined size of the files in one upload in bytes, leaving
+ * 1 MB of the request for multipart framing and form fields
+ */
+export const MAX_UPLOAD_TOTAL_SIZE = (SERVER_ACTION_BODY_LIMIT_MB - 1) * 1024 * 1024;
+
+/**
+ * Format a byte count as whole megabytes for messages
+ */
+export function formatMegabytes(bytes: number): string {
+  return `${Math.floor(bytes / (1024 * 1024))} MB`;
+}
@@ -0,0 +1,62 @@
+/**
+ * Communication Analytics API
+ *
+ * @file src/app/api/analytics/communications/route.ts
+ *
+ * GET /api/analytics/communications?from=<iso>&to=<iso>&adminId=<uuid>
+ *
+ * Response times, volume by type and direction, and busiest hours,
+ * team-wide and per admin. The range defaults to the last 30 days.
+ * Requires a signed-in admin session.
+ */
+
+import { NextResponse, type NextRequest } from 'next/server';
+import { createClient } from '@/lib/supabase/server';
+import { AnalyticsService } from '@/lib/services/analytics.service';
+import { communicationAnalyticsSchema } from '@/lib/validations/analytics';
+
+export async function GET(request: NextRequest) {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+  } = await supabase.auth.getUser();
+
+  if (!user) {
+    return NextResponse.json({ error: 'Unauthorized' }, { status: 401 });
+  }
+
+  const { data: admin } = await supabase
+    .from('admins')
+    .select('id')
+    .eq('id', user.id)
+    .single();
+
+  if (!admin) {
+    return NextResponse.json({ error: 'Forbidden' }, { status: 403 });
+  }
+
+  const params = request.nextUrl.searchParams;
+  const parsed = communicationAnalyticsSchema.safeParse({
+    from: params.get('from') ?? undefined,
+    to: params.get('to') ?? undefined,
+    adminId: params.get('adminId') ?? undefined,
+  });
+
+  if (!parsed.success) {
+    return NextResponse.json(
+      { error: parsed.error.errors[0]?.message ?? 'Invalid query' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const service = new AnalyticsService(supabase);
+    const analytics = await service.getCommunicationAnalytics(parsed.data);
+
+    return NextResponse.json(analytics);
+  } catch (error) {
+    console.error('Failed to get communication analytics:', error);
+    return NextResponse.json({ error: 'Internal error' }, { status: 500 });
+  }
+}
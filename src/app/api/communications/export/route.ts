@@ -0,0 +1,100 @@
+/**
+ * Communication Export API
+ *
+ * @file src/app/api/communications/export/route.ts
+ *
+ * GET /api/communications/export?format=csv|jsonl|pdf&timezone=<iana>
+ *     &customerId=<uuid>&view=<saved view id>&filters=<json>
+ *
+ * Downloads communication history as CSV, JSON Lines or a PDF transcript.
+ * Without customerId the export spans all customers. Filters come from a
+ * saved view, a JSON-encoded CommunicationFilters object, or both (the
+ * JSON filters win). Requires a signed-in admin session.
+ */
+
+import { NextResponse, type NextRequest } from 'next/server';
+import { z } from 'zod';
+import { createClient } from '@/lib/supabase/server';
+import { CommunicationExportService } from '@/lib/services/communication-export.service';
+import { CommunicationViewService } from '@/lib/services/communication-view.service';
+import { getErrorMessage, getErrorStatusCode, isAppError } from '@/lib/utils/errors';
+import { communicationExportSchema } from '@/lib/validations/export';
+
+export async function GET(request: NextRequest) {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+  } = await supabase.auth.getUser();
+
+  if (!user) {
+    return NextResponse.json({ error: 'Unauthorized' }, { status: 401 });
+  }
+
+  const { data: admin } = await supabase
+    .from('admins')
+    .select('id')
+    .eq('id', user.id)
+    .single();
+
+  if (!admin) {
+    return NextResponse.json({ error: 'Forbidden' }, { status: 403 });
+  }
+
+  const params = request.nextUrl.searchParams;
+  const viewId = params.get('view');
+
+  if (viewId && !z.string().uuid().safeParse(viewId).success) {
+    return NextResponse.json({ error: 'Invalid view ID' }, { status: 400 });
+  }
+
+  let filters: unknown = {};
+  try {
+    filters = JSON.parse(params.get('filters') ?? '{}');
+  } catch {
+    return NextResponse.json({ error: 'Invalid filters' }, { status: 400 });
+  }
+
+  try {
+    const view = viewId
+      ? await new CommunicationViewService(supabase).getById(viewId, admin.id)
+      : null;
+
+    const parsed = communicationExportSchema.safeParse({
+      ...view?.filters,
+      ...(typeof filters === 'object' && filters !== null ? filters : {}),
+      format: params.get('format') ?? undefined,
+      timezone: params.get('timezone') ?? undefined,
+      customerId: params.get('customerId') ?? undefined,
+    });
+
+    if (!parsed.success) {
+      return NextResponse.json(
+        { error: parsed.error.errors[0]?.message ?? 'Invalid query' },
+        { status: 400 }
+      );
+    }
+
+    const service = new CommunicationExportService(supabase);
+    const file = await service.export(parsed.data);
+
+    return new NextResponse(file.body, {
+      headers: {
+        'Content-Type': file.contentType,
+        'Content-Disposition': `attachment; filename="${file.filename}"`,
+        'Cache-Control': 'no-store',
+        'X-Export-Count': String(file.count),
+      },
+    });
+  } catch (error) {
+    if (isAppError(error)) {
+      return NextResponse.json(
+        { error: getErrorMessage(error) },
+        { status: getErrorStatusCode(error) }
+      );
+    }
+
+    console.error('Failed to export communications:', error);
+    return NextResponse.json({ error: 'Internal error' }, { status: 500 });
+  }
+}
@@ -0,0 +1,48 @@
+/**
+ * Communication Trash Purge
+ *
+ * @file src/app/api/cron/purge-communications/route.ts
+ *
+ * Permanently deletes communications (and their attachments) that have been
+ * in the trash longer than COMMUNICATION_TRASH_RETENTION_DAYS (default 30).
+ * Scheduled daily in vercel.json; any scheduler can call it.
+ *
+ * Authenticated with CRON_SECRET, sent as `Authorization: Bearer <secret>`.
+ */
+
+import { NextResponse, type NextRequest } from 'next/server';
+import { timingSafeEqual } from 'crypto';
+import { createAdminClient } from '@/lib/supabase/server';
+import {
+  COMMUNICATION_TRASH_RETENTION_DAYS,
+  CommunicationService,
+} from '@/lib/services/communication.service';
+
+export async function GET(request: NextRequest) {
+  const secret = process.env.CRON_SECRET;
+  const provided = request.headers.get('authorization')?.replace(/^Bearer\s+/i, '') ?? '';
+
+  if (!secret || !safeEqual(provided, secret)) {
+    return NextResponse.json({ error: 'Unauthorized' }, { status: 401 });
+  }
+
+  try {
+    const service = new CommunicationService(createAdminClient());
+    const purged = await service.purgeTrash(COMMUNICATION_TRASH_RETENTION_DAYS);
+
+    return NextResponse.json({ purged, retentionDays: COMMUNICATION_TRASH_RETENTION_DAYS });
+  } catch (error) {
+    console.error('Failed to purge communication trash:', error);
+    return NextResponse.json({ error: 'Internal error' }, { status: 500 });
+  }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+function safeEqual(a: string, b: string): boolean {
+  const left = Buffer.from(a);
+  const right = Buffer.from(b);
+  return left.length === right.length && timingSafeEqual(left, right);
+}
@@ -0,0 +1,65 @@
+/**
+ * Global Search API
+ *
+ * @file src/app/api/search/route.ts
+ *
+ * GET /api/search?q=<query>&limit=<per type>&types=customer,estimate
+ *
+ * Customers, communications, notes, estimates and properties matching a
+ * query, grouped by type with a score per result. Accepts phone digits,
+ * estimate numbers (EST-0042), addresses and free text.
+ * Requires a signed-in admin session.
+ */
+
+import { NextResponse, type NextRequest } from 'next/server';
+import { createClient } from '@/lib/supabase/server';
+import { SearchService } from '@/lib/services/search.service';
+import { globalSearchSchema } from '@/lib/validations/search';
+
+export async function GET(request: NextRequest) {
+  const supabase = await createClient();
+
+  const {
+    data: { user },
+  } = await supabase.auth.getUser();
+
+  if (!user) {
+    return NextResponse.json({ error: 'Unauthorized' }, { status: 401 });
+  }
+
+  const { data: admin } = await supabase
+    .from('admins')
+    .select('id')
+    .eq('id', user.id)
+    .single();
+
+  if (!admin) {
+    return NextResponse.json({ error: 'Forbidden' }, { status: 403 });
+  }
+
+  const params = request.nextUrl.searchParams;
+  const limit = params.get('limit');
+  const types = params.get('types');
+  const parsed = globalSearchSchema.safeParse({
+    query: params.get('q') ?? '',
+    limit: limit ? Number(limit) : undefined,
+    types: types ? types.split(',').filter(Boolean) : undefined,
+  });
+
+  if (!parsed.success) {
+    return NextResponse.json(
+      { error: parsed.error.errors[0]?.message ?? 'Invalid query' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const service = new SearchService(supabase);
+    const results = await service.globalSearch(parsed.data);
+
+    return NextResponse.json(results);
+  } catch (error) {
+    console.error('Failed to search:', error);
+    return NextResponse.json({ error: 'Internal error' }, { status: 500 });
+  }
+}
@@ -0,0 +1,90 @@
+/**
+ * SMS Delivery Status Webhook
+ *
+ * @file src/app/api/webhooks/sms-status/route.ts
+ *
+ * Receives delivery callbacks from the HTTP SMS gateway and updates the
+ * matching communication's delivery status. Accepts JSON or form-encoded
+ * bodies with a message ID (`id`, `sid`, `message_id`, `MessageSid`) and a
+ * status (`status`, `MessageStatus`).
+ *
+ * Authenticated with SMS_WEBHOOK_SECRET, sent either as the
+ * `x-webhook-secret` header or a `token` query parameter.
+ *
+ * Callbacks for an unknown message ID get a 404 so the gateway retries:
+ * a fast callback can arrive before the send has stored the ID.
+ */
+
+import { NextResponse, type NextRequest } from 'next/server';
+import { timingSafeEqual } from 'crypto';
+import { createAdminClient } from '@/lib/supabase/server';
+import { CommunicationService } from '@/lib/services/communication.service';
+import { mapSmsStatus } from '@/lib/notifications';
+
+export async function POST(request: NextRequest) {
+  const secret = process.env.SMS_WEBHOOK_SECRET;
+  const provided =
+    request.headers.get('x-webhook-secret') ?? request.nextUrl.searchParams.get('token') ?? '';
+
+  if (!secret || !safeEqual(provided, secret)) {
+    return NextResponse.json({ error: 'Unauthorized' }, { status: 401 });
+  }
+
+  const payload = await readPayload(request);
+  const id = payload.id ?? payload.sid ?? payload.message_id ?? payload.MessageSid;
+  const status = mapSmsStatus(payload.status ?? payload.MessageStatus);
+
+  if (!id || !status) {
+    return NextResponse.json({ error: 'Missing message id or status' }, { status: 400 });
+  }
+
+  try {
+    const service = new CommunicationService(createAdminClient());
+    const matched = await service.applyDeliveryCallback('http-sms', id, {
+      status,
+      error:
+        status === 'failed'
+          ? payload.error ?? payload.ErrorMessage ?? payload.ErrorCode ?? 'Delivery failed'
+          : null,
+    });
+
+    if (!matched) {
+      return NextResponse.json({ error: 'Unknown message id' }, { status: 404 });
+    }
+
+    return NextResponse.json({ received: true });
+  } catch (error) {
+    console.error('Failed to apply SMS status callback:', error);
+    return NextResponse.json({ error: 'Internal error' }, { status: 500 });
+  }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+async function readPayload(request: NextRequest): Promise<Record<string, string | undefined>> {
+  const contentType = request.headers.get('content-type') ?? '';
+
+  try {
+    if (contentType.includes('application/json')) {
+      const json = (await request.json()) as Record<string, unknown>;
+      return Object.fromEntries(
+        Object.entries(json).map(([key, value]) => [key, value == null ? undefined : String(value)])
+      );
+    }
+
+    const form = await request.formData();
+    return Object.fromEntries(
+      [...form.entries()].map(([key, value]) => [key, typeof value === 'string' ? value : undefined])
+    );
+  } catch {
+    return {};
+  }
+}
+
+function safeEqual(a: string, b: string): boolean {
+  const left = Buffer.from(a);
+  const right = Buffer.from(b);
+  return left.length === right.length && timingSafeEqual(left, right);
+}